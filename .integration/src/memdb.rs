@@ -14,8 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::NUM_COLS;
-use snarkvm_dpc::prelude::*;
+use snarkvm_dpc::{prelude::*, NUM_COLS};
 
 use parking_lot::RwLock;
 
@@ -14,10 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Ledger;
 use snarkvm_algorithms::traits::merkle_tree::LoadableMerkleParameters;
 use snarkvm_dpc::{
     block::Block,
+    ledger::Ledger,
     traits::{LedgerScheme, Storage, TransactionScheme},
     TransactionError,
 };
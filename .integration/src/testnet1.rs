@@ -14,9 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Ledger;
 use snarkvm_algorithms::{MerkleParameters, CRH};
-use snarkvm_dpc::{testnet1::instantiated::*, Account, DPCScheme, Storage};
+use snarkvm_dpc::{testnet1::instantiated::*, Account, DPCScheme, Ledger, Storage};
 use snarkvm_parameters::{LedgerMerkleTreeParameters, Parameter};
 use snarkvm_utilities::FromBytes;
 
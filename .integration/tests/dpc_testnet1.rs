@@ -20,6 +20,7 @@ use snarkvm_algorithms::{
 };
 use snarkvm_curves::bls12_377::{Fq, Fr};
 use snarkvm_dpc::{
+    ledger::Ledger,
     prelude::*,
     testnet1::{
         execute_inner_circuit,
@@ -34,7 +35,7 @@ use snarkvm_dpc::{
         TransactionKernel,
     },
 };
-use snarkvm_integration::{ledger::*, memdb::MemDb, storage::*, testnet1::*};
+use snarkvm_integration::{memdb::MemDb, storage::*, testnet1::*};
 use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
 use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
 
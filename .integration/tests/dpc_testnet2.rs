@@ -20,11 +20,12 @@ use snarkvm_algorithms::{
 };
 use snarkvm_curves::bls12_377::{Fq, Fr};
 use snarkvm_dpc::{
+    ledger::Ledger,
     prelude::*,
     testnet2::{
         execute_inner_circuit,
         execute_outer_circuit,
-        instantiated::*,
+        instantiated::{benchmark::*, *},
         program::NoopProgram,
         EncryptedRecord,
         InnerCircuit,
@@ -34,7 +35,7 @@ use snarkvm_dpc::{
         TransactionKernel,
     },
 };
-use snarkvm_integration::{ledger::*, memdb::MemDb, storage::*, testnet2::*};
+use snarkvm_integration::{memdb::MemDb, storage::*, testnet2::*};
 use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
 use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
 
@@ -74,217 +75,250 @@ fn test_testnet2_inner_circuit_sanity_check() {
     assert_eq!(expected_testnet2_inner_circuit_id, candidate_testnet2_inner_circuit_id);
 }
 
-// #[ignore]
-#[test]
-fn dpc_testnet2_integration_test() {
-    let mut rng = ChaChaRng::seed_from_u64(1231275789u64);
-
-    // // Generate or load parameters for the ledger, commitment schemes, and CRH.
-    // let (ledger_parameters, dpc) = setup_or_load_parameters::<_, MemDb>(false, &mut rng);
-
-    // NOTE: (alex) All setup procedures freshly generates public parameters instead of loading from files.
-    // Generate parameters for the ledger, commitment schemes, and CRH.
-    let crh_parameters = <snarkvm_dpc::testnet2::instantiated::MerkleTreeCRH as CRH>::setup(&mut rng);
-    let merkle_tree_hash_parameters = <CommitmentMerkleParameters as MerkleParameters>::H::from(crh_parameters);
-    let ledger_parameters = Arc::new(From::from(merkle_tree_hash_parameters));
-    // Setup DPC scheme (dominated by SNARK setup for inner, program and outer circuits)
-    let dpc = <Testnet2DPC as DPCScheme<MerkleTreeLedger<MemDb>>>::setup(&ledger_parameters, &mut rng)
-        .expect("DPC setup failed");
-
-    // Generate accounts.
-    let [genesis_account, recipient, _] = generate_test_accounts::<_, MemDb>(&dpc, &mut rng);
-
-    // Create a genesis block.
-    let genesis_block = Block {
-        header: BlockHeader {
-            previous_block_hash: BlockHeaderHash([0u8; 32]),
-            merkle_root_hash: MerkleRootHash([0u8; 32]),
-            pedersen_merkle_root_hash: PedersenMerkleRootHash([0u8; 32]),
-            time: 0,
-            difficulty_target: 0x07FF_FFFF_FFFF_FFFF_u64,
-            nonce: 0,
-            proof: ProofOfSuccinctWork([0u8; 972]),
-        },
-        transactions: Transactions::new(),
-    };
-
-    let ledger = initialize_test_blockchain::<Testnet2Transaction, CommitmentMerkleParameters, MemDb>(
-        ledger_parameters,
-        genesis_block,
-    );
-
-    // Generate dummy input records having as address the genesis address.
-    let old_private_keys = vec![genesis_account.private_key.clone(); Components::NUM_INPUT_RECORDS];
-
-    let mut joint_serial_numbers = vec![];
-    let mut old_records = vec![];
-    for i in 0..Components::NUM_INPUT_RECORDS {
-        let old_sn_nonce = <Components as DPCComponents>::SerialNumberNonceCRH::hash(
-            &dpc.system_parameters.serial_number_nonce,
-            &[64u8 + (i as u8); 1],
-        )
-        .unwrap();
-        let old_record = Record::new(
-            &dpc.system_parameters.record_commitment,
-            genesis_account.address.clone(),
-            true, // The input record is dummy
-            0,
-            Payload::default(),
-            dpc.noop_program.id(),
-            dpc.noop_program.id(),
-            old_sn_nonce,
-            &mut rng,
-        )
-        .unwrap();
-
-        let (sn, _) = old_record
-            .to_serial_number(&dpc.system_parameters.account_signature, &old_private_keys[i])
-            .unwrap();
-        joint_serial_numbers.extend_from_slice(&to_bytes_le![sn].unwrap());
-
-        old_records.push(old_record);
-    }
-
-    // Construct new records.
+/// Defines a test running a full transaction through the DPC for the given testnet2 components.
+macro_rules! dpc_testnet2_integration_test {
+    ($(#[$attr:meta])* $name:ident, $components:ty, $dpc:ty, $transaction:ty) => {
+        $(#[$attr])*
+        #[test]
+        fn $name() {
+            type L = Ledger<$transaction, CommitmentMerkleParameters, MemDb>;
+
+            let mut rng = ChaChaRng::seed_from_u64(1231275789u64);
+
+            // // Generate or load parameters for the ledger, commitment schemes, and CRH.
+            // let (ledger_parameters, dpc) = setup_or_load_parameters::<_, MemDb>(false, &mut rng);
+
+            // NOTE: (alex) All setup procedures freshly generates public parameters instead of loading from files.
+            // Generate parameters for the ledger, commitment schemes, and CRH.
+            let crh_parameters = <snarkvm_dpc::testnet2::instantiated::MerkleTreeCRH as CRH>::setup(&mut rng);
+            let merkle_tree_hash_parameters = <CommitmentMerkleParameters as MerkleParameters>::H::from(crh_parameters);
+            let ledger_parameters = Arc::new(From::from(merkle_tree_hash_parameters));
+            // Setup DPC scheme (dominated by SNARK setup for inner, program and outer circuits)
+            let dpc = <$dpc as DPCScheme<L>>::setup(&ledger_parameters, &mut rng).expect("DPC setup failed");
+
+            // Generate accounts.
+            let genesis_account = <$dpc as DPCScheme<L>>::create_account(&dpc, &mut rng).unwrap();
+            let recipient = <$dpc as DPCScheme<L>>::create_account(&dpc, &mut rng).unwrap();
+
+            // Create a genesis block.
+            let genesis_block = Block {
+                header: BlockHeader {
+                    previous_block_hash: BlockHeaderHash([0u8; 32]),
+                    merkle_root_hash: MerkleRootHash([0u8; 32]),
+                    pedersen_merkle_root_hash: PedersenMerkleRootHash([0u8; 32]),
+                    time: 0,
+                    difficulty_target: 0x07FF_FFFF_FFFF_FFFF_u64,
+                    nonce: 0,
+                    proof: ProofOfSuccinctWork([0u8; 972]),
+                },
+                transactions: Transactions::new(),
+            };
+
+            let ledger = initialize_test_blockchain::<$transaction, CommitmentMerkleParameters, MemDb>(
+                ledger_parameters,
+                genesis_block,
+            );
+
+            // Generate dummy input records having as address the genesis address.
+            let old_private_keys = vec![genesis_account.private_key.clone(); <$components>::NUM_INPUT_RECORDS];
+
+            let mut joint_serial_numbers = vec![];
+            let mut old_records = vec![];
+            for i in 0..<$components>::NUM_INPUT_RECORDS {
+                let old_sn_nonce = <$components as DPCComponents>::SerialNumberNonceCRH::hash(
+                    &dpc.system_parameters.serial_number_nonce,
+                    &[64u8 + (i as u8); 1],
+                )
+                .unwrap();
+                let old_record = Record::new(
+                    &dpc.system_parameters.record_commitment,
+                    genesis_account.address.clone(),
+                    true, // The input record is dummy
+                    0,
+                    Payload::default(),
+                    dpc.noop_program.id(),
+                    dpc.noop_program.id(),
+                    old_sn_nonce,
+                    &mut rng,
+                )
+                .unwrap();
 
-    // Set the new records' program to be the "always-accept" program.
-    let mut new_records = vec![];
-    for j in 0..Components::NUM_OUTPUT_RECORDS {
-        new_records.push(
-            Record::new_full(
-                &dpc.system_parameters.serial_number_nonce,
-                &dpc.system_parameters.record_commitment,
-                recipient.address.clone(),
-                false,
-                10,
-                Payload::default(),
-                dpc.noop_program.id(),
-                dpc.noop_program.id(),
-                j as u8,
-                joint_serial_numbers.clone(),
+                let (sn, _) = old_record
+                    .to_serial_number(&dpc.system_parameters.account_signature, &old_private_keys[i])
+                    .unwrap();
+                joint_serial_numbers.extend_from_slice(&to_bytes_le![sn].unwrap());
+
+                old_records.push(old_record);
+            }
+
+            // Construct new records.
+
+            // Set the new records' program to be the "always-accept" program.
+            let mut new_records = vec![];
+            for j in 0..<$components>::NUM_OUTPUT_RECORDS {
+                new_records.push(
+                    Record::new_full(
+                        &dpc.system_parameters.serial_number_nonce,
+                        &dpc.system_parameters.record_commitment,
+                        recipient.address.clone(),
+                        false,
+                        10,
+                        Payload::default(),
+                        dpc.noop_program.id(),
+                        dpc.noop_program.id(),
+                        j as u8,
+                        joint_serial_numbers.clone(),
+                        &mut rng,
+                    )
+                    .unwrap(),
+                );
+            }
+
+            let execute = Instant::now();
+
+            // Offline execution to generate a DPC transaction kernel.
+            let memo = [4u8; 32];
+            let transaction_kernel = <$dpc as DPCScheme<L>>::execute_offline_phase(
+                &dpc,
+                &old_private_keys,
+                old_records,
+                new_records,
+                memo,
                 &mut rng,
             )
-            .unwrap(),
-        );
-    }
-
-    let execute = Instant::now();
-
-    // Offline execution to generate a DPC transaction kernel.
-    let memo = [4u8; 32];
-    let transaction_kernel = <Testnet2DPC as DPCScheme<L>>::execute_offline_phase(
-        &dpc,
-        &old_private_keys,
-        old_records,
-        new_records,
-        memo,
-        &mut rng,
-    )
-    .unwrap();
-
-    let program_gen = Instant::now();
-
-    // Generate the program proofs
-    let mut program_proofs = vec![];
-    for i in 0..Components::NUM_TOTAL_RECORDS {
-        program_proofs.push(
-            dpc.noop_program
-                .execute(&transaction_kernel.into_local_data(), i as u8, &mut rng)
-                .unwrap(),
-        );
-    }
-    println!(
-        "⏱️ All {} program proof gen takes: {} ms",
-        Components::NUM_TOTAL_RECORDS,
-        program_gen.elapsed().as_millis()
-    );
-
-    let (new_records, transaction) = dpc
-        .execute_online_phase(&old_private_keys, transaction_kernel, program_proofs, &ledger, &mut rng)
-        .unwrap();
-    println!("⏱️ DPC::Execute takes: {} ms", execute.elapsed().as_millis());
-    {
-        let inner_proof_bytes = to_bytes_le!(transaction.transaction_proof.0).unwrap();
-        let outer_proof_bytes = to_bytes_le!(transaction.transaction_proof.1).unwrap();
-        println!(
-            "ℹ️️ total proof size: {} + {} = {} bytes",
-            inner_proof_bytes.len(),
-            outer_proof_bytes.len(),
-            inner_proof_bytes.len() + outer_proof_bytes.len()
-        );
-    }
-    let mem = proc_status::mem_usage().unwrap();
-    println!(
-        "⚠️ After Execute, Mem usage: current={} KB, peak={} KB",
-        mem.current / 1024,
-        mem.peak / 1024
-    );
-
-    // Check that the transaction is serialized and deserialized correctly
-    let transaction_bytes = to_bytes_le![transaction].unwrap();
-    let recovered_transaction = Testnet2Transaction::read_le(&transaction_bytes[..]).unwrap();
-    assert_eq!(transaction, recovered_transaction);
-
-    // Check that new_records can be decrypted from the transaction
-    {
-        let encrypted_records = transaction.encrypted_records();
-        let new_account_private_keys = vec![recipient.private_key; Components::NUM_OUTPUT_RECORDS];
-
-        for ((encrypted_record, private_key), new_record) in
-            encrypted_records.iter().zip(new_account_private_keys).zip(new_records)
-        {
-            let account_view_key = ViewKey::from_private_key(
-                &dpc.system_parameters.account_signature,
-                &dpc.system_parameters.account_commitment,
-                &private_key,
-            )
             .unwrap();
 
-            let decrypted_record = encrypted_record
-                .decrypt(&dpc.system_parameters, &account_view_key)
+            let program_gen = Instant::now();
+
+            // Generate the program proofs
+            let mut program_proofs = vec![];
+            for i in 0..<$components>::NUM_TOTAL_RECORDS {
+                program_proofs.push(
+                    dpc.noop_program
+                        .execute(&transaction_kernel.into_local_data(), i as u8, &mut rng)
+                        .unwrap(),
+                );
+            }
+            println!(
+                "⏱️ All {} program proof gen takes: {} ms",
+                <$components>::NUM_TOTAL_RECORDS,
+                program_gen.elapsed().as_millis()
+            );
+
+            let (new_records, transaction) = dpc
+                .execute_online_phase(&old_private_keys, transaction_kernel, program_proofs, &ledger, &mut rng)
                 .unwrap();
-
-            assert_eq!(decrypted_record, new_record);
+            println!("⏱️ DPC::Execute takes: {} ms", execute.elapsed().as_millis());
+            {
+                let inner_proof_bytes = to_bytes_le!(transaction.transaction_proof.0).unwrap();
+                let outer_proof_bytes = to_bytes_le!(transaction.transaction_proof.1).unwrap();
+                println!(
+                    "ℹ️️ total proof size: {} + {} = {} bytes",
+                    inner_proof_bytes.len(),
+                    outer_proof_bytes.len(),
+                    inner_proof_bytes.len() + outer_proof_bytes.len()
+                );
+            }
+            let mem = proc_status::mem_usage().unwrap();
+            println!(
+                "⚠️ After Execute, Mem usage: current={} KB, peak={} KB",
+                mem.current / 1024,
+                mem.peak / 1024
+            );
+
+            // Check that the transaction is serialized and deserialized correctly
+            let transaction_bytes = to_bytes_le![transaction].unwrap();
+            let recovered_transaction = <$transaction>::read_le(&transaction_bytes[..]).unwrap();
+            assert_eq!(transaction, recovered_transaction);
+
+            // Check that new_records can be decrypted from the transaction
+            {
+                let encrypted_records = transaction.encrypted_records();
+                let new_account_private_keys = vec![recipient.private_key; <$components>::NUM_OUTPUT_RECORDS];
+
+                for ((encrypted_record, private_key), new_record) in
+                    encrypted_records.iter().zip(new_account_private_keys).zip(new_records)
+                {
+                    let account_view_key = ViewKey::from_private_key(
+                        &dpc.system_parameters.account_signature,
+                        &dpc.system_parameters.account_commitment,
+                        &private_key,
+                    )
+                    .unwrap();
+
+                    let decrypted_record = encrypted_record
+                        .decrypt(&dpc.system_parameters, &account_view_key)
+                        .unwrap();
+
+                    assert_eq!(decrypted_record, new_record);
+                }
+            }
+
+            // Craft the block
+
+            let previous_block = ledger.get_latest_block().unwrap();
+
+            let mut transactions = Transactions::new();
+            transactions.push(transaction);
+
+            let transaction_ids = transactions.to_transaction_ids().unwrap();
+
+            let mut merkle_root_bytes = [0u8; 32];
+            merkle_root_bytes[..].copy_from_slice(&merkle_root(&transaction_ids));
+
+            let time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_secs() as i64;
+
+            let header = BlockHeader {
+                previous_block_hash: previous_block.header.get_hash(),
+                merkle_root_hash: MerkleRootHash(merkle_root_bytes),
+                time,
+                difficulty_target: previous_block.header.difficulty_target,
+                nonce: 0,
+                pedersen_merkle_root_hash: PedersenMerkleRootHash([0u8; 32]),
+                proof: ProofOfSuccinctWork([0u8; 972]),
+            };
+
+            let now = Instant::now();
+            assert!(dpc.verify_transactions(&transactions.0, &ledger));
+            println!("⏱️ DPC::Verify takes: {} ms", now.elapsed().as_millis());
+
+            let block = Block { header, transactions };
+
+            ledger.insert_and_commit(&block).unwrap();
+            assert_eq!(ledger.len(), 2);
         }
-    }
-
-    // Craft the block
-
-    let previous_block = ledger.get_latest_block().unwrap();
-
-    let mut transactions = Transactions::new();
-    transactions.push(transaction);
-
-    let transaction_ids = transactions.to_transaction_ids().unwrap();
-
-    let mut merkle_root_bytes = [0u8; 32];
-    merkle_root_bytes[..].copy_from_slice(&merkle_root(&transaction_ids));
-
-    let time = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .expect("Time went backwards")
-        .as_secs() as i64;
-
-    let header = BlockHeader {
-        previous_block_hash: previous_block.header.get_hash(),
-        merkle_root_hash: MerkleRootHash(merkle_root_bytes),
-        time,
-        difficulty_target: previous_block.header.difficulty_target,
-        nonce: 0,
-        pedersen_merkle_root_hash: PedersenMerkleRootHash([0u8; 32]),
-        proof: ProofOfSuccinctWork([0u8; 972]),
     };
-
-    let now = Instant::now();
-    assert!(dpc.verify_transactions(&transactions.0, &ledger));
-    println!("⏱️ DPC::Verify takes: {} ms", now.elapsed().as_millis());
-
-    let block = Block { header, transactions };
-
-    ledger.insert_and_commit(&block).unwrap();
-    assert_eq!(ledger.len(), 2);
 }
 
+dpc_testnet2_integration_test!(dpc_testnet2_integration_test_2x2, Components, Testnet2DPC, Testnet2Transaction);
+
+dpc_testnet2_integration_test!(
+    #[ignore]
+    dpc_testnet2_integration_test_1x1,
+    Components1x1,
+    Testnet2DPC1x1,
+    Testnet2Transaction1x1
+);
+
+dpc_testnet2_integration_test!(
+    #[ignore]
+    dpc_testnet2_integration_test_4x4,
+    Components4x4,
+    Testnet2DPC4x4,
+    Testnet2Transaction4x4
+);
+
+dpc_testnet2_integration_test!(
+    #[ignore]
+    dpc_testnet2_integration_test_8x8,
+    Components8x8,
+    Testnet2DPC8x8,
+    Testnet2Transaction8x8
+);
+
 #[ignore]
 #[test]
 fn test_testnet_2_transaction_kernel_serialization() {
@@ -0,0 +1,361 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::traits::MerkleParameters;
+use snarkvm_dpc::{
+    testnet2::instantiated::CommitmentMerkleParameters,
+    Block,
+    BlockHeader,
+    BlockHeaderHash,
+    FileDb,
+    Ledger,
+    LedgerScheme,
+    MerkleRootHash,
+    PedersenMerkleRootHash,
+    ProofOfSuccinctWork,
+    Storage,
+    StorageError,
+    TransactionError,
+    TransactionScheme,
+    Transactions,
+};
+use snarkvm_integration::{memdb::MemDb, storage::random_storage_path};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use std::{
+    io::{Read, Result as IoResult, Write},
+    sync::Arc,
+};
+
+/// A transaction with a serial number, commitment and memo derived from a single id byte.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LedgerTx {
+    id: [u8; 32],
+    serial_numbers: [[u8; 32]; 1],
+    commitments: [[u8; 32]; 1],
+    memo: [u8; 32],
+}
+
+impl LedgerTx {
+    fn new(id: u8) -> Self {
+        let derive = |domain: u8| {
+            let mut bytes = [id; 32];
+            bytes[0] = domain;
+            bytes
+        };
+        Self {
+            id: derive(0),
+            serial_numbers: [derive(1)],
+            commitments: [derive(2)],
+            memo: derive(3),
+        }
+    }
+}
+
+impl TransactionScheme for LedgerTx {
+    type Commitment = [u8; 32];
+    type Digest = [u8; 32];
+    type EncryptedRecord = [u8; 32];
+    type InnerCircuitID = [u8; 32];
+    type LocalDataRoot = [u8; 32];
+    type Memorandum = [u8; 32];
+    type ProgramCommitment = [u8; 32];
+    type SerialNumber = [u8; 32];
+    type Signature = [u8; 32];
+    type ValueBalance = i64;
+
+    fn transaction_id(&self) -> Result<[u8; 32], TransactionError> {
+        Ok(self.id)
+    }
+
+    fn network_id(&self) -> u8 {
+        0
+    }
+
+    fn ledger_digest(&self) -> &Self::Digest {
+        &[0u8; 32]
+    }
+
+    fn inner_circuit_id(&self) -> &Self::InnerCircuitID {
+        &[0u8; 32]
+    }
+
+    fn old_serial_numbers(&self) -> &[Self::SerialNumber] {
+        &self.serial_numbers
+    }
+
+    fn new_commitments(&self) -> &[Self::Commitment] {
+        &self.commitments
+    }
+
+    fn program_commitment(&self) -> &Self::ProgramCommitment {
+        &[0u8; 32]
+    }
+
+    fn local_data_root(&self) -> &Self::LocalDataRoot {
+        &[0u8; 32]
+    }
+
+    fn value_balance(&self) -> i64 {
+        0
+    }
+
+    fn memorandum(&self) -> &Self::Memorandum {
+        &self.memo
+    }
+
+    fn signatures(&self) -> &[Self::Signature] {
+        &[]
+    }
+
+    fn encrypted_records(&self) -> &[Self::EncryptedRecord] {
+        &[]
+    }
+
+    fn size(&self) -> usize {
+        1
+    }
+}
+
+impl ToBytes for LedgerTx {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.id[1].write_le(&mut writer)
+    }
+}
+
+impl FromBytes for LedgerTx {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let id: u8 = FromBytes::read_le(&mut reader)?;
+        Ok(Self::new(id))
+    }
+}
+
+type TestLedger<S> = Ledger<LedgerTx, CommitmentMerkleParameters, S>;
+
+fn ledger_parameters() -> Arc<CommitmentMerkleParameters> {
+    Arc::new(CommitmentMerkleParameters::setup(&mut ChaChaRng::seed_from_u64(1231275789u64)))
+}
+
+/// Returns a block on top of `previous_block_hash` with one transaction for each of the given ids.
+/// The `nonce` distinguishes blocks with the same parent and transactions.
+fn block(previous_block_hash: BlockHeaderHash, ids: &[u8], nonce: u32) -> Block<LedgerTx> {
+    Block {
+        header: BlockHeader {
+            previous_block_hash,
+            merkle_root_hash: MerkleRootHash([0u8; 32]),
+            pedersen_merkle_root_hash: PedersenMerkleRootHash([0u8; 32]),
+            proof: ProofOfSuccinctWork([0u8; ProofOfSuccinctWork::size()]),
+            time: 0,
+            difficulty_target: u64::MAX,
+            nonce,
+        },
+        transactions: Transactions(ids.iter().map(|id| LedgerTx::new(*id)).collect()),
+    }
+}
+
+fn genesis_block() -> Block<LedgerTx> {
+    block(BlockHeaderHash([0u8; 32]), &[0], 0)
+}
+
+/// Appends a block with the given transaction ids on top of the latest block, returning it.
+fn append<S: Storage>(ledger: &TestLedger<S>, ids: &[u8], nonce: u32) -> Block<LedgerTx> {
+    let latest_block_hash = ledger.get_latest_block().unwrap().header.get_hash();
+    let block = block(latest_block_hash, ids, nonce);
+    ledger.insert_and_commit(&block).unwrap();
+    block
+}
+
+/// Checks that the ledger indexes exactly the transactions with the given ids.
+fn assert_indexed<S: Storage>(ledger: &TestLedger<S>, ids: &[u8], absent_ids: &[u8]) {
+    for id in ids {
+        let tx = LedgerTx::new(*id);
+        assert!(ledger.contains_sn(&tx.serial_numbers[0]));
+        assert!(ledger.contains_cm(&tx.commitments[0]));
+        assert!(ledger.contains_memo(&tx.memo));
+        assert!(ledger.prove_cm(&tx.commitments[0]).is_ok());
+    }
+    for id in absent_ids {
+        let tx = LedgerTx::new(*id);
+        assert!(!ledger.contains_sn(&tx.serial_numbers[0]));
+        assert!(!ledger.contains_cm(&tx.commitments[0]));
+        assert!(!ledger.contains_memo(&tx.memo));
+    }
+    assert_eq!(ledger.current_cm_index().unwrap(), ids.len());
+    assert_eq!(ledger.current_sn_index().unwrap(), ids.len());
+    assert_eq!(ledger.current_memo_index().unwrap(), ids.len());
+}
+
+fn new_ledger() -> TestLedger<MemDb> {
+    TestLedger::<MemDb>::new(None, ledger_parameters(), genesis_block()).unwrap()
+}
+
+#[test]
+fn test_ledger_append_and_revert() {
+    let ledger = new_ledger();
+    let genesis_digest = ledger.digest().unwrap();
+
+    let block_1 = append(&ledger, &[1, 2], 0);
+    let digest_1 = ledger.digest().unwrap();
+    let block_2 = append(&ledger, &[3], 0);
+
+    assert_eq!(ledger.len(), 3);
+    assert_indexed(&ledger, &[0, 1, 2, 3], &[]);
+    assert!(ledger.validate_digest(&genesis_digest));
+    assert!(ledger.validate_digest(&digest_1));
+
+    // Revert the latest block.
+    assert_eq!(ledger.decommit_latest_block().unwrap(), block_2.header.get_hash());
+    assert_eq!(ledger.get_current_block_height(), 1);
+    assert_eq!(ledger.digest().unwrap(), digest_1);
+    assert_indexed(&ledger, &[0, 1, 2], &[3]);
+    assert!(!ledger.is_canon(&block_2.header.get_hash()));
+    assert!(ledger.block_hash_exists(&block_2.header.get_hash()));
+
+    // Recommit it.
+    ledger.commit(&block_2).unwrap();
+    assert_indexed(&ledger, &[0, 1, 2, 3], &[]);
+
+    // Revert back to the genesis block, which cannot be reverted.
+    assert_eq!(ledger.revert_blocks(2).unwrap(), vec![
+        block_2.header.get_hash(),
+        block_1.header.get_hash()
+    ]);
+    assert_eq!(ledger.digest().unwrap(), genesis_digest);
+    assert_indexed(&ledger, &[0], &[1, 2, 3]);
+    assert!(matches!(
+        ledger.decommit_latest_block(),
+        Err(StorageError::InvalidBlockDecommit)
+    ));
+    assert!(matches!(
+        ledger.revert_blocks(1),
+        Err(StorageError::InvalidBlockRemovalNum(1, 0))
+    ));
+}
+
+#[test]
+fn test_ledger_reorganize() {
+    let ledger = new_ledger();
+    let block_1 = append(&ledger, &[1], 0);
+    let block_2 = append(&ledger, &[2], 0);
+    append(&ledger, &[3], 0);
+
+    // Fork after block 1, reusing the transaction of the old block 3.
+    let fork_2 = block(block_1.header.get_hash(), &[3], 1);
+    let fork_3 = block(fork_2.header.get_hash(), &[4], 1);
+    let fork_4 = block(fork_3.header.get_hash(), &[5], 1);
+    ledger
+        .reorganize(&block_1.header.get_hash(), &[fork_2.clone(), fork_3, fork_4.clone()])
+        .unwrap();
+
+    assert_eq!(ledger.get_current_block_height(), 4);
+    assert_eq!(ledger.get_latest_block().unwrap(), fork_4);
+    assert!(!ledger.is_canon(&block_2.header.get_hash()));
+    assert_indexed(&ledger, &[0, 1, 3, 4, 5], &[2]);
+
+    // The chain must link to the fork point.
+    let unlinked = block(block_2.header.get_hash(), &[6], 1);
+    assert!(ledger.reorganize(&block_1.header.get_hash(), &[unlinked]).is_err());
+    assert_eq!(ledger.get_current_block_height(), 4);
+
+    // A fork whose second block spends an existing serial number is rolled back.
+    let digest = ledger.digest().unwrap();
+    let fork_3b = block(fork_2.header.get_hash(), &[6], 2);
+    let invalid = block(fork_3b.header.get_hash(), &[1], 2);
+    assert!(
+        ledger
+            .reorganize(&fork_2.header.get_hash(), &[fork_3b, invalid])
+            .is_err()
+    );
+    assert_eq!(ledger.get_latest_block().unwrap(), fork_4);
+    assert_eq!(ledger.digest().unwrap(), digest);
+    assert_indexed(&ledger, &[0, 1, 3, 4, 5], &[2, 6]);
+}
+
+#[test]
+fn test_ledger_rebuild_indexes() {
+    let ledger = new_ledger();
+    let block_1 = append(&ledger, &[1, 2], 0);
+    append(&ledger, &[3], 0);
+    let fork_2 = block(block_1.header.get_hash(), &[4], 1);
+    ledger.reorganize(&block_1.header.get_hash(), &[fork_2.clone()]).unwrap();
+    let digest = ledger.digest().unwrap();
+
+    ledger.rebuild_indexes().unwrap();
+
+    assert_eq!(ledger.get_latest_block().unwrap(), fork_2);
+    assert_eq!(ledger.get_block_number(&fork_2.header.get_hash()).unwrap(), 2);
+    assert_eq!(ledger.digest().unwrap(), digest);
+    assert_indexed(&ledger, &[0, 1, 2, 4], &[3]);
+    assert!(ledger.validate_digest(&digest));
+}
+
+#[test]
+fn test_ledger_load() {
+    let mut path = std::env::temp_dir();
+    path.push(random_storage_path());
+
+    let (block_2, digest) = {
+        let ledger = TestLedger::<FileDb>::new(Some(&path), ledger_parameters(), genesis_block()).unwrap();
+        append(&ledger, &[1], 0);
+        let block_2 = append(&ledger, &[2, 3], 0);
+        (block_2, ledger.digest().unwrap())
+    };
+
+    let ledger = TestLedger::<FileDb>::load(Some(&path), ledger_parameters()).unwrap();
+    assert_eq!(ledger.get_latest_block().unwrap(), block_2);
+    assert_eq!(ledger.digest().unwrap(), digest);
+    assert_indexed(&ledger, &[0, 1, 2, 3], &[]);
+
+    // Creating a new ledger over an existing one fails.
+    drop(ledger);
+    assert!(TestLedger::<FileDb>::new(Some(&path), ledger_parameters(), genesis_block()).is_err());
+
+    std::fs::remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn test_ledger_incremental_merkle_tree() {
+    let ledger = new_ledger();
+    append(&ledger, &[1, 2], 0);
+    let tracked_cm = LedgerTx::new(2).commitments[0];
+
+    let mut cm_merkle_tree = ledger.build_incremental_merkle_tree(&[tracked_cm]).unwrap();
+    assert_eq!(cm_merkle_tree.root().unwrap(), ledger.digest().unwrap());
+
+    // The path of the tracked commitment stays valid as the ledger grows.
+    append(&ledger, &[3], 0);
+    cm_merkle_tree.append(&LedgerTx::new(3).commitments[0]).unwrap();
+    let digest = ledger.digest().unwrap();
+    assert_eq!(cm_merkle_tree.checkpoint().unwrap(), digest);
+    let path = cm_merkle_tree.generate_proof(2, &tracked_cm).unwrap();
+    assert_eq!(ledger.prove_cm(&tracked_cm).unwrap().path, path.path);
+    assert!(TestLedger::<MemDb>::verify_cm(
+        ledger.parameters(),
+        &digest,
+        &tracked_cm,
+        &path
+    ));
+
+    ledger.store_incremental_merkle_tree("CM_MERKLE_TREE", &cm_merkle_tree).unwrap();
+    let loaded = ledger.load_incremental_merkle_tree("CM_MERKLE_TREE").unwrap().unwrap();
+    assert_eq!(loaded.root().unwrap(), digest);
+    assert_eq!(loaded.roots(), cm_merkle_tree.roots());
+    assert!(ledger.load_incremental_merkle_tree("MISSING").unwrap().is_none());
+}
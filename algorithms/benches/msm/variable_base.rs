@@ -14,12 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use criterion::Criterion;
+use criterion::{BenchmarkId, Criterion};
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
 use snarkvm_algorithms::msm::*;
 use snarkvm_curves::{
     bls12_377::{Fr, G1Projective},
+    bw6_761,
     traits::ProjectiveCurve,
 };
 use snarkvm_fields::PrimeField;
@@ -45,10 +46,58 @@ fn variable_base(c: &mut Criterion) {
     });
 }
 
+fn variable_base_strategies_bls12_377(c: &mut Criterion) {
+    const SAMPLES: usize = 200000;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES).map(|_| Fr::rand(&mut rng).to_repr()).collect::<Vec<_>>();
+    let g = (0..SAMPLES)
+        .map(|_| G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("MSM Variable Base BLS12-377 G1");
+    for strategy in &[MSMStrategy::Standard, MSMStrategy::Batched] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", strategy)),
+            strategy,
+            |b, strategy| {
+                b.iter(|| VariableBaseMSM::multi_scalar_mul_with_strategy(g.as_slice(), v.as_slice(), *strategy))
+            },
+        );
+    }
+    group.finish();
+}
+
+fn variable_base_strategies_bw6_761(c: &mut Criterion) {
+    const SAMPLES: usize = 100000;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES)
+        .map(|_| bw6_761::Fr::rand(&mut rng).to_repr())
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES)
+        .map(|_| bw6_761::G1Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("MSM Variable Base BW6-761 G1");
+    for strategy in &[MSMStrategy::Standard, MSMStrategy::Batched] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", strategy)),
+            strategy,
+            |b, strategy| {
+                b.iter(|| VariableBaseMSM::multi_scalar_mul_with_strategy(g.as_slice(), v.as_slice(), *strategy))
+            },
+        );
+    }
+    group.finish();
+}
+
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base
+    targets = variable_base, variable_base_strategies_bls12_377, variable_base_strategies_bw6_761
 }
 
 criterion_main!(variable_base_group);
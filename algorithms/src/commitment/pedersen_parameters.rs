@@ -18,7 +18,7 @@ use crate::{
     crh::{PedersenCRH, PedersenCRHParameters},
     traits::CRH,
 };
-use snarkvm_curves::traits::Group;
+use snarkvm_curves::traits::{Group, HashToCurve};
 use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
@@ -49,9 +49,33 @@ impl<G: Group, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>
         }
     }
 
+    /// Derives the bases and the randomness base from the generators for the given `seed`.
+    pub fn from_seed(seed: &[u8]) -> Self
+    where
+        G: HashToCurve,
+    {
+        let mut bases = G::derive_generators(seed, NUM_WINDOWS + 1)
+            .into_iter()
+            .map(|generator| Self::powers(generator, WINDOW_SIZE))
+            .collect::<Vec<Vec<G>>>();
+        let random_base = bases
+            .pop()
+            .expect("One more generator than windows is derived for the randomness base");
+        let crh_parameters = PedersenCRHParameters::from(bases.clone());
+        let crh = PedersenCRH::from(crh_parameters);
+        Self {
+            bases,
+            random_base,
+            crh,
+        }
+    }
+
     fn base<R: Rng>(num_powers: usize, rng: &mut R) -> Vec<G> {
+        Self::powers(G::rand(rng), num_powers)
+    }
+
+    fn powers(mut base: G, num_powers: usize) -> Vec<G> {
         let mut powers = Vec::with_capacity(num_powers);
-        let mut base = G::rand(rng);
         for _ in 0..num_powers {
             powers.push(base);
             base.double_in_place();
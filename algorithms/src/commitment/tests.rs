@@ -15,10 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    commitment::{PedersenCommitment, PedersenCompressedCommitment},
-    traits::CommitmentScheme,
+    commitment::{PedersenCommitment, PedersenCommitmentParameters, PedersenCompressedCommitment},
+    traits::{CommitmentScheme, CRH},
 };
-use snarkvm_curves::edwards_bls12::EdwardsProjective;
+use snarkvm_curves::{edwards_bls12::EdwardsProjective, Group};
+use snarkvm_fields::Zero;
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use rand::SeedableRng;
@@ -48,3 +49,29 @@ fn pedersen_commitment_parameters_serialization() {
 fn pedersen_compressed_commitment_parameters_serialization() {
     commitment_parameters_serialization::<PedersenCompressedCommitment<EdwardsProjective, NUM_WINDOWS, WINDOW_SIZE>>();
 }
+
+#[test]
+fn pedersen_commitment_parameters_from_seed() {
+    type Parameters = PedersenCommitmentParameters<EdwardsProjective, NUM_WINDOWS, WINDOW_SIZE>;
+
+    let parameters = Parameters::from_seed(b"snarkVM-algorithms-pedersen-commitment-test");
+    assert_eq!(
+        parameters,
+        Parameters::from_seed(b"snarkVM-algorithms-pedersen-commitment-test")
+    );
+
+    assert_eq!(NUM_WINDOWS, parameters.bases.len());
+    assert_eq!(WINDOW_SIZE, parameters.random_base.len());
+    assert_eq!(parameters.bases, parameters.crh.parameters.bases);
+    assert!(!parameters
+        .bases
+        .iter()
+        .any(|powers| powers[0] == parameters.random_base[0]));
+    for pair in parameters.random_base.windows(2) {
+        assert_eq!(pair[0].double(), pair[1]);
+    }
+
+    let commitment = PedersenCommitment::from(parameters);
+    let output = commitment.commit(&[1, 2, 3], &Zero::zero()).unwrap();
+    assert_eq!(output, commitment.parameters.crh.hash(&[1, 2, 3]).unwrap());
+}
@@ -20,7 +20,7 @@ use crate::{
     errors::CRHError,
     traits::CRH,
 };
-use snarkvm_curves::Group;
+use snarkvm_curves::{Group, HashToCurve};
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
 use snarkvm_utilities::biginteger::biginteger::BigInteger;
 
@@ -39,19 +39,31 @@ pub struct BoweHopwoodPedersenCRH<G: Group, const NUM_WINDOWS: usize, const WIND
 
 impl<G: Group, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> BoweHopwoodPedersenCRH<G, NUM_WINDOWS, WINDOW_SIZE> {
     pub fn create_generators<R: Rng>(rng: &mut R) -> Vec<Vec<G>> {
-        let mut generators = Vec::with_capacity(NUM_WINDOWS);
-        for _ in 0..NUM_WINDOWS {
-            let mut generators_for_segment = Vec::with_capacity(WINDOW_SIZE);
-            let mut base = G::rand(rng);
-            for _ in 0..WINDOW_SIZE {
-                generators_for_segment.push(base);
-                for _ in 0..4 {
-                    base.double_in_place();
-                }
+        (0..NUM_WINDOWS)
+            .map(|_| Self::generators_for_segment(G::rand(rng)))
+            .collect()
+    }
+
+    /// Derives the generators of each segment from the generators for the given `seed`.
+    pub fn create_generators_from_seed(seed: &[u8]) -> Vec<Vec<G>>
+    where
+        G: HashToCurve,
+    {
+        G::derive_generators(seed, NUM_WINDOWS)
+            .into_iter()
+            .map(Self::generators_for_segment)
+            .collect()
+    }
+
+    fn generators_for_segment(mut base: G) -> Vec<G> {
+        let mut generators_for_segment = Vec::with_capacity(WINDOW_SIZE);
+        for _ in 0..WINDOW_SIZE {
+            generators_for_segment.push(base);
+            for _ in 0..4 {
+                base.double_in_place();
             }
-            generators.push(generators_for_segment);
         }
-        generators
+        generators_for_segment
     }
 }
 
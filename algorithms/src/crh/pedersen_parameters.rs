@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::traits::crh::CRHParameters;
-use snarkvm_curves::Group;
+use snarkvm_curves::{Group, HashToCurve};
 use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
@@ -45,9 +45,25 @@ impl<G: Group, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize> PedersenCRHPa
         Self { bases }
     }
 
+    /// Derives the bases from the generators for the given `seed`.
+    pub fn from_seed(seed: &[u8]) -> Self
+    where
+        G: HashToCurve,
+    {
+        Self {
+            bases: G::derive_generators(seed, NUM_WINDOWS)
+                .into_iter()
+                .map(|generator| Self::powers(generator, WINDOW_SIZE))
+                .collect(),
+        }
+    }
+
     fn base<R: Rng>(num_powers: usize, rng: &mut R) -> Vec<G> {
+        Self::powers(G::rand(rng), num_powers)
+    }
+
+    fn powers(mut base: G, num_powers: usize) -> Vec<G> {
         let mut powers = Vec::with_capacity(num_powers);
-        let mut base = G::rand(rng);
         for _ in 0..num_powers {
             powers.push(base);
             base.double_in_place();
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crh::PoseidonParameters,
+    errors::CRHError,
+    traits::{CRHParameters, CRH},
+};
+use snarkvm_fields::{ConstraintFieldError, FieldParameters, PrimeField, ToConstraintField};
+
+use rand::Rng;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoseidonCRH<F: PrimeField, const INPUT_SIZE_FE: usize> {
+    pub parameters: PoseidonParameters<F>,
+}
+
+impl<F: PrimeField, const INPUT_SIZE_FE: usize> PoseidonCRH<F, INPUT_SIZE_FE> {
+    /// The number of input bits packed into each field element.
+    const FIELD_ELEMENT_SIZE_BITS: usize = (<F as PrimeField>::Parameters::CAPACITY as usize / 8) * 8;
+}
+
+impl<F: PrimeField, const INPUT_SIZE_FE: usize> CRH for PoseidonCRH<F, INPUT_SIZE_FE> {
+    type Output = F;
+    type Parameters = PoseidonParameters<F>;
+
+    const INPUT_SIZE_BITS: usize = Self::FIELD_ELEMENT_SIZE_BITS * INPUT_SIZE_FE;
+
+    fn setup<R: Rng>(rng: &mut R) -> Self {
+        Self {
+            parameters: PoseidonParameters::setup(rng),
+        }
+    }
+
+    /// Packs the input into field elements of `CAPACITY / 8` bytes and absorbs them into a Poseidon sponge.
+    fn hash(&self, input: &[u8]) -> Result<Self::Output, CRHError> {
+        if (input.len() * 8) > Self::INPUT_SIZE_BITS {
+            return Err(CRHError::IncorrectInputLength(
+                input.len(),
+                Self::FIELD_ELEMENT_SIZE_BITS,
+                INPUT_SIZE_FE,
+            ));
+        }
+
+        // Pad the input if it is not the current length.
+        let mut padded_input = input.to_vec();
+        padded_input.resize(Self::INPUT_SIZE_BITS / 8, 0u8);
+
+        let elements: Vec<F> = padded_input.to_field_elements()?;
+        debug_assert_eq!(elements.len(), INPUT_SIZE_FE);
+
+        Ok(self.parameters.evaluate(&elements))
+    }
+
+    fn parameters(&self) -> &Self::Parameters {
+        &self.parameters
+    }
+}
+
+impl<F: PrimeField, const INPUT_SIZE_FE: usize> From<PoseidonParameters<F>> for PoseidonCRH<F, INPUT_SIZE_FE> {
+    fn from(parameters: PoseidonParameters<F>) -> Self {
+        Self { parameters }
+    }
+}
+
+impl<F: PrimeField, const INPUT_SIZE_FE: usize> ToConstraintField<F> for PoseidonCRH<F, INPUT_SIZE_FE> {
+    #[inline]
+    fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
+        self.parameters.to_field_elements()
+    }
+}
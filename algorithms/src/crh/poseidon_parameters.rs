@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::CRHParameters;
+use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use rand::Rng;
+use std::io::{Read, Result as IoResult, Write};
+
+/// The number of field elements absorbed per permutation.
+pub const POSEIDON_RATE: usize = 2;
+/// The number of field elements of the state that are never directly absorbed into.
+pub const POSEIDON_CAPACITY: usize = 1;
+/// The number of full rounds, split evenly before and after the partial rounds.
+pub const POSEIDON_FULL_ROUNDS: u32 = 8;
+/// The security level, in bits, used to derive the number of partial rounds.
+const POSEIDON_SECURITY_LEVEL: u32 = 128;
+/// The S-box exponents considered by `setup`, in order of preference.
+const POSEIDON_ALPHA_CANDIDATES: [u64; 6] = [3, 5, 7, 11, 13, 17];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoseidonParameters<F: PrimeField> {
+    /// The number of rounds in which the S-box is applied to every element of the state.
+    pub full_rounds: u32,
+    /// The number of rounds in which the S-box is applied to the last element of the state.
+    pub partial_rounds: u32,
+    /// The exponent of the S-box.
+    pub alpha: u64,
+    /// The round constants, indexed by `ark[round][state_index]`.
+    pub ark: Vec<Vec<F>>,
+    /// The maximum distance separable matrix applied at the end of each round.
+    pub mds: Vec<Vec<F>>,
+    /// The number of state elements absorbed into per permutation.
+    pub rate: usize,
+    /// The number of state elements reserved for security.
+    pub capacity: usize,
+}
+
+impl<F: PrimeField> CRHParameters for PoseidonParameters<F> {
+    fn setup<R: Rng>(rng: &mut R) -> Self {
+        let width = POSEIDON_RATE + POSEIDON_CAPACITY;
+        let alpha = Self::alpha();
+        let partial_rounds = Self::partial_rounds(alpha, width);
+
+        let ark = (0..(POSEIDON_FULL_ROUNDS + partial_rounds))
+            .map(|_| (0..width).map(|_| F::rand(rng)).collect())
+            .collect();
+
+        // A Cauchy matrix 1 / (x_i + y_j), with x_i = i and y_j = width + j, is MDS.
+        let mds = (0..width)
+            .map(|i| {
+                (0..width)
+                    .map(|j| F::from((i + j + width) as u64).inverse().unwrap())
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            full_rounds: POSEIDON_FULL_ROUNDS,
+            partial_rounds,
+            alpha,
+            ark,
+            mds,
+            rate: POSEIDON_RATE,
+            capacity: POSEIDON_CAPACITY,
+        }
+    }
+}
+
+impl<F: PrimeField> PoseidonParameters<F> {
+    /// Returns the smallest candidate exponent `alpha` for which `x^alpha` is a permutation of `F`,
+    /// that is, for which `gcd(alpha, p - 1) = 1`.
+    pub fn alpha() -> u64 {
+        POSEIDON_ALPHA_CANDIDATES
+            .iter()
+            .copied()
+            .find(|alpha| {
+                // As every candidate is prime, alpha divides p - 1 exactly when p = 1 mod alpha.
+                let remainder = F::characteristic().iter().rev().fold(0u128, |remainder, limb| {
+                    ((remainder << 64) | *limb as u128) % *alpha as u128
+                });
+                remainder != 1
+            })
+            .expect("every Poseidon S-box candidate divides p - 1")
+    }
+
+    /// Returns the number of partial rounds needed against interpolation and Groebner basis attacks,
+    /// following the bound `log_alpha(2) * min(M, log_2(p)) + log_alpha(t)` with a 7.5% security margin.
+    pub fn partial_rounds(alpha: u64, width: usize) -> u32 {
+        let security_level = POSEIDON_SECURITY_LEVEL.min(F::size_in_bits() as u32) as f64;
+        let log2_alpha = (alpha as f64).log2();
+        let rounds = security_level / log2_alpha + (width as f64).log2() / log2_alpha;
+        (rounds * 1.075).ceil() as u32
+    }
+
+    /// Applies the Poseidon permutation to `state`, which must have `rate + capacity` elements.
+    pub fn permute(&self, state: &mut [F]) {
+        debug_assert_eq!(state.len(), self.rate + self.capacity);
+
+        let full_rounds_over_2 = self.full_rounds / 2;
+        for round in 0..(self.full_rounds + self.partial_rounds) {
+            for (element, constant) in state.iter_mut().zip(&self.ark[round as usize]) {
+                *element += constant;
+            }
+
+            let is_full_round = round < full_rounds_over_2 || round >= full_rounds_over_2 + self.partial_rounds;
+            if is_full_round {
+                for element in state.iter_mut() {
+                    *element = element.pow(&[self.alpha]);
+                }
+            } else {
+                let last = state.len() - 1;
+                state[last] = state[last].pow(&[self.alpha]);
+            }
+
+            let new_state: Vec<F> = self
+                .mds
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(state.iter())
+                        .fold(F::zero(), |sum, (coefficient, element)| sum + *coefficient * element)
+                })
+                .collect();
+            state.copy_from_slice(&new_state);
+        }
+    }
+
+    /// Absorbs `input` into a sponge, whose capacity is initialized with the length of `input`,
+    /// and returns the first element of the state after the final permutation.
+    pub fn evaluate(&self, input: &[F]) -> F {
+        let mut state = vec![F::zero(); self.rate + self.capacity];
+        state[self.rate] = F::from(input.len() as u64);
+
+        if input.is_empty() {
+            self.permute(&mut state);
+        }
+        for chunk in input.chunks(self.rate) {
+            for (element, input) in state.iter_mut().zip(chunk) {
+                *element += input;
+            }
+            self.permute(&mut state);
+        }
+
+        state[0]
+    }
+}
+
+impl<F: PrimeField> ToBytes for PoseidonParameters<F> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.full_rounds.write_le(&mut writer)?;
+        self.partial_rounds.write_le(&mut writer)?;
+        self.alpha.write_le(&mut writer)?;
+        (self.rate as u32).write_le(&mut writer)?;
+        (self.capacity as u32).write_le(&mut writer)?;
+        for matrix in &[&self.ark, &self.mds] {
+            (matrix.len() as u32).write_le(&mut writer)?;
+            for row in matrix.iter() {
+                (row.len() as u32).write_le(&mut writer)?;
+                for element in row {
+                    element.write_le(&mut writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> FromBytes for PoseidonParameters<F> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let full_rounds: u32 = FromBytes::read_le(&mut reader)?;
+        let partial_rounds: u32 = FromBytes::read_le(&mut reader)?;
+        let alpha: u64 = FromBytes::read_le(&mut reader)?;
+        let rate: u32 = FromBytes::read_le(&mut reader)?;
+        let capacity: u32 = FromBytes::read_le(&mut reader)?;
+
+        let mut matrices = Vec::with_capacity(2);
+        for _ in 0..2 {
+            let num_rows: u32 = FromBytes::read_le(&mut reader)?;
+            let mut matrix = Vec::with_capacity(num_rows as usize);
+            for _ in 0..num_rows {
+                let row_len: u32 = FromBytes::read_le(&mut reader)?;
+                let mut row = Vec::with_capacity(row_len as usize);
+                for _ in 0..row_len {
+                    let element: F = FromBytes::read_le(&mut reader)?;
+                    row.push(element);
+                }
+                matrix.push(row);
+            }
+            matrices.push(matrix);
+        }
+        let mds = matrices.pop().unwrap();
+        let ark = matrices.pop().unwrap();
+
+        Ok(Self {
+            full_rounds,
+            partial_rounds,
+            alpha,
+            ark,
+            mds,
+            rate: rate as usize,
+            capacity: capacity as usize,
+        })
+    }
+}
+
+impl<F: PrimeField> ToConstraintField<F> for PoseidonParameters<F> {
+    #[inline]
+    fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
+        Ok(Vec::new())
+    }
+}
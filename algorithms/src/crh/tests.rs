@@ -15,10 +15,20 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    crh::{BoweHopwoodPedersenCRH, BoweHopwoodPedersenCompressedCRH, PedersenCRH, PedersenCompressedCRH},
+    crh::{
+        BoweHopwoodPedersenCRH,
+        BoweHopwoodPedersenCompressedCRH,
+        PedersenCRH,
+        PedersenCRHParameters,
+        PedersenCompressedCRH,
+        PoseidonCRH,
+        PoseidonParameters,
+    },
+    errors::CRHError,
     traits::CRH,
 };
-use snarkvm_curves::edwards_bls12::EdwardsProjective;
+use snarkvm_curves::{bls12_377, bw6_761, edwards_bls12::EdwardsProjective, Group};
+use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use rand::SeedableRng;
@@ -30,6 +40,8 @@ const PEDERSEN_WINDOW_SIZE: usize = 128;
 const BHP_NUM_WINDOWS: usize = 8;
 const BHP_WINDOW_SIZE: usize = 63;
 
+const POSEIDON_INPUT_SIZE_FE: usize = 4;
+
 fn crh_parameters_serialization<C: CRH>() {
     let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -74,3 +86,97 @@ fn simple_bowe_hopwood_crh() {
 
     BoweHopwoodCRH::hash(&parameters, &[1, 2, 3]).unwrap();
 }
+
+#[test]
+fn pedersen_crh_parameters_from_seed() {
+    type Parameters = PedersenCRHParameters<EdwardsProjective, PEDERSEN_NUM_WINDOWS, PEDERSEN_WINDOW_SIZE>;
+
+    let parameters = Parameters::from_seed(b"snarkVM-algorithms-pedersen-crh-test");
+    assert_eq!(
+        parameters,
+        Parameters::from_seed(b"snarkVM-algorithms-pedersen-crh-test")
+    );
+    assert_ne!(
+        parameters,
+        Parameters::from_seed(b"snarkVM-algorithms-pedersen-crh-other-test")
+    );
+
+    assert_eq!(PEDERSEN_NUM_WINDOWS, parameters.bases.len());
+    for powers in &parameters.bases {
+        assert_eq!(PEDERSEN_WINDOW_SIZE, powers.len());
+        for pair in powers.windows(2) {
+            assert_eq!(pair[0].double(), pair[1]);
+        }
+    }
+
+    let crh = PedersenCRH::from(parameters);
+    crh.hash(&[1, 2, 3]).unwrap();
+}
+
+#[test]
+fn bowe_hopwood_crh_generators_from_seed() {
+    type BoweHopwoodCRH = BoweHopwoodPedersenCRH<EdwardsProjective, BHP_NUM_WINDOWS, BHP_WINDOW_SIZE>;
+
+    let generators = BoweHopwoodCRH::create_generators_from_seed(b"snarkVM-algorithms-bhp-crh-test");
+    assert_eq!(
+        generators,
+        BoweHopwoodCRH::create_generators_from_seed(b"snarkVM-algorithms-bhp-crh-test")
+    );
+    assert_eq!(BHP_NUM_WINDOWS, generators.len());
+    for generators_for_segment in &generators {
+        assert_eq!(BHP_WINDOW_SIZE, generators_for_segment.len());
+    }
+
+    let crh = BoweHopwoodCRH::from(PedersenCRHParameters::from(generators));
+    crh.hash(&[1, 2, 3]).unwrap();
+}
+
+#[test]
+fn poseidon_crh_parameters_serialization() {
+    crh_parameters_serialization::<PoseidonCRH<bls12_377::Fr, POSEIDON_INPUT_SIZE_FE>>();
+    crh_parameters_serialization::<PoseidonCRH<bw6_761::Fr, POSEIDON_INPUT_SIZE_FE>>();
+}
+
+#[test]
+fn poseidon_parameters_alpha() {
+    // 3 divides r - 1 for both scalar fields, so the smallest valid S-box exponent is larger.
+    assert_eq!(PoseidonParameters::<bls12_377::Fr>::alpha(), 11);
+    assert_eq!(PoseidonParameters::<bw6_761::Fr>::alpha(), 5);
+}
+
+type PoseidonTestCRH<F> = PoseidonCRH<F, POSEIDON_INPUT_SIZE_FE>;
+
+fn poseidon_crh<F: PrimeField>() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let crh = PoseidonTestCRH::<F>::setup(rng);
+
+    let input_size = PoseidonTestCRH::<F>::INPUT_SIZE_BITS / 8;
+    let input: Vec<u8> = (0..input_size).map(|i| i as u8).collect();
+
+    // Hashing is deterministic, and shorter inputs are zero-padded.
+    let output = crh.hash(&input).unwrap();
+    assert_eq!(output, crh.hash(&input).unwrap());
+    assert_eq!(crh.hash(&input[..3]).unwrap(), crh.hash(&[0, 1, 2, 0, 0]).unwrap());
+    assert_ne!(output, crh.hash(&input[..input_size - 1]).unwrap());
+
+    let mut longer_input = input.clone();
+    longer_input.push(0u8);
+    assert!(matches!(
+        crh.hash(&longer_input),
+        Err(CRHError::IncorrectInputLength(..))
+    ));
+
+    // The output depends on the parameters.
+    let other_crh = PoseidonTestCRH::<F>::setup(rng);
+    assert_ne!(output, other_crh.hash(&input).unwrap());
+}
+
+#[test]
+fn poseidon_crh_on_bls12_377() {
+    poseidon_crh::<bls12_377::Fr>();
+}
+
+#[test]
+fn poseidon_crh_on_bw6_761() {
+    poseidon_crh::<bw6_761::Fr>();
+}
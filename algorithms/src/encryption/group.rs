@@ -15,16 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{encryption::GroupEncryptionParameters, errors::EncryptionError, traits::EncryptionScheme};
-use snarkvm_curves::traits::{AffineCurve, Group, ProjectiveCurve};
-use snarkvm_fields::{Field, One, PrimeField, Zero};
-use snarkvm_utilities::{
-    errors::SerializationError,
-    from_bytes_le_to_bits_le,
-    rand::UniformRand,
-    serialize::*,
-    FromBytes,
-    ToBytes,
-};
+use snarkvm_curves::traits::{AffineCurve, ConstantTimeGroup, Group, ProjectiveCurve};
+use snarkvm_fields::{ConstantTimeField, One, PrimeField, Zero};
+use snarkvm_utilities::{errors::SerializationError, rand::UniformRand, serialize::*, FromBytes, ToBytes};
 
 use digest::Digest;
 use itertools::Itertools;
@@ -96,8 +89,14 @@ pub struct GroupEncryption<G: Group + ProjectiveCurve, SG: Group, D: Digest> {
     pub _hash: PhantomData<D>,
 }
 
-impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeserialize, D: Digest + Send + Sync>
-    EncryptionScheme for GroupEncryption<G, SG, D>
+impl<
+    G: Group + ProjectiveCurve + ConstantTimeGroup,
+    SG: Group + ConstantTimeGroup + CanonicalSerialize + CanonicalDeserialize,
+    D: Digest + Send + Sync,
+> EncryptionScheme for GroupEncryption<G, SG, D>
+where
+    <G as Group>::ScalarField: ConstantTimeField,
+    <SG as Group>::ScalarField: ConstantTimeField,
 {
     type BlindingExponent = <G as Group>::ScalarField;
     type Parameters = GroupEncryptionParameters<G>;
@@ -131,14 +130,8 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
     ) -> Result<<Self as EncryptionScheme>::PublicKey, EncryptionError> {
         let keygen_time = start_timer!(|| "GroupEncryption::generate_public_key");
 
-        let mut public_key = G::zero();
-        for (bit, base_power) in
-            from_bytes_le_to_bits_le(&private_key.to_bytes_le()?).zip_eq(&self.parameters.generator_powers)
-        {
-            if bit {
-                public_key += base_power;
-            }
-        }
+        // The private key is secret, so the multiplication must run in constant time.
+        let public_key = self.parameters.generator_powers[0].mul_constant_time(private_key);
         end_timer!(keygen_time);
 
         Ok(GroupEncryptionPublicKey(public_key))
@@ -155,7 +148,7 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
         while Self::Randomness::read_le(&z_bytes[..]).is_err() {
             y = Self::Randomness::rand(rng);
 
-            let affine = public_key.0.mul(y).into_affine();
+            let affine = public_key.0.mul_constant_time(&y).into_affine();
             debug_assert!(affine.is_in_correct_subgroup_assuming_on_curve());
             z_bytes = affine.to_x_coordinate().to_bytes_le()?;
         }
@@ -169,7 +162,7 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
         randomness: &Self::Randomness,
         message_length: usize,
     ) -> Result<Vec<Self::BlindingExponent>, EncryptionError> {
-        let record_view_key = public_key.0.mul(*randomness);
+        let record_view_key = public_key.0.mul_constant_time(randomness);
 
         let affine = record_view_key.into_affine();
         debug_assert!(affine.is_in_correct_subgroup_assuming_on_curve());
@@ -183,7 +176,7 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
         let mut blinding_exponents = vec![];
         for _ in 0..message_length {
             // 1 [/] (z [+] i)
-            match (z + i).inverse() {
+            match (z + i).inverse_constant_time() {
                 Some(val) => blinding_exponents.push(val),
                 None => return Err(EncryptionError::MissingInverse),
             };
@@ -200,16 +193,11 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
         randomness: &Self::Randomness,
         message: &[Self::Text],
     ) -> Result<Vec<Self::Text>, EncryptionError> {
-        let record_view_key = public_key.0.mul(*randomness);
-
-        let mut c_0 = G::zero();
-        for (bit, base_power) in
-            from_bytes_le_to_bits_le(&randomness.to_bytes_le()?).zip_eq(&self.parameters.generator_powers)
-        {
-            if bit {
-                c_0 += base_power;
-            }
-        }
+        // The randomness and the record view key are secret, so every multiplication
+        // by them must run in constant time.
+        let record_view_key = public_key.0.mul_constant_time(randomness);
+
+        let c_0 = self.parameters.generator_powers[0].mul_constant_time(randomness);
         let mut ciphertext = vec![c_0];
 
         let one = Self::Randomness::one();
@@ -219,7 +207,7 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
 
         for (m_i, blinding_exp) in message.iter().zip_eq(blinding_exponents) {
             // h_i <- 1 [/] (z [+] i) * record_view_key
-            let h_i = record_view_key.mul(blinding_exp);
+            let h_i = record_view_key.mul_constant_time(&blinding_exp);
 
             // c_i <- h_i + m_i
             let c_i = h_i + m_i;
@@ -239,7 +227,7 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
         assert!(!ciphertext.is_empty());
         let c_0 = &ciphertext[0];
 
-        let record_view_key = c_0.mul(*private_key);
+        let record_view_key = c_0.mul_constant_time(private_key);
 
         let affine = record_view_key.into_affine();
         debug_assert!(affine.is_in_correct_subgroup_assuming_on_curve());
@@ -253,8 +241,8 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
 
         for c_i in ciphertext.iter().skip(1) {
             // h_i <- 1 [/] (z [+] i) * record_view_key
-            let h_i = match &(z + i).inverse() {
-                Some(val) => record_view_key.mul(*val),
+            let h_i = match &(z + i).inverse_constant_time() {
+                Some(val) => record_view_key.mul_constant_time(val),
                 None => return Err(EncryptionError::MissingInverse),
             };
 
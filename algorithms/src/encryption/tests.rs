@@ -17,8 +17,9 @@
 use crate::{encryption::GroupEncryption, traits::EncryptionScheme};
 use snarkvm_curves::{
     edwards_bls12::{EdwardsAffine, EdwardsProjective},
-    traits::{Group, ProjectiveCurve},
+    traits::{tests_timing::timing_leakage_test, Group, ProjectiveCurve},
 };
+use snarkvm_fields::One;
 use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
 
 use blake2::Blake2s;
@@ -68,3 +69,18 @@ fn encryption_public_key_serialization() {
         assert_eq!(public_key, recovered_public_key);
     }
 }
+
+#[ignore]
+#[test]
+fn encryption_public_key_timing() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let encryption_scheme = TestEncryptionScheme::setup(rng);
+
+    timing_leakage_test(
+        rng,
+        <EdwardsProjective as Group>::ScalarField::one(),
+        |rng| encryption_scheme.generate_private_key(rng),
+        |private_key| encryption_scheme.generate_public_key(private_key).unwrap(),
+    );
+}
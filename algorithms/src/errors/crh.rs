@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use snarkvm_fields::ConstraintFieldError;
 use std::io::{Error, ErrorKind};
 
 #[derive(Debug, Error)]
@@ -21,6 +22,9 @@ pub enum CRHError {
     #[error("{}", _0)]
     AnyhowError(#[from] anyhow::Error),
 
+    #[error("{}", _0)]
+    ConstraintFieldError(ConstraintFieldError),
+
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
@@ -34,6 +38,12 @@ pub enum CRHError {
     Message(String),
 }
 
+impl From<ConstraintFieldError> for CRHError {
+    fn from(error: ConstraintFieldError) -> Self {
+        CRHError::ConstraintFieldError(error)
+    }
+}
+
 impl From<Error> for CRHError {
     fn from(error: Error) -> Self {
         CRHError::Crate("std::io", format!("{:?}", error))
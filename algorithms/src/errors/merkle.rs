@@ -40,6 +40,9 @@ pub enum MerkleError {
     #[error("Invalid tree depth: {}. Must be less than or equal to: {}", _0, _1)]
     InvalidTreeDepth(usize, usize),
 
+    #[error("The leaf of the key is occupied by another key")]
+    KeyCollision,
+
     #[error("{}", _0)]
     Message(String),
 }
@@ -14,11 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use snarkvm_fields::ConstraintFieldError;
+use std::io::Error;
+
 #[derive(Debug, Error)]
 pub enum PRFError {
     #[error("{}", _0)]
     AnyhowError(#[from] anyhow::Error),
 
+    #[error("{}", _0)]
+    ConstraintFieldError(ConstraintFieldError),
+
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
@@ -31,3 +37,15 @@ pub enum PRFError {
     #[error("element is not of prime order")]
     NotPrimeOrder,
 }
+
+impl From<ConstraintFieldError> for PRFError {
+    fn from(error: ConstraintFieldError) -> Self {
+        PRFError::ConstraintFieldError(error)
+    }
+}
+
+impl From<Error> for PRFError {
+    fn from(error: Error) -> Self {
+        PRFError::Crate("std::io", format!("{:?}", error))
+    }
+}
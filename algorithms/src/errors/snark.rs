@@ -28,6 +28,9 @@ pub enum SNARKError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("invalid MPC transcript: {}", _0)]
+    InvalidTranscript(String),
+
     #[error("{}", _0)]
     Message(String),
 
@@ -49,12 +49,13 @@ fn log2(number: usize) -> usize {
 
 /// Defines a domain over which finite field (I)FFTs can be performed. Works
 /// only for fields that have a large multiplicative subgroup of size that is
-/// a power-of-2.
+/// a power-of-2, or for mixed-radix domains, of size `2^k * b^m` where `b` is
+/// the field's `SMALL_SUBGROUP_BASE`.
 #[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct EvaluationDomain<F: FftField> {
     /// The size of the domain.
     pub size: u64,
-    /// `log_2(self.size)`.
+    /// `log_2(self.size)`, or the 2-adicity of `self.size` for a mixed-radix domain.
     pub log_size_of_group: u32,
     /// Size of the domain as a field element.
     pub size_as_field_element: F,
@@ -117,6 +118,37 @@ impl<F: FftField> EvaluationDomain<F> {
         })
     }
 
+    /// Construct the smallest domain of size `2^k * b^m` that is large enough for evaluations
+    /// of a polynomial having `num_coeffs` coefficients, where `b` is the field's `SMALL_SUBGROUP_BASE`.
+    /// For fields without a small subgroup, this is the same as `EvaluationDomain::new`.
+    pub fn new_mixed_radix(num_coeffs: usize) -> Option<Self> {
+        let size = Self::compute_size_of_mixed_radix_domain(num_coeffs)?;
+        if size.is_power_of_two() {
+            return Self::new(size);
+        }
+
+        // Compute the generator for the multiplicative subgroup.
+        // It should be the `size`-th root of unity.
+        let group_gen = F::get_root_of_unity(size)?;
+
+        // Check that it is indeed the `size`-th root of unity.
+        debug_assert_eq!(group_gen.pow([size as u64]), F::one());
+
+        let size = size as u64;
+        let size_as_field_element = F::from(size);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(EvaluationDomain {
+            size,
+            log_size_of_group: size.trailing_zeros(),
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            generator_inv: F::multiplicative_generator().inverse()?,
+        })
+    }
+
     /// Return the size of a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
     pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
@@ -128,6 +160,38 @@ impl<F: FftField> EvaluationDomain<F> {
         }
     }
 
+    /// Return the size of the smallest mixed-radix domain, of size `2^k * b^m`, that is large enough
+    /// for evaluations of a polynomial having `num_coeffs` coefficients.
+    pub fn compute_size_of_mixed_radix_domain(num_coeffs: usize) -> Option<usize> {
+        let (base, base_adicity) = match (
+            F::FftParameters::SMALL_SUBGROUP_BASE,
+            F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY,
+        ) {
+            (Some(base), Some(base_adicity)) => (base as usize, base_adicity),
+            _ => return Self::compute_size_of_domain(num_coeffs),
+        };
+
+        let mut best = None;
+        let mut base_part = 1usize;
+        for _ in 0..=base_adicity {
+            let two_part = (num_coeffs + base_part - 1) / base_part;
+            if let Some(two_part) = two_part.checked_next_power_of_two() {
+                if two_part.trailing_zeros() <= F::FftParameters::TWO_ADICITY {
+                    if let Some(size) = two_part.checked_mul(base_part) {
+                        if best.map_or(true, |best| size < best) {
+                            best = Some(size);
+                        }
+                    }
+                }
+            }
+            base_part = match base_part.checked_mul(base) {
+                Some(base_part) => base_part,
+                None => break,
+            };
+        }
+        best
+    }
+
     /// Return the size of `self`.
     pub fn size(&self) -> usize {
         self.size as usize
@@ -143,7 +207,7 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an FFT, modifying the vector in place.
     pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
         coeffs.resize(self.size(), T::zero());
-        best_fft(coeffs, self.group_gen, self.log_size_of_group)
+        self.domain_fft(coeffs, self.group_gen)
     }
 
     /// Compute an IFFT.
@@ -157,10 +221,22 @@ impl<F: FftField> EvaluationDomain<F> {
     #[inline]
     pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
         evals.resize(self.size(), T::zero());
-        best_fft(evals, self.group_gen_inv, self.log_size_of_group);
+        self.domain_fft(evals, self.group_gen_inv);
         cfg_iter_mut!(evals).for_each(|val| *val *= self.size_inv);
     }
 
+    /// Runs the FFT with the given root of unity, using the mixed-radix FFT if the domain
+    /// size is not a power of two.
+    fn domain_fft<T: DomainCoeff<F>>(&self, a: &mut [T], omega: F) {
+        if self.size.is_power_of_two() {
+            best_fft(a, omega, self.log_size_of_group)
+        } else {
+            let base = F::FftParameters::SMALL_SUBGROUP_BASE.expect("mixed-radix domains require a small subgroup");
+            let base_adicity = F::k_adicity(base as usize, self.size());
+            mixed_radix_fft(a, omega, self.log_size_of_group, base as usize, base_adicity)
+        }
+    }
+
     /// Compute an FFT over a coset of the domain.
     pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
         let mut coeffs = coeffs.to_vec();
@@ -171,7 +247,20 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an FFT over a coset of the domain, modifying the input vector
     /// in place.
     pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
-        Self::distribute_powers(coeffs, F::multiplicative_generator());
+        self.coset_fft_in_place_with_offset(coeffs, F::multiplicative_generator());
+    }
+
+    /// Compute an FFT over the coset `offset * self` of the domain.
+    pub fn coset_fft_with_offset<T: DomainCoeff<F>>(&self, coeffs: &[T], offset: F) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.coset_fft_in_place_with_offset(&mut coeffs, offset);
+        coeffs
+    }
+
+    /// Compute an FFT over the coset `offset * self` of the domain, modifying the input vector
+    /// in place.
+    pub fn coset_fft_in_place_with_offset<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>, offset: F) {
+        Self::distribute_powers(coeffs, offset);
         self.fft_in_place(coeffs);
     }
 
@@ -188,6 +277,22 @@ impl<F: FftField> EvaluationDomain<F> {
         Self::distribute_powers(evals, self.generator_inv);
     }
 
+    /// Compute an IFFT over the coset `offset * self` of the domain.
+    /// Panics if `offset` is zero.
+    pub fn coset_ifft_with_offset<T: DomainCoeff<F>>(&self, evals: &[T], offset: F) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.coset_ifft_in_place_with_offset(&mut evals, offset);
+        evals
+    }
+
+    /// Compute an IFFT over the coset `offset * self` of the domain, modifying the input vector in place.
+    /// Panics if `offset` is zero.
+    pub fn coset_ifft_in_place_with_offset<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>, offset: F) {
+        let offset_inv = offset.inverse().expect("the coset offset must be nonzero");
+        self.ifft_in_place(evals);
+        Self::distribute_powers(evals, offset_inv);
+    }
+
     fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut Vec<T>, g: F) {
         let mut pow = F::one();
         coeffs.iter_mut().for_each(|c| {
@@ -267,6 +372,17 @@ impl<F: FftField> EvaluationDomain<F> {
         cfg_iter_mut!(evals).for_each(|eval| *eval *= &i);
     }
 
+    /// Divides the evaluations over the coset `offset * self` by the vanishing polynomial,
+    /// which is constant on the coset. Panics if `offset` is in the domain.
+    pub fn divide_by_vanishing_poly_on_coset_with_offset_in_place(&self, evals: &mut [F], offset: F) {
+        let i = self
+            .evaluate_vanishing_polynomial(offset)
+            .inverse()
+            .expect("the coset offset must lie outside the domain");
+
+        cfg_iter_mut!(evals).for_each(|eval| *eval *= &i);
+    }
+
     /// Given an index which assumes the first elements of this domain are the elements of
     /// another (sub)domain with size size_s,
     /// this returns the actual index into this domain.
@@ -320,8 +436,8 @@ impl<F: FftField> EvaluationDomain<F> {
         // TODO: check if this method can replace parallel compute powers.
         let log_size = log2(self.size as usize);
 
-        // Early exit for short inputs.
-        if log_size <= LOG_ROOTS_OF_UNITY_PARALLEL_SIZE {
+        // Early exit for short inputs and mixed-radix domains.
+        if log_size <= LOG_ROOTS_OF_UNITY_PARALLEL_SIZE || !self.size.is_power_of_two() {
             Self::compute_powers_serial((self.size as usize) / 2, root)
         } else {
             let mut tmp = root;
@@ -494,6 +610,58 @@ pub(crate) fn parallel_radix2_fft<T: DomainCoeff<F>, F: FftField>(a: &mut [T], o
         .for_each(|(i, a)| *a = tmp[i % num_chunks][i / num_chunks]);
 }
 
+/// Computes an FFT of size `2^log_two_part * base^base_adicity` by splitting off one radix-`base`
+/// layer at a time, and finishing with a radix-2 FFT once the size is a power of two.
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(
+    a: &mut [T],
+    omega: F,
+    log_two_part: u32,
+    base: usize,
+    base_adicity: u32,
+) {
+    if base_adicity == 0 {
+        best_fft(a, omega, log_two_part);
+        return;
+    }
+
+    let n = a.len();
+    assert_eq!(n, (1 << log_two_part) * base.pow(base_adicity));
+    let m = n / base;
+
+    // Split `a` into `base` interleaved subsequences, and transform each over the subgroup of size `m`.
+    let mut subsequences: Vec<Vec<T>> = (0..base)
+        .map(|j| a.iter().skip(j).step_by(base).copied().collect())
+        .collect();
+    let omega_base = omega.pow(&[base as u64]);
+    cfg_iter_mut!(subsequences)
+        .for_each(|subsequence| mixed_radix_fft(subsequence, omega_base, log_two_part, base, base_adicity - 1));
+
+    // Recombine the subsequences, where a[k + m * s] is the sum over j of omega^(j * (k + m * s)) * subsequence_j[k].
+    let zeta = omega.pow(&[m as u64]);
+    let zeta_powers: Vec<F> = (0..base).map(|s| zeta.pow(&[s as u64])).collect();
+    let mut twiddle = F::one();
+    let mut terms = vec![T::zero(); base];
+    for k in 0..m {
+        let mut w = F::one();
+        for (term, subsequence) in terms.iter_mut().zip(&subsequences) {
+            *term = subsequence[k];
+            *term *= w;
+            w *= &twiddle;
+        }
+        for s in 0..base {
+            let mut sum = T::zero();
+            for (j, term) in terms.iter().enumerate() {
+                let mut t = *term;
+                t *= zeta_powers[(j * s) % base];
+                sum += t;
+            }
+            a[k + m * s] = sum;
+        }
+        twiddle *= &omega;
+    }
+}
+
 /// An iterator over the elements of the domain.
 pub struct Elements<F: FftField> {
     cur_elem: F,
@@ -698,4 +866,103 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_mixed_radix_domain_size() {
+        for &(num_coeffs, expected_size) in &[(0, 1), (3, 3), (5, 6), (9, 12), (13, 16), (700, 768), (1000, 1024)] {
+            assert_eq!(
+                EvaluationDomain::<Fr>::compute_size_of_mixed_radix_domain(num_coeffs),
+                Some(expected_size)
+            );
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(num_coeffs).unwrap();
+            assert_eq!(domain.size(), expected_size);
+            assert_eq!(domain.elements().count(), expected_size);
+            assert_eq!(domain.group_gen.pow([domain.size]), Fr::one());
+            assert_eq!(domain.group_gen * domain.group_gen_inv, Fr::one());
+
+            let z = domain.vanishing_polynomial();
+            for point in domain.elements() {
+                assert!(z.evaluate(point).is_zero());
+            }
+        }
+
+        // Power-of-two mixed-radix domains coincide with radix-2 domains.
+        assert_eq!(
+            EvaluationDomain::<Fr>::new_mixed_radix(1000),
+            EvaluationDomain::<Fr>::new(1000)
+        );
+    }
+
+    /// Tests that the mixed-radix FFTs agree with naive evaluation.
+    #[test]
+    fn test_mixed_radix_fft_correctness() {
+        let rng = &mut thread_rng();
+
+        for &domain_size in &[3, 6, 12, 24, 48, 96, 192] {
+            let domain = EvaluationDomain::<Fr>::new_mixed_radix(domain_size).unwrap();
+            assert_eq!(domain.size(), domain_size);
+
+            let random_polynomial = DensePolynomial::<Fr>::rand(domain_size - 1, rng);
+            let offset = Fr::rand(rng);
+            let polynomial_evaluations = domain.fft(&random_polynomial.coeffs);
+            let polynomial_coset_evaluations = domain.coset_fft_with_offset(&random_polynomial.coeffs, offset);
+            for (i, x) in domain.elements().enumerate() {
+                assert_eq!(polynomial_evaluations[i], random_polynomial.evaluate(x));
+                assert_eq!(polynomial_coset_evaluations[i], random_polynomial.evaluate(offset * x));
+            }
+
+            assert_eq!(
+                random_polynomial,
+                DensePolynomial::from_coefficients_vec(domain.ifft(&polynomial_evaluations)),
+                "domain size = {}",
+                domain_size
+            );
+            assert_eq!(
+                random_polynomial,
+                DensePolynomial::from_coefficients_vec(
+                    domain.coset_ifft_with_offset(&polynomial_coset_evaluations, offset)
+                ),
+                "domain size = {}",
+                domain_size
+            );
+        }
+    }
+
+    /// Tests that FFTs over a caller-chosen coset agree with naive evaluation.
+    #[test]
+    fn test_coset_fft_with_offset() {
+        let rng = &mut thread_rng();
+
+        let degree = 1 << 5;
+        let random_polynomial = DensePolynomial::<Fr>::rand(degree - 1, rng);
+        let domain = EvaluationDomain::<Fr>::new(degree).unwrap();
+
+        let offset = Fr::rand(rng);
+        let coset_evaluations = domain.coset_fft_with_offset(&random_polynomial.coeffs, offset);
+        for (i, x) in domain.elements().enumerate() {
+            assert_eq!(coset_evaluations[i], random_polynomial.evaluate(offset * x));
+        }
+        assert_eq!(
+            random_polynomial,
+            DensePolynomial::from_coefficients_vec(domain.coset_ifft_with_offset(&coset_evaluations, offset))
+        );
+
+        // The multiplicative generator is the default coset offset.
+        assert_eq!(
+            domain.coset_fft(&random_polynomial.coeffs),
+            domain.coset_fft_with_offset(&random_polynomial.coeffs, Fr::multiplicative_generator())
+        );
+
+        // Dividing by the vanishing polynomial on the coset agrees with naive division.
+        let mut quotient_evaluations = coset_evaluations.clone();
+        domain.divide_by_vanishing_poly_on_coset_with_offset_in_place(&mut quotient_evaluations, offset);
+        for ((quotient, evaluation), x) in quotient_evaluations
+            .iter()
+            .zip(&coset_evaluations)
+            .zip(domain.elements())
+        {
+            let vanishing = domain.evaluate_vanishing_polynomial(offset * x);
+            assert_eq!(*quotient * vanishing, *evaluation);
+        }
+    }
 }
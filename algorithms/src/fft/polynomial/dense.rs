@@ -17,7 +17,7 @@
 //! A polynomial represented in coefficient form.
 
 use crate::fft::{DenseOrSparsePolynomial, EvaluationDomain, Evaluations};
-use snarkvm_fields::{Field, PrimeField};
+use snarkvm_fields::{batch_inversion, Field, PrimeField};
 use snarkvm_utilities::{errors::SerializationError, serialize::*};
 
 use rand::Rng;
@@ -29,6 +29,10 @@ use std::{
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// The divisor degree and quotient length below which schoolbook division is used,
+/// instead of division by Newton iteration.
+const FAST_DIVISION_THRESHOLD: usize = 64;
+
 /// Stores a polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
 pub struct DensePolynomial<F: Field> {
@@ -123,8 +127,7 @@ impl<F: Field> DensePolynomial<F> {
     }
 
     /// Perform a naive n^2 multiplication of `self` by `other`.
-    #[cfg(test)]
-    fn naive_mul(&self, other: &Self) -> Self {
+    pub fn naive_mul(&self, other: &Self) -> Self {
         if self.is_zero() || other.is_zero() {
             DensePolynomial::zero()
         } else {
@@ -137,9 +140,144 @@ impl<F: Field> DensePolynomial<F> {
             DensePolynomial::from_coefficients_vec(result)
         }
     }
+
+    /// Returns the monic greatest common divisor of `self` and `other`,
+    /// or the zero polynomial if both are zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a: DenseOrSparsePolynomial<F> = self.clone().into();
+        let mut b: DenseOrSparsePolynomial<F> = other.clone().into();
+        while !b.is_zero() {
+            let (_, remainder) = a.divide_with_q_and_r(&b).expect("division failed");
+            a = b;
+            b = remainder.into();
+        }
+
+        let mut gcd: DensePolynomial<F> = a.into();
+        if let Some(leading_coeff_inv) = gcd.coeffs.last().and_then(|c| c.inverse()) {
+            gcd.coeffs.iter_mut().for_each(|c| *c *= &leading_coeff_inv);
+        }
+        gcd
+    }
+
+    /// Returns the formal derivative of `self`.
+    fn derivative(&self) -> Self {
+        let coeffs = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, coeff)| F::from(i as u128) * coeff)
+            .collect();
+        Self::from_coefficients_vec(coeffs)
+    }
+
+    /// Returns `self` modulo `x^n`.
+    fn truncate_mod_x_n(&self, n: usize) -> Self {
+        Self::from_coefficients_slice(&self.coeffs[..std::cmp::min(n, self.coeffs.len())])
+    }
 }
 
 impl<F: PrimeField> DensePolynomial<F> {
+    /// Performs O(nlogn) multiplication of `self` by `other`, over the smallest
+    /// (possibly mixed-radix) domain that fits their product.
+    pub fn fft_mul(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            DensePolynomial::zero()
+        } else {
+            let domain = EvaluationDomain::new_mixed_radix(self.coeffs.len() + other.coeffs.len() - 1)
+                .expect("field is not smooth enough to construct domain");
+            let mut self_evals = self.evaluate_over_domain_by_ref(domain);
+            let other_evals = other.evaluate_over_domain_by_ref(domain);
+            self_evals *= &other_evals;
+            self_evals.interpolate()
+        }
+    }
+
+    /// Divide `self` by `divisor`, and returns the quotient and remainder.
+    /// Large divisions are computed by Newton iteration, in O(nlogn).
+    /// Panics if `divisor` is zero.
+    pub fn divide_with_q_and_r(&self, divisor: &Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            panic!("Dividing by zero polynomial")
+        } else if self.is_zero()
+            || self.degree() < divisor.degree()
+            || divisor.degree() < FAST_DIVISION_THRESHOLD
+            || self.degree() - divisor.degree() < FAST_DIVISION_THRESHOLD
+        {
+            DenseOrSparsePolynomial::from(self).divide_with_q_and_r(&divisor.into())
+        } else {
+            // The reversed quotient is the reversed dividend over the reversed divisor, modulo x^quotient_len.
+            let quotient_len = self.degree() - divisor.degree() + 1;
+            let reversed_divisor = Self::from_coefficients_vec(divisor.coeffs.iter().rev().copied().collect());
+            let reversed_dividend = Self::from_coefficients_vec(self.coeffs.iter().rev().copied().collect());
+            let inverse = reversed_divisor.inverse_mod_x_n(quotient_len)?;
+
+            let mut quotient = reversed_dividend
+                .truncate_mod_x_n(quotient_len)
+                .fft_mul(&inverse)
+                .truncate_mod_x_n(quotient_len)
+                .coeffs;
+            quotient.resize(quotient_len, F::zero());
+            quotient.reverse();
+            let quotient = Self::from_coefficients_vec(quotient);
+
+            let remainder = Self::from_coefficients_vec((self - &divisor.fft_mul(&quotient)).coeffs);
+            Some((quotient, remainder))
+        }
+    }
+
+    /// Returns the inverse of `self` modulo `x^n`, computed by Newton iteration,
+    /// or `None` if the constant coefficient of `self` is zero.
+    fn inverse_mod_x_n(&self, n: usize) -> Option<Self> {
+        let mut inverse = Self::from_coefficients_vec(vec![self.coeffs.first()?.inverse()?]);
+        let mut precision = 1;
+        while precision < n {
+            precision = std::cmp::min(2 * precision, n);
+
+            // inverse = inverse * (2 - self * inverse) mod x^precision
+            let mut error = self
+                .truncate_mod_x_n(precision)
+                .fft_mul(&inverse)
+                .truncate_mod_x_n(precision);
+            error.coeffs.iter_mut().for_each(|c| *c = -*c);
+            error.coeffs[0] += F::one().double();
+            inverse = inverse.fft_mul(&error).truncate_mod_x_n(precision);
+        }
+        Some(inverse)
+    }
+
+    /// Evaluates `self` at each of the given `points`, in O(nlog^2n) using a subproduct tree.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        if points.is_empty() {
+            return vec![];
+        }
+        let mut evaluations = Vec::with_capacity(points.len());
+        SubproductTree::new(points).evaluate(self, &mut evaluations);
+        evaluations
+    }
+
+    /// Returns the unique polynomial of degree less than `points.len()` that passes through
+    /// each of the given `(x, y)` points, in O(nlog^2n) using a subproduct tree.
+    /// Returns `None` if two of the points have the same `x`.
+    pub fn interpolate(points: &[(F, F)]) -> Option<Self> {
+        if points.is_empty() {
+            return Some(Self::zero());
+        }
+        let xs: Vec<F> = points.iter().map(|(x, _)| *x).collect();
+        let tree = SubproductTree::new(&xs);
+
+        // The Lagrange weights are y_i / M'(x_i), where M is the product of all (X - x_i).
+        let mut weights = Vec::with_capacity(points.len());
+        tree.evaluate(&tree.product.derivative(), &mut weights);
+        if weights.iter().any(|weight| weight.is_zero()) {
+            return None;
+        }
+        batch_inversion(&mut weights);
+        weights.iter_mut().zip(points).for_each(|(weight, (_, y))| *weight *= y);
+
+        Some(tree.interpolate(&weights))
+    }
+
     /// Multiply `self` by the vanishing polynomial for the domain `domain`.
     /// Returns the quotient and remainder of the division.
     pub fn mul_by_vanishing_poly(&self, domain: EvaluationDomain<F>) -> DensePolynomial<F> {
@@ -348,18 +486,8 @@ impl<'a, 'b, F: PrimeField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<
     type Output = DensePolynomial<F>;
 
     #[inline]
-    #[allow(clippy::suspicious_arithmetic_impl)]
     fn mul(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
-        if self.is_zero() || other.is_zero() {
-            DensePolynomial::zero()
-        } else {
-            let domain = EvaluationDomain::new(self.coeffs.len() + other.coeffs.len())
-                .expect("field is not smooth enough to construct domain");
-            let mut self_evals = self.evaluate_over_domain_by_ref(domain);
-            let other_evals = other.evaluate_over_domain_by_ref(domain);
-            self_evals *= &other_evals;
-            self_evals.interpolate()
-        }
+        self.fft_mul(other)
     }
 }
 
@@ -377,6 +505,65 @@ impl<F: Field> DerefMut for DensePolynomial<F> {
     }
 }
 
+/// A binary tree of the products of `(X - x_i)` over contiguous ranges of points,
+/// used for multipoint evaluation and interpolation.
+struct SubproductTree<F: PrimeField> {
+    /// The product of `(X - x_i)` over the points below this node.
+    product: DensePolynomial<F>,
+    /// The left and right subtrees, or `None` for a leaf.
+    children: Option<Box<(SubproductTree<F>, SubproductTree<F>)>>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    /// Constructs the subproduct tree for a nonempty list of points.
+    fn new(points: &[F]) -> Self {
+        if points.len() == 1 {
+            Self {
+                product: DensePolynomial::from_coefficients_vec(vec![-points[0], F::one()]),
+                children: None,
+            }
+        } else {
+            let (left, right) = points.split_at(points.len() / 2);
+            let (left, right) = (Self::new(left), Self::new(right));
+            Self {
+                product: &left.product * &right.product,
+                children: Some(Box::new((left, right))),
+            }
+        }
+    }
+
+    /// Returns the number of points below this node.
+    fn num_points(&self) -> usize {
+        self.product.degree()
+    }
+
+    /// Appends the evaluations of `polynomial` at the points below this node to `evaluations`.
+    fn evaluate(&self, polynomial: &DensePolynomial<F>, evaluations: &mut Vec<F>) {
+        let (_, remainder) = polynomial.divide_with_q_and_r(&self.product).expect("division failed");
+        match &self.children {
+            None => evaluations.push(remainder.coeffs.first().copied().unwrap_or_else(F::zero)),
+            Some(children) => {
+                children.0.evaluate(&remainder, evaluations);
+                children.1.evaluate(&remainder, evaluations);
+            }
+        }
+    }
+
+    /// Returns the sum of `weights[i] * product / (X - x_i)` over the points below this node.
+    fn interpolate(&self, weights: &[F]) -> DensePolynomial<F> {
+        match &self.children {
+            None => DensePolynomial::from_coefficients_vec(vec![weights[0]]),
+            Some(children) => {
+                let (left, right) = (&children.0, &children.1);
+                let (left_weights, right_weights) = weights.split_at(left.num_points());
+                let left_sum = &left.interpolate(left_weights) * &right.product;
+                let right_sum = &right.interpolate(right_weights) * &left.product;
+                DensePolynomial::from_coefficients_vec((&left_sum + &right_sum).coeffs)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::fft::polynomial::*;
@@ -384,7 +571,7 @@ mod tests {
     use snarkvm_fields::{Field, One, Zero};
     use snarkvm_utilities::rand::UniformRand;
 
-    use rand::thread_rng;
+    use rand::{thread_rng, Rng};
 
     #[test]
     fn double_polynomials_random() {
@@ -500,6 +687,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn divide_polynomials_fast_random() {
+        let rng = &mut thread_rng();
+        for _ in 0..10 {
+            let divisor_degree = rng.gen_range(64..200);
+            let dividend_degree = divisor_degree + rng.gen_range(64..200);
+            let dividend = DensePolynomial::<Fr>::rand(dividend_degree, rng);
+            let divisor = DensePolynomial::<Fr>::rand(divisor_degree, rng);
+
+            let (quotient, remainder) = dividend.divide_with_q_and_r(&divisor).unwrap();
+            assert!(remainder.is_zero() || remainder.degree() < divisor.degree());
+            assert_eq!(dividend, &(&divisor * &quotient) + &remainder);
+            assert_eq!(
+                Some((quotient, remainder)),
+                DenseOrSparsePolynomial::divide_with_q_and_r(&(&dividend).into(), &(&divisor).into())
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_many_random() {
+        let rng = &mut thread_rng();
+        for &(degree, num_points) in &[(0, 1), (10, 0), (10, 5), (20, 20), (50, 130), (300, 257)] {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let points: Vec<Fr> = (0..num_points).map(|_| Fr::rand(rng)).collect();
+            let expected: Vec<Fr> = points.iter().map(|point| p.evaluate(*point)).collect();
+            assert_eq!(p.evaluate_many(&points), expected);
+        }
+    }
+
+    #[test]
+    fn interpolate_random() {
+        let rng = &mut thread_rng();
+        for num_points in (1..40).chain(vec![129, 300]) {
+            let p = DensePolynomial::<Fr>::rand(num_points - 1, rng);
+            let points: Vec<(Fr, Fr)> = (0..num_points)
+                .map(|_| {
+                    let x = Fr::rand(rng);
+                    (x, p.evaluate(x))
+                })
+                .collect();
+            assert_eq!(DensePolynomial::interpolate(&points), Some(p));
+        }
+        assert_eq!(DensePolynomial::<Fr>::interpolate(&[]), Some(DensePolynomial::zero()));
+
+        // Interpolating through fewer points than the degree of the polynomial loses it.
+        let p = DensePolynomial::<Fr>::rand(10, rng);
+        let points: Vec<(Fr, Fr)> = (0..5u64).map(|x| (Fr::from(x), p.evaluate(Fr::from(x)))).collect();
+        let q = DensePolynomial::interpolate(&points).unwrap();
+        assert!(q.degree() < 5);
+        assert_ne!(p, q);
+        for (x, y) in points {
+            assert_eq!(q.evaluate(x), y);
+        }
+
+        // Repeated x coordinates have no interpolant.
+        let points = [(Fr::one(), Fr::one()), (Fr::zero(), Fr::one()), (Fr::one(), Fr::zero())];
+        assert_eq!(DensePolynomial::interpolate(&points), None);
+    }
+
+    #[test]
+    fn gcd_random() {
+        let rng = &mut thread_rng();
+        for degree in 0..20 {
+            // Random polynomials a and b are coprime with high probability.
+            let a = DensePolynomial::<Fr>::rand(degree + 1, rng);
+            let b = DensePolynomial::<Fr>::rand(degree + 2, rng);
+            let c = DensePolynomial::<Fr>::rand(degree, rng);
+
+            let leading_coeff_inv = c.coeffs.last().unwrap().inverse().unwrap();
+            let monic_c =
+                DensePolynomial::from_coefficients_vec(c.coeffs.iter().map(|x| *x * leading_coeff_inv).collect());
+
+            assert_eq!((&a * &c).gcd(&(&b * &c)), monic_c);
+            assert_eq!(c.gcd(&DensePolynomial::zero()), monic_c);
+            assert_eq!(DensePolynomial::zero().gcd(&c), monic_c);
+        }
+        assert!(DensePolynomial::<Fr>::zero().gcd(&DensePolynomial::zero()).is_zero());
+    }
+
     #[test]
     fn mul_by_vanishing_poly() {
         let rng = &mut thread_rng();
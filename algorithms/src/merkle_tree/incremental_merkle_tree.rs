@@ -0,0 +1,353 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{MerklePath, MerkleTreeDigest},
+    traits::{MerkleParameters, CRH},
+};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::{
+    collections::BTreeMap,
+    io::{Read, Result as IoResult, Write},
+    sync::Arc,
+};
+
+/// The authentication data of a leaf that is tracked by an incremental Merkle tree.
+#[derive(Clone, Debug)]
+struct TrackedLeaf<P: MerkleParameters> {
+    /// The hash of the tracked leaf.
+    leaf_hash: MerkleTreeDigest<P>,
+
+    /// For each level, the sibling on the path from the leaf to the root, once the sibling subtree is complete.
+    siblings: Vec<Option<MerkleTreeDigest<P>>>,
+}
+
+/// An append-only Merkle tree that only stores its frontier, that is, the roots of the complete
+/// subtrees on the path to the next leaf, together with the authentication data of tracked leaves.
+///
+/// Appending a leaf costs `O(DEPTH)` hashes, and the roots and paths of the tree are the ones
+/// a `MerkleTree` built from the same leaves would produce.
+#[derive(Clone, Debug)]
+pub struct IncrementalMerkleTree<P: MerkleParameters> {
+    /// The number of leaves appended to the tree.
+    num_leaves: usize,
+
+    /// For each level, the root of the complete left subtree on the path to the next leaf, if any.
+    frontier: Vec<Option<MerkleTreeDigest<P>>>,
+
+    /// For each level, the root of an empty subtree, starting with the hash of an empty leaf.
+    empty_hashes: Vec<MerkleTreeDigest<P>>,
+
+    /// The roots recorded at each checkpoint, from oldest to newest.
+    roots: Vec<MerkleTreeDigest<P>>,
+
+    /// The tracked leaves, indexed by their position in the tree.
+    tracked_leaves: BTreeMap<usize, TrackedLeaf<P>>,
+
+    /// The Merkle tree parameters (e.g. the hash function).
+    parameters: Arc<P>,
+}
+
+impl<P: MerkleParameters> IncrementalMerkleTree<P> {
+    pub const DEPTH: u8 = P::DEPTH as u8;
+
+    /// Returns an empty incremental Merkle tree.
+    pub fn new(parameters: Arc<P>) -> Result<Self, MerkleError> {
+        let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+        let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+        let mut empty_hashes = Vec::with_capacity(P::DEPTH);
+        let mut empty_hash = parameters.hash_empty()?;
+        for _ in 0..P::DEPTH {
+            empty_hashes.push(empty_hash);
+            empty_hash = parameters.hash_inner_node(&empty_hash, &empty_hash, &mut buffer)?;
+        }
+
+        Ok(Self {
+            num_leaves: 0,
+            frontier: vec![None; P::DEPTH + 1],
+            empty_hashes,
+            roots: vec![],
+            tracked_leaves: BTreeMap::new(),
+            parameters,
+        })
+    }
+
+    /// Returns the number of leaves appended to the tree.
+    #[inline]
+    pub fn num_leaves(&self) -> usize {
+        self.num_leaves
+    }
+
+    /// Appends the given leaf to the tree, and returns its index.
+    pub fn append<L: ToBytes>(&mut self, leaf: &L) -> Result<usize, MerkleError> {
+        self.append_leaf(leaf, false)
+    }
+
+    /// Appends the given leaf to the tree and keeps its path up to date, and returns its index.
+    pub fn append_tracked<L: ToBytes>(&mut self, leaf: &L) -> Result<usize, MerkleError> {
+        self.append_leaf(leaf, true)
+    }
+
+    /// Stops keeping the path of the leaf at the given index up to date.
+    /// Returns `false` if the leaf was not tracked.
+    pub fn untrack(&mut self, index: usize) -> bool {
+        self.tracked_leaves.remove(&index).is_some()
+    }
+
+    /// Returns the indices of the tracked leaves, in increasing order.
+    pub fn tracked_indices(&self) -> impl Iterator<Item = &usize> {
+        self.tracked_leaves.keys()
+    }
+
+    /// Returns the current root of the tree.
+    pub fn root(&self) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+        let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+        let tree_depth = self.tree_depth();
+        let mut current_hash = match self.frontier[tree_depth] {
+            Some(subtree_root) => subtree_root,
+            None => self.next_subtree_root(tree_depth, &mut buffer)?,
+        };
+        for _ in tree_depth..P::DEPTH {
+            current_hash = self
+                .parameters
+                .hash_inner_node(&current_hash, &self.empty_hashes[0], &mut buffer)?;
+        }
+
+        Ok(current_hash)
+    }
+
+    /// Records the current root of the tree as a historical root, and returns it.
+    pub fn checkpoint(&mut self) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let root = self.root()?;
+        self.roots.push(root);
+        Ok(root)
+    }
+
+    /// Returns the roots recorded at each checkpoint, from oldest to newest.
+    #[inline]
+    pub fn roots(&self) -> &[MerkleTreeDigest<P>] {
+        &self.roots
+    }
+
+    /// Returns `true` if the given root was recorded at a checkpoint.
+    pub fn contains_root(&self, root: &MerkleTreeDigest<P>) -> bool {
+        self.roots.contains(root)
+    }
+
+    /// Returns the path from the tracked leaf at the given index to the current root.
+    pub fn generate_proof<L: ToBytes>(&self, index: usize, leaf: &L) -> Result<MerklePath<P>, MerkleError> {
+        let prove_time = start_timer!(|| "IncrementalMerkleTree::generate_proof");
+
+        let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+        let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+        // Check that the given index corresponds to a tracked leaf.
+        let tracked_leaf = match self.tracked_leaves.get(&index) {
+            Some(tracked_leaf) if tracked_leaf.leaf_hash == self.parameters.hash_leaf(leaf, &mut buffer)? => {
+                tracked_leaf
+            }
+            _ => return Err(MerkleError::IncorrectLeafIndex(index)),
+        };
+
+        // Iterate from the leaf up to the root of the smallest subtree that holds every leaf.
+        let tree_depth = self.tree_depth();
+        let mut path = Vec::with_capacity(P::DEPTH);
+        let mut current_hash = tracked_leaf.leaf_hash;
+        for level in 0..tree_depth {
+            let sibling_hash = match tracked_leaf.siblings[level] {
+                Some(sibling_hash) => sibling_hash,
+                // The sibling on the right is incomplete, so it is either empty or holds the next leaf.
+                None if self.num_leaves <= ((index >> level) | 1) << level => self.empty_hashes[level],
+                None => self.next_subtree_root(level, &mut buffer)?,
+            };
+
+            let (left, right) = if (index >> level) & 1 == 0 {
+                (current_hash, sibling_hash)
+            } else {
+                (sibling_hash, current_hash)
+            };
+            path.push((left, right));
+            current_hash = self.parameters.hash_inner_node(&left, &right, &mut buffer)?;
+        }
+
+        // Pad the path up to the depth of the tree, as `MerkleTree` does.
+        for _ in tree_depth..P::DEPTH {
+            path.push((current_hash, self.empty_hashes[0]));
+            current_hash = self
+                .parameters
+                .hash_inner_node(&current_hash, &self.empty_hashes[0], &mut buffer)?;
+        }
+
+        end_timer!(prove_time);
+
+        Ok(MerklePath {
+            parameters: self.parameters.clone(),
+            path,
+        })
+    }
+
+    /// Reads an incremental Merkle tree with the given parameters.
+    pub fn read_le<R: Read>(parameters: Arc<P>, mut reader: R) -> Result<Self, MerkleError> {
+        let mut tree = Self::new(parameters)?;
+
+        tree.num_leaves = u64::read_le(&mut reader)? as usize;
+        if tree.num_leaves > Self::capacity() {
+            return Err(MerkleError::InvalidTreeDepth(P::DEPTH + 1, P::DEPTH));
+        }
+        for level in 0..=P::DEPTH {
+            if tree.num_leaves.checked_shr(level as u32).unwrap_or(0) & 1 == 1 {
+                tree.frontier[level] = Some(FromBytes::read_le(&mut reader)?);
+            }
+        }
+
+        let num_roots = u32::read_le(&mut reader)?;
+        for _ in 0..num_roots {
+            tree.roots.push(FromBytes::read_le(&mut reader)?);
+        }
+
+        let num_tracked_leaves = u32::read_le(&mut reader)?;
+        for _ in 0..num_tracked_leaves {
+            let index = u64::read_le(&mut reader)? as usize;
+            let leaf_hash = FromBytes::read_le(&mut reader)?;
+            let mut siblings = Vec::with_capacity(P::DEPTH);
+            for _ in 0..P::DEPTH {
+                let is_complete = bool::read_le(&mut reader)?;
+                siblings.push(if is_complete {
+                    Some(FromBytes::read_le(&mut reader)?)
+                } else {
+                    None
+                });
+            }
+            tree.tracked_leaves.insert(index, TrackedLeaf { leaf_hash, siblings });
+        }
+
+        Ok(tree)
+    }
+
+    fn append_leaf<L: ToBytes>(&mut self, leaf: &L, track: bool) -> Result<usize, MerkleError> {
+        let index = self.num_leaves;
+        if index >= Self::capacity() {
+            return Err(MerkleError::InvalidTreeDepth(P::DEPTH + 1, P::DEPTH));
+        }
+
+        let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+        let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+        let leaf_hash = self.parameters.hash_leaf(leaf, &mut buffer)?;
+
+        if track {
+            // The siblings on the left are the complete subtrees of the frontier.
+            let siblings = (0..P::DEPTH)
+                .map(|level| {
+                    if (index >> level) & 1 == 1 {
+                        self.frontier[level]
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            self.tracked_leaves.insert(index, TrackedLeaf { leaf_hash, siblings });
+        }
+
+        // Merge the complete subtrees of the frontier with the new leaf, from the bottom up.
+        let mut current_hash = leaf_hash;
+        for level in 0..=P::DEPTH {
+            if level == P::DEPTH || (index >> level) & 1 == 0 {
+                self.frontier[level] = Some(current_hash);
+                break;
+            }
+
+            // The new leaf completes the subtree at this level, which is the right sibling of some tracked leaves.
+            for (tracked_index, tracked_leaf) in self.tracked_leaves.iter_mut() {
+                if (tracked_index >> level) ^ 1 == index >> level {
+                    tracked_leaf.siblings[level] = Some(current_hash);
+                }
+            }
+
+            let left = match self.frontier[level].take() {
+                Some(left) => left,
+                None => {
+                    return Err(MerkleError::Message(
+                        "missing frontier of incremental Merkle tree".into(),
+                    ));
+                }
+            };
+            current_hash = self.parameters.hash_inner_node(&left, &current_hash, &mut buffer)?;
+        }
+        self.num_leaves += 1;
+
+        Ok(index)
+    }
+
+    /// Returns the maximum number of leaves in the tree.
+    fn capacity() -> usize {
+        1usize.checked_shl(P::DEPTH as u32).unwrap_or(usize::MAX)
+    }
+
+    /// Returns the depth of the smallest subtree that holds every leaf, as in `MerkleTree`.
+    fn tree_depth(&self) -> usize {
+        self.num_leaves.next_power_of_two().trailing_zeros() as usize
+    }
+
+    /// Returns the root of the incomplete subtree at the given level that holds the next leaf.
+    fn next_subtree_root(&self, level: usize, buffer: &mut [u8]) -> Result<MerkleTreeDigest<P>, MerkleError> {
+        let mut current_hash = self.empty_hashes[0];
+        for (left, empty_hash) in self.frontier.iter().zip(&self.empty_hashes).take(level) {
+            current_hash = match left {
+                Some(left) => self.parameters.hash_inner_node(left, &current_hash, buffer)?,
+                None => self.parameters.hash_inner_node(&current_hash, empty_hash, buffer)?,
+            };
+        }
+        Ok(current_hash)
+    }
+}
+
+impl<P: MerkleParameters> ToBytes for IncrementalMerkleTree<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.num_leaves as u64).write_le(&mut writer)?;
+        for subtree_root in self.frontier.iter().flatten() {
+            subtree_root.write_le(&mut writer)?;
+        }
+
+        (self.roots.len() as u32).write_le(&mut writer)?;
+        for root in &self.roots {
+            root.write_le(&mut writer)?;
+        }
+
+        (self.tracked_leaves.len() as u32).write_le(&mut writer)?;
+        for (index, tracked_leaf) in &self.tracked_leaves {
+            (*index as u64).write_le(&mut writer)?;
+            tracked_leaf.leaf_hash.write_le(&mut writer)?;
+            for sibling in &tracked_leaf.siblings {
+                match sibling {
+                    Some(sibling) => {
+                        true.write_le(&mut writer)?;
+                        sibling.write_le(&mut writer)?;
+                    }
+                    None => false.write_le(&mut writer)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
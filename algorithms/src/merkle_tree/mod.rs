@@ -16,12 +16,18 @@
 
 #![allow(clippy::module_inception)]
 
+pub mod incremental_merkle_tree;
+pub use incremental_merkle_tree::*;
+
 pub mod merkle_path;
 pub use merkle_path::*;
 
 pub mod merkle_tree;
 pub use merkle_tree::*;
 
+pub mod sparse_merkle_tree;
+pub use sparse_merkle_tree::*;
+
 #[cfg(test)]
 pub mod tests;
 
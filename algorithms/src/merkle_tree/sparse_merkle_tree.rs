@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::MerkleTreeDigest,
+    traits::{MerkleParameters, CRH},
+};
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+use std::{collections::HashMap, sync::Arc};
+
+/// Stores the siblings of the path (in order) from the leaf of a key to the root of a sparse Merkle tree.
+/// The same path proves either that the key is in the tree, or that it is not.
+#[derive(Clone, Debug)]
+pub struct SparseMerklePath<P: MerkleParameters> {
+    pub parameters: Arc<P>,
+    pub siblings: Vec<MerkleTreeDigest<P>>,
+}
+
+impl<P: MerkleParameters> SparseMerklePath<P> {
+    /// Returns `true` if the path proves that the given key is in the tree with the given root.
+    pub fn verify_membership<K: ToBytes>(&self, root_hash: &MerkleTreeDigest<P>, key: &K) -> Result<bool, MerkleError> {
+        let key_hash = hash_key(&*self.parameters, key)?;
+        self.verify(root_hash, &key_hash, &key_hash)
+    }
+
+    /// Returns `true` if the path proves that the given key is not in the tree with the given root.
+    pub fn verify_non_membership<K: ToBytes>(
+        &self,
+        root_hash: &MerkleTreeDigest<P>,
+        key: &K,
+    ) -> Result<bool, MerkleError> {
+        let key_hash = hash_key(&*self.parameters, key)?;
+        self.verify(root_hash, &key_hash, &self.parameters.hash_empty()?)
+    }
+
+    /// Returns `true` if the given leaf at the position of the given key hash leads to the given root.
+    fn verify(
+        &self,
+        root_hash: &MerkleTreeDigest<P>,
+        key_hash: &MerkleTreeDigest<P>,
+        leaf_hash: &MerkleTreeDigest<P>,
+    ) -> Result<bool, MerkleError> {
+        if self.siblings.len() != P::DEPTH {
+            return Ok(false);
+        }
+
+        let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+        let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+        let mut current_hash = *leaf_hash;
+        for (sibling_hash, is_right) in self.siblings.iter().zip(key_position::<P>(key_hash)?) {
+            current_hash = if is_right {
+                self.parameters
+                    .hash_inner_node(sibling_hash, &current_hash, &mut buffer)?
+            } else {
+                self.parameters
+                    .hash_inner_node(&current_hash, sibling_hash, &mut buffer)?
+            };
+        }
+
+        Ok(root_hash == &current_hash)
+    }
+}
+
+/// A Merkle tree of depth `P::DEPTH` holding a set of keys, where each key is stored at the leaf
+/// given by the first `P::DEPTH` bits of its hash, and every other leaf is empty.
+///
+/// Two keys with the same leaf cannot both be in the tree, so the depth should make such
+/// collisions unlikely for the expected number of keys.
+#[derive(Clone, Debug)]
+pub struct SparseMerkleTree<P: MerkleParameters> {
+    /// The non-empty nodes, indexed by their level and by the position bits of the levels above them.
+    nodes: HashMap<(usize, Vec<bool>), MerkleTreeDigest<P>>,
+
+    /// For each level, the root of an empty subtree, starting with the hash of an empty leaf.
+    empty_hashes: Vec<MerkleTreeDigest<P>>,
+
+    /// The number of keys in the tree.
+    num_keys: usize,
+
+    /// The Merkle tree parameters (e.g. the hash function).
+    parameters: Arc<P>,
+}
+
+impl<P: MerkleParameters> SparseMerkleTree<P> {
+    pub const DEPTH: u8 = P::DEPTH as u8;
+
+    /// Returns an empty sparse Merkle tree.
+    pub fn new(parameters: Arc<P>) -> Result<Self, MerkleError> {
+        let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+        let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+        let mut empty_hashes = Vec::with_capacity(P::DEPTH + 1);
+        let mut empty_hash = parameters.hash_empty()?;
+        for _ in 0..P::DEPTH {
+            empty_hashes.push(empty_hash);
+            empty_hash = parameters.hash_inner_node(&empty_hash, &empty_hash, &mut buffer)?;
+        }
+        empty_hashes.push(empty_hash);
+
+        Ok(Self {
+            nodes: HashMap::new(),
+            empty_hashes,
+            num_keys: 0,
+            parameters,
+        })
+    }
+
+    #[inline]
+    pub fn root(&self) -> MerkleTreeDigest<P> {
+        self.node(P::DEPTH, &[])
+    }
+
+    /// Returns the number of keys in the tree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.num_keys
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.num_keys == 0
+    }
+
+    /// Returns `true` if the given key is in the tree.
+    pub fn contains<K: ToBytes>(&self, key: &K) -> Result<bool, MerkleError> {
+        let key_hash = hash_key(&*self.parameters, key)?;
+        let position = key_position::<P>(&key_hash)?;
+
+        Ok(self.nodes.get(&(0, position)) == Some(&key_hash))
+    }
+
+    /// Inserts the given key in the tree. Returns `false` if the key was already in the tree.
+    ///
+    /// A key that hashes to the empty leaf (e.g. an all-zero key, with a Pedersen hash) is rejected,
+    /// as it could not be told apart from the absence of a key.
+    pub fn insert<K: ToBytes>(&mut self, key: &K) -> Result<bool, MerkleError> {
+        let key_hash = hash_key(&*self.parameters, key)?;
+        if key_hash == self.empty_hashes[0] {
+            return Err(MerkleError::InvalidLeaf);
+        }
+        let position = key_position::<P>(&key_hash)?;
+
+        match self.nodes.get(&(0, position.clone())) {
+            Some(leaf_hash) if leaf_hash == &key_hash => return Ok(false),
+            Some(_) => return Err(MerkleError::KeyCollision),
+            None => {}
+        }
+
+        self.update_path(&position, Some(key_hash))?;
+        self.num_keys += 1;
+        Ok(true)
+    }
+
+    /// Removes the given key from the tree. Returns `false` if the key was not in the tree.
+    pub fn remove<K: ToBytes>(&mut self, key: &K) -> Result<bool, MerkleError> {
+        let key_hash = hash_key(&*self.parameters, key)?;
+        let position = key_position::<P>(&key_hash)?;
+
+        if self.nodes.get(&(0, position.clone())) != Some(&key_hash) {
+            return Ok(false);
+        }
+
+        self.update_path(&position, None)?;
+        self.num_keys -= 1;
+        Ok(true)
+    }
+
+    /// Returns the path from the leaf of the given key to the root, which proves either that
+    /// the key is in the tree, or that it is not.
+    pub fn generate_proof<K: ToBytes>(&self, key: &K) -> Result<SparseMerklePath<P>, MerkleError> {
+        let prove_time = start_timer!(|| "SparseMerkleTree::generate_proof");
+
+        let key_hash = hash_key(&*self.parameters, key)?;
+        let mut position = key_position::<P>(&key_hash)?;
+
+        let mut siblings = Vec::with_capacity(P::DEPTH);
+        for level in 0..P::DEPTH {
+            position[level] = !position[level];
+            siblings.push(self.node(level, &position[level..]));
+            position[level] = !position[level];
+        }
+
+        end_timer!(prove_time);
+
+        Ok(SparseMerklePath {
+            parameters: self.parameters.clone(),
+            siblings,
+        })
+    }
+
+    /// Returns the node at the given level and position, which is empty unless it was stored.
+    fn node(&self, level: usize, position: &[bool]) -> MerkleTreeDigest<P> {
+        match self.nodes.get(&(level, position.to_vec())) {
+            Some(node) => *node,
+            None => self.empty_hashes[level],
+        }
+    }
+
+    /// Sets the leaf at the given position, and updates the nodes on its path to the root.
+    fn update_path(&mut self, position: &[bool], leaf_hash: Option<MerkleTreeDigest<P>>) -> Result<(), MerkleError> {
+        let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+        let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+        let mut position = position.to_vec();
+        let mut current_hash = leaf_hash.unwrap_or(self.empty_hashes[0]);
+        for level in 0..=P::DEPTH {
+            // Only the non-empty nodes are stored.
+            if current_hash == self.empty_hashes[level] {
+                self.nodes.remove(&(level, position[level..].to_vec()));
+            } else {
+                self.nodes.insert((level, position[level..].to_vec()), current_hash);
+            }
+
+            if level < P::DEPTH {
+                let is_right = position[level];
+                position[level] = !is_right;
+                let sibling_hash = self.node(level, &position[level..]);
+                position[level] = is_right;
+
+                current_hash = if is_right {
+                    self.parameters
+                        .hash_inner_node(&sibling_hash, &current_hash, &mut buffer)?
+                } else {
+                    self.parameters
+                        .hash_inner_node(&current_hash, &sibling_hash, &mut buffer)?
+                };
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the hash of the given key, which is also the hash of its leaf.
+fn hash_key<P: MerkleParameters, K: ToBytes>(parameters: &P, key: &K) -> Result<MerkleTreeDigest<P>, MerkleError> {
+    let hash_input_size_in_bytes = (P::H::INPUT_SIZE_BITS / 8) * 2;
+    let mut buffer = vec![0u8; hash_input_size_in_bytes];
+
+    parameters.hash_leaf(key, &mut buffer)
+}
+
+/// Returns the position of the leaf of a key given its hash, as the first `P::DEPTH` little-endian bits of the hash.
+/// The bit at each level is set if the path from the leaf to the root goes through the right child.
+fn key_position<P: MerkleParameters>(key_hash: &MerkleTreeDigest<P>) -> Result<Vec<bool>, MerkleError> {
+    let key_hash_bytes = to_bytes_le![key_hash]?;
+    if key_hash_bytes.len() * 8 < P::DEPTH {
+        return Err(MerkleError::InvalidTreeDepth(P::DEPTH, key_hash_bytes.len() * 8));
+    }
+
+    Ok(key_hash_bytes
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+        .take(P::DEPTH)
+        .collect())
+}
@@ -19,7 +19,8 @@ use std::sync::Arc;
 use crate::{
     crh::{PedersenCRH, PedersenCompressedCRH},
     define_merkle_tree_parameters,
-    merkle_tree::MerkleTree,
+    merkle_tree::{IncrementalMerkleTree, MerkleTree, SparseMerkleTree},
+    errors::MerkleError,
     traits::{crh::CRH, merkle_tree::LoadableMerkleParameters},
 };
 use snarkvm_utilities::{to_bytes_le, ToBytes};
@@ -169,6 +170,174 @@ fn run_padded_merkle_tree_matches_hashing_test<P: LoadableMerkleParameters>() {
     assert_eq!(merkle_tree_root, expected_root);
 }
 
+/// Appends leaves to an incremental Merkle tree, and checks its roots and the paths of its tracked leaves
+/// against the ones of a Merkle tree built from the same leaves.
+fn run_incremental_merkle_tree_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+
+    let mut incremental_tree = IncrementalMerkleTree::<P>::new(parameters.clone()).unwrap();
+    let empty_tree = MerkleTree::<P>::new(parameters.clone(), &Vec::<[u8; 8]>::new()).unwrap();
+    assert_eq!(empty_tree.root(), incremental_tree.root().unwrap());
+
+    let mut leaves = vec![];
+    for i in 0..10u8 {
+        leaves.push([i, i, i, i, i, i, i, i]);
+    }
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        // Track every other leaf.
+        let index = if i % 2 == 0 {
+            incremental_tree.append_tracked(leaf).unwrap()
+        } else {
+            incremental_tree.append(leaf).unwrap()
+        };
+        assert_eq!(i, index);
+        assert_eq!(i + 1, incremental_tree.num_leaves());
+
+        let tree = MerkleTree::<P>::new(parameters.clone(), &leaves[..=i]).unwrap();
+        let root = incremental_tree.root().unwrap();
+        assert_eq!(tree.root(), root);
+
+        for &tracked_index in incremental_tree.tracked_indices() {
+            let leaf = &leaves[tracked_index];
+            let proof = incremental_tree.generate_proof(tracked_index, leaf).unwrap();
+            assert_eq!(tree.generate_proof(tracked_index, leaf).unwrap().path, proof.path);
+            assert!(proof.verify(&root, leaf).unwrap());
+        }
+    }
+
+    // Only tracked leaves at their own index have a path.
+    assert!(incremental_tree.generate_proof(1, &leaves[1]).is_err());
+    assert!(incremental_tree.generate_proof(0, &leaves[2]).is_err());
+    assert!(incremental_tree.untrack(0));
+    assert!(!incremental_tree.untrack(0));
+    assert!(incremental_tree.generate_proof(0, &leaves[0]).is_err());
+}
+
+fn run_incremental_merkle_tree_checkpoint_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+
+    let mut incremental_tree = IncrementalMerkleTree::<P>::new(parameters.clone()).unwrap();
+    for i in 0..3u8 {
+        incremental_tree.append_tracked(&[i; 8]).unwrap();
+    }
+    let first_root = incremental_tree.checkpoint().unwrap();
+    for i in 3..5u8 {
+        incremental_tree.append(&[i; 8]).unwrap();
+    }
+    let second_root = incremental_tree.checkpoint().unwrap();
+
+    assert_eq!(&[first_root, second_root], incremental_tree.roots());
+    assert!(incremental_tree.contains_root(&first_root));
+    assert!(!incremental_tree.contains_root(&<P::H as CRH>::Output::default()));
+
+    // The tree can be restored from its bytes and keeps updating the paths of its tracked leaves.
+    let bytes = to_bytes_le![incremental_tree].unwrap();
+    let mut restored_tree = IncrementalMerkleTree::<P>::read_le(parameters, &bytes[..]).unwrap();
+    assert_eq!(bytes, to_bytes_le![restored_tree].unwrap());
+    assert_eq!(incremental_tree.roots(), restored_tree.roots());
+
+    incremental_tree.append(&[5u8; 8]).unwrap();
+    restored_tree.append(&[5u8; 8]).unwrap();
+    let root = restored_tree.root().unwrap();
+    assert_eq!(incremental_tree.root().unwrap(), root);
+    for i in 0..3u8 {
+        let proof = restored_tree.generate_proof(i as usize, &[i; 8]).unwrap();
+        assert_eq!(incremental_tree.generate_proof(i as usize, &[i; 8]).unwrap().path, proof.path);
+        assert!(proof.verify(&root, &[i; 8]).unwrap());
+    }
+}
+
+fn run_full_incremental_merkle_tree_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+
+    let mut leaves = vec![];
+    for i in 0..1u8 << P::DEPTH {
+        leaves.push([i; 8]);
+    }
+
+    let mut incremental_tree = IncrementalMerkleTree::<P>::new(parameters.clone()).unwrap();
+    for leaf in &leaves {
+        incremental_tree.append_tracked(leaf).unwrap();
+    }
+    let tree = MerkleTree::<P>::new(parameters, &leaves).unwrap();
+    assert_eq!(tree.root(), incremental_tree.root().unwrap());
+    for (i, leaf) in leaves.iter().enumerate() {
+        assert_eq!(
+            tree.generate_proof(i, leaf).unwrap().path,
+            incremental_tree.generate_proof(i, leaf).unwrap().path
+        );
+    }
+
+    assert!(matches!(
+        incremental_tree.append(&[0u8; 8]),
+        Err(MerkleError::InvalidTreeDepth(_, _))
+    ));
+}
+
+fn run_sparse_merkle_tree_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+
+    let mut tree = SparseMerkleTree::<P>::new(parameters).unwrap();
+    let empty_root = tree.root();
+    assert!(tree.is_empty());
+
+    // The all-zero key hashes to the empty leaf.
+    assert!(matches!(tree.insert(&[0u8; 32]), Err(MerkleError::InvalidLeaf)));
+
+    let mut keys = vec![];
+    for i in 1..=8u8 {
+        keys.push([i; 32]);
+    }
+    let (present_keys, absent_keys) = keys.split_at(4);
+
+    for key in present_keys {
+        assert!(tree.insert(key).unwrap());
+        assert!(!tree.insert(key).unwrap());
+    }
+    assert_eq!(present_keys.len(), tree.len());
+
+    let root = tree.root();
+    for key in present_keys {
+        assert!(tree.contains(key).unwrap());
+        let proof = tree.generate_proof(key).unwrap();
+        assert_eq!(P::DEPTH, proof.siblings.len());
+        assert!(proof.verify_membership(&root, key).unwrap());
+        assert!(!proof.verify_non_membership(&root, key).unwrap());
+        assert!(!proof.verify_membership(&empty_root, key).unwrap());
+    }
+    for key in absent_keys {
+        assert!(!tree.contains(key).unwrap());
+        let proof = tree.generate_proof(key).unwrap();
+        assert!(proof.verify_non_membership(&root, key).unwrap());
+        assert!(!proof.verify_membership(&root, key).unwrap());
+    }
+
+    // Removing every key restores the empty tree.
+    assert!(!tree.remove(&absent_keys[0]).unwrap());
+    for key in present_keys {
+        assert!(tree.remove(key).unwrap());
+    }
+    assert!(tree.is_empty());
+    assert_eq!(empty_root, tree.root());
+}
+
+fn run_sparse_merkle_tree_collision_test<P: LoadableMerkleParameters>() {
+    let parameters = Arc::new(P::default());
+
+    // There are more keys than leaves, so some keys must share a leaf.
+    let mut tree = SparseMerkleTree::<P>::new(parameters).unwrap();
+    let mut collided = false;
+    for i in 1..=(1u8 << P::DEPTH) + 1 {
+        match tree.insert(&[i; 32]) {
+            Ok(inserted) => assert!(inserted),
+            Err(MerkleError::KeyCollision) => collided = true,
+            Err(error) => panic!("unexpected error: {}", error),
+        }
+    }
+    assert!(collided);
+}
+
 mod pedersen_crh_on_affine {
     use super::*;
     use snarkvm_curves::edwards_bls12::EdwardsAffine as Edwards;
@@ -288,4 +457,34 @@ mod pedersen_compressed_crh_on_projective {
         define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 3);
         run_padded_merkle_tree_matches_hashing_test::<MTParameters>();
     }
+
+    #[test]
+    fn incremental_merkle_tree_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32);
+        run_incremental_merkle_tree_test::<MTParameters>();
+    }
+
+    #[test]
+    fn incremental_merkle_tree_checkpoint_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32);
+        run_incremental_merkle_tree_checkpoint_test::<MTParameters>();
+    }
+
+    #[test]
+    fn depth3_full_incremental_merkle_tree_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 3);
+        run_full_incremental_merkle_tree_test::<MTParameters>();
+    }
+
+    #[test]
+    fn sparse_merkle_tree_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 32);
+        run_sparse_merkle_tree_test::<MTParameters>();
+    }
+
+    #[test]
+    fn depth2_sparse_merkle_tree_collision_test() {
+        define_merkle_tree_parameters!(MTParameters, PedersenCompressedCRH<Edwards, NUM_WINDOWS, WINDOW_SIZE>, 2);
+        run_sparse_merkle_tree_collision_test::<MTParameters>();
+    }
 }
@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_curves::traits::ProjectiveCurve;
-use snarkvm_fields::{FieldParameters, PrimeField};
-use snarkvm_utilities::ToBits;
+use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::BigInteger;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -60,16 +60,37 @@ impl FixedBaseMSM {
         multiples_of_g: &[Vec<T>],
         scalar: &T::ScalarField,
     ) -> T {
-        let mut scalar_val = scalar.to_repr().to_bits_be();
-        scalar_val.reverse();
+        // With a GLV endomorphism φ, `k g = k_1 g + k_2 φ(g)`, and both halves only need the lower half of the table.
+        if let Some([(k1_is_negative, k1), (k2_is_negative, k2)]) =
+            <T::Affine as AffineCurve>::glv_decomposition(scalar)
+        {
+            let k1_g = Self::windowed_mul_repr(outerc, window, multiples_of_g, &k1);
+            let k2_g = Self::windowed_mul_repr(outerc, window, multiples_of_g, &k2);
+            if let Some(k2_phi_g) = k2_g.glv_endomorphism() {
+                let k1_g = if k1_is_negative { -k1_g } else { k1_g };
+                let k2_phi_g = if k2_is_negative { -k2_phi_g } else { k2_phi_g };
+                return k1_g + k2_phi_g;
+            }
+        }
+
+        Self::windowed_mul_repr(outerc, window, multiples_of_g, &scalar.to_repr())
+    }
+
+    fn windowed_mul_repr<T: ProjectiveCurve>(
+        outerc: usize,
+        window: usize,
+        multiples_of_g: &[Vec<T>],
+        scalar: &<T::ScalarField as PrimeField>::BigInteger,
+    ) -> T {
+        // Skip the windows above the most significant bit of the scalar.
+        let num_bits = scalar.num_bits() as usize;
+        let outerc = std::cmp::min(outerc, (num_bits + window - 1) / window);
 
         let mut res = multiples_of_g[0][0];
         for outer in 0..outerc {
             let mut inner = 0usize;
             for i in 0..window {
-                if outer * window + i < (<T::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize)
-                    && scalar_val[outer * window + i]
-                {
+                if outer * window + i < num_bits && scalar.get_bit(outer * window + i) {
                     inner |= 1 << i;
                 }
             }
@@ -19,7 +19,7 @@ use snarkvm_curves::{
     bls12_377::{Fr, G1Projective},
     traits::{AffineCurve, ProjectiveCurve},
 };
-use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_fields::{FieldParameters, One, PrimeField, Zero};
 use snarkvm_utilities::{rand::UniformRand, BitIteratorBE};
 
 use rand::SeedableRng;
@@ -72,3 +72,27 @@ fn variable_base_test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.into_affine(), fast.into_affine());
 }
+
+#[test]
+fn fixed_base_test_with_bls12() {
+    const SAMPLES: usize = 1 << 6;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let mut v = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    v[0] = Fr::zero();
+    v[1] = -Fr::one();
+    let g = G1Projective::rand(&mut rng);
+
+    let scalar_size = <Fr as PrimeField>::Parameters::MODULUS_BITS as usize;
+    let window = FixedBaseMSM::get_mul_window_size(SAMPLES);
+    let table = FixedBaseMSM::get_window_table(scalar_size, window, g);
+    let fast = FixedBaseMSM::multi_scalar_mul(scalar_size, window, &table, &v);
+
+    let naive = v
+        .iter()
+        .map(|s| g.into_affine().mul_bits(BitIteratorBE::new(s.to_repr())))
+        .collect::<Vec<_>>();
+
+    assert_eq!(naive, fast);
+}
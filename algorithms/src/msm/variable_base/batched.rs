@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{traits::AffineCurve, Group, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField};
+use snarkvm_utilities::BigInteger;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub fn msm_batched<G: AffineCurve>(
+    bases: &[G],
+    scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+) -> G::Projective {
+    let size = std::cmp::min(bases.len(), scalars.len());
+    let c = if size < 32 {
+        3
+    } else {
+        (2.0 / 3.0 * (f64::from(size as u32)).log2() + 2.0).ceil() as usize
+    };
+
+    // Only the windows up to the most significant bit of the largest scalar are needed, and the top window
+    // must have fewer than `c - 1` of those bits, so that the final carry never overflows its digit.
+    let num_bits = cfg_iter!(scalars[..size])
+        .map(|s| s.num_bits() as usize)
+        .max()
+        .unwrap_or(0);
+    let num_windows = (num_bits + 1) / c + 1;
+
+    let digits: Vec<_> = cfg_iter!(scalars[..size])
+        .map(|scalar| signed_digits(scalar.as_ref(), c, num_windows))
+        .collect();
+
+    let zero = G::zero().into_projective();
+
+    // Each window is of size `c`, and its digits lie in [-2^(c-1), 2^(c-1)).
+    // As a point and its negation cost the same, we only need the 2^(c-1) buckets of the positive digits.
+    let window_sums: Vec<_> = cfg_into_iter!(0..num_windows)
+        .map(|w| {
+            let num_buckets = 1 << (c - 1);
+
+            // Lay out the points of each bucket contiguously, negating the bases with a negative digit.
+            let mut bucket_sizes = vec![0usize; num_buckets];
+            for scalar_digits in &digits {
+                if scalar_digits[w] != 0 {
+                    bucket_sizes[scalar_digits[w].unsigned_abs() as usize - 1] += 1;
+                }
+            }
+            let mut bucket_starts = Vec::with_capacity(num_buckets);
+            let mut num_points = 0;
+            for bucket_size in &bucket_sizes {
+                bucket_starts.push(num_points);
+                num_points += bucket_size;
+            }
+
+            let mut points = vec![G::zero(); num_points];
+            let mut next_indices = bucket_starts.clone();
+            for (scalar_digits, base) in digits.iter().zip(bases) {
+                let digit = scalar_digits[w];
+                if digit != 0 {
+                    let next_index = &mut next_indices[digit.unsigned_abs() as usize - 1];
+                    points[*next_index] = if digit > 0 { *base } else { -*base };
+                    *next_index += 1;
+                }
+            }
+
+            let buckets = sum_buckets(&mut points, &bucket_starts, &mut bucket_sizes);
+
+            let mut res = zero;
+            let mut running_sum = zero;
+            for bucket in buckets.iter().rev() {
+                running_sum.add_assign_mixed(bucket);
+                res += running_sum;
+            }
+
+            res
+        })
+        .collect();
+
+    // We store the sum for the lowest window.
+    let lowest = window_sums.first().unwrap();
+
+    // We're traversing windows from high to low.
+    window_sums[1..].iter().rev().fold(zero, |mut total, sum_i| {
+        total += sum_i;
+        for _ in 0..c {
+            total.double_in_place();
+        }
+        total
+    }) + lowest
+}
+
+/// Returns the digits of the given little-endian limbs in base 2^c, from the lowest to the highest,
+/// where each digit lies in [-2^(c-1), 2^(c-1)).
+fn signed_digits(limbs: &[u64], c: usize, num_windows: usize) -> Vec<i64> {
+    let mut digits = Vec::with_capacity(num_windows);
+    let mut carry = 0i64;
+    for w in 0..num_windows {
+        let window = window_bits(limbs, w * c, c) as i64 + carry;
+        // Digits from 2^(c-1) upwards are shifted down by 2^c, carrying one into the next window.
+        carry = (window + (1 << (c - 1))) >> c;
+        digits.push(window - (carry << c));
+    }
+    digits
+}
+
+/// Returns the `c` bits of the given little-endian limbs that start at the bit `start`.
+fn window_bits(limbs: &[u64], start: usize, c: usize) -> u64 {
+    let (limb, offset) = (start / 64, start % 64);
+    if limb >= limbs.len() {
+        return 0;
+    }
+
+    let mut bits = limbs[limb] >> offset;
+    if offset + c > 64 && limb + 1 < limbs.len() {
+        bits |= limbs[limb + 1] << (64 - offset);
+    }
+    bits & ((1 << c) - 1)
+}
+
+/// Returns the sum of the points of each bucket, where bucket `i` holds the `bucket_sizes[i]` points
+/// that start at `bucket_starts[i]`. The points of every bucket are added pairwise in rounds,
+/// and each round shares a single inversion across all of its affine additions.
+fn sum_buckets<G: AffineCurve>(points: &mut [G], bucket_starts: &[usize], bucket_sizes: &mut [usize]) -> Vec<G> {
+    let mut pairs = vec![];
+    loop {
+        pairs.clear();
+        for (&start, &size) in bucket_starts.iter().zip(bucket_sizes.iter()) {
+            for i in 0..size / 2 {
+                pairs.push((start + 2 * i, start + 2 * i + 1));
+            }
+        }
+        if pairs.is_empty() {
+            break;
+        }
+
+        batch_add_in_place(points, &pairs);
+
+        // The sums, and the unpaired last point of odd buckets, are at the even offsets of each bucket.
+        for (&start, size) in bucket_starts.iter().zip(bucket_sizes.iter_mut()) {
+            for i in 1..(*size + 1) / 2 {
+                points[start + i] = points[start + 2 * i];
+            }
+            *size = (*size + 1) / 2;
+        }
+    }
+
+    bucket_starts
+        .iter()
+        .zip(bucket_sizes.iter())
+        .map(|(&start, &size)| if size == 0 { G::zero() } else { points[start] })
+        .collect()
+}
+
+/// Adds the second point of each pair into the first one, using a single inversion for the whole batch.
+fn batch_add_in_place<G: AffineCurve>(points: &mut [G], pairs: &[(usize, usize)]) {
+    let mut inversion_tmp = G::BaseField::one();
+    for &(i, j) in pairs {
+        let (left, right) = points.split_at_mut(j);
+        G::batch_add_loop_1(&mut left[i], &mut right[0], &mut inversion_tmp);
+    }
+
+    // Only non-zero denominators are accumulated, so their product is invertible.
+    inversion_tmp = inversion_tmp.inverse().unwrap();
+
+    for &(i, j) in pairs.iter().rev() {
+        let (left, right) = points.split_at_mut(j);
+        G::batch_add_loop_2(&mut left[i], right[0], &mut inversion_tmp);
+    }
+}
@@ -23,6 +23,10 @@ use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
 use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::BitIteratorBE;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+mod batched;
 mod standard;
 
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
@@ -31,6 +35,22 @@ mod cuda;
 #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
 static HAS_CUDA_FAILED: AtomicBool = AtomicBool::new(false);
 
+/// The CPU algorithm used to compute a variable-base multi-scalar multiplication.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MSMStrategy {
+    /// Pippenger's algorithm, with unsigned window buckets summed in projective coordinates.
+    Standard,
+    /// Pippenger's algorithm, with signed window buckets summed by batches of affine additions
+    /// that share a single inversion.
+    Batched,
+}
+
+impl Default for MSMStrategy {
+    fn default() -> Self {
+        MSMStrategy::Standard
+    }
+}
+
 pub struct VariableBaseMSM;
 
 impl VariableBaseMSM {
@@ -62,7 +82,54 @@ impl VariableBaseMSM {
                 }
             }
         }
-        standard::msm_standard(bases, scalars)
+        match Self::glv_split(bases, scalars) {
+            Some((bases, scalars)) => standard::msm_standard(&bases, &scalars),
+            None => standard::msm_standard(bases, scalars),
+        }
+    }
+
+    /// Computes the multi-scalar multiplication on the CPU, using the given strategy.
+    pub fn multi_scalar_mul_with_strategy<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+        strategy: MSMStrategy,
+    ) -> G::Projective {
+        let msm = match strategy {
+            MSMStrategy::Standard => standard::msm_standard::<G>,
+            MSMStrategy::Batched => batched::msm_batched::<G>,
+        };
+        match Self::glv_split(bases, scalars) {
+            Some((bases, scalars)) => msm(&bases, &scalars),
+            None => msm(bases, scalars),
+        }
+    }
+
+    /// Splits each term `k P` into `k_1 P + k_2 φ(P)` with the GLV endomorphism `φ` of the curve,
+    /// folding the signs of `k_1` and `k_2` into the bases, so that the scalars are about half the size.
+    /// Returns `None` if the curve has no GLV endomorphism.
+    #[allow(clippy::type_complexity)]
+    fn glv_split<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Option<(Vec<G>, Vec<<G::ScalarField as PrimeField>::BigInteger>)> {
+        G::prime_subgroup_generator().glv_endomorphism()?;
+
+        let terms: Vec<_> = cfg_iter!(bases)
+            .zip(scalars)
+            .map(|(base, scalar)| {
+                let decomposition = G::ScalarField::from_repr(*scalar).and_then(|s| G::glv_decomposition(&s));
+                match (decomposition, base.glv_endomorphism()) {
+                    (Some([(k1_is_negative, k1), (k2_is_negative, k2)]), Some(endomorphism)) => [
+                        (if k1_is_negative { -*base } else { *base }, k1),
+                        (if k2_is_negative { -endomorphism } else { endomorphism }, k2),
+                    ],
+                    // Scalars that are not reduced modulo the group order are kept whole.
+                    _ => [(*base, *scalar), (G::zero(), Default::default())],
+                }
+            })
+            .collect();
+
+        Some(terms.iter().flat_map(|pair| pair.iter().copied()).unzip())
     }
 }
 
@@ -72,10 +139,11 @@ mod tests {
     use rand::SeedableRng;
     use rand_xorshift::XorShiftRng;
     use snarkvm_curves::{
-        bls12_377::{Fr, G1Affine, G1Projective},
+        bls12_377::{Fq, Fr, G1Affine, G1Projective},
+        bw6_761,
         traits::ProjectiveCurve,
     };
-    use snarkvm_fields::PrimeField;
+    use snarkvm_fields::{One, PrimeField};
     use snarkvm_utilities::{rand::UniformRand, BigInteger256};
 
     fn test_data(seed: u64, samples: usize) -> (Vec<G1Affine>, Vec<BigInteger256>) {
@@ -97,6 +165,99 @@ mod tests {
         assert_eq!(rust, naive);
     }
 
+    #[test]
+    fn test_batched() {
+        for &samples in &[0, 1, 31, 100, 1 << 10] {
+            let (bases, scalars) = test_data(334563456, samples);
+            let naive = VariableBaseMSM::msm_naive(bases.as_slice(), scalars.as_slice());
+            let batched = batched::msm_batched(bases.as_slice(), scalars.as_slice());
+            assert_eq!(naive, batched);
+        }
+    }
+
+    #[test]
+    fn test_batched_repeated_and_opposite_bases() {
+        let (mut bases, mut scalars) = test_data(334563456, 100);
+
+        // Equal bases with equal digits are doubled, and opposite ones cancel out.
+        for i in 0..25 {
+            bases[50 + i] = bases[i];
+            scalars[50 + i] = scalars[i];
+            bases[75 + i] = -bases[25 + i];
+            scalars[75 + i] = scalars[25 + i];
+        }
+        scalars[10] = Fr::zero().to_repr();
+        scalars[11] = Fr::one().to_repr();
+        bases[12] = G1Affine::zero();
+
+        let naive = VariableBaseMSM::msm_naive(bases.as_slice(), scalars.as_slice());
+        let batched = batched::msm_batched(bases.as_slice(), scalars.as_slice());
+        assert_eq!(naive, batched);
+    }
+
+    #[test]
+    fn test_batched_point_of_order_two() {
+        let (mut bases, mut scalars) = test_data(334563456, 10);
+
+        // The point (-1, 0) of y^2 = x^3 + 1 has order two, so doubling it in a bucket gives zero.
+        let point = G1Affine::new(-Fq::one(), Fq::zero(), false);
+        for i in 0..2 {
+            bases[i] = point;
+            scalars[i] = Fr::one().to_repr();
+        }
+
+        let naive = VariableBaseMSM::msm_naive(bases.as_slice(), scalars.as_slice());
+        let batched = batched::msm_batched(bases.as_slice(), scalars.as_slice());
+        assert_eq!(naive, batched);
+    }
+
+    #[test]
+    fn test_batched_bw6_761() {
+        let mut rng = XorShiftRng::seed_from_u64(334563456);
+
+        let scalars = (0..100)
+            .map(|_| bw6_761::Fr::rand(&mut rng).to_repr())
+            .collect::<Vec<_>>();
+        let bases = (0..100)
+            .map(|_| bw6_761::G1Projective::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+
+        let naive = VariableBaseMSM::msm_naive(bases.as_slice(), scalars.as_slice());
+        let batched =
+            VariableBaseMSM::multi_scalar_mul_with_strategy(bases.as_slice(), scalars.as_slice(), MSMStrategy::Batched);
+        assert_eq!(naive, batched);
+    }
+
+    #[test]
+    fn test_glv() {
+        let (bases, mut scalars) = test_data(334563456, 100);
+        // Scalars that are not reduced modulo the group order are not decomposed.
+        scalars[0] = BigInteger256([u64::MAX; 4]);
+        scalars[1] = Fr::zero().to_repr();
+
+        let naive = VariableBaseMSM::msm_naive(bases.as_slice(), scalars.as_slice());
+        for &strategy in &[MSMStrategy::Standard, MSMStrategy::Batched] {
+            let glv = VariableBaseMSM::multi_scalar_mul_with_strategy(bases.as_slice(), scalars.as_slice(), strategy);
+            assert_eq!(naive, glv);
+        }
+    }
+
+    #[test]
+    fn test_glv_bw6_761() {
+        let mut rng = XorShiftRng::seed_from_u64(334563456);
+
+        let scalars = (0..100)
+            .map(|_| bw6_761::Fr::rand(&mut rng).to_repr())
+            .collect::<Vec<_>>();
+        let bases = (0..100)
+            .map(|_| bw6_761::G1Projective::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+
+        let naive = VariableBaseMSM::msm_naive(bases.as_slice(), scalars.as_slice());
+        let glv = VariableBaseMSM::multi_scalar_mul(bases.as_slice(), scalars.as_slice());
+        assert_eq!(naive, glv);
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
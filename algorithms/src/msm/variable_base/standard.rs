@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_curves::{traits::AffineCurve, Group, ProjectiveCurve};
-use snarkvm_fields::{FieldParameters, One, PrimeField, Zero};
+use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_utilities::BigInteger;
 
 #[cfg(feature = "parallel")]
@@ -31,7 +31,12 @@ pub fn msm_standard<G: AffineCurve>(
         (2.0 / 3.0 * (f64::from(scalars.len() as u32)).log2() + 2.0).ceil() as usize
     };
 
-    let num_bits = <G::ScalarField as PrimeField>::Parameters::MODULUS_BITS as usize;
+    // Only the windows up to the most significant bit of the largest scalar are needed.
+    let num_bits = cfg_iter!(scalars)
+        .map(|s| s.num_bits() as usize)
+        .max()
+        .unwrap_or(0)
+        .max(1);
     let fr_one = G::ScalarField::one().to_repr();
 
     let zero = G::zero().into_projective();
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crh::PoseidonParameters,
+    errors::PRFError,
+    traits::{CRHParameters, PRF},
+};
+use snarkvm_curves::{bls12_377, bw6_761};
+use snarkvm_fields::{PrimeField, ToConstraintField};
+
+use once_cell::sync::Lazy;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use std::marker::PhantomData;
+
+/// A field with fixed Poseidon parameters for `PoseidonPRF`.
+pub trait PoseidonPRFField: PrimeField {
+    /// Returns the Poseidon parameters of the PRF over this field.
+    fn poseidon_prf_parameters() -> &'static PoseidonParameters<Self>;
+}
+
+macro_rules! impl_poseidon_prf_field {
+    ($field: ty, $seed: expr) => {
+        impl PoseidonPRFField for $field {
+            fn poseidon_prf_parameters() -> &'static PoseidonParameters<Self> {
+                static PARAMETERS: Lazy<PoseidonParameters<$field>> =
+                    Lazy::new(|| PoseidonParameters::setup(&mut ChaChaRng::seed_from_u64($seed)));
+                &PARAMETERS
+            }
+        }
+    };
+}
+
+impl_poseidon_prf_field!(bls12_377::Fr, 0x706f_7365_6964_6f6e);
+impl_poseidon_prf_field!(bw6_761::Fr, 0x706f_7365_6964_6f6e);
+
+/// A PRF over the scalar field `F`, evaluating Poseidon on the seed bytes followed by the input bytes.
+#[derive(Clone)]
+pub struct PoseidonPRF<F: PoseidonPRFField>(PhantomData<F>);
+
+impl<F: PoseidonPRFField> PRF for PoseidonPRF<F> {
+    type Input = F;
+    type Output = F;
+    type Seed = [u8; 32];
+
+    fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Result<Self::Output, PRFError> {
+        let eval_time = start_timer!(|| "PoseidonPRF::evaluate");
+        let mut bytes = seed.to_vec();
+        input.write_le(&mut bytes)?;
+
+        let elements: Vec<F> = bytes.as_slice().to_field_elements()?;
+        let output = F::poseidon_prf_parameters().evaluate(&elements);
+        end_timer!(eval_time);
+        Ok(output)
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    prf::{PoseidonPRF, PoseidonPRFField},
+    traits::PRF,
+};
+use snarkvm_curves::{bls12_377, bw6_761};
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+fn poseidon_prf<F: PoseidonPRFField>() {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+    let seed: [u8; 32] = rng.gen();
+    let other_seed: [u8; 32] = rng.gen();
+    let input = F::rand(rng);
+    let other_input = F::rand(rng);
+
+    let output = PoseidonPRF::<F>::evaluate(&seed, &input).unwrap();
+    assert_eq!(output, PoseidonPRF::<F>::evaluate(&seed, &input).unwrap());
+    assert_ne!(output, PoseidonPRF::<F>::evaluate(&other_seed, &input).unwrap());
+    assert_ne!(output, PoseidonPRF::<F>::evaluate(&seed, &other_input).unwrap());
+}
+
+#[test]
+fn poseidon_prf_on_bls12_377() {
+    poseidon_prf::<bls12_377::Fr>();
+}
+
+#[test]
+fn poseidon_prf_on_bw6_761() {
+    poseidon_prf::<bw6_761::Fr>();
+}
@@ -20,8 +20,8 @@ use crate::{
     signature::{Schnorr, SchnorrParameters, SchnorrPublicKey, SchnorrSignature},
     traits::{EncryptionScheme, SignatureScheme},
 };
-use snarkvm_curves::traits::{Group, ProjectiveCurve};
-use snarkvm_fields::PrimeField;
+use snarkvm_curves::traits::{ConstantTimeGroup, Group, ProjectiveCurve};
+use snarkvm_fields::{ConstantTimeField, PrimeField};
 use snarkvm_utilities::{serialize::*, to_bytes_le, FromBytes, ToBytes};
 
 use digest::Digest;
@@ -67,10 +67,14 @@ impl<G: Group + ProjectiveCurve, SG: Group + CanonicalSerialize + CanonicalDeser
     }
 }
 
-impl<G: Group + ProjectiveCurve, SG: Group + Hash + CanonicalSerialize + CanonicalDeserialize, D: Digest + Send + Sync>
-    SignatureScheme for GroupEncryption<G, SG, D>
+impl<
+    G: Group + ProjectiveCurve + ConstantTimeGroup,
+    SG: Group + ConstantTimeGroup + Hash + CanonicalSerialize + CanonicalDeserialize,
+    D: Digest + Send + Sync,
+> SignatureScheme for GroupEncryption<G, SG, D>
 where
-    <G as Group>::ScalarField: PrimeField,
+    <G as Group>::ScalarField: PrimeField + ConstantTimeField,
+    <SG as Group>::ScalarField: ConstantTimeField,
 {
     type Parameters = GroupEncryptionParameters<G>;
     type PrivateKey = <G as Group>::ScalarField;
@@ -15,8 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{signature::SchnorrParameters, SignatureError, SignatureScheme};
-use snarkvm_curves::traits::Group;
-use snarkvm_fields::{ConstraintFieldError, Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_curves::traits::{ConstantTimeGroup, Group};
+use snarkvm_fields::{ConstantTimeField, ConstraintFieldError, Field, One, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{
     bytes::{from_bytes_le_to_bits_le, FromBytes, ToBytes},
     errors::SerializationError,
@@ -113,10 +113,10 @@ pub struct Schnorr<G: Group, D: Digest> {
     pub parameters: SchnorrParameters<G, D>,
 }
 
-impl<G: Group + Hash + CanonicalSerialize + CanonicalDeserialize, D: Digest + Send + Sync> SignatureScheme
-    for Schnorr<G, D>
+impl<G: Group + ConstantTimeGroup + Hash + CanonicalSerialize + CanonicalDeserialize, D: Digest + Send + Sync>
+    SignatureScheme for Schnorr<G, D>
 where
-    <G as Group>::ScalarField: PrimeField,
+    <G as Group>::ScalarField: PrimeField + ConstantTimeField,
 {
     type Parameters = SchnorrParameters<G, D>;
     type PrivateKey = <G as Group>::ScalarField;
@@ -145,14 +145,8 @@ where
     fn generate_public_key(&self, private_key: &Self::PrivateKey) -> Result<Self::PublicKey, SignatureError> {
         let keygen_time = start_timer!(|| "SchnorrSignature::generate_public_key");
 
-        let mut public_key = G::zero();
-        for (bit, base_power) in
-            from_bytes_le_to_bits_le(&to_bytes_le![private_key]?).zip_eq(&self.parameters.generator_powers)
-        {
-            if bit {
-                public_key += base_power;
-            }
-        }
+        // The private key is secret, so the multiplication must run in constant time.
+        let public_key = self.parameters.generator_powers[0].mul_constant_time(private_key);
         end_timer!(keygen_time);
 
         Ok(SchnorrPublicKey(public_key))
@@ -171,14 +165,8 @@ where
             let random_scalar: <G as Group>::ScalarField = <G as Group>::ScalarField::rand(rng);
             // Commit to the random scalar via r := k · g.
             // This is the prover's first msg in the Sigma protocol.
-            let mut prover_commitment = G::zero();
-            for (bit, base_power) in
-                from_bytes_le_to_bits_le(&to_bytes_le![random_scalar]?).zip_eq(&self.parameters.generator_powers)
-            {
-                if bit {
-                    prover_commitment += base_power;
-                }
-            }
+            // The random scalar reveals the private key, so this must run in constant time.
+            let prover_commitment = self.parameters.generator_powers[0].mul_constant_time(&random_scalar);
 
             // Hash everything to get verifier challenge.
             let mut hash_input = Vec::new();
@@ -192,8 +180,8 @@ where
             };
         };
 
-        // k - xe;
-        let prover_response = random_scalar - (verifier_challenge * private_key);
+        // k - xe, which is computed in constant time as it depends on the private key;
+        let prover_response = random_scalar.sub_constant_time(&verifier_challenge.mul_constant_time(private_key));
         let signature = SchnorrSignature {
             prover_response,
             verifier_challenge,
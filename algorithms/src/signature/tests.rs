@@ -18,8 +18,9 @@ use crate::{encryption::GroupEncryption, signature::Schnorr, traits::SignatureSc
 use snarkvm_curves::{
     edwards_bls12::{EdwardsAffine, EdwardsProjective},
     edwards_bw6::EdwardsAffine as Edwards,
-    traits::Group,
+    traits::{tests_timing::timing_leakage_test, Group},
 };
+use snarkvm_fields::One;
 use snarkvm_utilities::{rand::UniformRand, to_bytes_le, FromBytes, ToBytes};
 
 use blake2::Blake2s;
@@ -104,3 +105,23 @@ fn group_encryption_signature_test() {
 fn group_encryption_signature_scheme_parameters_serialization() {
     signature_scheme_parameter_serialization::<TestGroupEncryptionSignature>();
 }
+
+#[ignore]
+#[test]
+fn schnorr_signature_timing() {
+    let message = "Hi, I am a Schnorr signature!";
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let schnorr_signature = TestSignature::setup(rng).unwrap();
+
+    let sign_rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    timing_leakage_test(
+        rng,
+        <Edwards as Group>::ScalarField::one(),
+        |rng| schnorr_signature.generate_private_key(rng).unwrap(),
+        |private_key| {
+            schnorr_signature
+                .sign(private_key, message.as_bytes(), sign_rng)
+                .unwrap()
+        },
+    );
+}
@@ -46,6 +46,9 @@ mod prover;
 /// Verify proofs for the Groth16 zkSNARK construction.
 mod verifier;
 
+/// Run a multi-party ceremony for the public parameters of the Groth16 zkSNARK construction.
+pub mod mpc;
+
 #[cfg(test)]
 mod tests;
 
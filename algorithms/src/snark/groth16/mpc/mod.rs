@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A multi-party ceremony for the public parameters of the Groth16 zkSNARK, following [[BGM17]][bgm17].
+//!
+//! Phase 1 is a powers-of-tau ceremony, which computes the powers of `τ`, `α τ` and `β τ` that are shared
+//! by every circuit of up to a given size. The same transcript provides the universal parameters of KZG10.
+//! Phase 2 starts from a verified phase 1, derives the proving key of a specific circuit with `δ = 1`,
+//! and lets each participant multiply `δ` by a fresh random factor. The resulting keys are secure as long as
+//! a single participant in each phase discards their factors.
+//!
+//! [bgm17]: https://eprint.iacr.org/2017/1050
+
+use crate::{cfg_iter, errors::SNARKError, msm::VariableBaseMSM};
+use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_utilities::{errors::SerializationError, rand::UniformRand, serialize::*};
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The first phase of the ceremony, which is independent of the circuit.
+mod powers_of_tau;
+pub use powers_of_tau::*;
+
+/// The second phase of the ceremony, which is specific to a circuit.
+mod phase2;
+pub use phase2::*;
+
+/// A proof of knowledge of a scalar `x`, consisting of `x s` for a point `s` chosen by the prover,
+/// and `x r` for a point `r` in G2 that is derived from the transcript and from `(s, x s)`.
+#[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+pub struct KnowledgeProof<E: PairingEngine> {
+    /// A random point in G1.
+    pub s: E::G1Affine,
+    /// `x s`.
+    pub s_x: E::G1Affine,
+    /// `x r`.
+    pub r_x: E::G2Affine,
+}
+
+impl<E: PairingEngine> KnowledgeProof<E> {
+    fn new<R: Rng>(x: E::Fr, challenge: &[u8], tag: &[u8], rng: &mut R) -> Result<Self, SNARKError> {
+        let s = E::G1Projective::rand(rng);
+        let s_x = (s * x).into_affine();
+        let s = s.into_affine();
+        let r = hash_to_g2::<E>(challenge, tag, &s, &s_x)?;
+        let r_x = (r.into_projective() * x).into_affine();
+        Ok(Self { s, s_x, r_x })
+    }
+
+    /// Verifies the proof, and returns `(r, x r)` to check other points against `x`.
+    fn verify(&self, challenge: &[u8], tag: &[u8]) -> Result<(E::G2Affine, E::G2Affine), SNARKError> {
+        if self.s.is_zero() || self.s_x.is_zero() {
+            return Err(invalid("a proof of knowledge is degenerate"));
+        }
+        let r = hash_to_g2::<E>(challenge, tag, &self.s, &self.s_x)?;
+        if !same_ratio::<E>((self.s, self.s_x), (r, self.r_x)) {
+            return Err(invalid("a proof of knowledge does not verify"));
+        }
+        Ok((r, self.r_x))
+    }
+}
+
+/// Returns the SHA-256 hash of the given serializable values.
+fn hash<T: CanonicalSerialize + ?Sized>(prefix: &[u8], values: &T) -> Result<Vec<u8>, SNARKError> {
+    let mut bytes = prefix.to_vec();
+    CanonicalSerialize::serialize(values, &mut bytes).map_err(|_| invalid("could not serialize the transcript"))?;
+    Ok(Sha256::digest(&bytes).to_vec())
+}
+
+/// Deterministically derives a point in G2, of unknown discrete logarithm, from the given inputs.
+fn hash_to_g2<E: PairingEngine>(
+    challenge: &[u8],
+    tag: &[u8],
+    s: &E::G1Affine,
+    s_x: &E::G1Affine,
+) -> Result<E::G2Affine, SNARKError> {
+    let mut prefix = challenge.to_vec();
+    prefix.extend_from_slice(tag);
+
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&hash(&prefix, &[*s, *s_x][..])?);
+    Ok(E::G2Projective::rand(&mut ChaChaRng::from_seed(seed)).into_affine())
+}
+
+/// Checks that `g1.1 = x g1.0` and `g2.1 = x g2.0` for the same `x`.
+fn same_ratio<E: PairingEngine>(g1: (E::G1Affine, E::G1Affine), g2: (E::G2Affine, E::G2Affine)) -> bool {
+    E::pairing(g1.0, g2.1) == E::pairing(g1.1, g2.0)
+}
+
+/// Returns a random linear combination of `lhs` and the same combination of `rhs`,
+/// which have the same ratio (with high probability) only if every pair `(lhs[i], rhs[i])` does.
+fn merge_pairs<G: AffineCurve, R: Rng>(lhs: &[G], rhs: &[G], rng: &mut R) -> (G, G) {
+    let coefficients: Vec<_> = (0..lhs.len()).map(|_| G::ScalarField::rand(rng).to_repr()).collect();
+    (
+        VariableBaseMSM::multi_scalar_mul(lhs, &coefficients).into_affine(),
+        VariableBaseMSM::multi_scalar_mul(rhs, &coefficients).into_affine(),
+    )
+}
+
+/// Returns `(Σ c_i powers[i], Σ c_i powers[i + 1])` for random `c_i`,
+/// which have the same ratio `x` (with high probability) only if `powers[i + 1] = x powers[i]` for all `i`.
+fn power_pairs<G: AffineCurve, R: Rng>(powers: &[G], rng: &mut R) -> (G, G) {
+    merge_pairs(&powers[..powers.len() - 1], &powers[1..], rng)
+}
+
+/// Multiplies `powers[i]` by `scale * factor^i`.
+fn scale_powers<G: AffineCurve>(powers: &mut [G], scale: G::ScalarField, factor: G::ScalarField) {
+    let mut scalars = Vec::with_capacity(powers.len());
+    let mut scalar = scale;
+    for _ in 0..powers.len() {
+        scalars.push(scalar);
+        scalar *= &factor;
+    }
+
+    let scaled = cfg_iter!(powers)
+        .zip(scalars)
+        .map(|(power, scalar)| power.into_projective() * scalar)
+        .collect();
+    powers.copy_from_slice(&G::Projective::batch_normalization_into_affine(scaled));
+}
+
+/// Multiplies every element of `elements` by `scalar`.
+fn scale<G: AffineCurve>(elements: &mut [G], scalar: G::ScalarField) {
+    let scaled = cfg_iter!(elements)
+        .map(|element| element.into_projective() * scalar)
+        .collect();
+    elements.copy_from_slice(&G::Projective::batch_normalization_into_affine(scaled));
+}
+
+/// Samples a nonzero field element.
+fn nonzero_rand<F: Field, R: Rng>(rng: &mut R) -> F {
+    loop {
+        let x = F::rand(rng);
+        if !x.is_zero() {
+            return x;
+        }
+    }
+}
+
+fn invalid(message: &str) -> SNARKError {
+    SNARKError::InvalidTranscript(message.to_string())
+}
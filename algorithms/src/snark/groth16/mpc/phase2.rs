@@ -0,0 +1,294 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    fft::EvaluationDomain,
+    snark::groth16::{KeypairAssembly, ProvingKey, VerifyingKey},
+};
+use snarkvm_fields::{One, Zero};
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, Index, SynthesisError};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::io::{Read, Result as IoResult, Write};
+
+const DELTA_PROOF_TAG: &[u8] = b"delta";
+
+/// A participant's contribution to phase 2 of the ceremony.
+#[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+pub struct Phase2Contribution<E: PairingEngine> {
+    /// `δ G` after this contribution.
+    pub delta_g1: E::G1Affine,
+    /// A proof of knowledge of the factor applied to `δ`.
+    pub delta_proof: KnowledgeProof<E>,
+}
+
+/// The transcript of phase 2 of the ceremony, for a specific circuit.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Phase2Parameters<E: PairingEngine> {
+    /// The proving key after the latest contribution.
+    pub proving_key: ProvingKey<E>,
+    /// The hash of the initial proving key, which identifies the circuit and the phase 1 transcript.
+    pub circuit_hash: Vec<u8>,
+    /// The contributions to phase 2, in the order they were made.
+    pub contributions: Vec<Phase2Contribution<E>>,
+}
+
+impl<E: PairingEngine> Phase2Parameters<E> {
+    /// Derives the initial proving key for `circuit` from the powers-of-tau transcript `phase1`, with `γ = δ = 1`.
+    /// The phase 1 transcript should be verified first.
+    pub fn new<C: ConstraintSynthesizer<E::Fr>>(circuit: &C, phase1: &PowersOfTau<E>) -> Result<Self, SNARKError> {
+        let setup_time = start_timer!(|| "Groth16 MPC::Phase 2 setup");
+        let mut assembly = KeypairAssembly::<E> {
+            num_public_variables: 0,
+            num_private_variables: 0,
+            at: vec![],
+            bt: vec![],
+            ct: vec![],
+        };
+
+        // Allocate the "one" input variable
+        assembly.alloc_input(|| "", || Ok(E::Fr::one()))?;
+
+        // Synthesize the circuit.
+        let synthesis_time = start_timer!(|| "Constraint synthesis");
+        circuit.generate_constraints(&mut assembly)?;
+        end_timer!(synthesis_time);
+
+        let num_constraints = assembly.num_constraints();
+        let num_public_variables = assembly.num_public_variables;
+        let num_variables = num_public_variables + assembly.num_private_variables;
+
+        let domain = EvaluationDomain::<E::Fr>::new(num_constraints + num_public_variables)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_size = domain.size();
+        if domain_size > phase1.max_domain_size() {
+            return Err(invalid(&format!(
+                "the circuit needs an evaluation domain of size {}, but phase 1 supports up to {}",
+                domain_size,
+                phase1.max_domain_size()
+            )));
+        }
+
+        // Evaluate the Lagrange polynomials of the domain at `τ`, in the exponent.
+        let lagrange_time = start_timer!(|| "Evaluate Lagrange coefficients");
+        let lagrange_g1 = lagrange_basis(&domain, &phase1.powers_of_g[..domain_size]);
+        let lagrange_g2 = lagrange_basis(&domain, &phase1.powers_of_h[..domain_size]);
+        let alpha_lagrange_g1 = lagrange_basis(&domain, &phase1.powers_of_alpha_g[..domain_size]);
+        let beta_lagrange_g1 = lagrange_basis(&domain, &phase1.powers_of_beta_g[..domain_size]);
+        end_timer!(lagrange_time);
+
+        // Evaluate the QAP polynomials of every variable at `τ`, in the exponent.
+        let qap_time = start_timer!(|| "R1CS to QAP Instance Map with Evaluation");
+        let mut a_g1 = vec![E::G1Projective::zero(); num_variables];
+        let mut b_g1 = vec![E::G1Projective::zero(); num_variables];
+        let mut b_g2 = vec![E::G2Projective::zero(); num_variables];
+        // The evaluations of `β A_i + α B_i + C_i`.
+        let mut abc_g1 = vec![E::G1Projective::zero(); num_variables];
+
+        let variable_index = |index: Index| match index {
+            Index::Public(i) => i,
+            Index::Private(i) => num_public_variables + i,
+        };
+        let constraints = assembly.at.iter().zip(&assembly.bt).zip(&assembly.ct);
+        for (i, ((at_i, bt_i), ct_i)) in constraints.enumerate() {
+            for &(coeff, index) in at_i {
+                let index = variable_index(index);
+                a_g1[index] += lagrange_g1[i] * coeff;
+                abc_g1[index] += beta_lagrange_g1[i] * coeff;
+            }
+            for &(coeff, index) in bt_i {
+                let index = variable_index(index);
+                b_g1[index] += lagrange_g1[i] * coeff;
+                b_g2[index] += lagrange_g2[i] * coeff;
+                abc_g1[index] += alpha_lagrange_g1[i] * coeff;
+            }
+            for &(coeff, index) in ct_i {
+                abc_g1[variable_index(index)] += lagrange_g1[i] * coeff;
+            }
+        }
+        // Each public variable is also constrained by `x_i * 0 = 0`, to keep the A polynomials independent.
+        let public_variables = a_g1.iter_mut().zip(&mut abc_g1).take(num_public_variables);
+        for (i, (a, abc)) in public_variables.enumerate() {
+            *a += lagrange_g1[num_constraints + i];
+            *abc += beta_lagrange_g1[num_constraints + i];
+        }
+        end_timer!(qap_time);
+
+        // The H-query is `{ \tau^i Z(\tau) G }`, where `Z(X) = X^n - 1` is the vanishing polynomial of the domain.
+        let h_query = (0..domain_size - 1)
+            .map(|i| phase1.powers_of_g[i + domain_size].into_projective() - phase1.powers_of_g[i].into_projective())
+            .collect();
+
+        let mut abc_g1 = E::G1Projective::batch_normalization_into_affine(abc_g1);
+        let l_query = abc_g1.split_off(num_public_variables);
+
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        let proving_key = ProvingKey {
+            vk: VerifyingKey {
+                alpha_g1: phase1.powers_of_alpha_g[0],
+                beta_g2: phase1.beta_h,
+                gamma_g2: h,
+                delta_g2: h,
+                gamma_abc_g1: abc_g1,
+            },
+            beta_g1: phase1.powers_of_beta_g[0],
+            delta_g1: g,
+            a_query: E::G1Projective::batch_normalization_into_affine(a_g1),
+            b_g1_query: E::G1Projective::batch_normalization_into_affine(b_g1),
+            b_g2_query: E::G2Projective::batch_normalization_into_affine(b_g2),
+            h_query: E::G1Projective::batch_normalization_into_affine(h_query),
+            l_query,
+        };
+        let circuit_hash = hash(&[], &proving_key)?;
+        end_timer!(setup_time);
+
+        Ok(Self {
+            proving_key,
+            circuit_hash,
+            contributions: vec![],
+        })
+    }
+
+    /// Returns the verifying key after the latest contribution.
+    pub fn verifying_key(&self) -> &VerifyingKey<E> {
+        &self.proving_key.vk
+    }
+
+    /// Multiplies `δ` by a fresh random factor, and appends a contribution proving knowledge of it.
+    /// The factor is discarded when this returns.
+    pub fn contribute<R: Rng>(&mut self, rng: &mut R) -> Result<(), SNARKError> {
+        let contribute_time = start_timer!(|| "Groth16 MPC::Phase 2 contribution");
+        let challenge = self.challenge(self.contributions.len())?;
+
+        let delta = nonzero_rand::<E::Fr, _>(rng);
+        let delta_proof = KnowledgeProof::new(delta, &challenge, DELTA_PROOF_TAG, rng)?;
+        let delta_inverse = delta.inverse().ok_or(SynthesisError::UnexpectedIdentity)?;
+
+        let proving_key = &mut self.proving_key;
+        proving_key.delta_g1 = (proving_key.delta_g1.into_projective() * delta).into_affine();
+        proving_key.vk.delta_g2 = (proving_key.vk.delta_g2.into_projective() * delta).into_affine();
+        scale(&mut proving_key.h_query, delta_inverse);
+        scale(&mut proving_key.l_query, delta_inverse);
+
+        self.contributions.push(Phase2Contribution {
+            delta_g1: self.proving_key.delta_g1,
+            delta_proof,
+        });
+        end_timer!(contribute_time);
+        Ok(())
+    }
+
+    /// Verifies that `self` is the result of contributions to the initial proving key
+    /// derived for `circuit` from `phase1`.
+    pub fn verify<C: ConstraintSynthesizer<E::Fr>, R: Rng>(
+        &self,
+        circuit: &C,
+        phase1: &PowersOfTau<E>,
+        rng: &mut R,
+    ) -> Result<(), SNARKError> {
+        self.verify_extends(&Self::new(circuit, phase1)?, rng)
+    }
+
+    /// Verifies that `self` is the result of further contributions to `previous`.
+    pub fn verify_extends<R: Rng>(&self, previous: &Self, rng: &mut R) -> Result<(), SNARKError> {
+        let verify_time = start_timer!(|| "Groth16 MPC::Phase 2 verification");
+        if self.circuit_hash != previous.circuit_hash {
+            return Err(invalid("the circuit does not match the previous transcript"));
+        }
+        if !self.contributions.starts_with(&previous.contributions) {
+            return Err(invalid("the contributions do not extend the previous transcript"));
+        }
+
+        // Only `δ` and the queries that are divided by it may change.
+        let (current, previous_key) = (&self.proving_key, &previous.proving_key);
+        if current.vk.alpha_g1 != previous_key.vk.alpha_g1
+            || current.vk.beta_g2 != previous_key.vk.beta_g2
+            || current.vk.gamma_g2 != previous_key.vk.gamma_g2
+            || current.vk.gamma_abc_g1 != previous_key.vk.gamma_abc_g1
+            || current.beta_g1 != previous_key.beta_g1
+            || current.a_query != previous_key.a_query
+            || current.b_g1_query != previous_key.b_g1_query
+            || current.b_g2_query != previous_key.b_g2_query
+            || current.h_query.len() != previous_key.h_query.len()
+            || current.l_query.len() != previous_key.l_query.len()
+        {
+            return Err(invalid("the proving key differs from the previous one in more than δ"));
+        }
+
+        // Check that each new contribution multiplies the previous `δ G` by the factor it proves knowledge of.
+        let mut delta_g1 = previous_key.delta_g1;
+        for (i, contribution) in self.contributions.iter().enumerate().skip(previous.contributions.len()) {
+            let challenge = self.challenge(i)?;
+            let delta_ratio = contribution.delta_proof.verify(&challenge, DELTA_PROOF_TAG)?;
+            if !same_ratio::<E>((delta_g1, contribution.delta_g1), delta_ratio) {
+                return Err(invalid(&format!("contribution {} does not extend the previous one", i)));
+            }
+            delta_g1 = contribution.delta_g1;
+        }
+        if current.delta_g1 != delta_g1 {
+            return Err(invalid("the proving key does not match the last contribution"));
+        }
+
+        // Check that `δ` changed by the same factor in both groups, and that the H and L queries were divided by it.
+        let delta_ratio_g2 = (previous_key.vk.delta_g2, current.vk.delta_g2);
+        let current_queries = [&current.h_query[..], &current.l_query[..]].concat();
+        let previous_queries = [&previous_key.h_query[..], &previous_key.l_query[..]].concat();
+        if !same_ratio::<E>((previous_key.delta_g1, current.delta_g1), delta_ratio_g2)
+            || !same_ratio::<E>(merge_pairs(&current_queries, &previous_queries, rng), delta_ratio_g2)
+        {
+            return Err(invalid("the proving key is not consistent with δ"));
+        }
+        end_timer!(verify_time);
+        Ok(())
+    }
+
+    /// Returns the challenge that the proof of knowledge in the contribution at `index` is bound to,
+    /// which is the hash of the circuit and all preceding contributions.
+    fn challenge(&self, index: usize) -> Result<Vec<u8>, SNARKError> {
+        hash(&self.circuit_hash, &self.contributions[..index])
+    }
+}
+
+impl<E: PairingEngine> From<Phase2Parameters<E>> for ProvingKey<E> {
+    fn from(other: Phase2Parameters<E>) -> Self {
+        other.proving_key
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Phase2Parameters<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        CanonicalSerialize::serialize(self, &mut writer)?;
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Phase2Parameters<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(CanonicalDeserialize::deserialize(&mut reader)?)
+    }
+}
+
+/// Returns `{ L_i(\tau) P }` for the Lagrange polynomials `L_i` of `domain`, given `powers = { \tau^i P }`.
+fn lagrange_basis<G: AffineCurve>(domain: &EvaluationDomain<G::ScalarField>, powers: &[G]) -> Vec<G::Projective> {
+    let mut basis: Vec<_> = powers.iter().map(|power| power.into_projective()).collect();
+    domain.ifft_in_place(&mut basis);
+    basis
+}
@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_fields::One;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::io::{Read, Result as IoResult, Write};
+
+const TAU_PROOF_TAG: &[u8] = b"tau";
+const GAMMA_PROOF_TAG: &[u8] = b"gamma";
+const ALPHA_PROOF_TAG: &[u8] = b"alpha";
+const BETA_PROOF_TAG: &[u8] = b"beta";
+
+/// A participant's contribution to the powers-of-tau ceremony.
+#[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+pub struct Phase1Contribution<E: PairingEngine> {
+    /// `τ G` after this contribution.
+    pub tau_g: E::G1Affine,
+    /// `γ G` after this contribution.
+    pub gamma_g: E::G1Affine,
+    /// `α G` after this contribution.
+    pub alpha_g: E::G1Affine,
+    /// `β G` after this contribution.
+    pub beta_g: E::G1Affine,
+    /// A proof of knowledge of the factor applied to `τ`.
+    pub tau_proof: KnowledgeProof<E>,
+    /// A proof of knowledge of the factor applied to `γ`.
+    pub gamma_proof: KnowledgeProof<E>,
+    /// A proof of knowledge of the factor applied to `α`.
+    pub alpha_proof: KnowledgeProof<E>,
+    /// A proof of knowledge of the factor applied to `β`.
+    pub beta_proof: KnowledgeProof<E>,
+}
+
+/// The transcript of a powers-of-tau ceremony for polynomials of up to a given maximum degree.
+///
+/// The powers of `τ` and `γ τ` are the universal parameters of KZG10, and the powers of `τ`, `α τ`
+/// and `β τ` are the first phase of the Groth16 ceremony, for evaluation domains of up to
+/// [`max_domain_size`](Self::max_domain_size) elements.
+#[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""), Eq(bound = ""))]
+pub struct PowersOfTau<E: PairingEngine> {
+    /// Group elements of the form `{ \tau^i G }`, where `i` ranges from 0 to `max_degree`.
+    pub powers_of_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \tau^i \gamma G }`, where `i` ranges from 0 to `max_degree + 1`.
+    pub powers_of_gamma_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \alpha \tau^i G }`, where `i` ranges from 0 to `max_degree`.
+    pub powers_of_alpha_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta \tau^i G }`, where `i` ranges from 0 to `max_degree`.
+    pub powers_of_beta_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \tau^i H }`, where `i` ranges from 0 to `max_degree`.
+    pub powers_of_h: Vec<E::G2Affine>,
+    /// Group elements of the form `{ \tau^{-i} H }`, where `i` ranges from 0 to `max_degree`.
+    pub neg_powers_of_h: Vec<E::G2Affine>,
+    /// \beta times the generator of G2.
+    pub beta_h: E::G2Affine,
+    /// The contributions to the ceremony, in the order they were made.
+    pub contributions: Vec<Phase1Contribution<E>>,
+}
+
+impl<E: PairingEngine> PowersOfTau<E> {
+    /// Initializes a transcript for polynomials of up to `max_degree`, with `τ = γ = α = β = 1`.
+    pub fn new(max_degree: usize) -> Result<Self, SNARKError> {
+        if max_degree < 1 {
+            return Err(invalid("the maximum degree must be at least one"));
+        }
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        Ok(Self {
+            powers_of_g: vec![g; max_degree + 1],
+            powers_of_gamma_g: vec![g; max_degree + 2],
+            powers_of_alpha_g: vec![g; max_degree + 1],
+            powers_of_beta_g: vec![g; max_degree + 1],
+            powers_of_h: vec![h; max_degree + 1],
+            neg_powers_of_h: vec![h; max_degree + 1],
+            beta_h: h,
+            contributions: vec![],
+        })
+    }
+
+    /// Returns the maximum degree of the polynomials supported by `self`.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_g.len().saturating_sub(1)
+    }
+
+    /// Returns the maximum size of the Groth16 evaluation domains supported by `self`,
+    /// whose H-query needs the powers of `τ` up to `2 size - 2`.
+    pub fn max_domain_size(&self) -> usize {
+        self.max_degree() / 2 + 1
+    }
+
+    /// Multiplies `τ`, `γ`, `α` and `β` by fresh random factors, and appends a contribution
+    /// proving knowledge of them. The factors are discarded when this returns.
+    pub fn contribute<R: Rng>(&mut self, rng: &mut R) -> Result<(), SNARKError> {
+        let contribute_time = start_timer!(|| format!("PowersOfTau::Contribute with degree {}", self.max_degree()));
+        let challenge = self.challenge(self.contributions.len())?;
+
+        let tau = nonzero_rand::<E::Fr, _>(rng);
+        let gamma = nonzero_rand::<E::Fr, _>(rng);
+        let alpha = nonzero_rand::<E::Fr, _>(rng);
+        let beta = nonzero_rand::<E::Fr, _>(rng);
+        let tau_proof = KnowledgeProof::new(tau, &challenge, TAU_PROOF_TAG, rng)?;
+        let gamma_proof = KnowledgeProof::new(gamma, &challenge, GAMMA_PROOF_TAG, rng)?;
+        let alpha_proof = KnowledgeProof::new(alpha, &challenge, ALPHA_PROOF_TAG, rng)?;
+        let beta_proof = KnowledgeProof::new(beta, &challenge, BETA_PROOF_TAG, rng)?;
+
+        let tau_inverse = tau
+            .inverse()
+            .ok_or_else(|| invalid("the factor applied to τ is zero"))?;
+        scale_powers(&mut self.powers_of_g, E::Fr::one(), tau);
+        scale_powers(&mut self.powers_of_gamma_g, gamma, tau);
+        scale_powers(&mut self.powers_of_alpha_g, alpha, tau);
+        scale_powers(&mut self.powers_of_beta_g, beta, tau);
+        scale_powers(&mut self.powers_of_h, E::Fr::one(), tau);
+        scale_powers(&mut self.neg_powers_of_h, E::Fr::one(), tau_inverse);
+        self.beta_h = (self.beta_h.into_projective() * beta).into_affine();
+
+        self.contributions.push(Phase1Contribution {
+            tau_g: self.powers_of_g[1],
+            gamma_g: self.powers_of_gamma_g[0],
+            alpha_g: self.powers_of_alpha_g[0],
+            beta_g: self.powers_of_beta_g[0],
+            tau_proof,
+            gamma_proof,
+            alpha_proof,
+            beta_proof,
+        });
+        end_timer!(contribute_time);
+        Ok(())
+    }
+
+    /// Verifies every contribution in the transcript, and that the accumulated powers
+    /// are consistent with the last one.
+    pub fn verify<R: Rng>(&self, rng: &mut R) -> Result<(), SNARKError> {
+        let verify_time = start_timer!(|| format!("PowersOfTau::Verify with degree {}", self.max_degree()));
+        let max_degree = self.max_degree();
+        if max_degree < 1 {
+            return Err(invalid("the maximum degree must be at least one"));
+        }
+        if self.powers_of_gamma_g.len() != max_degree + 2
+            || self.powers_of_alpha_g.len() != max_degree + 1
+            || self.powers_of_beta_g.len() != max_degree + 1
+            || self.powers_of_h.len() != max_degree + 1
+            || self.neg_powers_of_h.len() != max_degree + 1
+        {
+            return Err(invalid("the number of powers does not match the maximum degree"));
+        }
+
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        if self.powers_of_g[0] != g || self.powers_of_h[0] != h || self.neg_powers_of_h[0] != h {
+            return Err(invalid("the powers do not start with the generators"));
+        }
+
+        // Check that each contribution multiplies the previous `τ G`, `γ G`, `α G` and `β G`
+        // by the factors it proves knowledge of.
+        let (mut tau_g, mut gamma_g, mut alpha_g, mut beta_g) = (g, g, g, g);
+        for (i, contribution) in self.contributions.iter().enumerate() {
+            let challenge = self.challenge(i)?;
+            let tau_ratio = contribution.tau_proof.verify(&challenge, TAU_PROOF_TAG)?;
+            let gamma_ratio = contribution.gamma_proof.verify(&challenge, GAMMA_PROOF_TAG)?;
+            let alpha_ratio = contribution.alpha_proof.verify(&challenge, ALPHA_PROOF_TAG)?;
+            let beta_ratio = contribution.beta_proof.verify(&challenge, BETA_PROOF_TAG)?;
+            if !same_ratio::<E>((tau_g, contribution.tau_g), tau_ratio)
+                || !same_ratio::<E>((gamma_g, contribution.gamma_g), gamma_ratio)
+                || !same_ratio::<E>((alpha_g, contribution.alpha_g), alpha_ratio)
+                || !same_ratio::<E>((beta_g, contribution.beta_g), beta_ratio)
+            {
+                return Err(invalid(&format!("contribution {} does not extend the previous one", i)));
+            }
+            tau_g = contribution.tau_g;
+            gamma_g = contribution.gamma_g;
+            alpha_g = contribution.alpha_g;
+            beta_g = contribution.beta_g;
+        }
+        if self.powers_of_g[1] != tau_g
+            || self.powers_of_gamma_g[0] != gamma_g
+            || self.powers_of_alpha_g[0] != alpha_g
+            || self.powers_of_beta_g[0] != beta_g
+        {
+            return Err(invalid("the powers do not match the last contribution"));
+        }
+
+        // Check that the powers are consecutive powers of `τ`, and that `β` is consistent across groups.
+        let tau_h = self.powers_of_h[1];
+        if !same_ratio::<E>((g, tau_g), (h, tau_h))
+            || !same_ratio::<E>((g, beta_g), (h, self.beta_h))
+            || !same_ratio::<E>(power_pairs(&self.powers_of_g, rng), (h, tau_h))
+            || !same_ratio::<E>(power_pairs(&self.powers_of_gamma_g, rng), (h, tau_h))
+            || !same_ratio::<E>(power_pairs(&self.powers_of_alpha_g, rng), (h, tau_h))
+            || !same_ratio::<E>(power_pairs(&self.powers_of_beta_g, rng), (h, tau_h))
+            || !same_ratio::<E>((g, tau_g), power_pairs(&self.powers_of_h, rng))
+            || !same_ratio::<E>((tau_g, g), power_pairs(&self.neg_powers_of_h, rng))
+        {
+            return Err(invalid("the powers are not consecutive powers of τ"));
+        }
+        end_timer!(verify_time);
+        Ok(())
+    }
+
+    /// Verifies `self`, and that it extends the contributions of `previous`.
+    pub fn verify_extends<R: Rng>(&self, previous: &Self, rng: &mut R) -> Result<(), SNARKError> {
+        if self.max_degree() != previous.max_degree() {
+            return Err(invalid("the maximum degree does not match the previous transcript"));
+        }
+        if !self.contributions.starts_with(&previous.contributions) {
+            return Err(invalid("the contributions do not extend the previous transcript"));
+        }
+        self.verify(rng)
+    }
+
+    /// Returns the challenge that the proofs of knowledge in the contribution at `index` are bound to,
+    /// which is the hash of the maximum degree and all preceding contributions.
+    fn challenge(&self, index: usize) -> Result<Vec<u8>, SNARKError> {
+        hash(&(self.max_degree() as u64).to_le_bytes(), &self.contributions[..index])
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PowersOfTau<E> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        CanonicalSerialize::serialize(self, &mut writer)?;
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PowersOfTau<E> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(CanonicalDeserialize::deserialize(&mut reader)?)
+    }
+}
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{
+    batch_verify_proofs,
     create_random_proof,
     generate_random_parameters,
     prepare_verifying_key,
@@ -91,4 +92,26 @@ impl<E: PairingEngine, C: ConstraintSynthesizer<E::Fr>, V: ToConstraintField<E::
         end_timer!(verify_time);
         Ok(result)
     }
+
+    fn batch_verify<R: Rng>(
+        verifying_key: &Self::PreparedVerifyingKey,
+        inputs_and_proofs: &[(&Self::VerifierInput, &Self::Proof)],
+        rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        let verify_time = start_timer!(|| format!("{{Groth 2016}}::BatchVerify w/ {} proofs", inputs_and_proofs.len()));
+        let conversion_time = start_timer!(|| "Convert inputs to E::Fr");
+        let inputs = inputs_and_proofs
+            .iter()
+            .map(|(input, _)| input.to_field_elements())
+            .collect::<Result<Vec<_>, _>>()?;
+        end_timer!(conversion_time);
+        let inputs_and_proofs = inputs
+            .iter()
+            .zip(inputs_and_proofs)
+            .map(|(input, (_, proof))| (input.as_slice(), *proof))
+            .collect::<Vec<_>>();
+        let result = batch_verify_proofs(&verifying_key, &inputs_and_proofs, rng)?;
+        end_timer!(verify_time);
+        Ok(result)
+    }
 }
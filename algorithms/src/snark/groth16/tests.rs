@@ -50,7 +50,18 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
 
 mod bls12_377 {
     use super::*;
-    use crate::snark::groth16::{create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof};
+    use crate::{
+        snark::groth16::{
+            batch_verify_proofs,
+            create_random_proof,
+            generate_random_parameters,
+            prepare_verifying_key,
+            verify_proof,
+            Groth16,
+            Proof,
+        },
+        traits::SNARK,
+    };
     use core::ops::MulAssign;
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
     use snarkvm_utilities::rand::{test_rng, UniformRand};
@@ -75,6 +86,71 @@ mod bls12_377 {
             assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
         }
     }
+
+    #[test]
+    fn batch_verify() {
+        let rng = &mut test_rng();
+
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(parameters.vk.clone());
+
+        let mut inputs = vec![];
+        let mut proofs = vec![];
+        for _ in 0..10 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let mut c = a;
+            c.mul_assign(&b);
+
+            proofs.push(create_random_proof(&MySillyCircuit { a: Some(a), b: Some(b) }, &parameters, rng).unwrap());
+            inputs.push(vec![c]);
+        }
+
+        let mut inputs_and_proofs: Vec<(&[Fr], &Proof<Bls12_377>)> =
+            inputs.iter().map(|input| input.as_slice()).zip(&proofs).collect();
+        assert!(batch_verify_proofs(&pvk, &inputs_and_proofs, rng).unwrap());
+        assert!(batch_verify_proofs(&pvk, &inputs_and_proofs[..1], rng).unwrap());
+        assert!(batch_verify_proofs::<Bls12_377, _>(&pvk, &[], rng).unwrap());
+
+        // A single invalid proof fails the whole batch.
+        let invalid_input = [Fr::rand(rng)];
+        inputs_and_proofs[4].0 = &invalid_input[..];
+        assert!(!batch_verify_proofs(&pvk, &inputs_and_proofs, rng).unwrap());
+
+        inputs_and_proofs[4] = (inputs[4].as_slice(), &proofs[5]);
+        assert!(!batch_verify_proofs(&pvk, &inputs_and_proofs, rng).unwrap());
+
+        // Inputs of the wrong length are rejected.
+        inputs_and_proofs[4] = (&[][..], &proofs[4]);
+        assert!(batch_verify_proofs(&pvk, &inputs_and_proofs, rng).is_err());
+    }
+
+    #[test]
+    fn batch_verify_snark() {
+        type TestSNARK = Groth16<Bls12_377, MySillyCircuit<Fr>, [Fr]>;
+
+        let rng = &mut test_rng();
+
+        let (parameters, pvk) = TestSNARK::setup(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+
+        let mut inputs = vec![];
+        let mut proofs = vec![];
+        for _ in 0..4 {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+
+            proofs.push(TestSNARK::prove(&parameters, &MySillyCircuit { a: Some(a), b: Some(b) }, rng).unwrap());
+            inputs.push(vec![a * b]);
+        }
+
+        let mut inputs_and_proofs: Vec<(&[Fr], &Proof<Bls12_377>)> =
+            inputs.iter().map(|input| input.as_slice()).zip(&proofs).collect();
+        assert!(TestSNARK::batch_verify(&pvk, &inputs_and_proofs, rng).unwrap());
+
+        inputs_and_proofs[0].1 = &proofs[1];
+        assert!(!TestSNARK::batch_verify(&pvk, &inputs_and_proofs, rng).unwrap());
+    }
 }
 
 mod bw6_761 {
@@ -162,3 +238,122 @@ mod serialization {
         assert_eq!(recovered_proof.compressed, false);
     }
 }
+
+mod mpc {
+    use super::*;
+    use crate::snark::groth16::{
+        create_random_proof,
+        mpc::{Phase2Parameters, PowersOfTau},
+        prepare_verifying_key,
+        verify_proof,
+        ProvingKey,
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::{
+        rand::{test_rng, UniformRand},
+        FromBytes,
+        ToBytes,
+    };
+
+    #[test]
+    fn test_ceremony_produces_valid_parameters() {
+        let rng = &mut test_rng();
+        let circuit = MySillyCircuit { a: None, b: None };
+
+        let mut phase1 = PowersOfTau::<Bls12_377>::new(32).unwrap();
+        for _ in 0..2 {
+            let previous = phase1.clone();
+            phase1.contribute(rng).unwrap();
+            phase1.verify_extends(&previous, rng).unwrap();
+        }
+        let phase1_bytes = phase1.to_bytes_le().unwrap();
+        assert_eq!(PowersOfTau::read_le(&phase1_bytes[..]).unwrap(), phase1);
+
+        let mut phase2 = Phase2Parameters::new(&circuit, &phase1).unwrap();
+        phase2.verify(&circuit, &phase1, rng).unwrap();
+        for _ in 0..3 {
+            let previous = phase2.clone();
+            phase2.contribute(rng).unwrap();
+            phase2.verify_extends(&previous, rng).unwrap();
+        }
+        phase2.verify(&circuit, &phase1, rng).unwrap();
+        let phase2_bytes = phase2.to_bytes_le().unwrap();
+        assert_eq!(Phase2Parameters::read_le(&phase2_bytes[..]).unwrap(), phase2);
+
+        // The final proving key is readable as a regular one, and produces valid proofs.
+        let proving_key_bytes = ProvingKey::from(phase2).to_bytes_le().unwrap();
+        let parameters = ProvingKey::<Bls12_377>::read_le(&proving_key_bytes[..]).unwrap();
+        let pvk = prepare_verifying_key(parameters.vk.clone());
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let proof = create_random_proof(&MySillyCircuit { a: Some(a), b: Some(b) }, &parameters, rng).unwrap();
+        assert!(verify_proof(&pvk, &proof, &[a * b]).unwrap());
+        assert!(!verify_proof(&pvk, &proof, &[a]).unwrap());
+    }
+
+    #[test]
+    fn test_ceremony_rejects_invalid_transcripts() {
+        let rng = &mut test_rng();
+        let circuit = MySillyCircuit { a: None, b: None };
+
+        let mut phase1 = PowersOfTau::<Bls12_377>::new(16).unwrap();
+        phase1.contribute(rng).unwrap();
+        phase1.contribute(rng).unwrap();
+        phase1.verify(rng).unwrap();
+
+        let mut tampered = phase1.clone();
+        tampered.powers_of_alpha_g.swap(2, 3);
+        assert!(tampered.verify(rng).is_err());
+
+        let mut tampered = phase1.clone();
+        tampered.powers_of_gamma_g[9] = tampered.powers_of_g[1];
+        assert!(tampered.verify(rng).is_err());
+
+        let mut tampered = phase1.clone();
+        tampered.neg_powers_of_h.swap(1, 2);
+        assert!(tampered.verify(rng).is_err());
+
+        let mut tampered = phase1.clone();
+        tampered.contributions[1].beta_proof = tampered.contributions[0].beta_proof.clone();
+        assert!(tampered.verify(rng).is_err());
+
+        // Phase 1 must cover the evaluation domain of the circuit.
+        let small_phase1 = PowersOfTau::<Bls12_377>::new(8).unwrap();
+        assert!(Phase2Parameters::new(&circuit, &small_phase1).is_err());
+
+        let mut phase2 = Phase2Parameters::new(&circuit, &phase1).unwrap();
+        phase2.contribute(rng).unwrap();
+        let first = phase2.clone();
+        phase2.contribute(rng).unwrap();
+        phase2.verify(&circuit, &phase1, rng).unwrap();
+
+        let mut tampered = phase2.clone();
+        tampered.proving_key.l_query[0] = tampered.proving_key.l_query[1];
+        assert!(tampered.verify(&circuit, &phase1, rng).is_err());
+
+        let mut tampered = phase2.clone();
+        tampered.proving_key.vk.delta_g2 = first.proving_key.vk.delta_g2;
+        assert!(tampered.verify(&circuit, &phase1, rng).is_err());
+
+        let mut tampered = phase2.clone();
+        tampered.proving_key.a_query.swap(0, 1);
+        assert!(tampered.verify(&circuit, &phase1, rng).is_err());
+
+        // Contributions cannot be dropped from the chain.
+        let mut tampered = phase2.clone();
+        tampered.contributions.remove(0);
+        assert!(tampered.verify(&circuit, &phase1, rng).is_err());
+
+        // A transcript must extend the contributions of the previous one, for the same phase 1.
+        assert!(phase2.verify_extends(&first, rng).is_ok());
+        let mut other = Phase2Parameters::new(&circuit, &phase1).unwrap();
+        other.contribute(rng).unwrap();
+        other.contribute(rng).unwrap();
+        assert!(other.verify_extends(&first, rng).is_err());
+
+        let mut other_phase1 = phase1.clone();
+        other_phase1.contribute(rng).unwrap();
+        assert!(phase2.verify(&circuit, &other_phase1, rng).is_err());
+    }
+}
@@ -15,10 +15,12 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{PreparedVerifyingKey, Proof, VerifyingKey};
-use snarkvm_curves::traits::{PairingCurve, PairingEngine};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, PrimeField, Zero};
 use snarkvm_r1cs::errors::SynthesisError;
 
 use core::ops::{AddAssign, Mul, Neg};
+use rand::Rng;
 
 pub fn prepare_verifying_key<E: PairingEngine>(vk: VerifyingKey<E>) -> PreparedVerifyingKey<E> {
     let alpha_g1_beta_g2 = E::pairing(vk.alpha_g1, vk.beta_g2);
@@ -61,3 +63,59 @@ pub fn verify_proof<E: PairingEngine>(
 
     Ok(test == pvk.alpha_g1_beta_g2)
 }
+
+/// Verifies a batch of proofs against the same verifying key, returning `true` iff every proof is valid.
+///
+/// Each verification equation `e(A, B) = e(alpha, beta) * e(g_ic, gamma) * e(C, delta)` is raised to
+/// a random 128-bit scalar `r_i`, and the results are multiplied together. This takes a single Miller loop
+/// over `n + 2` pairs and a single final exponentiation, and accepts a batch containing an invalid proof
+/// with probability at most `2^-128`.
+pub fn batch_verify_proofs<E: PairingEngine, R: Rng>(
+    pvk: &PreparedVerifyingKey<E>,
+    inputs_and_proofs: &[(&[E::Fr], &Proof<E>)],
+    rng: &mut R,
+) -> Result<bool, SynthesisError> {
+    if inputs_and_proofs
+        .iter()
+        .any(|(public_inputs, _)| (public_inputs.len() + 1) != pvk.gamma_abc_g1().len())
+    {
+        return Err(SynthesisError::MalformedVerifyingKey);
+    }
+    if inputs_and_proofs.is_empty() {
+        return Ok(true);
+    }
+
+    let mut randomizer_sum = E::Fr::zero();
+    let mut randomized_a = Vec::with_capacity(inputs_and_proofs.len());
+    let mut randomized_g_ic = E::G1Projective::zero();
+    let mut randomized_c = E::G1Projective::zero();
+
+    for (public_inputs, proof) in inputs_and_proofs {
+        let randomizer = E::Fr::from(rng.gen::<u128>());
+        randomizer_sum += randomizer;
+
+        let mut g_ic = pvk.gamma_abc_g1()[0].into_projective();
+        for (i, b) in public_inputs.iter().zip(pvk.gamma_abc_g1().iter().skip(1)) {
+            g_ic.add_assign(b.into_projective().mul(*i));
+        }
+
+        randomized_a.push(proof.a.into_projective().mul(randomizer));
+        randomized_g_ic.add_assign(g_ic.mul(randomizer));
+        randomized_c.add_assign(proof.c.into_projective().mul(randomizer));
+    }
+
+    let randomized_a = E::G1Projective::batch_normalization_into_affine(randomized_a);
+    let mut pairs = randomized_a
+        .iter()
+        .zip(inputs_and_proofs)
+        .map(|(a, (_, proof))| (a.prepare(), proof.b.prepare()))
+        .collect::<Vec<_>>();
+    pairs.push((randomized_g_ic.into_affine().prepare(), pvk.gamma_g2_neg_pc.clone()));
+    pairs.push((randomized_c.into_affine().prepare(), pvk.delta_g2_neg_pc.clone()));
+
+    let qap = E::miller_loop(pairs.iter().map(|(a, b)| (a, b)));
+
+    let test = E::final_exponentiation(&qap).ok_or(SynthesisError::UnexpectedIdentity)?;
+
+    Ok(test == pvk.alpha_g1_beta_g2.pow(randomizer_sum.to_repr()))
+}
@@ -54,4 +54,19 @@ pub trait SNARK {
         input: &Self::VerifierInput,
         proof: &Self::Proof,
     ) -> Result<bool, SNARKError>;
+
+    /// Returns `true` iff every proof in the batch is valid for its input.
+    /// SNARKs that support batch verification override this to verify the proofs together.
+    fn batch_verify<R: Rng>(
+        verifying_key: &Self::PreparedVerifyingKey,
+        inputs_and_proofs: &[(&Self::VerifierInput, &Self::Proof)],
+        _rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        for (input, proof) in inputs_and_proofs {
+            if !Self::verify(verifying_key, input, proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
@@ -17,10 +17,11 @@
 pub(crate) mod g1 {
     use snarkvm_curves::{
         bls12_377::{Fr, G1Affine, G1Projective as G1},
-        traits::ProjectiveCurve,
+        traits::{AffineCurve, ProjectiveCurve},
         Group,
     };
-    use snarkvm_utilities::rand::UniformRand;
+    use snarkvm_fields::PrimeField;
+    use snarkvm_utilities::{rand::UniformRand, BitIteratorBE};
 
     use criterion::Criterion;
     use rand::SeedableRng;
@@ -49,6 +50,25 @@ pub(crate) mod g1 {
         });
     }
 
+    pub fn bench_g1_mul_assign_naive(c: &mut Criterion) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let v: Vec<(G1Affine, Fr)> = (0..SAMPLES)
+            .map(|_| (G1::rand(&mut rng).into(), Fr::rand(&mut rng)))
+            .collect();
+
+        let mut count = 0;
+        c.bench_function("bls12_377: g1_mul_assign_naive", |c| {
+            c.iter(|| {
+                let tmp = v[count].0.mul_bits(BitIteratorBE::new(v[count].1.to_repr()));
+                count = (count + 1) % SAMPLES;
+                tmp
+            })
+        });
+    }
+
     pub fn bench_g1_add_assign(c: &mut Criterion) {
         const SAMPLES: usize = 1000;
 
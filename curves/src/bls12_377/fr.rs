@@ -64,6 +64,21 @@ impl FftParameters for FrParameters {
         0xd7aacc7c59724826,
         0xd1ba211c5cc349c,
     ]);
+
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(1);
+    /// A primitive (2^s * 3)-th root of unity whose cube is `TWO_ADIC_ROOT_OF_UNITY`,
+    /// so that power-of-two domains keep their existing generators.
+    /// 6238465316407347989389334371955096722201265967237416567785330701773625701165
+    #[rustfmt::skip]
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInteger> = Some(BigInteger([
+        0x8f3d74cb3b71e0af,
+        0xa6d488573c68abb7,
+        0x4ead3df9bb26b052,
+        0x1264fc998916a0a5,
+    ]));
 }
 
 impl FieldParameters for FrParameters {
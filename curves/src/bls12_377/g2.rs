@@ -19,7 +19,8 @@ use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
 
 use crate::{
     bls12_377::{g1::Bls12_377G1Parameters, Fq, Fq2, Fr},
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    hash_to_curve::svdw::{self, SvdWParameters},
+    traits::{MapToCurve, ModelParameters, ShortWeierstrassParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -86,6 +87,130 @@ impl ShortWeierstrassParameters for Bls12_377G2Parameters {
     }
 }
 
+impl SvdWParameters for Bls12_377G2Parameters {
+    /// Z = [2, 0]
+    const Z: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x59bfffffffffed0,
+                0xa2813f06ffffff62,
+                0x3efb675314fa7fe4,
+                0xf69d2f6edcf8c60b,
+                0x99e92b7f007909d0,
+                0x11accc3c5fbe934,
+            ])
+        ),
+        field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+    );
+    /// C1 = g(Z) = [8,
+    /// -1 / 5]
+    const C1: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xc5e7ffffffffb3e,
+                0x5bee41939ffffd87,
+                0xbe06d8ecdfd76f92,
+                0xa62f09d571f8f10e,
+                0xdb2ea27b28a194cd,
+                0x10ebe82e86582fc,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x8072266666666685,
+                0x8df55926899999a9,
+                0x7fe4561ad64f34cf,
+                0xb95da6d8b6e4f01b,
+                0x4b747cccfc142743,
+                0x39c3fa70f49f43,
+            ])
+        ),
+    );
+    /// C2 = -Z / 2 = [-1, 0]
+    const C2: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0x823ac00000000099,
+                0xc5cabdc0b000004f,
+                0x7f75ae862f8c080d,
+                0x9ed4423b9278b089,
+                0x79467000ec64c452,
+                0x120d3e434c71c50,
+            ])
+        ),
+        field!(Fq, BigInteger384([0, 0, 0, 0, 0, 0])),
+    );
+    /// C3 = sqrt(-g(Z) (3 Z^2 + 4 A)) =
+    /// [176167996011041038227027268172818786419688168714579319282718903616278011457418412984061471725424117204123011379096,
+    /// 209524757051059563433482416591076552384687058796958840533487795092388893314652805681173445630970430848221848549578]
+    const C3: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xf2e4443443a239a0,
+                0xb1706a328d2d4baa,
+                0xd408869c3d3950ef,
+                0x8c5b740d00dc2848,
+                0x9c5df20787ab65b2,
+                0x11985aa9c872bda,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x977c4ed70661597a,
+                0xed9b151a15f45247,
+                0xf71665a9a86de5ac,
+                0x62b3059de3585834,
+                0x87d05ad0803bd9ea,
+                0x110a4dcdaecddb4,
+            ])
+        ),
+    );
+    /// C4 = -4 g(Z) / (3 Z^2 + 4 A) = [-8 / 3,
+    /// 1 / 15]
+    const C4: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger384([
+                0xa9e65555555556ec,
+                0xf0b8285195555628,
+                0xd54aa3d0dc13b579,
+                0x2f5ce35adaa5bcaf,
+                0x906d2301e58aff38,
+                0xc4920317b6df9d,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger384([
+                0x56dcddddddddddd4,
+                0x2db2015f37777772,
+                0x8a5a595c4be8b110,
+                0x2041bbb36e056126,
+                0x7e422da67ad9b5fd,
+                0x7c276e8cf025e2,
+            ])
+        ),
+    );
+}
+
+impl MapToCurve for Bls12_377G2Parameters {
+    #[inline]
+    fn map_to_curve(u: &Fq2) -> Option<(Fq2, Fq2)> {
+        svdw::map_to_curve::<Self>(u)
+    }
+}
+
 pub const G2_GENERATOR_X: Fq2 = field!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = field!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
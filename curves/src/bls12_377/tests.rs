@@ -33,7 +33,10 @@ use crate::{
         G2Affine,
         G2Projective,
     },
-    templates::{short_weierstrass_jacobian::tests::sw_tests, twisted_edwards_extended::tests::edwards_test},
+    templates::{
+        short_weierstrass_jacobian::tests::{sw_glv_tests, sw_tests},
+        twisted_edwards_extended::tests::edwards_test,
+    },
     traits::{
         tests_curve::curve_tests,
         tests_group::group_test,
@@ -406,6 +409,27 @@ fn test_fq_root_of_unity() {
     assert!(Fq::multiplicative_generator().sqrt().is_none());
 }
 
+#[test]
+fn test_fr_large_subgroup_root_of_unity() {
+    let root = Fr::large_subgroup_root_of_unity().unwrap();
+    assert_eq!(root.pow([3]), Fr::two_adic_root_of_unity());
+    assert!(!root.pow([1 << 47]).is_one());
+    assert_eq!(root.pow([3 << 47]), Fr::one());
+
+    // Power-of-two roots of unity are unaffected by the small subgroup.
+    let mut two_adic_root = Fr::two_adic_root_of_unity();
+    for _ in 0..37 {
+        two_adic_root.square_in_place();
+    }
+    assert_eq!(Fr::get_root_of_unity(1 << 10), Some(two_adic_root));
+
+    let omega = Fr::get_root_of_unity(3 << 10).unwrap();
+    assert_eq!(omega.pow([3]), two_adic_root);
+    assert_eq!(omega.pow([3 << 10]), Fr::one());
+    assert!(!omega.pow([1 << 10]).is_one());
+    assert!(Fr::get_root_of_unity(9 << 10).is_none());
+}
+
 #[test]
 fn test_fq_ordering() {
     // BigInteger384's ordering is well-tested, but we still need to make sure the
@@ -569,6 +593,11 @@ fn test_g1_projective_curve() {
     sw_tests::<Bls12_377G1Parameters>();
 }
 
+#[test]
+fn test_g1_glv() {
+    sw_glv_tests::<Bls12_377G1Parameters>();
+}
+
 #[test]
 fn test_g1_projective_group() {
     let a: G1Projective = rand::random();
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{field, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use crate::{
+    bn254::{g1::Bn254G1Parameters, Fq, Fq2, Fr},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+};
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Bn254G2Parameters;
+
+impl ModelParameters for Bn254G2Parameters {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Bn254G2Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (G2_GENERATOR_X, G2_GENERATOR_Y);
+    /// COEFF_A = [0, 0]
+    const COEFF_A: Fq2 = field!(Fq2, Bn254G1Parameters::COEFF_A, Bn254G1Parameters::COEFF_A,);
+    // The twist is y^2 = x^3 + b / (u + 9), where b is the COEFF_B of G1.
+    /// COEFF_B = [
+    /// 19485874751759354771024239261021720505790618469301721065564631296452457478373,
+    /// 266929791119991161246907387137283842545076965332900288569378510910307636690]
+    const COEFF_B: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger256([
+                0x3bf938e377b802a8,
+                0x20b1b273633535d,
+                0x26b7edf049755260,
+                0x2514c6324384a86d,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger256([
+                0x38e7ecccd1dcff67,
+                0x65f0b37d93ce0d3e,
+                0xd749d0dd22ac00aa,
+                0x141b9ce4a688d4d,
+            ])
+        )
+    );
+    /// COFACTOR =
+    /// 21888242871839275222246405745257275088844257914179612981679871602714643921549
+    const COFACTOR: &'static [u64] = &[
+        0x345f2299c0f9fa8d,
+        0x6ceecda572a2489,
+        0xb85045b68181585e,
+        0x30644e72e131a029,
+    ];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    /// = 10944121435919637613327163357776759465618812564592884533313067514031822496649
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger256([
+            0x7fff17d53ff2895e,
+            0xd0617390cf7919e5,
+            0xb9af426b22d0eb61,
+            0x270485e31bd72a4d,
+        ])
+    );
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// G2_GENERATOR_X = [
+/// 10857046999023057135944570762232829481370756359578518086990519993285655852781,
+/// 11559732032986387107991004021392285783925812861821192530917403151452391805634]
+pub const G2_GENERATOR_X: Fq2 = field!(
+    Fq2,
+    field!(
+        Fq,
+        BigInteger256([
+            0x8e83b5d102bc2026,
+            0xdceb1935497b0172,
+            0xfbb8264797811adf,
+            0x19573841af96503b,
+        ])
+    ),
+    field!(
+        Fq,
+        BigInteger256([
+            0xafb4737da84c6140,
+            0x6043dd5a5802d8c4,
+            0x9e950fc52a02f86,
+            0x14fef0833aea7b6b,
+        ])
+    )
+);
+
+/// G2_GENERATOR_Y = [
+/// 8495653923123431417604973247489272438418190587263600148770280649306958101930,
+/// 4082367875863433681332203403145435568316851327593401208105741076214120093531]
+pub const G2_GENERATOR_Y: Fq2 = field!(
+    Fq2,
+    field!(
+        Fq,
+        BigInteger256([
+            0x619dfa9d886be9f6,
+            0xfe7fd297f59e9b78,
+            0xff9e1a62231b7dfe,
+            0x28fd7eebae9e4206,
+        ])
+    ),
+    field!(
+        Fq,
+        BigInteger256([
+            0x64095b56c71856ee,
+            0xdc57f922327d3cbb,
+            0x55f935be33351076,
+            0xda4a0e693fd6482,
+        ])
+    )
+);
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::field;
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use crate::{
+    bn254::{
+        g1::Bn254G1Parameters,
+        g2::Bn254G2Parameters,
+        Fq,
+        Fq12,
+        Fq12Parameters,
+        Fq2,
+        Fq2Parameters,
+        Fq6Parameters,
+    },
+    templates::bn::{
+        Bn,
+        BnParameters,
+        G1Affine as BnG1Affine,
+        G1Prepared,
+        G1Projective as BnG1Projective,
+        G2Affine as BnG2Affine,
+        G2Prepared,
+        G2Projective as BnG2Projective,
+        TwistType,
+    },
+    traits::{PairingCurve, PairingEngine},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bn254Parameters;
+
+impl BnParameters for Bn254Parameters {
+    type Fp = Fq;
+    type Fp12Params = Fq12Parameters;
+    type Fp2Params = Fq2Parameters;
+    type Fp6Params = Fq6Parameters;
+    type G1Parameters = Bn254G1Parameters;
+    type G2Parameters = Bn254G2Parameters;
+
+    /// 6x + 2 = 29793968203157093288, in non-adjacent form
+    const ATE_LOOP_COUNT: &'static [i8] = &[
+        0, 0, 0, 1, 0, 1, 0, -1, 0, 0, -1, 0, 0, 0, 1, 0, 0, -1, 0, -1, 0, 0, 0, 1, 0, -1, 0, 0, 0, 0, -1, 0, 0, 1, 0,
+        -1, 0, 0, 1, 0, 0, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, -1, 0, -1, 0, 0, 0, 1, 0, -1, 0, 1,
+    ];
+    const TWIST_MUL_BY_Q_X: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger256([
+                0xb5773b104563ab30,
+                0x347f91c8a9aa6454,
+                0x7a007127242e0991,
+                0x1956bcd8118214ec,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger256([
+                0x6e849f1ea0aa4757,
+                0xaa1c7b6d89f89141,
+                0xb6e713cdfae0ca3a,
+                0x26694fbb4e82ebc3,
+            ])
+        )
+    );
+    const TWIST_MUL_BY_Q_Y: Fq2 = field!(
+        Fq2,
+        field!(
+            Fq,
+            BigInteger256([
+                0xe4bbdd0c2936b629,
+                0xbb30f162e133bacb,
+                0x31a9d1b6f9645366,
+                0x253570bea500f8dd,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger256([
+                0xa1d77ce45ffe77c7,
+                0x7affd117826d1db,
+                0x6d16bd27bb7edc6b,
+                0x2c87200285defecc,
+            ])
+        )
+    );
+    const TWIST_TYPE: TwistType = TwistType::D;
+    /// x = 4965661367192848881
+    const X: &'static [u64] = &[0x44e992b44a6909f1];
+    /// `x` is positive.
+    const X_IS_NEGATIVE: bool = false;
+}
+
+pub type Bn254 = Bn<Bn254Parameters>;
+
+pub type G1Affine = BnG1Affine<Bn254Parameters>;
+pub type G1Projective = BnG1Projective<Bn254Parameters>;
+
+impl PairingCurve for G1Affine {
+    type Engine = Bn254;
+    type PairWith = G2Affine;
+    type PairingResult = Fq12;
+    type Prepared = G1Prepared<Bn254Parameters>;
+
+    fn prepare(&self) -> Self::Prepared {
+        Self::Prepared::from_affine(*self)
+    }
+
+    fn pairing_with(&self, other: &Self::PairWith) -> Self::PairingResult {
+        Bn254::pairing(*self, *other)
+    }
+}
+
+pub type G2Affine = BnG2Affine<Bn254Parameters>;
+pub type G2Projective = BnG2Projective<Bn254Parameters>;
+
+impl PairingCurve for G2Affine {
+    type Engine = Bn254;
+    type PairWith = G1Affine;
+    type PairingResult = Fq12;
+    type Prepared = G2Prepared<Bn254Parameters>;
+
+    fn prepare(&self) -> Self::Prepared {
+        Self::Prepared::from_affine(*self)
+    }
+
+    fn pairing_with(&self, other: &Self::PairWith) -> Self::PairingResult {
+        Bn254::pairing(*other, *self)
+    }
+}
@@ -19,7 +19,8 @@ use snarkvm_utilities::biginteger::{BigInteger384, BigInteger768};
 
 use crate::{
     bw6_761::{Fq, Fr},
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    hash_to_curve::sswu::{self, SWUParameters},
+    traits::{GLVDecomposition, GLVParameters, MapToCurve, ModelParameters, ShortWeierstrassParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -81,6 +82,355 @@ impl ShortWeierstrassParameters for BW6_761G1Parameters {
     fn mul_by_a(_elem: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline(always)]
+    fn glv_endomorphism_x(x: &Self::BaseField) -> Option<Self::BaseField> {
+        Some(Self::endomorphism_x(x))
+    }
+
+    #[inline(always)]
+    fn glv_decomposition(scalar: &Self::ScalarField) -> Option<GLVDecomposition<Self::ScalarField>> {
+        Some(Self::decompose_scalar(scalar))
+    }
+}
+
+impl GLVParameters for BW6_761G1Parameters {
+    /// ENDO_COEFF = BETA =
+    /// 4922464560225523242118178942575080391082002530232324381063048548642823052024664478336818169867474395270858391911405337707247735739826664939444490469542109391530482826728203582549674992333383150446779312029624171857054392282775648
+    const ENDO_COEFF: Fq = field!(
+        Fq,
+        BigInteger768([
+            0x7f96b51bd840c549,
+            0xd59782096496171f,
+            0x49b046fd9ce14bbc,
+            0x4b6163bba7527a56,
+            0xef6c92fb771d59f1,
+            0x425bedbac1dfdc7,
+            0xd3ac39de759c0ffd,
+            0x9f43ed0e063a81d0,
+            0x5bd7d20b4f9a3ce2,
+            0x411f03c36cf5c3c,
+            0x2d658fd49661c472,
+            0x1100249ae760b93,
+        ])
+    );
+    /// LAMBDA =
+    /// 258664426012969093929703085429980814127835149614277183275038967946009968870203535512256352201271898244626862047231
+    const LAMBDA: Fr = field!(
+        Fr,
+        BigInteger384([
+            0x2c766f925a7b8727,
+            0x3d7f6b0253d58b5,
+            0x838ec0deec122131,
+            0xbd5eb3e9f658bb10,
+            0x6942bd126ed3e52e,
+            0x1673786dd04ed6a,
+        ])
+    );
+    /// The basis is ((a_1, b_1), (a_2, b_2)) =
+    /// ((293634935485640680722085584138834120324914961969255022593, -293634935485640680722085584138834120315328839056164388863),
+    ///  (293634935485640680722085584138834120315328839056164388863, 587269870971281361444171168277668240640243801025419411456)).
+    /// B1 = -293634935485640680722085584138834120315328839056164388863
+    const B1: Fr = field!(
+        Fr,
+        BigInteger384([
+            0x74e339ddb5613b70,
+            0xb9fb95ba7f4cc592,
+            0x455ac137f7424867,
+            0xd79cc4a654e7545b,
+            0x899bf95012409d81,
+            0xb12b68b8940f1b,
+        ])
+    );
+    /// B2 = 587269870971281361444171168277668240640243801025419411456
+    const B2: Fr = field!(
+        Fr,
+        BigInteger384([
+            0xa7cada2793875586,
+            0x45fd2ce31904fcc2,
+            0x415bbc5a34cdbad7,
+            0xe8b865456e813812,
+            0xbf3cdd30fb65ddaf,
+            0xf13c57340c0553,
+        ])
+    );
+    /// Q1 = round(2^384 b_2 / r) = 89458034301964758156995792381393618105150552387511985416827
+    const Q1: (bool, &'static [u64]) = (false, &[
+        0x2289a5d86c78aa7b,
+        0x85f6e7602b7a9d8c,
+        0x4061751dd380c860,
+        0xe,
+    ]);
+    /// Q2 = round(-2^384 b_1 / r) = 44729017150982379078497896190696809051845155550103861279600
+    const Q2: (bool, &'static [u64]) = (false, &[
+        0x7ccf39ddb5613b70,
+        0x42fb73b015bd4e9e,
+        0x2030ba8ee9c06430,
+        0x7,
+    ]);
+}
+
+impl SWUParameters for BW6_761G1Parameters {
+    /// ISO_COEFF_A = -15
+    const ISO_COEFF_A: Fq = field!(
+        Fq,
+        BigInteger768([
+            0xa8e4000000072b3c,
+            0x35abfae6c006c105,
+            0x23436575f1383210,
+            0x2ee07ddcd0a2eb83,
+            0x579d3aaa87e53337,
+            0x5330da1e8ca7d71f,
+            0x457cf0bdce5e57c0,
+            0x208ea7365ed59e46,
+            0x627d1e2d003bf801,
+            0x8afa5e39ff173fe1,
+            0x74cd2141c1f20824,
+            0xe10848c83c66d6,
+        ])
+    );
+    /// ISO_COEFF_B = -22
+    const ISO_COEFF_B: Fq = field!(
+        Fq,
+        BigInteger768([
+            0x84090000000a837f,
+            0x8a656c40b009e7ba,
+            0xf92b52fb359bce6b,
+            0x6735b1031532cbc2,
+            0xc0f253b0a0ac2941,
+            0x19f7968872a2d5ec,
+            0xb9fade7d110a0ea1,
+            0xa419bb43e95895e,
+            0xa8259e8bc46ef91f,
+            0x16a6c653b142bda5,
+            0x3243dca070206187,
+            0xe9141a272ca77b,
+        ])
+    );
+    /// Z = 3
+    const Z: Fq = field!(
+        Fq,
+        BigInteger768([
+            0x608fffffffe917f,
+            0xf0872a0affea6b5,
+            0xdbcc4aca877f009b,
+            0x8f40fd962cad79dd,
+            0xc6bd61ba58be2823,
+            0x42b336e6f6d86c23,
+            0xf5e8f13f9607ab11,
+            0x69f8b7fad28d630a,
+            0x720309801a155974,
+            0xd08d9f2bb533068e,
+            0x86f85c32de30fd9d,
+            0xf5e67cd377b979,
+        ])
+    );
+    /// ISOGENY_X_NUMERATOR = [-3 / 4, 1 / 2, 1 / 4]
+    const ISOGENY_X_NUMERATOR: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger768([
+                0x7ba5000000005bc3,
+                0x35e232f1f0005673,
+                0x8e902b7918dc68e7,
+                0xc258064b0a6e8bc6,
+                0x2ac7dc8886cb75c2,
+                0xd0f5a4818708645b,
+                0x8379726fe3eb1dfc,
+                0x41a0bb8f84bde183,
+                0x781fdb1bd99c9933,
+                0x3a261e4fb3278ffe,
+                0x52a3db4349b28068,
+                0xb406a0a030524,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger768([
+                0xfb4fffffffffc330,
+                0x2074b24effffc6b4,
+                0x5a53337936b8278b,
+                0x39860afa32a61376,
+                0x2f634f8d48c05b9d,
+                0x23b81847b2a110ce,
+                0x558e305f8130ae05,
+                0xc9e7471b95da050e,
+                0xe6ec6737c49cc35e,
+                0xff5551673471245b,
+                0x5497f3fdd230548e,
+                0xba6fd1f655db44,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger768([
+                0x7da7ffffffffe198,
+                0x903a59277fffe35a,
+                0x2d2999bc9b5c13c5,
+                0x9cc3057d195309bb,
+                0x17b1a7c6a4602dce,
+                0x91dc0c23d9508867,
+                0x2ac7182fc0985702,
+                0x64f3a38dcaed0287,
+                0xf376339be24e61af,
+                0x7faaa8b39a38922d,
+                0x2a4bf9fee9182a47,
+                0x5d37e8fb2aeda2,
+            ])
+        ),
+    ];
+    /// ISOGENY_X_DENOMINATOR = [2, 1]
+    const ISOGENY_X_DENOMINATOR: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger768([
+                0x405ffffffff0baa,
+                0xb4b04c6b1fff19ce,
+                0x3d32dc8704ff55bc,
+                0xb4d5fe641dc8fbe9,
+                0xd9d3967c3b297017,
+                0x81cccf44a4904817,
+                0x4e9b4b7fb95a720b,
+                0x46a5cffc8c5e4207,
+                0xf6acb100116390f8,
+                0x8b0914c7ce22045e,
+                0xaf503d773ecb53be,
+                0xa3eefde24fd0fb,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger768([
+                0x202ffffffff85d5,
+                0x5a5826358fff8ce7,
+                0x9e996e43827faade,
+                0xda6aff320ee47df4,
+                0xece9cb3e1d94b80b,
+                0xc0e667a25248240b,
+                0xa74da5bfdcad3905,
+                0x2352e7fe462f2103,
+                0x7b56588008b1c87c,
+                0x45848a63e711022f,
+                0xd7a81ebb9f65a9df,
+                0x51f77ef127e87d,
+            ])
+        ),
+    ];
+    /// ISOGENY_Y_NUMERATOR = [7 / 8, 1 / 2, 1 / 8]
+    const ISOGENY_Y_NUMERATOR: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger768([
+                0xc32effffffff9509,
+                0x923af9a1cfff9b39,
+                0x804a16534d1a0fb,
+                0x8c097c73823af917,
+                0x6110f75acb64a124,
+                0x77f86190659fdfd8,
+                0x11ea19a7fc610d84,
+                0x70d9163760b89fc0,
+                0x819b3eb2178a97a4,
+                0x85af360a19f4b918,
+                0xc28221bc2ad994bb,
+                0x235b8a739271ac,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger768([
+                0xfb4fffffffffc330,
+                0x2074b24effffc6b4,
+                0x5a53337936b8278b,
+                0x39860afa32a61376,
+                0x2f634f8d48c05b9d,
+                0x23b81847b2a110ce,
+                0x558e305f8130ae05,
+                0xc9e7471b95da050e,
+                0xe6ec6737c49cc35e,
+                0xff5551673471245b,
+                0x5497f3fdd230548e,
+                0xba6fd1f655db44,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger768([
+                0x3ed3fffffffff0cc,
+                0xc81d2c93bffff1ad,
+                0x9694ccde4dae09e2,
+                0x4e6182be8ca984dd,
+                0x8bd8d3e3523016e7,
+                0x48ee0611eca84433,
+                0x95638c17e04c2b81,
+                0xb279d1c6e5768143,
+                0xf9bb19cdf12730d7,
+                0xbfd55459cd1c4916,
+                0x1525fcff748c1523,
+                0x2e9bf47d9576d1,
+            ])
+        ),
+    ];
+    /// ISOGENY_Y_DENOMINATOR = [4, 4, 1]
+    const ISOGENY_Y_DENOMINATOR: &'static [Fq] = &[
+        field!(
+            Fq,
+            BigInteger768([
+                0x136efffffffe16c9,
+                0x82cf5a6dcffe3319,
+                0x6458c05f1f0e0741,
+                0xd10ae605e52a4eda,
+                0x41ca591c0266e100,
+                0x7d0fd59c3626929f,
+                0x9967dc004d00c112,
+                0x1ccff9c033379af5,
+                0x9ad6ec10a23f63af,
+                0x5cec11251a72c235,
+                0x8d18b1ae789ba83e,
+                0x24f5d6c91bd3ec,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger768([
+                0x136efffffffe16c9,
+                0x82cf5a6dcffe3319,
+                0x6458c05f1f0e0741,
+                0xd10ae605e52a4eda,
+                0x41ca591c0266e100,
+                0x7d0fd59c3626929f,
+                0x9967dc004d00c112,
+                0x1ccff9c033379af5,
+                0x9ad6ec10a23f63af,
+                0x5cec11251a72c235,
+                0x8d18b1ae789ba83e,
+                0x24f5d6c91bd3ec,
+            ])
+        ),
+        field!(
+            Fq,
+            BigInteger768([
+                0x202ffffffff85d5,
+                0x5a5826358fff8ce7,
+                0x9e996e43827faade,
+                0xda6aff320ee47df4,
+                0xece9cb3e1d94b80b,
+                0xc0e667a25248240b,
+                0xa74da5bfdcad3905,
+                0x2352e7fe462f2103,
+                0x7b56588008b1c87c,
+                0x45848a63e711022f,
+                0xd7a81ebb9f65a9df,
+                0x51f77ef127e87d,
+            ])
+        ),
+    ];
+}
+
+impl MapToCurve for BW6_761G1Parameters {
+    #[inline]
+    fn map_to_curve(u: &Fq) -> Option<(Fq, Fq)> {
+        sswu::map_to_curve::<Self>(u)
+    }
 }
 
 /// G1_GENERATOR_X =
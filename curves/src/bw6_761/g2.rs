@@ -19,7 +19,8 @@ use snarkvm_utilities::biginteger::{BigInteger384, BigInteger768};
 
 use crate::{
     bw6_761::{Fq, Fr},
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    hash_to_curve::svdw::{self, SvdWParameters},
+    traits::{MapToCurve, ModelParameters, ShortWeierstrassParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -83,6 +84,107 @@ impl ShortWeierstrassParameters for Bls12_377G2Parameters {
     }
 }
 
+impl SvdWParameters for Bls12_377G2Parameters {
+    /// Z = 1
+    const Z: Fq = field!(
+        Fq,
+        BigInteger768([
+            0x202ffffffff85d5,
+            0x5a5826358fff8ce7,
+            0x9e996e43827faade,
+            0xda6aff320ee47df4,
+            0xece9cb3e1d94b80b,
+            0xc0e667a25248240b,
+            0xa74da5bfdcad3905,
+            0x2352e7fe462f2103,
+            0x7b56588008b1c87c,
+            0x45848a63e711022f,
+            0xd7a81ebb9f65a9df,
+            0x51f77ef127e87d,
+        ])
+    );
+    /// C1 = g(Z) = 5
+    const C1: Fq = field!(
+        Fq,
+        BigInteger768([
+            0x1571fffffffd9c9e,
+            0xdd2780a35ffdc000,
+            0x2f22ea2a18db21f,
+            0xab75e537f40ecccf,
+            0x2eb4245a1ffb990c,
+            0x3df63d3e886eb6ab,
+            0x40b581c029adfa18,
+            0x4022e1be7966bbf9,
+            0x162d4490aaf12c2b,
+            0xa2709b890183c465,
+            0x64c0d06a1801521d,
+            0x76ed55ba43bc6a,
+        ])
+    );
+    /// C2 = -Z / 2 = -1 / 2
+    const C2: Fq = field!(
+        Fq,
+        BigInteger768([
+            0xf94d000000003d5b,
+            0xc61c8c19700039cd,
+            0xbbb9c535b4387cac,
+            0x5f1b0bc823c19581,
+            0x4279844f2b2ba391,
+            0x62d1b0a56058ecc2,
+            0xae408a9fa48374ff,
+            0xa6945f1d4faae40a,
+            0x6b960eb7bbeafae2,
+            0xb9d0c7034d60222c,
+            0x7cefd54232caaaaf,
+            0x687853052df2c6,
+        ])
+    );
+    /// C3 = sqrt(-g(Z) (3 Z^2 + 4 A)) =
+    /// 6512576023559706936744848619562637035199231935950679198114013134058099510631371887763048712345836581232434377246238992664408977779581985040178329784886723976248606232769709853447081673540049678898922843971020315598735020444998836
+    const C3: Fq = field!(
+        Fq,
+        BigInteger768([
+            0x2c04670501330fa9,
+            0xe26d268c6fc2bfdf,
+            0x462fb38ca114d8fb,
+            0x34ab58a6df1b41ba,
+            0x449e0e9ba9a7b744,
+            0xba2cdeb49bf6bbd5,
+            0xcaf30b31dccd2645,
+            0x279cfeba08dbe7c2,
+            0xe7325541f4280829,
+            0x6a84e0be4fb9963,
+            0x138de010bf24b74,
+            0x10499094ce297e2,
+        ])
+    );
+    /// C4 = -4 g(Z) / (3 Z^2 + 4 A) = -20 / 3
+    const C4: Fq = field!(
+        Fq,
+        BigInteger768([
+            0xd43955555558853c,
+            0x61e2525c15585603,
+            0x6ece62bb0c83ed75,
+            0xe6e4420dd8767ae1,
+            0x4363f586feb32d7,
+            0xb61a0f9363645e87,
+            0x5976f6fea55d8be5,
+            0xeb2105f890283c2a,
+            0xfb1c41d3c7736d72,
+            0x739ca6820067582e,
+            0xe65ea11d3bf79181,
+            0xe54e69ac556cd5,
+        ])
+    );
+}
+
+impl MapToCurve for Bls12_377G2Parameters {
+    #[inline]
+    fn map_to_curve(u: &Fq) -> Option<(Fq, Fq)> {
+        svdw::map_to_curve::<Self>(u)
+    }
+}
+
 /// G2_GENERATOR_X =
 ///  6445332910596979336035888152774071626898886139774101364933948236926875073754470830732273879639675437155036544153105017729592600560631678554299562762294743927912429096636156401171909259073181112518725201388196280039960074422214428
 pub const G2_GENERATOR_X: Fq = field!(
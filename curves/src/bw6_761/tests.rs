@@ -28,7 +28,7 @@ use crate::{
         G2Projective,
         BW6_761,
     },
-    templates::short_weierstrass_jacobian::tests::sw_tests,
+    templates::short_weierstrass_jacobian::tests::{sw_glv_tests, sw_tests},
     traits::{tests_curve::curve_tests, tests_group::group_test, AffineCurve, PairingEngine},
 };
 use snarkvm_fields::{
@@ -82,6 +82,11 @@ fn test_g1_projective_curve() {
     sw_tests::<BW6_761G1Parameters>();
 }
 
+#[test]
+fn test_g1_glv() {
+    sw_glv_tests::<BW6_761G1Parameters>();
+}
+
 #[test]
 fn test_g1_projective_group() {
     let a: G1Projective = rand::random();
@@ -17,8 +17,9 @@
 use crate::{
     edwards_bls12::{Fq, Fr},
     errors::GroupError,
+    hash_to_curve::elligator2::{self, Elligator2Parameters},
     templates::twisted_edwards_extended::{Affine, Projective},
-    traits::{AffineCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
+    traits::{AffineCurve, MapToCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
 };
 use snarkvm_fields::field;
 use snarkvm_utilities::biginteger::BigInteger256;
@@ -108,6 +109,26 @@ impl MontgomeryParameters for EdwardsParameters {
     );
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// Z = 11
+    const Z: Fq = field!(
+        Fq,
+        BigInteger256([
+            0x19beffffffffff6a,
+            0x761e46b21fffff64,
+            0x565ad035f75edf35,
+            0xf929a91a9d71f63,
+        ])
+    );
+}
+
+impl MapToCurve for EdwardsParameters {
+    #[inline]
+    fn map_to_curve(u: &Fq) -> Option<(Fq, Fq)> {
+        elligator2::map_to_curve::<Self>(u)
+    }
+}
+
 impl FromStr for EdwardsAffine {
     type Err = GroupError;
 
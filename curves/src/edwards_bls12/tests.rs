@@ -20,7 +20,9 @@ use crate::{
     traits::{
         tests_curve::curve_tests,
         tests_group::group_test,
+        tests_timing::timing_leakage_test,
         AffineCurve,
+        ConstantTimeGroup,
         Group,
         MontgomeryParameters,
         ProjectiveCurve,
@@ -29,6 +31,7 @@ use crate::{
 };
 use snarkvm_fields::{
     tests_field::{field_serialization_test, field_test, primefield_test},
+    ConstantTimeField,
     Field,
     LegendreSymbol,
     One,
@@ -57,6 +60,21 @@ fn test_edwards_bls12_fq() {
     field_serialization_test::<Fq>();
 }
 
+#[ignore]
+#[test]
+fn test_inverse_constant_time_timing() {
+    timing_leakage_test(&mut thread_rng(), Fq::one(), Fq::rand, |a| a.inverse_constant_time());
+}
+
+#[ignore]
+#[test]
+fn test_mul_constant_time_timing() {
+    let generator = EdwardsProjective::prime_subgroup_generator();
+    timing_leakage_test(&mut thread_rng(), Fr::one(), Fr::rand, |scalar| {
+        generator.mul_constant_time(scalar)
+    });
+}
+
 #[test]
 fn test_projective_curve() {
     curve_tests::<EdwardsProjective>();
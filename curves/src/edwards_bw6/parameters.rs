@@ -17,8 +17,9 @@
 use crate::{
     edwards_bw6::{Fq, Fr},
     errors::GroupError,
+    hash_to_curve::elligator2::{self, Elligator2Parameters},
     templates::twisted_edwards_extended::{Affine, Projective},
-    traits::{AffineCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
+    traits::{AffineCurve, MapToCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
 };
 use snarkvm_fields::field;
 use snarkvm_utilities::biginteger::BigInteger384 as BigInteger;
@@ -118,6 +119,28 @@ impl MontgomeryParameters for EdwardsParameters {
     );
 }
 
+impl Elligator2Parameters for EdwardsParameters {
+    /// Z = 5
+    const Z: Fq = field!(
+        Fq,
+        BigInteger([
+            0x88fd3ffffffffd07,
+            0x7f37c04d4ffffe74,
+            0xfe81201ffa68f7bb,
+            0x4e661ca22778db8c,
+            0xba8be6fd148d4f4f,
+            0x114c5a35730b618,
+        ])
+    );
+}
+
+impl MapToCurve for EdwardsParameters {
+    #[inline]
+    fn map_to_curve(u: &Fq) -> Option<(Fq, Fq)> {
+        elligator2::map_to_curve::<Self>(u)
+    }
+}
+
 impl FromStr for EdwardsAffine {
     type Err = GroupError;
 
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    hash_to_curve::is_square,
+    traits::{HashToField, MontgomeryParameters, TwistedEdwardsParameters},
+};
+use snarkvm_fields::{Field, One, SquareRootField, Zero};
+
+/// The parameters of the Elligator 2 map to a twisted Edwards curve, which is evaluated on its
+/// birationally equivalent Montgomery curve `B t^2 = s^3 + A s^2 + s`.
+pub trait Elligator2Parameters: TwistedEdwardsParameters {
+    /// The non-square `Z` of the map, as chosen by the `find_z_ell2` procedure of the specification.
+    const Z: Self::BaseField;
+}
+
+/// Maps `u` to a point on the twisted Edwards curve of `P` with the Elligator 2 map.
+/// The exceptional points of the birational map are sent to the identity `(0, 1)`.
+pub fn map_to_curve<P: Elligator2Parameters>(u: &P::BaseField) -> Option<(P::BaseField, P::BaseField)>
+where
+    P::BaseField: HashToField,
+{
+    let coeff_a = <P::MontgomeryParameters as MontgomeryParameters>::COEFF_A;
+    let coeff_b = <P::MontgomeryParameters as MontgomeryParameters>::COEFF_B;
+    let one = P::BaseField::one();
+
+    // Map to the curve y^2 = x^3 + c1 x^2 + c2 x, with c1 = A / B and c2 = 1 / B^2.
+    let c1 = coeff_a / coeff_b;
+    let c2 = coeff_b.square().inverse().unwrap();
+
+    let mut tv1 = P::Z * u.square();
+    if tv1 == -one {
+        tv1 = P::BaseField::zero();
+    }
+    let x1 = -c1 * (one + tv1).inverse().unwrap();
+    let gx1 = (x1.square() + c1 * x1 + c2) * x1;
+
+    // Exactly one of g(x1) and g(x2) = Z u^2 g(x1) is a square, unless g(x1) is zero.
+    let gx1_is_square = is_square(&gx1);
+    let (x, gx) = match gx1_is_square {
+        true => (x1, gx1),
+        false => (-x1 - c1, tv1 * gx1),
+    };
+    let mut y = gx.sqrt().unwrap();
+    if y.sgn0() == gx1_is_square {
+        y = -y;
+    }
+
+    // Scale to the Montgomery curve, and send (s, t) to the Edwards point (s / t, (s - 1) / (s + 1)).
+    let (s, t) = (x * coeff_b, y * coeff_b);
+    match (t.inverse(), (s + one).inverse()) {
+        (Some(t_inv), Some(s_plus_one_inv)) => Some((s * t_inv, (s - one) * s_plus_one_inv)),
+        _ => Some((P::BaseField::zero(), one)),
+    }
+}
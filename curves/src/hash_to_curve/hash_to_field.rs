@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::HashToField;
+use snarkvm_fields::{
+    Fp2,
+    Fp256,
+    Fp256Parameters,
+    Fp2Parameters,
+    Fp384,
+    Fp384Parameters,
+    Fp768,
+    Fp768Parameters,
+    PrimeField,
+    Zero,
+};
+use snarkvm_utilities::biginteger::BigInteger;
+
+use sha2::{Digest, Sha256};
+
+/// The security level in bits of `hash_to_field`.
+const SECURITY_BITS: usize = 128;
+
+/// The output size in bytes of SHA-256.
+const SHA256_OUTPUT_BYTES: usize = 32;
+
+/// The input block size in bytes of SHA-256.
+const SHA256_BLOCK_BYTES: usize = 64;
+
+/// Expands `message` into `len_in_bytes` uniformly random bytes under the domain separation tag `dst`,
+/// with SHA-256, as in the `expand_message_xmd` function of the specification. Tags longer than
+/// 255 bytes are first hashed down to 32 bytes.
+///
+/// # Panics
+///
+/// Panics if `len_in_bytes` is larger than `255 * 32`.
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    let ell = (len_in_bytes + SHA256_OUTPUT_BYTES - 1) / SHA256_OUTPUT_BYTES;
+    assert!(
+        ell <= 255,
+        "expand_message_xmd supports at most {} bytes",
+        255 * SHA256_OUTPUT_BYTES
+    );
+
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = Sha256::new().chain(b"H2C-OVERSIZE-DST-").chain(dst).finalize();
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    let b_0 = Sha256::new()
+        .chain(&[0u8; SHA256_BLOCK_BYTES])
+        .chain(message)
+        .chain(&(len_in_bytes as u16).to_be_bytes())
+        .chain(&[0u8])
+        .chain(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_BYTES);
+    let mut b_i = Sha256::new().chain(&b_0).chain(&[1u8]).chain(&dst_prime).finalize();
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let b_0_xor_b_i: Vec<u8> = b_0.iter().zip(&b_i).map(|(a, b)| a ^ b).collect();
+        b_i = Sha256::new()
+            .chain(&b_0_xor_b_i)
+            .chain(&[i as u8])
+            .chain(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Hashes `message` to `count` field elements under the domain separation tag `dst`,
+/// as in the `hash_to_field` function of the specification.
+pub fn hash_to_field<F: HashToField>(message: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+    let element_bytes = F::EXTENSION_DEGREE * F::PRIME_FIELD_BYTES;
+    let uniform_bytes = expand_message_xmd(message, dst, count * element_bytes);
+    uniform_bytes.chunks(element_bytes).map(F::from_uniform_bytes).collect()
+}
+
+/// Returns the big-endian `bytes` modulo the characteristic of `F`.
+fn from_be_bytes_mod_order<F: PrimeField>(bytes: &[u8]) -> F {
+    let base = F::from(256u16);
+    bytes.iter().fold(F::zero(), |acc, byte| acc * base + F::from(*byte))
+}
+
+macro_rules! impl_hash_to_field_for_prime_field {
+    ($field: ident, $params: ident) => {
+        impl<P: $params> HashToField for $field<P> {
+            const EXTENSION_DEGREE: usize = 1;
+            const PRIME_FIELD_BYTES: usize = (P::MODULUS_BITS as usize + SECURITY_BITS + 7) / 8;
+
+            fn from_uniform_bytes(bytes: &[u8]) -> Self {
+                from_be_bytes_mod_order(bytes)
+            }
+
+            fn sgn0(&self) -> bool {
+                self.to_repr().is_odd()
+            }
+        }
+    };
+}
+
+impl_hash_to_field_for_prime_field!(Fp256, Fp256Parameters);
+impl_hash_to_field_for_prime_field!(Fp384, Fp384Parameters);
+impl_hash_to_field_for_prime_field!(Fp768, Fp768Parameters);
+
+impl<P: Fp2Parameters> HashToField for Fp2<P>
+where
+    P::Fp: HashToField,
+{
+    const EXTENSION_DEGREE: usize = 2 * P::Fp::EXTENSION_DEGREE;
+    const PRIME_FIELD_BYTES: usize = P::Fp::PRIME_FIELD_BYTES;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let (c0, c1) = bytes.split_at(bytes.len() / 2);
+        Self::new(P::Fp::from_uniform_bytes(c0), P::Fp::from_uniform_bytes(c1))
+    }
+
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+}
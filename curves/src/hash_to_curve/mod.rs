@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Hashing to elliptic curves, following the [IETF hash-to-curve specification][h2c].
+//!
+//! A message is hashed to field elements with `expand_message_xmd` over SHA-256, and each field element
+//! is mapped to the curve by its `MapToCurve` parameters: the simplified SWU map through a 2-isogeny
+//! for BLS12-377 G1 and BW6-761 G1, the Shallue-van de Woestijne map for their G2 twists (which have
+//! no low-degree isogeny to a curve with `AB != 0`), and Elligator 2 for the twisted Edwards curves.
+//!
+//! [h2c]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/
+
+mod hash_to_field;
+pub use hash_to_field::*;
+
+pub mod elligator2;
+
+pub mod sswu;
+
+pub mod svdw;
+
+#[cfg(test)]
+mod tests;
+
+use crate::traits::{AffineCurve, HashToField, ProjectiveCurve};
+
+/// Hashes `message` to the prime-order subgroup, by mapping two field elements to the curve
+/// with `map_to_curve` and clearing the cofactor of their sum.
+pub fn hash_to_curve<G: AffineCurve>(message: &[u8], dst: &[u8], map_to_curve: impl Fn(&G::BaseField) -> G) -> G
+where
+    G::BaseField: HashToField,
+{
+    let u = hash_to_field::<G::BaseField>(message, dst, 2);
+    let q = map_to_curve(&u[0]).into_projective() + map_to_curve(&u[1]).into_projective();
+    q.into_affine().mul_by_cofactor()
+}
+
+/// Encodes `message` to the prime-order subgroup, by mapping a single field element to the curve
+/// with `map_to_curve` and clearing its cofactor.
+pub fn encode_to_curve<G: AffineCurve>(message: &[u8], dst: &[u8], map_to_curve: impl Fn(&G::BaseField) -> G) -> G
+where
+    G::BaseField: HashToField,
+{
+    let u = hash_to_field::<G::BaseField>(message, dst, 1);
+    map_to_curve(&u[0]).mul_by_cofactor()
+}
+
+/// Returns whether `x` is a square, including zero.
+fn is_square<F: HashToField>(x: &F) -> bool {
+    !x.legendre().is_qnr()
+}
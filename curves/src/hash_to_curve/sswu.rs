@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    hash_to_curve::is_square,
+    traits::{HashToField, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{Field, One, SquareRootField, Zero};
+
+/// The parameters of the simplified Shallue-van de Woestijne-Ulas map to a short Weierstrass curve,
+/// evaluated on a curve `E': y^2 = x^3 + A' x + B'` with `A' B' != 0` and sent to this curve by an isogeny.
+pub trait SWUParameters: ShortWeierstrassParameters {
+    /// The coefficient `A'` of the isogenous curve.
+    const ISO_COEFF_A: Self::BaseField;
+    /// The coefficient `B'` of the isogenous curve.
+    const ISO_COEFF_B: Self::BaseField;
+    /// The non-square `Z` of the map, as chosen by the `find_z_sswu` procedure of the specification.
+    const Z: Self::BaseField;
+
+    /// The coefficients, from the constant term up, of the polynomials of the isogeny
+    /// `(x', y') -> (x_num(x') / x_den(x'), y' * y_num(x') / y_den(x'))` from the isogenous curve to this curve.
+    const ISOGENY_X_NUMERATOR: &'static [Self::BaseField];
+    /// The coefficients of `x_den`.
+    const ISOGENY_X_DENOMINATOR: &'static [Self::BaseField];
+    /// The coefficients of `y_num`.
+    const ISOGENY_Y_NUMERATOR: &'static [Self::BaseField];
+    /// The coefficients of `y_den`.
+    const ISOGENY_Y_DENOMINATOR: &'static [Self::BaseField];
+}
+
+/// Maps `u` to a point on the curve of `P`, or `None` for the point at infinity,
+/// with the simplified SWU map followed by the isogeny.
+pub fn map_to_curve<P: SWUParameters>(u: &P::BaseField) -> Option<(P::BaseField, P::BaseField)>
+where
+    P::BaseField: HashToField,
+{
+    let (a, b, z) = (P::ISO_COEFF_A, P::ISO_COEFF_B, P::Z);
+    let g = |x: P::BaseField| (x.square() + a) * x + b;
+
+    // x1 = (-B / A) (1 + 1 / (Z^2 u^4 + Z u^2)), or B / (Z A) if the denominator is zero.
+    let z_u2 = z * u.square();
+    let x1 = match (z_u2.square() + z_u2).inverse() {
+        Some(tv1) => -b / a * (P::BaseField::one() + tv1),
+        None => b / (z * a),
+    };
+
+    // Exactly one of g(x1) and g(x2) = Z^3 u^6 g(x1) is a square.
+    let gx1 = g(x1);
+    let (x, gx) = if is_square(&gx1) {
+        (x1, gx1)
+    } else {
+        let x2 = z_u2 * x1;
+        (x2, g(x2))
+    };
+    let mut y = gx.sqrt().unwrap();
+    if y.sgn0() != u.sgn0() {
+        y = -y;
+    }
+
+    isogeny_map::<P>(x, y)
+}
+
+/// Sends a point of the isogenous curve to the curve of `P`,
+/// or returns `None` if its image is the point at infinity.
+fn isogeny_map<P: SWUParameters>(x: P::BaseField, y: P::BaseField) -> Option<(P::BaseField, P::BaseField)> {
+    let evaluate = |coefficients: &[P::BaseField]| {
+        coefficients
+            .iter()
+            .rev()
+            .fold(P::BaseField::zero(), |acc, coefficient| acc * x + coefficient)
+    };
+
+    let x_den = evaluate(P::ISOGENY_X_DENOMINATOR).inverse()?;
+    let y_den = evaluate(P::ISOGENY_Y_DENOMINATOR).inverse()?;
+    Some((
+        evaluate(P::ISOGENY_X_NUMERATOR) * x_den,
+        y * evaluate(P::ISOGENY_Y_NUMERATOR) * y_den,
+    ))
+}
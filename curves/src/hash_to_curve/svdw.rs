@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    hash_to_curve::is_square,
+    traits::{HashToField, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{Field, One, SquareRootField};
+
+/// The parameters of the Shallue-van de Woestijne map to a short Weierstrass curve `y^2 = g(x) = x^3 + A x + B`,
+/// which applies to every curve, including those with `A = 0` and no low-degree isogeny to a curve with `A B != 0`.
+pub trait SvdWParameters: ShortWeierstrassParameters {
+    /// The constant `Z` of the map, as chosen by the `find_z_svdw` procedure of the specification.
+    const Z: Self::BaseField;
+    /// `C1 = g(Z)`.
+    const C1: Self::BaseField;
+    /// `C2 = -Z / 2`.
+    const C2: Self::BaseField;
+    /// `C3 = sqrt(-g(Z) (3 Z^2 + 4 A))`, with `sgn0(C3) = 0`.
+    const C3: Self::BaseField;
+    /// `C4 = -4 g(Z) / (3 Z^2 + 4 A)`.
+    const C4: Self::BaseField;
+}
+
+/// Maps `u` to a point on the curve of `P` with the Shallue-van de Woestijne map.
+/// The point is never the point at infinity.
+pub fn map_to_curve<P: SvdWParameters>(u: &P::BaseField) -> Option<(P::BaseField, P::BaseField)>
+where
+    P::BaseField: HashToField,
+{
+    let g = |x: P::BaseField| (x.square() + P::COEFF_A) * x + P::COEFF_B;
+    let one = P::BaseField::one();
+
+    let tv1 = u.square() * P::C1;
+    let tv2 = one + tv1;
+    let tv1 = one - tv1;
+    let tv3 = (tv1 * tv2).inverse().unwrap_or_default();
+    let tv4 = *u * tv1 * tv3 * P::C3;
+
+    // At least one of g(x1), g(x2) and g(x3) is a square.
+    let x1 = P::C2 - tv4;
+    let x2 = P::C2 + tv4;
+    let x = if is_square(&g(x1)) {
+        x1
+    } else if is_square(&g(x2)) {
+        x2
+    } else {
+        (tv2.square() * tv3).square() * P::C4 + P::Z
+    };
+    let mut y = g(x).sqrt().unwrap();
+    if y.sgn0() != u.sgn0() {
+        y = -y;
+    }
+
+    Some((x, y))
+}
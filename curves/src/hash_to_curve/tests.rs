@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bls12_377,
+    bw6_761,
+    edwards_bls12,
+    edwards_bw6,
+    hash_to_curve::expand_message_xmd,
+    templates::{short_weierstrass_jacobian, twisted_edwards_extended},
+    traits::{
+        AffineCurve,
+        HashToCurve,
+        HashToField,
+        MapToCurve,
+        ProjectiveCurve,
+        ShortWeierstrassParameters,
+        TwistedEdwardsParameters,
+    },
+};
+use snarkvm_fields::Zero;
+use snarkvm_utilities::rand::UniformRand;
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use std::str::FromStr;
+
+const ITERATIONS: usize = 100;
+
+const DST: &[u8] = b"snarkVM-curves-hash-to-curve-test";
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn hash_to_curve_test<G: AffineCurve + HashToCurve>() {
+    let a = G::hash_to_curve(b"abc", DST);
+    assert!(!a.is_zero());
+    assert!(a.is_on_curve());
+    assert!(a.is_in_correct_subgroup_assuming_on_curve());
+
+    // The output only depends on the message and the domain separation tag.
+    assert_eq!(a, G::hash_to_curve(b"abc", DST));
+    assert_ne!(a, G::hash_to_curve(b"abd", DST));
+    assert_ne!(a, G::hash_to_curve(b"abc", b"snarkVM-curves-hash-to-curve-other-test"));
+
+    let b = G::encode_to_curve(b"abc", DST);
+    assert!(b.is_on_curve());
+    assert!(b.is_in_correct_subgroup_assuming_on_curve());
+    assert_ne!(a, b);
+
+    let generators = G::derive_generators(DST, 8);
+    assert_eq!(generators.len(), 8);
+    for (i, generator) in generators.iter().enumerate() {
+        assert!(!generator.is_zero());
+        assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+        assert_eq!(*generator, G::hash_to_curve(&(i as u32).to_le_bytes(), DST));
+        assert!(!generators[..i].contains(generator));
+    }
+}
+
+fn sw_map_to_curve_test<P: ShortWeierstrassParameters + MapToCurve>()
+where
+    P::BaseField: HashToField,
+{
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut inputs = vec![P::BaseField::zero()];
+    inputs.extend((0..ITERATIONS).map(|_| P::BaseField::rand(&mut rng)));
+    for u in inputs {
+        if let Some((x, y)) = P::map_to_curve(&u) {
+            assert!(short_weierstrass_jacobian::Affine::<P>::new(x, y, false).is_on_curve());
+        }
+    }
+}
+
+fn edwards_map_to_curve_test<P: TwistedEdwardsParameters + MapToCurve>()
+where
+    P::BaseField: HashToField,
+{
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut inputs = vec![P::BaseField::zero()];
+    inputs.extend((0..ITERATIONS).map(|_| P::BaseField::rand(&mut rng)));
+    for u in inputs {
+        let (x, y) = P::map_to_curve(&u).unwrap();
+        assert!(twisted_edwards_extended::Affine::<P>::new(x, y).is_on_curve());
+    }
+}
+
+#[test]
+fn test_expand_message_xmd() {
+    // Test vectors from the specification, for expand_message_xmd with SHA-256.
+    let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    assert_eq!(
+        hex(&expand_message_xmd(b"", dst, 0x20)),
+        "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+    );
+    assert_eq!(
+        hex(&expand_message_xmd(b"abc", dst, 0x20)),
+        "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+    );
+    assert_eq!(
+        hex(&expand_message_xmd(b"", dst, 0x80)),
+        "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced"
+    );
+}
+
+#[test]
+fn test_bls12_377_g1() {
+    use bls12_377::{Fq, G1Affine, G1Projective};
+
+    sw_map_to_curve_test::<bls12_377::Bls12_377G1Parameters>();
+    hash_to_curve_test::<G1Affine>();
+    assert_eq!(
+        G1Affine::hash_to_curve(b"abc", DST),
+        G1Affine::new(
+            Fq::from_str("206523376236943642013887891143558467181954428519836333961175035309708740368462275926726735123897864709992144899426").unwrap(),
+            Fq::from_str("196442581764951291741785895063740555672327386884428386278834886587772791941200161022107713132714990390060071387756").unwrap(),
+            false
+        )
+    );
+    assert_eq!(
+        G1Projective::hash_to_curve(b"abc", DST).into_affine(),
+        G1Affine::hash_to_curve(b"abc", DST)
+    );
+}
+
+#[test]
+fn test_bls12_377_g2() {
+    use bls12_377::{Fq, Fq2, G2Affine};
+
+    sw_map_to_curve_test::<bls12_377::Bls12_377G2Parameters>();
+    hash_to_curve_test::<G2Affine>();
+    assert_eq!(
+        G2Affine::hash_to_curve(b"abc", DST),
+        G2Affine::new(
+            Fq2::new(Fq::from_str("198865358412965470132295904974932183090239676132456237742905357931330550958264550435967477354869635068421148860065").unwrap(), Fq::from_str("251472458344830004481895497604062318740719426303587283606118256606453638634762848291408179139125033643028419289872").unwrap()),
+            Fq2::new(Fq::from_str("113365598525536316511657628535124990604476540648799459267206368446507638166109337074870524123976012032037244075960").unwrap(), Fq::from_str("144406343562835192327672226052478368888748565079438395377653800089749427990229218967493825007196706098367292765668").unwrap()),
+            false
+        )
+    );
+}
+
+#[test]
+fn test_bw6_761_g1() {
+    use bw6_761::{Fq, G1Affine};
+
+    sw_map_to_curve_test::<bw6_761::BW6_761G1Parameters>();
+    hash_to_curve_test::<G1Affine>();
+    assert_eq!(
+        G1Affine::hash_to_curve(b"abc", DST),
+        G1Affine::new(
+            Fq::from_str("4801234000386110321855675256733369806039467461823599180575258395378032990651545667829870430691351342190916812874348533812570165082986242405382056289053320679465977504187718225669504683740957524874196339899158268427090589928949555").unwrap(),
+            Fq::from_str("1838523129452020818628251489200834945817757627558907934246142253040590719760924365798106108880402429249112348693093415439781831215554252810541284782431810568714419759397553040810636930491153234441052333179767350125364331655534676").unwrap(),
+            false
+        )
+    );
+}
+
+#[test]
+fn test_bw6_761_g2() {
+    use bw6_761::{Fq, G2Affine};
+
+    sw_map_to_curve_test::<bw6_761::Bls12_377G2Parameters>();
+    hash_to_curve_test::<G2Affine>();
+    assert_eq!(
+        G2Affine::hash_to_curve(b"abc", DST),
+        G2Affine::new(
+            Fq::from_str("279947024704573953385357776072366367741857471624191635127338865464504277882689005206949191649562477191005214867993842060368735684483216986904242063781274189151309931110351377380918723203849337654593550644907665635242435202643678").unwrap(),
+            Fq::from_str("3145459460257451972626321464446278379383723441165049409445637502121236440104608276558818547966653931884675573174481356311770525238677933366441010450550046789529683536847484721554940111521072390501362655112376728430631793312197797").unwrap(),
+            false
+        )
+    );
+}
+
+#[test]
+fn test_edwards_bls12() {
+    use edwards_bls12::{EdwardsAffine, EdwardsParameters, EdwardsProjective, Fq};
+
+    edwards_map_to_curve_test::<EdwardsParameters>();
+    hash_to_curve_test::<EdwardsAffine>();
+    assert_eq!(
+        EdwardsAffine::hash_to_curve(b"abc", DST),
+        EdwardsAffine::new(
+            Fq::from_str("6320133047558243387600301810904380080493405718115758871392692983494765722497").unwrap(),
+            Fq::from_str("6778204245709543536516670722482049220092828579294275149807305943738930904545").unwrap()
+        )
+    );
+    assert_eq!(
+        EdwardsProjective::hash_to_curve(b"abc", DST).into_affine(),
+        EdwardsAffine::hash_to_curve(b"abc", DST)
+    );
+}
+
+#[test]
+fn test_edwards_bw6() {
+    use edwards_bw6::{EdwardsAffine, EdwardsParameters, Fq};
+
+    edwards_map_to_curve_test::<EdwardsParameters>();
+    hash_to_curve_test::<EdwardsAffine>();
+    assert_eq!(
+        EdwardsAffine::hash_to_curve(b"abc", DST),
+        EdwardsAffine::new(
+            Fq::from_str("12692090438711559409386411104152609864322843988812575716344315406915146865534416593897038454970819438449187350196").unwrap(),
+            Fq::from_str("66853953438789953707634180312467111205953258295970857446307539492072467376986167916945498609235333282474112747079").unwrap()
+        )
+    );
+}
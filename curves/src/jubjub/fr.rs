@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// Jubjub scalar field.
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 1;
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T =
+    /// 6554484396890773809930967563523245729705921265872317281365359162392183254198
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        0xaa9f02ab1d6124de,
+        0xb3524a6466112932,
+        0x7342261215ac260b,
+        0x4d6b87b1da259e2,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 6
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        0x720b1b19d49ea8f1,
+        0xbf4aa36101f13a58,
+        0x5fa8cc968193ccbb,
+        0xe70cbdc7dccf3ac,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 1991615062597996281u64;
+    /// MODULUS = 6554484396890773809930967563523245729705921265872317281365359162392183254199
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        0xd0970e5ed6f72cb7,
+        0xa6682093ccc81082,
+        0x6673b0101343b00,
+        0xe7db4ea6533afa9,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 252;
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x684b872f6b7b965b,
+        0x53341049e6640841,
+        0x83339d80809a1d80,
+        0x73eda753299d7d4,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        0x25f80bb3b99607d9,
+        0xf315d62f66b6e750,
+        0x932514eeeb8814f4,
+        0x9a6fc6f479155c6,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        0x67719aa495e57731,
+        0x51b0cef09ce3fc26,
+        0x69dab7fac026e9a5,
+        0x4f6547b8d127688,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 4;
+    // T and T_MINUS_ONE_DIV_TWO, where MODULUS - 1 = 2^S * T
+
+    /// T = (MODULUS - 1) // 2^S =
+    /// 3277242198445386904965483781761622864852960632936158640682679581196091627099
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x684b872f6b7b965b,
+        0x53341049e6640841,
+        0x83339d80809a1d80,
+        0x73eda753299d7d4,
+    ]);
+    /// (T - 1) // 2 =
+    /// 1638621099222693452482741890880811432426480316468079320341339790598045813549
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xb425c397b5bdcb2d,
+        0x299a0824f3320420,
+        0x4199cec0404d0ec0,
+        0x39f6d3a994cebea,
+    ]);
+}
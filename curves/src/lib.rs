@@ -35,7 +35,7 @@
     private_in_public,
     unsafe_code
 )]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(any(test, feature = "timing")), forbid(unsafe_code))]
 // Documentation
 #![cfg_attr(nightly, feature(doc_cfg, external_doc))]
 // TODO (howardwu): Reenable after completing documentation in snarkVM-models.
@@ -53,6 +53,10 @@ extern crate thiserror;
 
 pub mod bls12_377;
 
+pub mod bls12_381;
+
+pub mod bn254;
+
 pub mod bw6_761;
 
 pub mod edwards_bls12;
@@ -62,6 +66,10 @@ pub mod edwards_bw6;
 pub mod errors;
 pub use errors::*;
 
+pub mod hash_to_curve;
+
+pub mod jubjub;
+
 pub mod templates;
 
 #[cfg_attr(test, macro_use)]
@@ -15,9 +15,20 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    hash_to_curve,
     impl_sw_curve_serializer,
     templates::short_weierstrass_jacobian::Projective,
-    traits::{AffineCurve, Group, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+    traits::{
+        glv_mul,
+        AffineCurve,
+        GLVDecomposition,
+        Group,
+        HashToCurve,
+        HashToField,
+        MapToCurve,
+        ProjectiveCurve,
+        ShortWeierstrassParameters as Parameters,
+    },
 };
 use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand, serialize::*, FromBytes, ToBytes};
@@ -178,6 +189,59 @@ impl<P: Parameters> AffineCurve for Affine<P> {
             y2 == x3b
         }
     }
+
+    fn batch_add_loop_1(a: &mut Self, b: &mut Self, inversion_tmp: &mut Self::BaseField) {
+        if a.is_zero() || b.is_zero() {
+            // The addition is completed by `batch_add_loop_2`.
+        } else if a.x == b.x {
+            if a.y == b.y && !a.y.is_zero() {
+                // Doubling, with the slope (3x^2 + a) / 2y. Doubling is rare in a batch,
+                // so the inversion of two is computed here rather than passed in.
+                let half = P::BaseField::one().double().inverse().unwrap();
+                let x_squared = b.x.square();
+                b.x -= &b.y; // x - y
+                a.x = b.y.double(); // denominator = 2y
+                a.y = x_squared.double() + x_squared + P::COEFF_A; // numerator = 3x^2 + a
+                b.y -= &(a.y * half); // y - (3x^2 + a) / 2
+                a.y *= *inversion_tmp;
+                *inversion_tmp *= &a.x;
+            } else {
+                // The points are opposite, or a point of order two is doubled, so their sum is zero.
+                *a = Self::zero();
+                *b = Self::zero();
+            }
+        } else {
+            a.x -= &b.x; // denominator = x1 - x2
+            a.y -= &b.y; // numerator = y1 - y2
+            a.y *= *inversion_tmp;
+            *inversion_tmp *= &a.x;
+        }
+    }
+
+    fn batch_add_loop_2(a: &mut Self, b: Self, inversion_tmp: &mut Self::BaseField) {
+        if a.is_zero() {
+            *a = b;
+        } else if !b.is_zero() {
+            let lambda = a.y * *inversion_tmp;
+            *inversion_tmp *= &a.x;
+
+            // x3 = lambda^2 - x1 - x2, or lambda^2 - 2x when doubling.
+            a.x += &b.x.double();
+            a.x = lambda.square() - a.x;
+            // y3 = lambda * (x2 - x3) - y2, or lambda * (x - x3) - y when doubling.
+            a.y = lambda * (b.x - a.x) - b.y;
+        }
+    }
+
+    #[inline]
+    fn glv_endomorphism(&self) -> Option<Self> {
+        P::glv_endomorphism_x(&self.x).map(|x| Self::new(x, self.y, self.infinity))
+    }
+
+    #[inline]
+    fn glv_decomposition(scalar: &Self::ScalarField) -> Option<GLVDecomposition<Self::ScalarField>> {
+        P::glv_decomposition(scalar)
+    }
 }
 
 impl<P: Parameters> Group for Affine<P> {
@@ -251,6 +315,9 @@ impl<P: Parameters> Mul<P::ScalarField> for Affine<P> {
     type Output = Self;
 
     fn mul(self, other: P::ScalarField) -> Self {
+        if let (Some(decomposition), Some(endomorphism)) = (P::glv_decomposition(&other), self.glv_endomorphism()) {
+            return glv_mul(self.into_projective(), endomorphism.into_projective(), &decomposition).into();
+        }
         self.mul_bits(BitIteratorBE::new(other.to_repr())).into()
     }
 }
@@ -331,4 +398,27 @@ impl<P: Parameters> From<Projective<P>> for Affine<P> {
     }
 }
 
+impl<P: Parameters + MapToCurve> HashToCurve for Affine<P>
+where
+    P::BaseField: HashToField,
+{
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        hash_to_curve::hash_to_curve(message, dst, Self::from_map_to_curve)
+    }
+
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        hash_to_curve::encode_to_curve(message, dst, Self::from_map_to_curve)
+    }
+}
+
+impl<P: Parameters + MapToCurve> Affine<P> {
+    /// Returns the point that `u` maps to, before clearing the cofactor.
+    fn from_map_to_curve(u: &P::BaseField) -> Self {
+        match P::map_to_curve(u) {
+            Some((x, y)) => Self::new(x, y, false),
+            None => Self::zero(),
+        }
+    }
+}
+
 impl_sw_curve_serializer!(Parameters);
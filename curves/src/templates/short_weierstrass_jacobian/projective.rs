@@ -16,7 +16,16 @@
 
 use crate::{
     templates::short_weierstrass_jacobian::Affine,
-    traits::{AffineCurve, Group, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+    traits::{
+        glv_mul,
+        AffineCurve,
+        Group,
+        HashToCurve,
+        HashToField,
+        MapToCurve,
+        ProjectiveCurve,
+        ShortWeierstrassParameters as Parameters,
+    },
 };
 use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand, serialize::*, FromBytes, ToBytes};
@@ -299,6 +308,11 @@ impl<P: Parameters> ProjectiveCurve for Projective<P> {
     fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
         P::empirical_recommended_wnaf_for_num_scalars(num_scalars)
     }
+
+    #[inline]
+    fn glv_endomorphism(&self) -> Option<Self> {
+        P::glv_endomorphism_x(&self.x).map(|x| Self::new(x, self.y, self.z))
+    }
 }
 
 impl<P: Parameters> Group for Projective<P> {
@@ -505,6 +519,10 @@ impl<P: Parameters> Mul<P::ScalarField> for Projective<P> {
     #[allow(clippy::suspicious_arithmetic_impl)]
     #[inline]
     fn mul(self, other: P::ScalarField) -> Self {
+        if let (Some(decomposition), Some(endomorphism)) = (P::glv_decomposition(&other), self.glv_endomorphism()) {
+            return glv_mul(self, endomorphism, &decomposition);
+        }
+
         let mut res = Self::zero();
 
         let mut found_one = false;
@@ -543,3 +561,16 @@ impl<P: Parameters> From<Affine<P>> for Projective<P> {
         }
     }
 }
+
+impl<P: Parameters + MapToCurve> HashToCurve for Projective<P>
+where
+    P::BaseField: HashToField,
+{
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        Affine::<P>::hash_to_curve(message, dst).into_projective()
+    }
+
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        Affine::<P>::encode_to_curve(message, dst).into_projective()
+    }
+}
@@ -17,13 +17,16 @@
 use super::{Affine, Projective};
 use crate::traits::{
     pairing_engine::{AffineCurve, ProjectiveCurve},
+    GLVParameters,
     ShortWeierstrassParameters,
 };
-use snarkvm_fields::Zero;
+use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_utilities::{
+    biginteger::BigInteger,
     io::Cursor,
     rand::UniformRand,
     serialize::{CanonicalDeserialize, CanonicalSerialize},
+    BitIteratorBE,
 };
 
 use rand::SeedableRng;
@@ -139,3 +142,49 @@ pub fn sw_from_random_bytes<P: ShortWeierstrassParameters>() {
         }
     }
 }
+
+pub fn sw_glv_tests<P: GLVParameters>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let generator = Projective::<P>::prime_subgroup_generator();
+
+    // The endomorphism acts as multiplication by `λ` on the prime-order subgroup.
+    let naive = generator
+        .into_affine()
+        .mul_bits(BitIteratorBE::new(P::LAMBDA.to_repr()));
+    assert_eq!(generator.glv_endomorphism().unwrap(), naive);
+    assert_eq!(generator.into_affine().glv_endomorphism().unwrap(), naive.into_affine());
+
+    let edge_cases = [
+        P::ScalarField::zero(),
+        P::ScalarField::one(),
+        -P::ScalarField::one(),
+        P::LAMBDA,
+        -P::LAMBDA,
+    ];
+    let random_scalars: Vec<_> = (0..ITERATIONS).map(|_| P::ScalarField::rand(&mut rng)).collect();
+    let scalars = edge_cases.iter().chain(&random_scalars).copied();
+
+    for k in scalars {
+        // The halves recombine to the scalar, and are about half its size.
+        let [(k1_is_negative, k1), (k2_is_negative, k2)] = P::decompose_scalar(&k);
+        let (k1, k2) = (
+            P::ScalarField::from_repr(k1).unwrap(),
+            P::ScalarField::from_repr(k2).unwrap(),
+        );
+        let k1 = if k1_is_negative { -k1 } else { k1 };
+        let k2 = if k2_is_negative { -k2 } else { k2 };
+        assert_eq!(k1 + k2 * P::LAMBDA, k);
+
+        let max_bits = <P::ScalarField as PrimeField>::size_in_bits() / 2 + 2;
+        for (_, half) in &P::decompose_scalar(&k) {
+            assert!(half.num_bits() as usize <= max_bits);
+        }
+
+        // The GLV scalar multiplication matches the naive double-and-add.
+        let a = Projective::<P>::rand(&mut rng);
+        let naive = a.into_affine().mul_bits(BitIteratorBE::new(k.to_repr()));
+        assert_eq!(a * k, naive);
+        assert_eq!(a.into_affine() * k, naive.into_affine());
+    }
+}
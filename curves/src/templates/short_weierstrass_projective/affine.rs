@@ -165,6 +165,49 @@ impl<P: Parameters> AffineCurve for Affine<P> {
             y2 == x3b
         }
     }
+
+    fn batch_add_loop_1(a: &mut Self, b: &mut Self, inversion_tmp: &mut Self::BaseField) {
+        if a.is_zero() || b.is_zero() {
+            // The addition is completed by `batch_add_loop_2`.
+        } else if a.x == b.x {
+            if a.y == b.y && !a.y.is_zero() {
+                // Doubling, with the slope (3x^2 + a) / 2y. Doubling is rare in a batch,
+                // so the inversion of two is computed here rather than passed in.
+                let half = P::BaseField::one().double().inverse().unwrap();
+                let x_squared = b.x.square();
+                b.x -= &b.y; // x - y
+                a.x = b.y.double(); // denominator = 2y
+                a.y = x_squared.double() + x_squared + P::COEFF_A; // numerator = 3x^2 + a
+                b.y -= &(a.y * half); // y - (3x^2 + a) / 2
+                a.y *= *inversion_tmp;
+                *inversion_tmp *= &a.x;
+            } else {
+                // The points are opposite, or a point of order two is doubled, so their sum is zero.
+                *a = Self::zero();
+                *b = Self::zero();
+            }
+        } else {
+            a.x -= &b.x; // denominator = x1 - x2
+            a.y -= &b.y; // numerator = y1 - y2
+            a.y *= *inversion_tmp;
+            *inversion_tmp *= &a.x;
+        }
+    }
+
+    fn batch_add_loop_2(a: &mut Self, b: Self, inversion_tmp: &mut Self::BaseField) {
+        if a.is_zero() {
+            *a = b;
+        } else if !b.is_zero() {
+            let lambda = a.y * *inversion_tmp;
+            *inversion_tmp *= &a.x;
+
+            // x3 = lambda^2 - x1 - x2, or lambda^2 - 2x when doubling.
+            a.x += &b.x.double();
+            a.x = lambda.square() - a.x;
+            // y3 = lambda * (x2 - x3) - y2, or lambda * (x - x3) - y when doubling.
+            a.y = lambda * (b.x - a.x) - b.y;
+        }
+    }
 }
 
 impl<P: Parameters> Group for Affine<P> {
@@ -15,11 +15,22 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    hash_to_curve,
     impl_edwards_curve_serializer,
     templates::twisted_edwards_extended::Projective,
-    traits::{AffineCurve, Group, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
+    traits::{
+        AffineCurve,
+        ConstantTimeGroup,
+        Group,
+        HashToCurve,
+        HashToField,
+        MapToCurve,
+        MontgomeryParameters,
+        ProjectiveCurve,
+        TwistedEdwardsParameters as Parameters,
+    },
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{impl_add_sub_from_field_ref, ConstantTimeField, Field, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand, serialize::*, FromBytes, ToBytes};
 
 use rand::{
@@ -227,6 +238,18 @@ impl<P: Parameters> Group for Affine<P> {
     }
 }
 
+impl<P: Parameters> ConstantTimeGroup for Affine<P>
+where
+    P::BaseField: ConstantTimeField,
+    P::ScalarField: ConstantTimeField,
+{
+    fn mul_constant_time(&self, scalar: &P::ScalarField) -> Self {
+        // The projective product is normalized, so it is converted without an inversion.
+        let result = Projective::from(*self).mul_constant_time(scalar);
+        Self::new(result.x, result.y)
+    }
+}
+
 impl<P: Parameters> Neg for Affine<P> {
     type Output = Self;
 
@@ -352,4 +375,27 @@ impl<P: Parameters> From<Projective<P>> for Affine<P> {
     }
 }
 
+impl<P: Parameters + MapToCurve> HashToCurve for Affine<P>
+where
+    P::BaseField: HashToField,
+{
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        hash_to_curve::hash_to_curve(message, dst, Self::from_map_to_curve)
+    }
+
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        hash_to_curve::encode_to_curve(message, dst, Self::from_map_to_curve)
+    }
+}
+
+impl<P: Parameters + MapToCurve> Affine<P> {
+    /// Returns the point that `u` maps to, before clearing the cofactor.
+    fn from_map_to_curve(u: &P::BaseField) -> Self {
+        match P::map_to_curve(u) {
+            Some((x, y)) => Self::new(x, y),
+            None => Self::zero(),
+        }
+    }
+}
+
 impl_edwards_curve_serializer!(Parameters);
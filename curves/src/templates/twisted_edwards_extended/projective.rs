@@ -16,9 +16,18 @@
 
 use crate::{
     templates::twisted_edwards_extended::Affine,
-    traits::{AffineCurve, Group, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
+    traits::{
+        AffineCurve,
+        ConstantTimeGroup,
+        Group,
+        HashToCurve,
+        HashToField,
+        MapToCurve,
+        ProjectiveCurve,
+        TwistedEdwardsParameters as Parameters,
+    },
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
+use snarkvm_fields::{impl_add_sub_from_field_ref, ConstantTimeField, Field, One, PrimeField, Zero};
 use snarkvm_utilities::{bititerator::BitIteratorBE, rand::UniformRand, serialize::*, FromBytes, ToBytes};
 
 use rand::{
@@ -30,6 +39,7 @@ use std::{
     io::{Read, Result as IoResult, Write},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 #[derive(Derivative)]
 #[derivative(
@@ -251,6 +261,112 @@ impl<P: Parameters> Group for Projective<P> {
     }
 }
 
+impl<P: Parameters> Projective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    /// Returns `self + other` with the unified addition law of `add_assign`,
+    /// using only constant-time field arithmetic.
+    #[allow(clippy::many_single_char_names)]
+    fn add_constant_time(&self, other: &Self) -> Self {
+        // A = x1 * x2
+        let a = self.x.mul_constant_time(&other.x);
+
+        // B = y1 * y2
+        let b = self.y.mul_constant_time(&other.y);
+
+        // C = d * t1 * t2
+        let c = P::COEFF_D.mul_constant_time(&self.t).mul_constant_time(&other.t);
+
+        // D = z1 * z2
+        let d = self.z.mul_constant_time(&other.z);
+
+        // H = B - aA
+        let h = b.sub_constant_time(&P::COEFF_A.mul_constant_time(&a));
+
+        // E = (x1 + y1) * (x2 + y2) - A - B
+        let e = self
+            .x
+            .add_constant_time(&self.y)
+            .mul_constant_time(&other.x.add_constant_time(&other.y))
+            .sub_constant_time(&a)
+            .sub_constant_time(&b);
+
+        // F = D - C
+        let f = d.sub_constant_time(&c);
+
+        // G = D + C
+        let g = d.add_constant_time(&c);
+
+        Self::new(
+            e.mul_constant_time(&f),
+            g.mul_constant_time(&h),
+            e.mul_constant_time(&h),
+            f.mul_constant_time(&g),
+        )
+    }
+}
+
+impl<P: Parameters> ConstantTimeGroup for Projective<P>
+where
+    P::BaseField: ConstantTimeField,
+    P::ScalarField: ConstantTimeField,
+{
+    /// Performs a fixed-window scalar multiplication over every bit of the scalar,
+    /// reading the whole window table at each step. The unified addition law is complete,
+    /// so no step depends on the intermediate points. The result is normalized with a
+    /// constant-time inversion, so its conversion into affine coordinates is free.
+    fn mul_constant_time(&self, scalar: &P::ScalarField) -> Self {
+        const WINDOW_SIZE: usize = 4;
+
+        // Precompute [0, self, 2 * self, ..., 15 * self].
+        let mut table = Vec::with_capacity(1 << WINDOW_SIZE);
+        table.push(Self::zero());
+        for i in 1..(1 << WINDOW_SIZE) {
+            table.push(table[i - 1].add_constant_time(self));
+        }
+
+        let bits = BitIteratorBE::new(scalar.to_repr_constant_time()).collect::<Vec<_>>();
+
+        let mut result = Self::zero();
+        for window in bits.chunks(WINDOW_SIZE) {
+            for _ in 0..WINDOW_SIZE {
+                result = result.add_constant_time(&result);
+            }
+
+            let index = window.iter().fold(0u8, |index, bit| (index << 1) | *bit as u8);
+            let mut entry = Self::zero();
+            for (i, candidate) in table.iter().enumerate() {
+                entry.conditional_assign(candidate, (i as u8).ct_eq(&index));
+            }
+            result = result.add_constant_time(&entry);
+        }
+
+        // The extended Z coordinate is nonzero for every point on a complete twisted Edwards curve.
+        let z_inv = result.z.inverse_constant_time().unwrap();
+        Self::new(
+            result.x.mul_constant_time(&z_inv),
+            result.y.mul_constant_time(&z_inv),
+            result.t.mul_constant_time(&z_inv),
+            P::BaseField::one(),
+        )
+    }
+}
+
+impl<P: Parameters> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Neg for Projective<P> {
     type Output = Self;
 
@@ -376,3 +492,16 @@ impl<P: Parameters> From<Affine<P>> for Projective<P> {
         Self::new(p.x, p.y, p.x * p.y, P::BaseField::one())
     }
 }
+
+impl<P: Parameters + MapToCurve> HashToCurve for Projective<P>
+where
+    P::BaseField: HashToField,
+{
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        Affine::<P>::hash_to_curve(message, dst).into_projective()
+    }
+
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self {
+        Affine::<P>::encode_to_curve(message, dst).into_projective()
+    }
+}
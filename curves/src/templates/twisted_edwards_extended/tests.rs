@@ -26,10 +26,11 @@ use snarkvm_utilities::{
 
 use crate::traits::{
     pairing_engine::{AffineCurve, ProjectiveCurve},
+    ConstantTimeGroup,
     MontgomeryParameters,
     TwistedEdwardsParameters,
 };
-use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_fields::{ConstantTimeField, Field, One, PrimeField, Zero};
 
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
@@ -51,11 +52,13 @@ where
 
 pub fn edwards_test<P: TwistedEdwardsParameters>()
 where
-    P::BaseField: PrimeField,
+    P::BaseField: PrimeField + ConstantTimeField,
+    P::ScalarField: ConstantTimeField,
 {
     edwards_curve_serialization_test::<P>();
     edwards_from_random_bytes::<P>();
     edwards_from_x_and_y_coordinates::<P>();
+    edwards_constant_time_mul_test::<P>();
 }
 
 pub fn edwards_curve_serialization_test<P: TwistedEdwardsParameters>() {
@@ -183,3 +186,26 @@ where
         }
     }
 }
+
+pub fn edwards_constant_time_mul_test<P: TwistedEdwardsParameters>()
+where
+    P::BaseField: ConstantTimeField,
+    P::ScalarField: ConstantTimeField,
+{
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = Projective::<P>::rand(&mut rng);
+        let scalar = P::ScalarField::rand(&mut rng);
+
+        let expected = a * scalar;
+        let candidate = a.mul_constant_time(&scalar);
+        assert_eq!(expected, candidate);
+        assert!(candidate.is_normalized());
+        assert_eq!(a.into_affine().mul_constant_time(&scalar), expected.into_affine());
+
+        assert!(a.mul_constant_time(&P::ScalarField::zero()).is_zero());
+        assert_eq!(a.mul_constant_time(&P::ScalarField::one()), a);
+        assert!(Projective::<P>::zero().mul_constant_time(&scalar).is_zero());
+    }
+}
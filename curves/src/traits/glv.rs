@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::{ProjectiveCurve, ShortWeierstrassParameters};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::biginteger::BigInteger;
+
+/// A scalar `k` decomposed as `k = k_1 + k_2 λ`, where each half is given by its sign (`true` if negative)
+/// and its magnitude, which is about half the size of `k`.
+pub type GLVDecomposition<F> = [(bool, <F as PrimeField>::BigInteger); 2];
+
+/// The parameters of an endomorphism `φ(x, y) = (β x, y)` of a short Weierstrass curve, which acts as
+/// multiplication by `λ` on the prime-order subgroup. Following [[GLV01]][glv01], a scalar multiplication
+/// `k P` is computed as `k_1 P + k_2 φ(P)`, where `k_1` and `k_2` are about half the size of `k`.
+///
+/// The decomposition is only meaningful for points in the prime-order subgroup.
+///
+/// [glv01]: https://www.iacr.org/archive/crypto2001/21390189.pdf
+pub trait GLVParameters: ShortWeierstrassParameters {
+    /// A primitive cube root of unity `β` in the base field.
+    const ENDO_COEFF: Self::BaseField;
+    /// The primitive cube root of unity `λ` in the scalar field, such that `φ(P) = λ P`.
+    const LAMBDA: Self::ScalarField;
+    /// The second coordinate `b_1` of a short basis `(a_1, b_1), (a_2, b_2)` of the lattice of all `(k_1, k_2)`
+    /// with `k_1 + k_2 λ = 0 mod r`, ordered so that `a_1 b_2 - a_2 b_1 = r`.
+    const B1: Self::ScalarField;
+    /// The second coordinate `b_2` of the short basis.
+    const B2: Self::ScalarField;
+    /// `round(2^N b_2 / r)`, as its sign and little-endian limbs,
+    /// where `N` is the size in bits of the scalar field representation.
+    const Q1: (bool, &'static [u64]);
+    /// `round(-2^N b_1 / r)`, as its sign and little-endian limbs.
+    const Q2: (bool, &'static [u64]);
+
+    /// Returns `β x`.
+    #[inline(always)]
+    fn endomorphism_x(x: &Self::BaseField) -> Self::BaseField {
+        *x * Self::ENDO_COEFF
+    }
+
+    /// Decomposes `k` into `k_1 + k_2 λ`, by rounding `(k, 0)` to a nearby point of the lattice.
+    fn decompose_scalar(k: &Self::ScalarField) -> GLVDecomposition<Self::ScalarField> {
+        let k_repr = k.to_repr();
+        let c1: Self::ScalarField = scaled_quotient(k_repr.as_ref(), Self::Q1);
+        let c2: Self::ScalarField = scaled_quotient(k_repr.as_ref(), Self::Q2);
+
+        // `(k_1, k_2) = (k, 0) - c_1 (a_1, b_1) - c_2 (a_2, b_2)`, where `k_1 = k - k_2 λ` as `a_i = -b_i λ`.
+        let k2 = -(c1 * Self::B1 + c2 * Self::B2);
+        let k1 = *k - k2 * Self::LAMBDA;
+        [signed_magnitude(k1), signed_magnitude(k2)]
+    }
+}
+
+/// Returns `k P + k' φ(P)` for `decomposition = [k, k']`, given `base = P` and `endomorphism = φ(P)`,
+/// with a joint double-and-add over the bits of both halves.
+pub fn glv_mul<G: ProjectiveCurve>(base: G, endomorphism: G, decomposition: &GLVDecomposition<G::ScalarField>) -> G {
+    let [(k1_is_negative, k1), (k2_is_negative, k2)] = decomposition;
+    let p1 = if *k1_is_negative { -base } else { base };
+    let p2 = if *k2_is_negative { -endomorphism } else { endomorphism };
+    let p1_plus_p2 = p1 + p2;
+
+    let mut res = G::zero();
+    for i in (0..k1.num_bits().max(k2.num_bits()) as usize).rev() {
+        res.double_in_place();
+        match (k1.get_bit(i), k2.get_bit(i)) {
+            (true, true) => res += p1_plus_p2,
+            (true, false) => res += p1,
+            (false, true) => res += p2,
+            (false, false) => (),
+        }
+    }
+    res
+}
+
+/// Returns `± floor(k q / 2^N)` for `quotient = (is_negative, q)`, where `N` is the size in bits of `k`.
+fn scaled_quotient<F: PrimeField>(k: &[u64], (is_negative, q): (bool, &[u64])) -> F {
+    let mut product = vec![0u64; k.len() + q.len()];
+    for (i, k_i) in k.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, q_j) in q.iter().enumerate() {
+            let t = u128::from(product[i + j]) + u128::from(*k_i) * u128::from(*q_j) + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + q.len()] = carry as u64;
+    }
+
+    // The quotient is at most `|b_i|`, which is much smaller than the modulus.
+    let mut quotient = F::BigInteger::default();
+    quotient.as_mut()[..q.len()].copy_from_slice(&product[k.len()..]);
+    let quotient = F::from_repr(quotient).unwrap();
+    if is_negative {
+        -quotient
+    } else {
+        quotient
+    }
+}
+
+/// Returns the sign and magnitude of `x`, as the smaller of `x` and `-x`.
+fn signed_magnitude<F: PrimeField>(x: F) -> (bool, F::BigInteger) {
+    let (repr, neg_repr) = (x.to_repr(), (-x).to_repr());
+    if neg_repr < repr {
+        (true, neg_repr)
+    } else {
+        (false, repr)
+    }
+}
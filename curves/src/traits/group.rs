@@ -61,3 +61,11 @@ pub trait Group:
     /// Sets `self := self + self`.
     fn double_in_place(&mut self);
 }
+
+/// The interface for a group whose scalar multiplication runs in constant time.
+pub trait ConstantTimeGroup: Group {
+    /// Returns `self * scalar`, performing the same sequence of group operations
+    /// and memory accesses for every scalar.
+    #[must_use]
+    fn mul_constant_time(&self, scalar: &Self::ScalarField) -> Self;
+}
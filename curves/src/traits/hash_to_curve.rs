@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::traits::ModelParameters;
+use snarkvm_fields::SquareRootField;
+
+/// A field whose elements can be sampled from uniformly random bytes, as in the `hash_to_field`
+/// function of the [IETF hash-to-curve specification][h2c].
+///
+/// [h2c]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/
+pub trait HashToField: SquareRootField {
+    /// The number of prime field elements that make up an element of this field.
+    const EXTENSION_DEGREE: usize;
+    /// The number of bytes reduced into each prime field element, `L = ceil((ceil(log2(p)) + k) / 8)`,
+    /// for a security level of `k = 128` bits.
+    const PRIME_FIELD_BYTES: usize;
+
+    /// Returns the field element of the `EXTENSION_DEGREE * PRIME_FIELD_BYTES` big-endian `bytes`,
+    /// where each prime field coefficient is read from `PRIME_FIELD_BYTES` bytes modulo the characteristic.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+
+    /// Returns the "sign" of this element, as defined by the `sgn0` function of the specification.
+    fn sgn0(&self) -> bool;
+}
+
+/// The parameters of a curve model with a deterministic map from its base field to the curve.
+pub trait MapToCurve: ModelParameters {
+    /// Maps `u` to the affine coordinates of a point on the curve, or `None` for the point at infinity.
+    /// The point is not necessarily in the prime-order subgroup.
+    fn map_to_curve(u: &Self::BaseField) -> Option<(Self::BaseField, Self::BaseField)>;
+}
+
+/// Curve elements that messages can be hashed to, following the IETF hash-to-curve specification.
+/// Every element is in the prime-order subgroup, and its discrete logarithm is unknown.
+pub trait HashToCurve: Sized {
+    /// Hashes `message` to an element that is indistinguishable from a uniformly random one,
+    /// under the domain separation tag `dst`.
+    #[must_use]
+    fn hash_to_curve(message: &[u8], dst: &[u8]) -> Self;
+
+    /// Encodes `message` to an element under the domain separation tag `dst`. This is about twice
+    /// as fast as `hash_to_curve`, but the output is not uniformly distributed.
+    #[must_use]
+    fn encode_to_curve(message: &[u8], dst: &[u8]) -> Self;
+
+    /// Derives `count` independent generators for the domain separation tag `dst`,
+    /// by hashing the little-endian bytes of each index `i` as a `u32` to the curve.
+    /// The generators have no known discrete logarithm relation, and as `dst` is public,
+    /// anyone can reproduce the parameters derived from them.
+    #[must_use]
+    fn derive_generators(dst: &[u8], count: usize) -> Vec<Self> {
+        (0..count as u32)
+            .map(|i| Self::hash_to_curve(&i.to_le_bytes(), dst))
+            .collect()
+    }
+}
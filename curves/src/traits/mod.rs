@@ -14,12 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod glv;
+pub use glv::*;
+
 pub mod group;
 pub use group::*;
 
+pub mod hash_to_curve;
+pub use hash_to_curve::*;
+
 pub mod pairing_engine;
 pub use pairing_engine::*;
 
 pub mod tests_group;
 
 pub mod tests_curve;
+
+#[cfg(any(test, feature = "timing"))]
+pub mod tests_timing;
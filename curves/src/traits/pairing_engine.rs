@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::traits::Group;
+use crate::traits::{GLVDecomposition, Group};
 use snarkvm_fields::{Field, PrimeField, SquareRootField};
 use snarkvm_utilities::{biginteger::BigInteger, serialize::*, BitIteratorBE, ToBytes};
 
@@ -146,6 +146,14 @@ pub trait ProjectiveCurve:
     /// inclusive.
     #[must_use]
     fn recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize;
+
+    /// Returns the image of this element under the GLV endomorphism of the curve, if it has one.
+    ///
+    /// The default implementation returns `None`.
+    #[must_use]
+    fn glv_endomorphism(&self) -> Option<Self> {
+        None
+    }
 }
 
 /// Affine representation of an elliptic curve point guaranteed to be
@@ -222,6 +230,37 @@ pub trait AffineCurve:
 
     /// Checks that the current point is on the elliptic curve.
     fn is_on_curve(&self) -> bool;
+
+    /// Starts the affine addition of `b` to `a` as part of a batch of additions that share one inversion.
+    /// The denominator of the addition is multiplied into `inversion_tmp`, and the addition is
+    /// completed by `batch_add_loop_2` once the product of the denominators has been inverted.
+    ///
+    /// The default implementation leaves the whole addition to `batch_add_loop_2`.
+    fn batch_add_loop_1(_a: &mut Self, _b: &mut Self, _inversion_tmp: &mut Self::BaseField) {}
+
+    /// Completes the affine addition of `b` to `a` started by `batch_add_loop_1`, where `inversion_tmp`
+    /// is the inverse of the product of the denominators up to and including this addition.
+    /// The batch must be completed in the reverse order in which it was started.
+    ///
+    /// The default implementation adds the points in projective coordinates.
+    fn batch_add_loop_2(a: &mut Self, b: Self, _inversion_tmp: &mut Self::BaseField) {
+        *a = (a.into_projective() + b.into_projective()).into_affine();
+    }
+
+    /// Returns the image of this element under the GLV endomorphism of the curve, if it has one.
+    ///
+    /// The default implementation returns `None`.
+    #[must_use]
+    fn glv_endomorphism(&self) -> Option<Self> {
+        None
+    }
+
+    /// Decomposes `scalar` for a GLV scalar multiplication, if the curve has a GLV endomorphism.
+    ///
+    /// The default implementation returns `None`.
+    fn glv_decomposition(_scalar: &Self::ScalarField) -> Option<GLVDecomposition<Self::ScalarField>> {
+        None
+    }
 }
 
 pub trait PairingCurve: AffineCurve {
@@ -265,6 +304,19 @@ pub trait ShortWeierstrassParameters: ModelParameters {
         copy
     }
 
+    /// Returns `β x`, if the curve has a GLV endomorphism `(x, y) -> (β x, y)`.
+    /// Curves that implement `GLVParameters` override this and `glv_decomposition`.
+    #[inline(always)]
+    fn glv_endomorphism_x(_x: &Self::BaseField) -> Option<Self::BaseField> {
+        None
+    }
+
+    /// Decomposes `scalar` for a GLV scalar multiplication, if the curve has a GLV endomorphism.
+    #[inline(always)]
+    fn glv_decomposition(_scalar: &Self::ScalarField) -> Option<GLVDecomposition<Self::ScalarField>> {
+        None
+    }
+
     #[inline(always)]
     fn empirical_recommended_wnaf_for_scalar(scalar: <Self::ScalarField as PrimeField>::BigInteger) -> usize {
         let num_bits = scalar.num_bits() as usize;
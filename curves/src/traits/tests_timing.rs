@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use rand::Rng;
+use std::time::Instant;
+
+/// The number of measurements taken by a timing leakage test.
+pub const TIMING_SAMPLES: usize = 10_000;
+
+/// The Welch t-statistic above which the timings of the two input classes are considered
+/// distinguishable, as in dudect.
+pub const TIMING_THRESHOLD: f64 = 4.5;
+
+/// Tests that the running time of `operation` does not depend on its input.
+///
+/// This follows "Dude, is my code constant time?" (Reparaz, Balasch and Verbauwhede):
+/// each measurement runs `operation` on an input from one of two randomly interleaved classes,
+/// where the fixed class always uses `fixed` and the random class uses a fresh input from `sample`.
+/// After cropping outliers, the test fails if Welch's t-test distinguishes the two classes.
+///
+/// The measurements are sensitive to noise from other processes, so the tests that call
+/// this function are ignored by default and should be run locally in release mode.
+pub fn timing_leakage_test<I: Clone, O, R: Rng>(
+    rng: &mut R,
+    fixed: I,
+    mut sample: impl FnMut(&mut R) -> I,
+    mut operation: impl FnMut(&I) -> O,
+) {
+    // Prepare every input ahead of time, so that only the operation is measured.
+    let classes: Vec<bool> = (0..TIMING_SAMPLES).map(|_| rng.gen()).collect();
+    let inputs: Vec<I> = classes
+        .iter()
+        .map(|&is_random| if is_random { sample(rng) } else { fixed.clone() })
+        .collect();
+
+    // Warm up the caches and the branch predictor.
+    for input in inputs.iter().take(TIMING_SAMPLES / 100) {
+        black_box(operation(black_box(input)));
+    }
+
+    let timings: Vec<f64> = inputs
+        .iter()
+        .map(|input| {
+            let start = Instant::now();
+            black_box(operation(black_box(input)));
+            start.elapsed().as_nanos() as f64
+        })
+        .collect();
+
+    // Crop the slowest measurements, which are dominated by interrupts and scheduling.
+    let mut sorted = timings.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let cutoff = sorted[TIMING_SAMPLES * 95 / 100];
+
+    let mut fixed_timings = Vec::with_capacity(TIMING_SAMPLES);
+    let mut random_timings = Vec::with_capacity(TIMING_SAMPLES);
+    for (&is_random, &timing) in classes.iter().zip(&timings) {
+        if timing <= cutoff {
+            if is_random {
+                random_timings.push(timing);
+            } else {
+                fixed_timings.push(timing);
+            }
+        }
+    }
+
+    let t = welch_t_statistic(&fixed_timings, &random_timings);
+    assert!(
+        t.abs() < TIMING_THRESHOLD,
+        "The running time depends on the input (t = {:.2})",
+        t
+    );
+}
+
+/// Returns `value` through a volatile read, so that the compiler cannot optimize away
+/// the computation of `value` or assume anything about the result.
+#[allow(unsafe_code)]
+fn black_box<T>(value: T) -> T {
+    // Safety: `value` is a valid, aligned `T`, and it is forgotten after being copied, so it is dropped only once.
+    let result = unsafe { core::ptr::read_volatile(&value) };
+    core::mem::forget(value);
+    result
+}
+
+/// Returns Welch's t-statistic for the difference between the means of two samples.
+fn welch_t_statistic(a: &[f64], b: &[f64]) -> f64 {
+    let mean_and_variance = |samples: &[f64]| {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        (mean, variance / n)
+    };
+
+    let (mean_a, variance_a) = mean_and_variance(a);
+    let (mean_b, variance_b) = mean_and_variance(b);
+    (mean_a - mean_b) / (variance_a + variance_b).sqrt()
+}
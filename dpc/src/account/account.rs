@@ -18,11 +18,12 @@ use crate::{
     errors::AccountError,
     traits::{AccountScheme, DPCComponents},
     Address,
+    Mnemonic,
     PrivateKey,
 };
 
 use rand::{CryptoRng, Rng};
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 #[derive(Derivative)]
 #[derivative(Clone(bound = "C: DPCComponents"))]
@@ -31,6 +32,28 @@ pub struct Account<C: DPCComponents> {
     pub address: Address<C>,
 }
 
+impl<C: DPCComponents> Account<C> {
+    /// Derives the account at a given index from a mnemonic phrase.
+    pub fn from_mnemonic(
+        signature_parameters: &C::AccountSignature,
+        commitment_parameters: &C::AccountCommitment,
+        encryption_parameters: &C::AccountEncryption,
+        phrase: &str,
+        index: u32,
+    ) -> Result<Self, AccountError> {
+        let mnemonic = Mnemonic::from_str(phrase)?;
+        let private_key = PrivateKey::from_mnemonic(signature_parameters, commitment_parameters, &mnemonic, index)?;
+        let address = Address::from_private_key(
+            signature_parameters,
+            commitment_parameters,
+            encryption_parameters,
+            &private_key,
+        )?;
+
+        Ok(Self { private_key, address })
+    }
+}
+
 impl<C: DPCComponents> AccountScheme for Account<C> {
     type Address = Address<C>;
     type CommitmentScheme = C::AccountCommitment;
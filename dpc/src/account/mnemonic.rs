@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account::wordlist::ENGLISH_WORDLIST, AccountError};
+
+use rand::{CryptoRng, Rng};
+use sha2::{Digest, Sha256};
+use std::{fmt, str::FromStr};
+
+/// The number of words in a mnemonic, which encode a 256-bit seed and its 8-bit checksum.
+pub const MNEMONIC_NUM_WORDS: usize = 24;
+
+/// The number of bits encoded by each word of a mnemonic.
+const BITS_PER_WORD: usize = 11;
+
+/// A BIP-39 mnemonic phrase for the seed from which account private keys are derived.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic {
+    seed: [u8; 32],
+}
+
+impl Mnemonic {
+    /// Creates a new mnemonic from a random seed.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        Self { seed: rng.gen() }
+    }
+
+    /// Creates the mnemonic for a given seed.
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        Self { seed }
+    }
+
+    /// Returns the seed encoded by the mnemonic.
+    pub fn seed(&self) -> &[u8; 32] {
+        &self.seed
+    }
+
+    /// Returns the checksum of a seed, which is the first byte of its SHA-256 digest.
+    fn checksum(seed: &[u8; 32]) -> u8 {
+        Sha256::digest(seed)[0]
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = AccountError;
+
+    /// Reads in a mnemonic phrase of whitespace-separated words.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s.split_whitespace().collect::<Vec<_>>();
+        if words.len() != MNEMONIC_NUM_WORDS {
+            return Err(AccountError::InvalidMnemonicLength(words.len()));
+        }
+
+        // Concatenate the 11-bit index of every word, most significant bit first.
+        let mut bits = Vec::with_capacity(MNEMONIC_NUM_WORDS * BITS_PER_WORD);
+        for word in words {
+            let index = match ENGLISH_WORDLIST.binary_search(&word) {
+                Ok(index) => index,
+                Err(_) => return Err(AccountError::InvalidMnemonicWord(word.to_string())),
+            };
+            bits.extend((0..BITS_PER_WORD).rev().map(|i| (index >> i) & 1 == 1));
+        }
+
+        // The first 256 bits are the seed, and the last 8 bits are its checksum.
+        let mut bytes = bits
+            .chunks(8)
+            .map(|chunk| chunk.iter().fold(0u8, |byte, bit| (byte << 1) | *bit as u8));
+
+        let mut seed = [0u8; 32];
+        for (byte, value) in seed.iter_mut().zip(&mut bytes) {
+            *byte = value;
+        }
+
+        match bytes.next() {
+            Some(checksum) if checksum == Self::checksum(&seed) => Ok(Self { seed }),
+            _ => Err(AccountError::InvalidMnemonicChecksum),
+        }
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let checksum = Self::checksum(&self.seed);
+
+        // Split the seed and its checksum into 11-bit word indices, most significant bit first.
+        let bits = self
+            .seed
+            .iter()
+            .chain(&[checksum])
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .collect::<Vec<_>>();
+
+        let words = bits
+            .chunks(BITS_PER_WORD)
+            .map(|word| ENGLISH_WORDLIST[word.iter().fold(0usize, |index, bit| (index << 1) | *bit as usize)])
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", words.join(" "))
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Mnemonic {{ seed: {:?} }}", self.seed)
+    }
+}
@@ -25,11 +25,16 @@ pub use account_format::*;
 pub mod address;
 pub use address::*;
 
+pub mod mnemonic;
+pub use mnemonic::*;
+
 pub mod private_key;
 pub use private_key::*;
 
 pub mod view_key;
 pub use view_key::*;
 
+mod wordlist;
+
 #[cfg(test)]
 pub mod tests;
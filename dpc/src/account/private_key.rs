@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{account_format, traits::DPCComponents, AccountError};
+use crate::{account_format, traits::DPCComponents, AccountError, Mnemonic};
 use snarkvm_algorithms::{
     prf::Blake2s,
     traits::{CommitmentScheme, EncryptionScheme, SignatureScheme, PRF},
@@ -51,6 +51,7 @@ impl<C: DPCComponents> PrivateKey<C> {
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
     ];
     const INPUT_SK_SIG: [u8; 32] = [0u8; 32];
+    const INPUT_CHILD_SEED_PREFIX: [u8; 12] = *b"AleoChildKey";
 
     /// Creates a new account private key.
     pub fn new<R: Rng + CryptoRng>(
@@ -71,6 +72,29 @@ impl<C: DPCComponents> PrivateKey<C> {
         }
     }
 
+    /// Derives the account private key at a given index from a mnemonic.
+    ///
+    /// Every child is hardened: its seed is derived from the secret seed of the mnemonic,
+    /// so a child private key reveals neither the mnemonic nor the private keys of its siblings.
+    pub fn from_mnemonic(
+        signature_parameters: &C::AccountSignature,
+        commitment_parameters: &C::AccountCommitment,
+        mnemonic: &Mnemonic,
+        index: u32,
+    ) -> Result<Self, AccountError> {
+        // Derive child seeds until a valid private key is found.
+        for attempt in 0..u32::MAX {
+            let seed = Self::derive_child_seed(mnemonic.seed(), index, attempt)?;
+
+            // Returns the private key if it is valid.
+            if let Ok(private_key) = Self::from_seed(signature_parameters, commitment_parameters, &seed) {
+                return Ok(private_key);
+            }
+        }
+
+        Err(AccountError::InvalidPrivateKeySeed)
+    }
+
     /// Derives the account private key from a given seed and verifies it is well-formed.
     pub fn from_seed(
         signature_parameters: &C::AccountSignature,
@@ -203,6 +227,16 @@ impl<C: DPCComponents> PrivateKey<C> {
         })
     }
 
+    /// Derives the seed of the child account at a given index from a mnemonic seed and an attempt counter.
+    fn derive_child_seed(seed: &[u8; 32], index: u32, attempt: u32) -> Result<[u8; 32], AccountError> {
+        let mut child_seed_input = [0u8; 32];
+        child_seed_input[0..12].copy_from_slice(&Self::INPUT_CHILD_SEED_PREFIX);
+        child_seed_input[12..16].copy_from_slice(&index.to_le_bytes());
+        child_seed_input[16..20].copy_from_slice(&attempt.to_le_bytes());
+
+        Ok(Blake2s::evaluate(seed, &child_seed_input)?)
+    }
+
     /// Generate the randomness rpk for the commitment scheme from a given seed and counter
     fn derive_r_pk(
         seed: &[u8; 32],
@@ -15,9 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    account::{Account, Address, PrivateKey, ViewKey},
+    account::{Account, Address, Mnemonic, PrivateKey, ViewKey},
     testnet1::{instantiated::Components, parameters::SystemParameters},
     traits::account::AccountScheme,
+    AccountError,
 };
 
 use rand::thread_rng;
@@ -68,3 +69,109 @@ pub fn test_address_from_str() {
     assert!(address.is_ok());
     assert_eq!(address_string, address.unwrap().to_string());
 }
+
+#[test]
+pub fn test_mnemonic_test_vectors() {
+    let test_vectors = [
+        (
+            [0x00u8; 32],
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+        ),
+        (
+            [0x7fu8; 32],
+            "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title",
+        ),
+        (
+            [0x80u8; 32],
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+        ),
+        (
+            [0xffu8; 32],
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+        ),
+    ];
+
+    for (seed, phrase) in test_vectors.iter() {
+        let mnemonic = Mnemonic::from_seed(*seed);
+        assert_eq!(*phrase, mnemonic.to_string());
+        assert_eq!(mnemonic, Mnemonic::from_str(phrase).unwrap());
+    }
+}
+
+#[test]
+pub fn test_mnemonic_from_str() {
+    let rng = &mut thread_rng();
+
+    let mnemonic = Mnemonic::new(rng);
+    assert_eq!(mnemonic, Mnemonic::from_str(&mnemonic.to_string()).unwrap());
+
+    // The checksum of the zero seed is not zero.
+    let invalid_checksum = vec!["abandon"; 24].join(" ");
+    assert!(matches!(
+        Mnemonic::from_str(&invalid_checksum),
+        Err(AccountError::InvalidMnemonicChecksum)
+    ));
+
+    let invalid_length = vec!["abandon"; 12].join(" ");
+    assert!(matches!(
+        Mnemonic::from_str(&invalid_length),
+        Err(AccountError::InvalidMnemonicLength(12))
+    ));
+
+    let invalid_word = format!("{} aleo", vec!["abandon"; 23].join(" "));
+    assert!(matches!(
+        Mnemonic::from_str(&invalid_word),
+        Err(AccountError::InvalidMnemonicWord(_))
+    ));
+}
+
+#[test]
+pub fn test_private_key_from_mnemonic() {
+    let parameters = SystemParameters::<Components>::load().unwrap();
+    let mnemonic = Mnemonic::from_seed([0x7fu8; 32]);
+
+    let test_vectors = [
+        (0, "2fbe9707b6fde2a33ef23ca0af2df390c1b3f853faf2c2023c360ab68759bb03"),
+        (1, "df608ce915b15ed14e52cbc7c232c14be730aa2c0cf945801aa0031e1f5e0c84"),
+        (
+            1 << 31,
+            "8adf47f9bc439bcacebf4daf4951889b63e8e2ae8e89a1ee99a1778575b2ab32",
+        ),
+    ];
+
+    for (index, seed) in test_vectors.iter() {
+        let private_key = PrivateKey::<Components>::from_mnemonic(
+            &parameters.account_signature,
+            &parameters.account_commitment,
+            &mnemonic,
+            *index,
+        )
+        .unwrap();
+
+        assert_eq!(*seed, hex::encode(private_key.seed));
+        assert!(private_key.is_valid(&parameters.account_signature, &parameters.account_commitment));
+    }
+}
+
+#[test]
+pub fn test_account_from_mnemonic() {
+    let parameters = SystemParameters::<Components>::load().unwrap();
+    let phrase = Mnemonic::new(&mut thread_rng()).to_string();
+
+    let from_mnemonic = |index| {
+        Account::<Components>::from_mnemonic(
+            &parameters.account_signature,
+            &parameters.account_commitment,
+            &parameters.account_encryption,
+            &phrase,
+            index,
+        )
+        .unwrap()
+    };
+
+    // The same index always derives the same account, and distinct indices derive distinct accounts.
+    assert_eq!(from_mnemonic(0).private_key, from_mnemonic(0).private_key);
+    assert_eq!(from_mnemonic(0).address, from_mnemonic(0).address);
+    assert_ne!(from_mnemonic(0).private_key, from_mnemonic(1).private_key);
+    assert_ne!(from_mnemonic(0).address, from_mnemonic(1).address);
+}
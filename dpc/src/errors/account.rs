@@ -39,6 +39,15 @@ pub enum AccountError {
     #[error("invalid character length: {}", _0)]
     InvalidCharacterLength(usize),
 
+    #[error("invalid mnemonic checksum")]
+    InvalidMnemonicChecksum,
+
+    #[error("invalid mnemonic length: {}", _0)]
+    InvalidMnemonicLength(usize),
+
+    #[error("invalid mnemonic word: {}", _0)]
+    InvalidMnemonicWord(String),
+
     #[error("invalid prefix: {:?}", _0)]
     InvalidPrefix(String),
 
@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::prelude::*;
+use snarkvm_algorithms::traits::LoadableMerkleParameters;
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+use std::{collections::HashSet, sync::atomic::Ordering};
+
+impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P, S> {
+    /// Removes the latest block from the canon chain, reverting its serial numbers, commitments and memos.
+    /// The block itself remains in storage as a non-canon block. Returns the hash of the decommitted block.
+    pub fn decommit_latest_block(&self) -> Result<BlockHeaderHash, StorageError> {
+        let height = self.get_current_block_height();
+        if height == 0 {
+            return Err(StorageError::InvalidBlockDecommit);
+        }
+
+        let block_hash = self.get_block_hash(height)?;
+        let block = self.get_block(&block_hash)?;
+
+        let mut database_transaction = DatabaseTransaction::new();
+
+        let mut sn_index = self.current_sn_index()?;
+        let mut cm_index = self.current_cm_index()?;
+        let mut memo_index = self.current_memo_index()?;
+
+        let mut removed_cms = HashSet::new();
+        for transaction in block.transactions.0.iter() {
+            for sn in transaction.old_serial_numbers() {
+                database_transaction.push(Op::Delete {
+                    col: COL_SERIAL_NUMBER,
+                    key: to_bytes_le![sn]?.to_vec(),
+                });
+                sn_index -= 1;
+            }
+
+            for cm in transaction.new_commitments() {
+                let cm_bytes = to_bytes_le![cm]?.to_vec();
+                database_transaction.push(Op::Delete {
+                    col: COL_COMMITMENT,
+                    key: cm_bytes.clone(),
+                });
+                removed_cms.insert(cm_bytes);
+                cm_index -= 1;
+            }
+
+            database_transaction.push(Op::Delete {
+                col: COL_MEMO,
+                key: to_bytes_le![transaction.memorandum()]?.to_vec(),
+            });
+            memo_index -= 1;
+        }
+
+        // Update the database state for current indexes
+
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_SN_INDEX.as_bytes().to_vec(),
+            value: (sn_index as u32).to_le_bytes().to_vec(),
+        });
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_CM_INDEX.as_bytes().to_vec(),
+            value: (cm_index as u32).to_le_bytes().to_vec(),
+        });
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_MEMO_INDEX.as_bytes().to_vec(),
+            value: (memo_index as u32).to_le_bytes().to_vec(),
+        });
+
+        // Update the best block number and remove the block location
+
+        let new_best_block_number = height - 1;
+
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_BEST_BLOCK_NUMBER.as_bytes().to_vec(),
+            value: new_best_block_number.to_le_bytes().to_vec(),
+        });
+        database_transaction.push(Op::Delete {
+            col: COL_BLOCK_LOCATOR,
+            key: block_hash.0.to_vec(),
+        });
+        database_transaction.push(Op::Delete {
+            col: COL_BLOCK_LOCATOR,
+            key: height.to_le_bytes().to_vec(),
+        });
+
+        // Remove the digest of the decommitted block, and restore the previous one
+
+        for (digest, block_number) in self.storage.get_col(COL_DIGEST)? {
+            if bytes_to_u32(&block_number) == height {
+                database_transaction.push(Op::Delete {
+                    col: COL_DIGEST,
+                    key: digest.to_vec(),
+                });
+            }
+        }
+
+        let mut remaining_cms = self.stored_commitments()?;
+        remaining_cms.retain(|(cm, _)| {
+            to_bytes_le![cm]
+                .map(|cm_bytes| !removed_cms.contains(&cm_bytes))
+                .unwrap_or(true)
+        });
+        let new_cm_merkle_tree = self.build_merkle_tree(remaining_cms)?;
+        let new_digest = new_cm_merkle_tree.root();
+
+        database_transaction.push(Op::Insert {
+            col: COL_DIGEST,
+            key: to_bytes_le![new_digest]?.to_vec(),
+            value: new_best_block_number.to_le_bytes().to_vec(),
+        });
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_DIGEST.as_bytes().to_vec(),
+            value: to_bytes_le![new_digest]?.to_vec(),
+        });
+
+        self.storage.batch(database_transaction)?;
+
+        *self.cm_merkle_tree.write() = new_cm_merkle_tree;
+        self.current_block_height.fetch_sub(1, Ordering::SeqCst);
+
+        Ok(block_hash)
+    }
+
+    /// Removes the latest `num_blocks` blocks from the canon chain.
+    /// Returns the hashes of the decommitted blocks, starting with the latest.
+    pub fn revert_blocks(&self, num_blocks: u32) -> Result<Vec<BlockHeaderHash>, StorageError> {
+        let height = self.get_current_block_height();
+        if num_blocks > height {
+            return Err(StorageError::InvalidBlockRemovalNum(num_blocks, height));
+        }
+
+        let mut decommitted = Vec::with_capacity(num_blocks as usize);
+        for _ in 0..num_blocks {
+            decommitted.push(self.decommit_latest_block()?);
+        }
+
+        Ok(decommitted)
+    }
+
+    /// Replaces the canon chain following the canon block `fork_point` with the given blocks,
+    /// which must each extend the one before, starting from `fork_point`.
+    ///
+    /// If any of the new blocks fails to commit, the previous canon chain is restored and the error is returned.
+    pub fn reorganize(&self, fork_point: &BlockHeaderHash, blocks: &[Block<T>]) -> Result<(), StorageError> {
+        if !self.is_canon(fork_point) {
+            return Err(StorageError::MissingBlockNumber(fork_point.to_string()));
+        }
+        let fork_point_number = self.get_block_number(fork_point)?;
+
+        let mut previous_block_hash = fork_point.clone();
+        for block in blocks {
+            if block.header.previous_block_hash != previous_block_hash {
+                return Err(StorageError::Message(format!(
+                    "block {} does not extend block {}",
+                    block.header.get_hash(),
+                    previous_block_hash
+                )));
+            }
+            previous_block_hash = block.header.get_hash();
+        }
+
+        let decommitted = self.revert_blocks(self.get_current_block_height() - fork_point_number)?;
+
+        for (num_committed, block) in blocks.iter().enumerate() {
+            if let Err(error) = self.insert_and_commit(block) {
+                // Restore the previous canon chain; its blocks are still in storage.
+                self.revert_blocks(num_committed as u32)?;
+                for block_hash in decommitted.iter().rev() {
+                    self.commit(&self.get_block(block_hash)?)?;
+                }
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::prelude::*;
+use snarkvm_algorithms::{merkle_tree::IncrementalMerkleTree, traits::LoadableMerkleParameters};
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P, S> {
+    /// Builds an incremental commitment Merkle tree from the stored commitments, tracking the given commitments.
+    /// The root of the resulting tree is the current ledger digest.
+    pub fn build_incremental_merkle_tree(
+        &self,
+        tracked_cms: &[T::Commitment],
+    ) -> Result<IncrementalMerkleTree<P>, StorageError> {
+        let mut cm_and_indices = self.stored_commitments()?;
+        cm_and_indices.sort_by(|&(_, i), &(_, j)| i.cmp(&j));
+
+        let mut cm_merkle_tree = IncrementalMerkleTree::new(self.ledger_parameters.clone())?;
+        for (cm, _) in cm_and_indices {
+            if tracked_cms.contains(&cm) {
+                cm_merkle_tree.append_tracked(&cm)?;
+            } else {
+                cm_merkle_tree.append(&cm)?;
+            }
+        }
+        cm_merkle_tree.checkpoint()?;
+
+        Ok(cm_merkle_tree)
+    }
+
+    /// Stores the given incremental Merkle tree under the given key.
+    pub fn store_incremental_merkle_tree(
+        &self,
+        key: &str,
+        merkle_tree: &IncrementalMerkleTree<P>,
+    ) -> Result<(), StorageError> {
+        let mut database_transaction = DatabaseTransaction::new();
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: key.as_bytes().to_vec(),
+            value: to_bytes_le![merkle_tree]?,
+        });
+
+        self.storage.batch(database_transaction)
+    }
+
+    /// Returns the incremental Merkle tree stored under the given key, if any.
+    pub fn load_incremental_merkle_tree(&self, key: &str) -> Result<Option<IncrementalMerkleTree<P>>, StorageError> {
+        match self.storage.get(COL_META, key.as_bytes())? {
+            Some(bytes) => Ok(Some(IncrementalMerkleTree::read_le(
+                self.ledger_parameters.clone(),
+                &bytes[..],
+            )?)),
+            None => Ok(None),
+        }
+    }
+}
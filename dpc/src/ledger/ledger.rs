@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::*;
+use super::*;
+use crate::prelude::*;
 use snarkvm_algorithms::{merkle_tree::*, traits::LoadableMerkleParameters};
-use snarkvm_dpc::prelude::*;
 use snarkvm_utilities::{has_duplicates, to_bytes_le, FromBytes, ToBytes};
 
 use parking_lot::RwLock;
@@ -207,6 +207,53 @@ impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P,
         }
     }
 
+    /// Opens a ledger that was previously created in the storage at the given path.
+    pub fn load(path: Option<&Path>, parameters: Arc<P>) -> anyhow::Result<Self> {
+        let storage = S::open(path, None)?;
+
+        let block_height = match storage.get(COL_META, KEY_BEST_BLOCK_NUMBER.as_bytes())? {
+            Some(block_num) => bytes_to_u32(&block_num),
+            None => return Err(LedgerError::Message("missing ledger in storage".into()).into()),
+        };
+
+        let leaves: &[[u8; 32]] = &[];
+        let empty_cm_merkle_tree = MerkleTree::<P>::new(parameters.clone(), leaves)?;
+
+        let ledger = Self {
+            current_block_height: AtomicU32::new(block_height),
+            storage,
+            cm_merkle_tree: RwLock::new(empty_cm_merkle_tree),
+            ledger_parameters: parameters,
+            _transaction: PhantomData,
+        };
+
+        let commitments = ledger.stored_commitments()?;
+        *ledger.cm_merkle_tree.write() = ledger.build_merkle_tree(commitments)?;
+
+        Ok(ledger)
+    }
+
+    /// Get every stored commitment along with its index
+    pub fn stored_commitments(&self) -> Result<Vec<(T::Commitment, usize)>, StorageError> {
+        let mut commitments = Vec::new();
+        for (cm_bytes, cm_index_bytes) in self.storage.get_col(COL_COMMITMENT)? {
+            let cm = T::Commitment::read_le(&cm_bytes[..])?;
+            commitments.push((cm, bytes_to_u32(&cm_index_bytes[0..4]) as usize));
+        }
+
+        Ok(commitments)
+    }
+
+    /// Build a commitment merkle tree from scratch with the given commitments
+    pub fn build_merkle_tree(&self, commitments: Vec<(T::Commitment, usize)>) -> Result<MerkleTree<P>, StorageError> {
+        let mut cm_and_indices = commitments;
+        cm_and_indices.sort_by(|&(_, i), &(_, j)| i.cmp(&j));
+
+        let leaves: Vec<_> = cm_and_indices.into_iter().map(|(cm, _)| cm).collect();
+
+        Ok(MerkleTree::new(self.ledger_parameters.clone(), &leaves)?)
+    }
+
     /// Build a new commitment merkle tree from the stored commitments
     pub fn rebuild_merkle_tree(&self, additional_cms: Vec<(T::Commitment, usize)>) -> Result<(), StorageError> {
         let mut new_cm_and_indices = additional_cms;
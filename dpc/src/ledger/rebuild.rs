@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::prelude::*;
+use snarkvm_algorithms::{merkle_tree::MerkleTree, traits::LoadableMerkleParameters};
+use snarkvm_utilities::{to_bytes_le, FromBytes, ToBytes};
+
+use std::collections::HashSet;
+
+impl<T: TransactionScheme, P: LoadableMerkleParameters, S: Storage> Ledger<T, P, S> {
+    /// Rebuilds the block locator, transaction location, serial number, commitment, memo and digest indexes
+    /// from the raw blocks in storage, following the canon chain back from its latest block.
+    pub fn rebuild_indexes(&self) -> Result<(), StorageError> {
+        let height = self.get_current_block_height();
+
+        // Walk back from the latest block to recover the canon chain.
+        let mut canon_blocks = Vec::with_capacity(height as usize + 1);
+        let mut block_hash = self.get_block_hash(height)?;
+        loop {
+            let block = self.get_block(&block_hash)?;
+            let previous_block_hash = block.header.previous_block_hash.clone();
+            canon_blocks.push((block_hash, block));
+
+            if previous_block_hash == BlockHeaderHash([0u8; 32]) {
+                break;
+            }
+            if canon_blocks.len() > height as usize {
+                return Err(StorageError::Message(format!(
+                    "the canon chain is longer than the block height {}",
+                    height
+                )));
+            }
+            block_hash = previous_block_hash;
+        }
+        if canon_blocks.len() != height as usize + 1 {
+            return Err(StorageError::Message(format!(
+                "the canon chain is shorter than the block height {}",
+                height
+            )));
+        }
+        canon_blocks.reverse();
+
+        let mut database_transaction = DatabaseTransaction::new();
+
+        // Clear the existing indexes.
+
+        for col in &[
+            COL_BLOCK_LOCATOR,
+            COL_TRANSACTION_LOCATION,
+            COL_SERIAL_NUMBER,
+            COL_COMMITMENT,
+            COL_MEMO,
+            COL_DIGEST,
+        ] {
+            for key in self.storage.get_keys(*col)? {
+                database_transaction.push(Op::Delete {
+                    col: *col,
+                    key: key.to_vec(),
+                });
+            }
+        }
+
+        // Every stored block keeps its transaction locations, whether it is canon or not.
+
+        for (block_hash, block_transactions) in self.storage.get_col(COL_BLOCK_TRANSACTIONS)? {
+            let block_transactions = Transactions::<T>::read_le(&block_transactions[..])?;
+            for (index, transaction) in block_transactions.0.iter().enumerate() {
+                let transaction_location = TransactionLocation {
+                    index: index as u32,
+                    block_hash: BlockHeaderHash::new(block_hash.to_vec()).0,
+                };
+                database_transaction.push(Op::Insert {
+                    col: COL_TRANSACTION_LOCATION,
+                    key: transaction.transaction_id()?.to_vec(),
+                    value: to_bytes_le![transaction_location]?.to_vec(),
+                });
+            }
+        }
+
+        // Replay the canon chain.
+
+        let leaves: &[[u8; 32]] = &[];
+        let mut cm_merkle_tree = MerkleTree::<P>::new(self.ledger_parameters.clone(), leaves)?;
+
+        let mut serial_numbers = HashSet::new();
+        let mut commitments = HashSet::new();
+        let mut memos = HashSet::new();
+
+        for (block_number, (block_hash, block)) in canon_blocks.iter().enumerate() {
+            let block_number = block_number as u32;
+
+            database_transaction.push(Op::Insert {
+                col: COL_BLOCK_LOCATOR,
+                key: block_hash.0.to_vec(),
+                value: block_number.to_le_bytes().to_vec(),
+            });
+            database_transaction.push(Op::Insert {
+                col: COL_BLOCK_LOCATOR,
+                key: block_number.to_le_bytes().to_vec(),
+                value: block_hash.0.to_vec(),
+            });
+
+            let mut block_cms = vec![];
+            for transaction in block.transactions.0.iter() {
+                for sn in transaction.old_serial_numbers() {
+                    let sn_bytes = to_bytes_le![sn]?;
+                    if !serial_numbers.insert(sn_bytes.clone()) {
+                        return Err(StorageError::ExistingSn(sn_bytes));
+                    }
+                    database_transaction.push(Op::Insert {
+                        col: COL_SERIAL_NUMBER,
+                        key: sn_bytes,
+                        value: (serial_numbers.len() as u32 - 1).to_le_bytes().to_vec(),
+                    });
+                }
+
+                for cm in transaction.new_commitments() {
+                    let cm_bytes = to_bytes_le![cm]?;
+                    if !commitments.insert(cm_bytes.clone()) {
+                        return Err(StorageError::ExistingCm(cm_bytes));
+                    }
+                    database_transaction.push(Op::Insert {
+                        col: COL_COMMITMENT,
+                        key: cm_bytes,
+                        value: (commitments.len() as u32 - 1).to_le_bytes().to_vec(),
+                    });
+                    block_cms.push(cm.clone());
+                }
+
+                let memo_bytes = to_bytes_le![transaction.memorandum()]?;
+                if !memos.insert(memo_bytes.clone()) {
+                    return Err(StorageError::ExistingMemo(memo_bytes));
+                }
+                database_transaction.push(Op::Insert {
+                    col: COL_MEMO,
+                    key: memo_bytes,
+                    value: (memos.len() as u32 - 1).to_le_bytes().to_vec(),
+                });
+            }
+
+            cm_merkle_tree = cm_merkle_tree.rebuild(commitments.len() - block_cms.len(), &block_cms)?;
+            database_transaction.push(Op::Insert {
+                col: COL_DIGEST,
+                key: to_bytes_le![cm_merkle_tree.root()]?.to_vec(),
+                value: block_number.to_le_bytes().to_vec(),
+            });
+        }
+
+        // Update the database state for current indexes
+
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_SN_INDEX.as_bytes().to_vec(),
+            value: (serial_numbers.len() as u32).to_le_bytes().to_vec(),
+        });
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_CM_INDEX.as_bytes().to_vec(),
+            value: (commitments.len() as u32).to_le_bytes().to_vec(),
+        });
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_MEMO_INDEX.as_bytes().to_vec(),
+            value: (memos.len() as u32).to_le_bytes().to_vec(),
+        });
+        database_transaction.push(Op::Insert {
+            col: COL_META,
+            key: KEY_CURR_DIGEST.as_bytes().to_vec(),
+            value: to_bytes_le![cm_merkle_tree.root()]?.to_vec(),
+        });
+
+        self.storage.batch(database_transaction)?;
+
+        *self.cm_merkle_tree.write() = cm_merkle_tree;
+
+        Ok(())
+    }
+}
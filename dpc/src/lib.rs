@@ -74,6 +74,12 @@ pub mod testnet2;
 pub mod errors;
 pub use errors::*;
 
+pub mod ledger;
+pub use ledger::*;
+
+pub mod storage;
+pub use storage::*;
+
 pub mod traits;
 pub use traits::*;
 
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Checks that a `Storage` implementation behaves as the ledger expects.
+//!
+//! Each check panics on the first violation, so they can be called directly from the tests of any `Storage` implementation.
+
+use crate::{DatabaseTransaction, Op, Storage};
+
+use std::path::Path;
+
+/// Returns the sorted keys of the given column.
+fn sorted_keys<S: Storage>(storage: &S, col: u32) -> Vec<Vec<u8>> {
+    let mut keys: Vec<Vec<u8>> = storage.get_keys(col).unwrap().into_iter().map(Vec::from).collect();
+    keys.sort();
+    keys
+}
+
+/// Checks reads, writes, and batches on an empty storage instance, using columns `0` and `1`.
+pub fn test_storage<S: Storage>(storage: &S) {
+    // Reads from an empty storage.
+    assert_eq!(storage.get(0, b"a").unwrap(), None);
+    assert!(!storage.exists(0, b"a"));
+    assert!(storage.get_col(0).unwrap().is_empty());
+    assert!(storage.get_keys(0).unwrap().is_empty());
+
+    // Single writes, which are visible in their column only.
+    storage.put(0, b"a", b"1").unwrap();
+    storage.put(0, b"b", b"2").unwrap();
+    storage.put(1, b"a", b"3").unwrap();
+    assert_eq!(storage.get(0, b"a").unwrap(), Some(b"1".to_vec()));
+    assert_eq!(storage.get(1, b"a").unwrap(), Some(b"3".to_vec()));
+    assert!(storage.exists(0, b"b"));
+    assert!(!storage.exists(1, b"b"));
+    assert_eq!(sorted_keys(storage, 0), vec![b"a".to_vec(), b"b".to_vec()]);
+
+    // Overwrites replace the previous value.
+    storage.put(0, b"a", b"4").unwrap();
+    assert_eq!(storage.get(0, b"a").unwrap(), Some(b"4".to_vec()));
+
+    let mut col = storage.get_col(0).unwrap();
+    col.sort();
+    assert_eq!(col, vec![
+        (b"a".to_vec().into_boxed_slice(), b"4".to_vec().into_boxed_slice()),
+        (b"b".to_vec().into_boxed_slice(), b"2".to_vec().into_boxed_slice()),
+    ]);
+
+    // An empty batch is a no-op.
+    storage.batch(DatabaseTransaction::new()).unwrap();
+    assert_eq!(sorted_keys(storage, 0).len(), 2);
+
+    // Batch operations are applied in order.
+    let mut batch = DatabaseTransaction::new();
+    batch.push_vec(vec![
+        Op::Insert {
+            col: 0,
+            key: b"c".to_vec(),
+            value: b"5".to_vec(),
+        },
+        Op::Delete {
+            col: 0,
+            key: b"b".to_vec(),
+        },
+        Op::Insert {
+            col: 1,
+            key: b"b".to_vec(),
+            value: b"6".to_vec(),
+        },
+        Op::Delete {
+            col: 1,
+            key: b"b".to_vec(),
+        },
+        Op::Insert {
+            col: 1,
+            key: b"c".to_vec(),
+            value: b"7".to_vec(),
+        },
+        Op::Delete {
+            col: 1,
+            key: b"missing".to_vec(),
+        },
+    ]);
+    storage.batch(batch).unwrap();
+    assert_eq!(sorted_keys(storage, 0), vec![b"a".to_vec(), b"c".to_vec()]);
+    assert_eq!(sorted_keys(storage, 1), vec![b"a".to_vec(), b"c".to_vec()]);
+    assert_eq!(storage.get(0, b"b").unwrap(), None);
+    assert_eq!(storage.get(1, b"c").unwrap(), Some(b"7".to_vec()));
+}
+
+/// Checks that writes survive reopening a storage created at the given path.
+/// Always passes for in-memory storage.
+pub fn test_persistence<S: Storage>(path: &Path) {
+    if S::IN_MEMORY {
+        return;
+    }
+
+    {
+        let storage = S::open(Some(path), None).unwrap();
+        storage.put(0, b"a", b"1").unwrap();
+        storage.put(0, b"b", b"2").unwrap();
+        let mut batch = DatabaseTransaction::new();
+        batch.push(Op::Delete {
+            col: 0,
+            key: b"b".to_vec(),
+        });
+        batch.push(Op::Insert {
+            col: 1,
+            key: b"c".to_vec(),
+            value: b"3".to_vec(),
+        });
+        storage.batch(batch).unwrap();
+    }
+
+    let storage = S::open(Some(path), None).unwrap();
+    assert_eq!(storage.get(0, b"a").unwrap(), Some(b"1".to_vec()));
+    assert!(!storage.exists(0, b"b"));
+    assert_eq!(storage.get(1, b"c").unwrap(), Some(b"3".to_vec()));
+}
+
+/// Checks that a read-only secondary instance opened with `secondary_path` only observes the writes
+/// of the primary instance at `path` once it catches up. Always passes for in-memory storage.
+pub fn test_secondary<S: Storage>(path: &Path, secondary_path: &Path) {
+    if S::IN_MEMORY {
+        return;
+    }
+
+    let primary = S::open(Some(path), None).unwrap();
+    primary.put(0, b"a", b"1").unwrap();
+
+    let secondary = S::open(Some(path), Some(secondary_path)).unwrap();
+    assert_eq!(secondary.get(0, b"a").unwrap(), Some(b"1".to_vec()));
+
+    // The secondary is read-only.
+    assert!(secondary.put(0, b"b", b"2").is_err());
+    assert!(!primary.exists(0, b"b"));
+
+    let mut batch = DatabaseTransaction::new();
+    batch.push(Op::Delete {
+        col: 0,
+        key: b"a".to_vec(),
+    });
+    batch.push(Op::Insert {
+        col: 0,
+        key: b"c".to_vec(),
+        value: b"3".to_vec(),
+    });
+    primary.batch(batch).unwrap();
+
+    // The secondary only observes new writes once it catches up.
+    assert!(secondary.exists(0, b"a"));
+    assert!(!secondary.exists(0, b"c"));
+    secondary.try_catch_up_with_primary().unwrap();
+    assert!(!secondary.exists(0, b"a"));
+    assert_eq!(secondary.get(0, b"c").unwrap(), Some(b"3".to_vec()));
+
+    // Catching up without new writes is a no-op.
+    secondary.try_catch_up_with_primary().unwrap();
+    assert_eq!(sorted_keys(&secondary, 0), vec![b"c".to_vec()]);
+}
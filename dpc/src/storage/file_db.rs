@@ -0,0 +1,775 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::StorageError, DatabaseTransaction, Op, Storage};
+
+use blake2::{digest::Digest, Blake2s};
+use parking_lot::{Mutex, RwLock};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    convert::TryInto,
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+/// The name of the log file inside the storage directory.
+const LOG_FILE_NAME: &str = "batches.log";
+
+/// The name of the snapshot file inside the storage directory.
+const SNAPSHOT_FILE_NAME: &str = "snapshot";
+
+/// The name of the lock file held by the primary instance inside the storage directory.
+const LOCK_FILE_NAME: &str = "LOCK";
+
+/// The size of the generation that the log and snapshot files start with.
+const GENERATION_SIZE: usize = 8;
+
+/// The size of a record header: the payload length followed by its checksum.
+const RECORD_HEADER_SIZE: usize = 4 + CHECKSUM_SIZE;
+
+/// The number of bytes of the payload digest stored in a record header.
+const CHECKSUM_SIZE: usize = 8;
+
+/// The log is compacted once it is larger than both this size and the latest snapshot.
+const MIN_COMPACTION_SIZE: u64 = 64 * 1024 * 1024;
+
+/// The maximum number of entries in each record of a snapshot.
+const SNAPSHOT_RECORD_ENTRIES: usize = 4096;
+
+type Column = BTreeMap<Box<[u8]>, Box<[u8]>>;
+
+/// The log file of a `FileDb`, along with the offset up to which it has been applied.
+struct Log {
+    file: File,
+    offset: u64,
+    /// The generation of the snapshot that the log was started from.
+    generation: u64,
+    /// The size of the snapshot that the log was started from.
+    snapshot_len: u64,
+}
+
+/// The lock file of a primary `FileDb`, which is removed when it is dropped.
+struct LockFile(PathBuf);
+
+impl LockFile {
+    /// Creates the lock file at the given path, failing if it already exists.
+    fn acquire(path: &Path) -> Result<Self, StorageError> {
+        match OpenOptions::new().write(true).create_new(true).open(path) {
+            Ok(_) => Ok(Self(path.to_path_buf())),
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => Err(StorageError::Message(format!(
+                "storage is already opened by another primary instance; remove {} if none is running",
+                path.display()
+            ))),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+impl Drop for LockFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+///
+/// A persistent storage backed by a snapshot and a log of the batches written since, in a single directory.
+///
+/// Every `put` and `batch` is appended to the log as one checksummed record before being applied,
+/// so a record is either fully persisted or, if interrupted by a crash, discarded when the storage
+/// is next opened. Each `batch` is synced to disk, along with any `put` logged before it.
+/// The contents of all columns are kept in memory.
+///
+/// Once the log outgrows the latest snapshot, the contents of all columns are written to a new
+/// snapshot and the log is restarted, so that opening the storage replays a bounded log.
+///
+/// Only one primary instance may open a directory at a time, which is enforced by a lock file.
+/// Opening with a `secondary_path` creates a read-only instance that follows the log of the
+/// primary instance at `path`, picking up its writes on `try_catch_up_with_primary`.
+///
+pub struct FileDb {
+    cols: RwLock<HashMap<u32, Column>>,
+    log: Mutex<Log>,
+    path: PathBuf,
+    is_secondary: bool,
+    _lock: Option<LockFile>,
+}
+
+impl FileDb {
+    /// Returns the checksum of the given record payload.
+    fn checksum(payload: &[u8]) -> [u8; CHECKSUM_SIZE] {
+        let digest = Blake2s::digest(payload);
+        let mut checksum = [0u8; CHECKSUM_SIZE];
+        checksum.copy_from_slice(&digest[..CHECKSUM_SIZE]);
+        checksum
+    }
+
+    /// Encodes the given transaction as a log record.
+    fn encode(transaction: &DatabaseTransaction) -> Vec<u8> {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&(transaction.0.len() as u32).to_le_bytes());
+        for operation in &transaction.0 {
+            match operation {
+                Op::Insert { col, key, value } => {
+                    payload.push(0);
+                    payload.extend_from_slice(&col.to_le_bytes());
+                    payload.extend_from_slice(&(key.len() as u32).to_le_bytes());
+                    payload.extend_from_slice(key);
+                    payload.extend_from_slice(&(value.len() as u32).to_le_bytes());
+                    payload.extend_from_slice(value);
+                }
+                Op::Delete { col, key } => {
+                    payload.push(1);
+                    payload.extend_from_slice(&col.to_le_bytes());
+                    payload.extend_from_slice(&(key.len() as u32).to_le_bytes());
+                    payload.extend_from_slice(key);
+                }
+            }
+        }
+
+        let mut record = Vec::with_capacity(RECORD_HEADER_SIZE + payload.len());
+        record.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        record.extend_from_slice(&Self::checksum(&payload));
+        record.extend_from_slice(&payload);
+        record
+    }
+
+    /// Decodes the payload of a log record, returning `None` if it is malformed.
+    fn decode(mut payload: &[u8]) -> Option<DatabaseTransaction> {
+        fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+            if bytes.len() < len {
+                return None;
+            }
+            let (head, tail) = bytes.split_at(len);
+            *bytes = tail;
+            Some(head)
+        }
+
+        fn take_u32(bytes: &mut &[u8]) -> Option<u32> {
+            Some(u32::from_le_bytes(take(bytes, 4)?.try_into().ok()?))
+        }
+
+        let num_operations = take_u32(&mut payload)?;
+        let mut transaction = DatabaseTransaction::new();
+        for _ in 0..num_operations {
+            let tag = take(&mut payload, 1)?[0];
+            let col = take_u32(&mut payload)?;
+            let key_len = take_u32(&mut payload)? as usize;
+            let key = take(&mut payload, key_len)?.to_vec();
+            match tag {
+                0 => {
+                    let value_len = take_u32(&mut payload)? as usize;
+                    let value = take(&mut payload, value_len)?.to_vec();
+                    transaction.push(Op::Insert { col, key, value });
+                }
+                1 => transaction.push(Op::Delete { col, key }),
+                _ => return None,
+            }
+        }
+
+        if payload.is_empty() { Some(transaction) } else { None }
+    }
+
+    /// Decodes every complete and valid record at the start of the given bytes, returning them along with
+    /// the number of bytes they span. Decoding stops at the first truncated or corrupted record.
+    fn decode_records(bytes: &[u8]) -> (Vec<DatabaseTransaction>, usize) {
+        let mut transactions = Vec::new();
+        let mut remaining = bytes;
+        while remaining.len() >= RECORD_HEADER_SIZE {
+            let (header, rest) = remaining.split_at(RECORD_HEADER_SIZE);
+            let payload_len = u32::from_le_bytes(header[..4].try_into().unwrap()) as usize;
+            if rest.len() < payload_len {
+                break;
+            }
+
+            let payload = &rest[..payload_len];
+            if header[4..] != Self::checksum(payload) {
+                break;
+            }
+            match Self::decode(payload) {
+                Some(transaction) => transactions.push(transaction),
+                None => break,
+            }
+
+            remaining = &rest[payload_len..];
+        }
+
+        (transactions, bytes.len() - remaining.len())
+    }
+
+    /// Reads every complete and valid record following the log's current offset, and advances the offset past them.
+    /// Reading stops at the first truncated or corrupted record.
+    fn read_log(log: &mut Log) -> Result<Vec<DatabaseTransaction>, StorageError> {
+        let mut bytes = Vec::new();
+        log.file.seek(SeekFrom::Start(log.offset))?;
+        log.file.read_to_end(&mut bytes)?;
+
+        let (transactions, len) = Self::decode_records(&bytes);
+        log.offset += len as u64;
+
+        Ok(transactions)
+    }
+
+    /// Reads the generation that the given log file starts with.
+    fn read_generation(file: &mut File) -> Result<u64, StorageError> {
+        let mut generation = [0u8; GENERATION_SIZE];
+        file.seek(SeekFrom::Start(0))?;
+        file.read_exact(&mut generation)?;
+        Ok(u64::from_le_bytes(generation))
+    }
+
+    /// Reads the snapshot at the given path, returning its generation, its columns, and its size.
+    /// A missing snapshot is the empty snapshot of generation `0`.
+    fn read_snapshot(path: &Path) -> Result<(u64, HashMap<u32, Column>, u64), StorageError> {
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok((0, HashMap::new(), 0)),
+            Err(error) => return Err(error.into()),
+        };
+
+        // A snapshot is replaced atomically, so it must consist of valid records only.
+        let (transactions, len) = Self::decode_records(bytes.get(GENERATION_SIZE..).unwrap_or_default());
+        if bytes.len() < GENERATION_SIZE || GENERATION_SIZE + len != bytes.len() {
+            return Err(StorageError::Message(format!(
+                "corrupted snapshot at {}",
+                path.display()
+            )));
+        }
+
+        let generation = u64::from_le_bytes(bytes[..GENERATION_SIZE].try_into().unwrap());
+        let mut cols = HashMap::new();
+        for transaction in transactions {
+            Self::apply(&mut cols, transaction);
+        }
+
+        Ok((generation, cols, bytes.len() as u64))
+    }
+
+    /// Replaces the file at the given path with the given contents, such that a crash leaves either the old or the new file.
+    fn write_atomically(path: &Path, contents: &[u8]) -> Result<(), StorageError> {
+        let temp_path = path.with_extension("tmp");
+        let mut file = File::create(&temp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        fs::rename(&temp_path, path)?;
+
+        // Persist the rename itself.
+        #[cfg(unix)]
+        File::open(path.parent().unwrap_or_else(|| Path::new(".")))?.sync_all()?;
+
+        Ok(())
+    }
+
+    /// Loads the snapshot in the given directory and replays the log written since, returning the columns and the log.
+    fn load(path: &Path, is_secondary: bool) -> Result<(HashMap<u32, Column>, Log), StorageError> {
+        let log_path = path.join(LOG_FILE_NAME);
+        loop {
+            let (generation, mut cols, snapshot_len) = Self::read_snapshot(&path.join(SNAPSHOT_FILE_NAME))?;
+
+            let file = if is_secondary {
+                File::open(&log_path)?
+            } else {
+                if !log_path.exists() {
+                    Self::write_atomically(&log_path, &generation.to_le_bytes())?;
+                }
+                OpenOptions::new().read(true).append(true).open(&log_path)?
+            };
+
+            let mut log = Log {
+                file,
+                offset: GENERATION_SIZE as u64,
+                generation: 0,
+                snapshot_len,
+            };
+            log.generation = Self::read_generation(&mut log.file)?;
+
+            match log.generation.cmp(&generation) {
+                Ordering::Equal => {
+                    for transaction in Self::read_log(&mut log)? {
+                        Self::apply(&mut cols, transaction);
+                    }
+
+                    // Discard the remains of a write that was interrupted before it completed.
+                    if !is_secondary {
+                        Self::discard_torn_tail(&mut log)?;
+                    }
+
+                    return Ok((cols, log));
+                }
+                // A compaction wrote the snapshot but has not restarted the log yet,
+                // so every batch in the log is already part of the snapshot.
+                Ordering::Less => {
+                    if is_secondary {
+                        log.offset = log.file.metadata()?.len();
+                        return Ok((cols, log));
+                    }
+                    Self::write_atomically(&log_path, &generation.to_le_bytes())?;
+                }
+                // The primary completed a compaction after the snapshot was read.
+                Ordering::Greater if is_secondary => {}
+                Ordering::Greater => {
+                    return Err(StorageError::Message(format!(
+                        "the log at {} is newer than its snapshot",
+                        log_path.display()
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Writes the given columns to a new snapshot and restarts the log from it.
+    fn compact_log(&self, log: &mut Log, cols: &HashMap<u32, Column>) -> Result<(), StorageError> {
+        let generation = log.generation + 1;
+
+        let mut snapshot = generation.to_le_bytes().to_vec();
+        let mut transaction = DatabaseTransaction::new();
+        for (col, column) in cols {
+            for (key, value) in column {
+                transaction.push(Op::Insert {
+                    col: *col,
+                    key: key.to_vec(),
+                    value: value.to_vec(),
+                });
+                if transaction.0.len() == SNAPSHOT_RECORD_ENTRIES {
+                    snapshot.extend_from_slice(&Self::encode(&transaction));
+                    transaction = DatabaseTransaction::new();
+                }
+            }
+        }
+        snapshot.extend_from_slice(&Self::encode(&transaction));
+
+        // The snapshot is written first, so that a crash in between leaves a log that it already contains.
+        let log_path = self.path.join(LOG_FILE_NAME);
+        Self::write_atomically(&self.path.join(SNAPSHOT_FILE_NAME), &snapshot)?;
+        Self::write_atomically(&log_path, &generation.to_le_bytes())?;
+
+        *log = Log {
+            file: OpenOptions::new().read(true).append(true).open(&log_path)?,
+            offset: GENERATION_SIZE as u64,
+            generation,
+            snapshot_len: snapshot.len() as u64,
+        };
+
+        Ok(())
+    }
+
+    /// Discards everything in the log file past its offset, which is the remains of a write that did not complete.
+    fn discard_torn_tail(log: &mut Log) -> Result<(), StorageError> {
+        if log.file.metadata()?.len() > log.offset {
+            log.file.set_len(log.offset)?;
+            log.file.sync_all()?;
+        }
+        Ok(())
+    }
+
+    /// Applies the given transaction to the in-memory columns.
+    fn apply(cols: &mut HashMap<u32, Column>, transaction: DatabaseTransaction) {
+        for operation in transaction.0 {
+            match operation {
+                Op::Insert { col, key, value } => {
+                    cols.entry(col).or_default().insert(key.into(), value.into());
+                }
+                Op::Delete { col, key } => {
+                    if let Some(column) = cols.get_mut(&col) {
+                        column.remove(&key[..]);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Appends the given transaction to the log, syncing the log if `sync` is set, and applies it.
+    fn append(&self, transaction: DatabaseTransaction, sync: bool) -> Result<(), StorageError> {
+        if self.is_secondary {
+            return Err(StorageError::Message(
+                "can't write to a read-only secondary FileDb".into(),
+            ));
+        }
+        if transaction.0.is_empty() {
+            return Ok(());
+        }
+
+        let record = Self::encode(&transaction);
+
+        // The log stays locked until the batch is applied, so batches are applied in the order they were logged.
+        let mut log = self.log.lock();
+
+        // Compact the log once it outgrows the snapshot, so that the work of compacting is amortized over the batches.
+        if log.offset > MIN_COMPACTION_SIZE.max(log.snapshot_len) {
+            self.compact_log(&mut log, &self.cols.read())?;
+        }
+
+        // Every record is appended right after the last committed one, so that a torn record
+        // can never hide the batches logged after it when the log is next read.
+        Self::discard_torn_tail(&mut log)?;
+        let result = log
+            .file
+            .write_all(&record)
+            .and_then(|_| if sync { log.file.sync_data() } else { Ok(()) });
+        if let Err(error) = result {
+            // Roll back the failed batch, so that it can't be recovered when the log is next read.
+            Self::discard_torn_tail(&mut log)?;
+            return Err(error.into());
+        }
+        log.offset += record.len() as u64;
+
+        Self::apply(&mut self.cols.write(), transaction);
+
+        Ok(())
+    }
+
+    /// Writes the contents of all columns to a new snapshot and restarts the log from it.
+    pub fn compact(&self) -> Result<(), StorageError> {
+        if self.is_secondary {
+            return Err(StorageError::Message(
+                "can't compact a read-only secondary FileDb".into(),
+            ));
+        }
+
+        let mut log = self.log.lock();
+        self.compact_log(&mut log, &self.cols.read())
+    }
+
+    /// Returns `true` if this is a read-only secondary instance.
+    pub fn is_secondary(&self) -> bool {
+        self.is_secondary
+    }
+}
+
+impl Storage for FileDb {
+    const IN_MEMORY: bool = false;
+
+    fn open(path: Option<&Path>, secondary_path: Option<&Path>) -> Result<Self, StorageError> {
+        let path = path.ok_or_else(|| StorageError::Message("FileDb requires a storage path".into()))?;
+        let is_secondary = secondary_path.is_some();
+
+        let lock = if is_secondary {
+            if !path.join(LOG_FILE_NAME).exists() {
+                return Err(StorageError::Message(format!(
+                    "missing primary storage at {}",
+                    path.display()
+                )));
+            }
+            None
+        } else {
+            fs::create_dir_all(path)?;
+            Some(LockFile::acquire(&path.join(LOCK_FILE_NAME))?)
+        };
+
+        let (cols, log) = Self::load(path, is_secondary)?;
+
+        Ok(Self {
+            cols: RwLock::new(cols),
+            log: Mutex::new(log),
+            path: path.to_path_buf(),
+            is_secondary,
+            _lock: lock,
+        })
+    }
+
+    fn get(&self, col: u32, key: &[u8]) -> Result<Option<Vec<u8>>, StorageError> {
+        Ok(self
+            .cols
+            .read()
+            .get(&col)
+            .and_then(|column| column.get(key))
+            .map(|v| v.to_vec()))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn get_col(&self, col: u32) -> Result<Vec<(Box<[u8]>, Box<[u8]>)>, StorageError> {
+        Ok(self
+            .cols
+            .read()
+            .get(&col)
+            .map(|column| column.clone().into_iter().collect())
+            .unwrap_or_default())
+    }
+
+    fn get_keys(&self, col: u32) -> Result<Vec<Box<[u8]>>, StorageError> {
+        Ok(self
+            .cols
+            .read()
+            .get(&col)
+            .map(|column| column.keys().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    fn put<K: AsRef<[u8]>, V: AsRef<[u8]>>(&self, col: u32, key: K, value: V) -> Result<(), StorageError> {
+        let transaction = DatabaseTransaction(vec![Op::Insert {
+            col,
+            key: key.as_ref().to_vec(),
+            value: value.as_ref().to_vec(),
+        }]);
+
+        // A single write is synced along with the next batch, or when the storage is dropped.
+        self.append(transaction, false)
+    }
+
+    fn batch(&self, transaction: DatabaseTransaction) -> Result<(), StorageError> {
+        self.append(transaction, true)
+    }
+
+    fn exists(&self, col: u32, key: &[u8]) -> bool {
+        self.cols
+            .read()
+            .get(&col)
+            .map(|column| column.contains_key(key))
+            .unwrap_or(false)
+    }
+
+    fn try_catch_up_with_primary(&self) -> Result<(), StorageError> {
+        if !self.is_secondary {
+            return Err(StorageError::Message("FileDb is not a secondary instance".into()));
+        }
+
+        let mut log = self.log.lock();
+
+        // If the primary has restarted its log since, reload from its latest snapshot instead.
+        let generation = Self::read_generation(&mut File::open(self.path.join(LOG_FILE_NAME))?)?;
+        if generation != log.generation {
+            let (cols, new_log) = Self::load(&self.path, true)?;
+            *self.cols.write() = cols;
+            *log = new_log;
+            return Ok(());
+        }
+
+        let transactions = Self::read_log(&mut log)?;
+        if !transactions.is_empty() {
+            let mut cols = self.cols.write();
+            for transaction in transactions {
+                Self::apply(&mut cols, transaction);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for FileDb {
+    fn drop(&mut self) {
+        // Persist the single writes logged since the last batch.
+        if !self.is_secondary {
+            let _ = self.log.get_mut().file.sync_data();
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::conformance;
+
+    use rand::{thread_rng, Rng};
+    use std::path::PathBuf;
+
+    fn temp_path() -> PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("file_db-{}", thread_rng().gen::<u64>()));
+        path
+    }
+
+    #[test]
+    fn test_conformance() {
+        let path = temp_path();
+        let secondary_path = temp_path();
+
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        conformance::test_storage(&storage);
+        drop(storage);
+        fs::remove_dir_all(&path).unwrap();
+
+        conformance::test_persistence::<FileDb>(&path);
+        fs::remove_dir_all(&path).unwrap();
+
+        conformance::test_secondary::<FileDb>(&path, &secondary_path);
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_interrupted_batch_is_discarded() {
+        let path = temp_path();
+
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        storage.put(0, b"key", b"value").unwrap();
+        drop(storage);
+
+        // Simulate a crash partway through writing a batch.
+        let record = FileDb::encode(&DatabaseTransaction(vec![Op::Insert {
+            col: 0,
+            key: b"other".to_vec(),
+            value: b"value".to_vec(),
+        }]));
+        let mut file = OpenOptions::new().append(true).open(path.join(LOG_FILE_NAME)).unwrap();
+        file.write_all(&record[..record.len() - 1]).unwrap();
+        drop(file);
+
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        assert_eq!(storage.get(0, b"key").unwrap(), Some(b"value".to_vec()));
+        assert!(!storage.exists(0, b"other"));
+
+        // The storage remains writable after discarding the partial batch.
+        storage.put(0, b"another", b"value").unwrap();
+        drop(storage);
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        assert!(storage.exists(0, b"another"));
+        assert_eq!(storage.get_keys(0).unwrap().len(), 2);
+
+        drop(storage);
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_torn_batch_is_discarded_before_next_batch() {
+        let path = temp_path();
+
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        storage.put(0, b"key", b"value").unwrap();
+
+        // Simulate a batch whose write failed partway, leaving a torn record at the end of the log.
+        let record = FileDb::encode(&DatabaseTransaction(vec![Op::Insert {
+            col: 0,
+            key: b"torn".to_vec(),
+            value: b"value".to_vec(),
+        }]));
+        let mut file = OpenOptions::new().append(true).open(path.join(LOG_FILE_NAME)).unwrap();
+        file.write_all(&record[..record.len() - 1]).unwrap();
+        drop(file);
+
+        // The next batch is committed, and must survive a reopen.
+        storage.put(0, b"other", b"value").unwrap();
+        drop(storage);
+
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        assert_eq!(storage.get(0, b"key").unwrap(), Some(b"value".to_vec()));
+        assert_eq!(storage.get(0, b"other").unwrap(), Some(b"value".to_vec()));
+        assert!(!storage.exists(0, b"torn"));
+        assert_eq!(storage.get_keys(0).unwrap().len(), 2);
+
+        drop(storage);
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_primary_is_locked() {
+        let path = temp_path();
+        let secondary_path = temp_path();
+
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        assert!(FileDb::open(Some(&path), None).is_err());
+
+        // A secondary instance doesn't take the lock.
+        let secondary = FileDb::open(Some(&path), Some(&secondary_path)).unwrap();
+        assert!(secondary.is_secondary());
+
+        // The lock is released once the primary instance is dropped.
+        drop(storage);
+        let storage = FileDb::open(Some(&path), None).unwrap();
+
+        drop(storage);
+        drop(secondary);
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_compaction() {
+        let path = temp_path();
+        let secondary_path = temp_path();
+
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        storage.put(0, b"key", b"value").unwrap();
+        storage.put(0, b"deleted", b"value").unwrap();
+        storage.put(1, b"key", b"old").unwrap();
+        storage
+            .batch(DatabaseTransaction(vec![
+                Op::Delete {
+                    col: 0,
+                    key: b"deleted".to_vec(),
+                },
+                Op::Insert {
+                    col: 1,
+                    key: b"key".to_vec(),
+                    value: b"new".to_vec(),
+                },
+            ]))
+            .unwrap();
+        let secondary = FileDb::open(Some(&path), Some(&secondary_path)).unwrap();
+
+        // Compacting leaves an empty log behind.
+        storage.compact().unwrap();
+        assert_eq!(
+            fs::metadata(path.join(LOG_FILE_NAME)).unwrap().len(),
+            GENERATION_SIZE as u64
+        );
+        storage.put(0, b"other", b"value").unwrap();
+
+        // The secondary instance follows the primary across the compaction.
+        secondary.try_catch_up_with_primary().unwrap();
+        assert_eq!(secondary.get(0, b"other").unwrap(), Some(b"value".to_vec()));
+        assert_eq!(secondary.get(1, b"key").unwrap(), Some(b"new".to_vec()));
+        assert!(!secondary.exists(0, b"deleted"));
+
+        drop(storage);
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        assert_eq!(storage.get(0, b"key").unwrap(), Some(b"value".to_vec()));
+        assert_eq!(storage.get(0, b"other").unwrap(), Some(b"value".to_vec()));
+        assert_eq!(storage.get(1, b"key").unwrap(), Some(b"new".to_vec()));
+        assert!(!storage.exists(0, b"deleted"));
+
+        drop(storage);
+        drop(secondary);
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_interrupted_compaction_is_completed() {
+        let path = temp_path();
+
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        storage.put(0, b"key", b"value").unwrap();
+        storage
+            .batch(DatabaseTransaction(vec![Op::Delete {
+                col: 0,
+                key: b"key".to_vec(),
+            }]))
+            .unwrap();
+        storage.put(0, b"other", b"value").unwrap();
+
+        // Simulate a crash after the snapshot was written, but before the log was restarted.
+        let log = fs::read(path.join(LOG_FILE_NAME)).unwrap();
+        storage.compact().unwrap();
+        drop(storage);
+        fs::write(path.join(LOG_FILE_NAME), log).unwrap();
+
+        // Replaying the stale log is skipped, and the log is restarted from the snapshot.
+        let storage = FileDb::open(Some(&path), None).unwrap();
+        assert!(!storage.exists(0, b"key"));
+        assert_eq!(storage.get(0, b"other").unwrap(), Some(b"value".to_vec()));
+        assert_eq!(
+            fs::metadata(path.join(LOG_FILE_NAME)).unwrap().len(),
+            GENERATION_SIZE as u64
+        );
+
+        drop(storage);
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_missing_path() {
+        assert!(FileDb::open(None, None).is_err());
+        assert!(FileDb::open(Some(&temp_path()), Some(&temp_path())).is_err());
+    }
+}
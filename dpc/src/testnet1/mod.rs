@@ -36,7 +36,7 @@ use snarkvm_parameters::{prelude::*, testnet1::*};
 use snarkvm_utilities::{has_duplicates, rand::UniformRand, to_bytes_le, FromBytes, ToBytes};
 
 use itertools::Itertools;
-use rand::{CryptoRng, Rng};
+use rand::{thread_rng, CryptoRng, Rng};
 use std::{sync::Arc, time::Instant};
 
 pub mod inner_circuit;
@@ -644,68 +644,151 @@ where
     fn verify(&self, transaction: &Self::Transaction, ledger: &L) -> bool {
         let verify_time = start_timer!(|| "DPC::verify");
 
-        // Returns false if the number of serial numbers in the transaction is incorrect.
+        let outer_snark_input = match self.verify_without_outer_proof(transaction, ledger) {
+            Some(outer_snark_input) => outer_snark_input,
+            None => return false,
+        };
+
+        match C::OuterSNARK::verify(
+            &self.outer_snark_parameters.1,
+            &outer_snark_input,
+            &transaction.transaction_proof.1,
+        ) {
+            Ok(is_valid) => {
+                if !is_valid {
+                    eprintln!("Transaction proof failed to verify.");
+                    return false;
+                }
+            }
+            _ => {
+                eprintln!("Unable to verify transaction proof.");
+                return false;
+            }
+        }
+
+        end_timer!(verify_time);
+
+        true
+    }
+
+    /// Returns true iff all the transactions in the block are valid according to the ledger.
+    /// The outer proofs of the transactions are verified together as a single batch.
+    fn verify_transactions(&self, transactions: &[Self::Transaction], ledger: &L) -> bool {
+        let verify_time = start_timer!(|| "DPC::verify_transactions");
+
+        let mut outer_snark_inputs = Vec::with_capacity(transactions.len());
+        for transaction in transactions {
+            match self.verify_without_outer_proof(transaction, ledger) {
+                Some(outer_snark_input) => outer_snark_inputs.push(outer_snark_input),
+                None => return false,
+            }
+        }
+
+        let inputs_and_proofs = outer_snark_inputs
+            .iter()
+            .zip(transactions)
+            .map(|(outer_snark_input, transaction)| (outer_snark_input, &transaction.transaction_proof.1))
+            .collect::<Vec<_>>();
+
+        match C::OuterSNARK::batch_verify(&self.outer_snark_parameters.1, &inputs_and_proofs, &mut thread_rng()) {
+            Ok(is_valid) => {
+                if !is_valid {
+                    eprintln!("Transaction proofs failed to batch verify.");
+                    return false;
+                }
+            }
+            _ => {
+                eprintln!("Unable to batch verify transaction proofs.");
+                return false;
+            }
+        }
+
+        end_timer!(verify_time);
+
+        true
+    }
+}
+
+impl<C: Testnet1Components> DPC<C> {
+    /// Checks the transaction against the ledger, including its signatures and inner proof.
+    /// Returns the verifier input for the outer proof of the transaction, or `None` if any check fails.
+    fn verify_without_outer_proof<L>(
+        &self,
+        transaction: &Transaction<C>,
+        ledger: &L,
+    ) -> Option<OuterCircuitVerifierInput<C>>
+    where
+        L: LedgerScheme<
+            Commitment = <C::RecordCommitment as CommitmentScheme>::Output,
+            MerkleParameters = C::MerkleParameters,
+            MerklePath = MerklePath<C::MerkleParameters>,
+            MerkleTreeDigest = MerkleTreeDigest<C::MerkleParameters>,
+            SerialNumber = <C::AccountSignature as SignatureScheme>::PublicKey,
+            Transaction = Transaction<C>,
+        >,
+    {
+        // Returns `None` if the number of serial numbers in the transaction is incorrect.
         if transaction.old_serial_numbers().len() != C::NUM_INPUT_RECORDS {
             eprintln!("Transaction contains incorrect number of serial numbers");
-            return false;
+            return None;
         }
 
-        // Returns false if there are duplicate serial numbers in the transaction.
+        // Returns `None` if there are duplicate serial numbers in the transaction.
         if has_duplicates(transaction.old_serial_numbers().iter()) {
             eprintln!("Transaction contains duplicate serial numbers");
-            return false;
+            return None;
         }
 
-        // Returns false if the number of commitments in the transaction is incorrect.
+        // Returns `None` if the number of commitments in the transaction is incorrect.
         if transaction.new_commitments().len() != C::NUM_OUTPUT_RECORDS {
             eprintln!("Transaction contains incorrect number of commitments");
-            return false;
+            return None;
         }
 
-        // Returns false if there are duplicate commitments numbers in the transaction.
+        // Returns `None` if there are duplicate commitments numbers in the transaction.
         if has_duplicates(transaction.new_commitments().iter()) {
             eprintln!("Transaction contains duplicate commitments");
-            return false;
+            return None;
         }
 
         let ledger_time = start_timer!(|| "Ledger checks");
 
-        // Returns false if the transaction memo previously existed in the ledger.
+        // Returns `None` if the transaction memo previously existed in the ledger.
         if ledger.contains_memo(transaction.memorandum()) {
             eprintln!("Ledger already contains this transaction memo.");
-            return false;
+            return None;
         }
 
-        // Returns false if any transaction serial number previously existed in the ledger.
+        // Returns `None` if any transaction serial number previously existed in the ledger.
         for sn in transaction.old_serial_numbers() {
             if ledger.contains_sn(sn) {
                 eprintln!("Ledger already contains this transaction serial number.");
-                return false;
+                return None;
             }
         }
 
-        // Returns false if any transaction commitment previously existed in the ledger.
+        // Returns `None` if any transaction commitment previously existed in the ledger.
         for cm in transaction.new_commitments() {
             if ledger.contains_cm(cm) {
                 eprintln!("Ledger already contains this transaction commitment.");
-                return false;
+                return None;
             }
         }
 
-        // Returns false if the ledger digest in the transaction is invalid.
+        // Returns `None` if the ledger digest in the transaction is invalid.
         if !ledger.validate_digest(&transaction.ledger_digest) {
             eprintln!("Ledger digest is invalid.");
-            return false;
+            return None;
         }
 
         end_timer!(ledger_time);
 
         let signature_time = start_timer!(|| "Signature checks");
 
-        // Returns false if the number of signatures in the transaction is incorrect.
+        // Returns `None` if the number of signatures in the transaction is incorrect.
         if transaction.signatures().len() != C::NUM_OUTPUT_RECORDS {
             eprintln!("Transaction contains incorrect number of commitments");
-            return false;
+            return None;
         }
 
         let signature_message = match to_bytes_le![
@@ -721,7 +804,7 @@ where
             Ok(message) => message,
             _ => {
                 eprintln!("Unable to construct signature message.");
-                return false;
+                return None;
             }
         };
 
@@ -731,12 +814,12 @@ where
                 Ok(is_valid) => {
                     if !is_valid {
                         eprintln!("Signature failed to verify.");
-                        return false;
+                        return None;
                     }
                 }
                 _ => {
                     eprintln!("Unable to verify signature.");
-                    return false;
+                    return None;
                 }
             }
         }
@@ -745,10 +828,10 @@ where
 
         // Construct the ciphertext hashes
 
-        // Returns false if the number of encrypted records in the transaction is incorrect.
+        // Returns `None` if the number of encrypted records in the transaction is incorrect.
         if transaction.encrypted_records().len() != C::NUM_OUTPUT_RECORDS {
             eprintln!("Transaction contains incorrect number of encrypted records");
-            return false;
+            return None;
         }
 
         let mut new_encrypted_record_hashes = Vec::with_capacity(C::NUM_OUTPUT_RECORDS);
@@ -757,7 +840,7 @@ where
                 Ok(hash) => new_encrypted_record_hashes.push(hash),
                 _ => {
                     eprintln!("Unable to hash encrypted record.");
-                    return false;
+                    return None;
                 }
             }
         }
@@ -783,7 +866,7 @@ where
         //     Ok(bytes) => bytes,
         //     _ => {
         //         eprintln!("Unable to convert inner snark vk into bytes.");
-        //         return false;
+        //         return None;
         //     }
         // };
         let now = Instant::now();
@@ -795,12 +878,12 @@ where
             Ok(is_valid) => {
                 if !is_valid {
                     eprintln!("Inner UTXO proof failed to verify.");
-                    return false;
+                    return None;
                 }
             }
             _ => {
                 eprintln!("Unable to verify inner UTXO proof.");
-                return false;
+                return None;
             }
         }
         println!("⏱️ Inner proof verification takes: {} ms", now.elapsed().as_millis());
@@ -821,41 +904,11 @@ where
         //         Ok(hash) => hash,
         //         _ => {
         //             eprintln!("Unable to hash inner snark vk.");
-        //             return false;
+        //             return None;
         //         }
         //     },
         // };
 
-        match C::OuterSNARK::verify(
-            &self.outer_snark_parameters.1,
-            &outer_snark_input,
-            &transaction.transaction_proof.1,
-        ) {
-            Ok(is_valid) => {
-                if !is_valid {
-                    eprintln!("Transaction proof failed to verify.");
-                    return false;
-                }
-            }
-            _ => {
-                eprintln!("Unable to verify transaction proof.");
-                return false;
-            }
-        }
-
-        end_timer!(verify_time);
-
-        true
-    }
-
-    /// Returns true iff all the transactions in the block are valid according to the ledger.
-    fn verify_transactions(&self, transactions: &[Self::Transaction], ledger: &L) -> bool {
-        for transaction in transactions {
-            if !self.verify(transaction, ledger) {
-                return false;
-            }
-        }
-
-        true
+        Some(outer_snark_input)
     }
 }
@@ -1264,21 +1264,26 @@ where
         let memo = UInt8::alloc_input_vec_le(cs.ns(|| "Allocate memorandum"), memo)?;
         let network_id = UInt8::alloc_input_vec_le(cs.ns(|| "Allocate network id"), &[network_id])?;
 
+        // The local data tree has two input and two output leaves, so a single record fills both of its leaves.
+        // Only the 2x2 components are deployable, as other arities are benchmark-only.
         let mut old_record_commitment_bytes = vec![];
         let mut input_bytes = vec![];
         for i in 0..2 {
             let mut cs = cs.ns(|| format!("Construct local data with input record {}", i));
+            let record_index = i.min(C::NUM_INPUT_RECORDS - 1);
 
-            input_bytes.extend_from_slice(&old_serial_numbers_gadgets[i].to_bytes(&mut cs.ns(|| "old_serial_number"))?);
             input_bytes.extend_from_slice(
-                &old_record_commitments_gadgets[i].to_bytes(&mut cs.ns(|| "old_record_commitment"))?,
+                &old_serial_numbers_gadgets[record_index].to_bytes(&mut cs.ns(|| "old_serial_number"))?,
+            );
+            input_bytes.extend_from_slice(
+                &old_record_commitments_gadgets[record_index].to_bytes(&mut cs.ns(|| "old_record_commitment"))?,
             );
             input_bytes.extend_from_slice(&memo);
             input_bytes.extend_from_slice(&network_id);
 
             let commitment_randomness = LocalDataCommitmentGadget::RandomnessGadget::alloc(
                 cs.ns(|| format!("Allocate old record local data commitment randomness {}", i)),
-                || Ok(&local_data_commitment_randomizers[i]),
+                || Ok(&local_data_commitment_randomizers[record_index]),
             )?;
 
             let commitment = LocalDataCommitmentGadget::check_commitment_gadget(
@@ -1299,15 +1304,17 @@ where
         let mut input_bytes = vec![];
         for j in 0..2 {
             let mut cs = cs.ns(|| format!("Construct local data with output record {}", j));
+            let record_index = j.min(C::NUM_OUTPUT_RECORDS - 1);
 
-            input_bytes
-                .extend_from_slice(&new_record_commitments_gadgets[j].to_bytes(&mut cs.ns(|| "record_commitment"))?);
+            input_bytes.extend_from_slice(
+                &new_record_commitments_gadgets[record_index].to_bytes(&mut cs.ns(|| "record_commitment"))?,
+            );
             input_bytes.extend_from_slice(&memo);
             input_bytes.extend_from_slice(&network_id);
 
             let commitment_randomness = LocalDataCommitmentGadget::RandomnessGadget::alloc(
                 cs.ns(|| format!("Allocate new record local data commitment randomness {}", j)),
-                || Ok(&local_data_commitment_randomizers[C::NUM_INPUT_RECORDS + j]),
+                || Ok(&local_data_commitment_randomizers[C::NUM_INPUT_RECORDS + record_index]),
             )?;
 
             let commitment = LocalDataCommitmentGadget::check_commitment_gadget(
@@ -1360,9 +1367,11 @@ where
 
         {
             // FIXME: (alex) temp hack for benchmarking 2-in-2-out to 4-in-4-out
+            // The records beyond the second are not part of the local data root, so the components
+            // for these arities live in `instantiated::benchmark` and must not be deployed.
             let mut input_bytes = vec![];
             match (C::NUM_INPUT_RECORDS, C::NUM_OUTPUT_RECORDS) {
-                (2, 2) => {
+                (1, 1) | (2, 2) => {
                     // local data root is already correctly and sufficiently checked above
                     // thus do nothing
                 }
@@ -1396,9 +1405,11 @@ where
                         new_record_commitment_bytes,
                     )?;
                 }
-                (4, 4) => {
-                    // need two extra inner commitment and two extra hashes
-                    for i in 0..2 {
+                (num_input_records, num_output_records)
+                    if num_input_records == num_output_records && num_input_records >= 4 =>
+                {
+                    // need an extra inner commitment and an extra hash for every record pair beyond the second
+                    for i in 0..num_input_records - 2 {
                         let mut cs =
                             cs.ns(|| format!("SIMULATE: Construct local data with output record {}, {}", 0, i));
 
@@ -1434,7 +1445,7 @@ where
                         )?;
                     }
                 }
-                _ => panic!("Only support 2x2, 3x3, 4x4 transactions."),
+                _ => panic!("Only support 1x1, 2x2, 3x3, and 4x4 or larger square transactions."),
             }
         }
         // if C::NUM_INPUT_RECORDS
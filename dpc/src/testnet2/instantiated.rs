@@ -63,106 +63,156 @@ use snarkvm_polycommit::marlin_pc::{marlin_kzg10::MarlinKZG10Gadget, MarlinKZG10
 
 use blake2::Blake2s as Blake2sHash;
 
-pub type Testnet2DPC = DPC<Components>;
-pub type Testnet2Transaction = Transaction<Components>;
-
 pub type MerkleTreeCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBls12, 8, 32>;
 
 define_merkle_tree_parameters!(CommitmentMerkleParameters, MerkleTreeCRH, 32);
 
-pub struct Components;
-
-// TODO (raychu86): Optimize each of the window sizes in the type declarations below.
-#[rustfmt::skip]
-impl DPCComponents for Components {
-    const NETWORK_ID: u8 = Network::Testnet2.id();
-
-    const NUM_INPUT_RECORDS: usize = 2;
-    const NUM_OUTPUT_RECORDS: usize = 2;
-    
-    type InnerCurve = Bls12_377;
-    type OuterCurve = BW6_761;
-
-    type InnerScalarField = <Self::InnerCurve as PairingEngine>::Fr;
-    type OuterScalarField = <Self::OuterCurve as PairingEngine>::Fr;
-    
-    type AccountCommitment = PedersenCompressedCommitment<EdwardsBls12, 8, 192>;
-    type AccountCommitmentGadget = PedersenCompressedCommitmentGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
-    
-    type AccountEncryption = GroupEncryption<EdwardsBls12, EdwardsAffine, Blake2sHash>;
-    type AccountEncryptionGadget = GroupEncryptionGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
-
-    type AccountSignature = Schnorr<EdwardsAffine, Blake2sHash>;
-    type AccountSignatureGadget = SchnorrPublicKeyRandomizationGadget<EdwardsAffine, Self::InnerScalarField, EdwardsBls12Gadget, FpGadget<Self::InnerScalarField>>;
-    
-    type EncryptedRecordCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBls12, 48, 44>;
-    type EncryptedRecordCRHGadget = BoweHopwoodPedersenCompressedCRHGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
-
-    // Due to increase in size of inner_snark_vk which contains the public input which
-    // further contains the input serial numbers and output record commitments.
-    type InnerCircuitIDCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBW6, { 196 + 50 * Self::NUM_TOTAL_RECORDS }, 63>;
-    type InnerCircuitIDCRHGadget = BoweHopwoodPedersenCompressedCRHGadget<EdwardsBW6, Self::OuterScalarField, EdwardsBW6Gadget>;
-    
-    type LocalDataCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBls12, 16, 32>;
-    type LocalDataCRHGadget = BoweHopwoodPedersenCompressedCRHGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
-    
-    type LocalDataCommitment = PedersenCompressedCommitment<EdwardsBls12, 8, 129>;
-    type LocalDataCommitmentGadget = PedersenCompressedCommitmentGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
-    
-    type PRF = Blake2s;
-    type PRFGadget = Blake2sGadget;
-    
-    type ProgramVerificationKeyCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBW6, 4096, 80>;
-    type ProgramVerificationKeyCRHGadget = BoweHopwoodPedersenCompressedCRHGadget<EdwardsBW6, Self::OuterScalarField, EdwardsBW6Gadget>;
-    
-    type ProgramVerificationKeyCommitment = Blake2sCommitment;
-    type ProgramVerificationKeyCommitmentGadget = Blake2sCommitmentGadget;
-    
-    type RecordCommitment = PedersenCompressedCommitment<EdwardsBls12, 8, 233>;
-    type RecordCommitmentGadget = PedersenCompressedCommitmentGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
-    
-    type SerialNumberNonceCRH =
-        BoweHopwoodPedersenCompressedCRH<
-            EdwardsBls12,
-            { if Self::NUM_INPUT_RECORDS == 2 || Self::NUM_INPUT_RECORDS == 3 { 32 } else { 40 } },
-            { if Self::NUM_INPUT_RECORDS == 2 || Self::NUM_INPUT_RECORDS == 3 { 63 } else { 58 } }
-        >;
-    // type SerialNumberNonceCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBls12, 32, 63>;
-    type SerialNumberNonceCRHGadget = BoweHopwoodPedersenCompressedCRHGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
+/// Defines a set of testnet2 components for transactions with the given number of input and output records,
+/// along with its DPC and transaction types.
+///
+/// The serial number nonce CRH hashes the record number, the nonce randomness, and every input serial number,
+/// so its window parameters must grow with the number of input records, and cover a whole number of bytes.
+macro_rules! define_testnet2_components {
+    (
+        $(#[$attr:meta])*
+        $components:ident, $dpc:ident, $transaction:ident,
+        $num_input_records:expr, $num_output_records:expr,
+        $serial_number_nonce_crh_num_windows:expr, $serial_number_nonce_crh_window_size:expr
+    ) => {
+        pub type $dpc = DPC<$components>;
+        pub type $transaction = Transaction<$components>;
+
+        $(#[$attr])*
+        pub struct $components;
+
+        // TODO (raychu86): Optimize each of the window sizes in the type declarations below.
+        impl DPCComponents for $components {
+            const NETWORK_ID: u8 = Network::Testnet2.id();
+
+            const NUM_INPUT_RECORDS: usize = $num_input_records;
+            const NUM_OUTPUT_RECORDS: usize = $num_output_records;
+
+            type InnerCurve = Bls12_377;
+            type OuterCurve = BW6_761;
+
+            type InnerScalarField = <Self::InnerCurve as PairingEngine>::Fr;
+            type OuterScalarField = <Self::OuterCurve as PairingEngine>::Fr;
+
+            type AccountCommitment = PedersenCompressedCommitment<EdwardsBls12, 8, 192>;
+            type AccountCommitmentGadget = PedersenCompressedCommitmentGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
+
+            type AccountEncryption = GroupEncryption<EdwardsBls12, EdwardsAffine, Blake2sHash>;
+            type AccountEncryptionGadget = GroupEncryptionGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
+
+            type AccountSignature = Schnorr<EdwardsAffine, Blake2sHash>;
+            type AccountSignatureGadget = SchnorrPublicKeyRandomizationGadget<EdwardsAffine, Self::InnerScalarField, EdwardsBls12Gadget, FpGadget<Self::InnerScalarField>>;
+
+            type EncryptedRecordCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBls12, 48, 44>;
+            type EncryptedRecordCRHGadget = BoweHopwoodPedersenCompressedCRHGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
+
+            // Due to increase in size of inner_snark_vk which contains the public input which
+            // further contains the input serial numbers and output record commitments.
+            type InnerCircuitIDCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBW6, { 196 + 50 * Self::NUM_TOTAL_RECORDS }, 63>;
+            type InnerCircuitIDCRHGadget = BoweHopwoodPedersenCompressedCRHGadget<EdwardsBW6, Self::OuterScalarField, EdwardsBW6Gadget>;
+
+            type LocalDataCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBls12, 16, 32>;
+            type LocalDataCRHGadget = BoweHopwoodPedersenCompressedCRHGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
+
+            type LocalDataCommitment = PedersenCompressedCommitment<EdwardsBls12, 8, 129>;
+            type LocalDataCommitmentGadget = PedersenCompressedCommitmentGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
+
+            type PRF = Blake2s;
+            type PRFGadget = Blake2sGadget;
+
+            type ProgramVerificationKeyCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBW6, 4096, 80>;
+            type ProgramVerificationKeyCRHGadget = BoweHopwoodPedersenCompressedCRHGadget<EdwardsBW6, Self::OuterScalarField, EdwardsBW6Gadget>;
+
+            type ProgramVerificationKeyCommitment = Blake2sCommitment;
+            type ProgramVerificationKeyCommitmentGadget = Blake2sCommitmentGadget;
+
+            type RecordCommitment = PedersenCompressedCommitment<EdwardsBls12, 8, 233>;
+            type RecordCommitmentGadget = PedersenCompressedCommitmentGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
+
+            type SerialNumberNonceCRH = BoweHopwoodPedersenCompressedCRH<EdwardsBls12, $serial_number_nonce_crh_num_windows, $serial_number_nonce_crh_window_size>;
+            type SerialNumberNonceCRHGadget = BoweHopwoodPedersenCompressedCRHGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
+        }
+
+        impl Testnet2Components for $components {
+            type EncryptionGroup = EdwardsBls12;
+            type EncryptionGroupGadget = EdwardsBls12Gadget;
+            type EncryptionParameters = EdwardsParameters;
+            type FiatShamirRng = FiatShamirAlgebraicSpongeRng<
+                Self::InnerScalarField,
+                Self::OuterScalarField,
+                PoseidonSponge<Self::OuterScalarField>,
+            >;
+            type InnerSNARK =
+                Groth16<Self::InnerCurve, InnerCircuit<$components>, InnerCircuitVerifierInput<$components>>;
+            type InnerSNARKGadget = Groth16VerifierGadget<Self::InnerCurve, Self::OuterScalarField, PairingGadget>;
+            type MarlinMode = MarlinTestnet2Mode;
+            type MerkleHashGadget =
+                BoweHopwoodPedersenCompressedCRHGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
+            type MerkleParameters = CommitmentMerkleParameters;
+            type NoopProgramSNARK = MarlinSNARK<
+                Self::InnerScalarField,
+                Self::OuterScalarField,
+                Self::PolynomialCommitment,
+                Self::FiatShamirRng,
+                Self::MarlinMode,
+                NoopCircuit<Self>,
+                ProgramLocalData<Self>,
+            >;
+            type NoopProgramSNARKGadget = MarlinVerificationGadget<
+                Self::InnerScalarField,
+                Self::OuterScalarField,
+                Self::PolynomialCommitment,
+                MarlinKZG10Gadget<Self::InnerCurve, Self::OuterCurve, PairingGadget>,
+            >;
+            type OuterSNARK =
+                Groth16<Self::OuterCurve, OuterCircuit<$components>, OuterCircuitVerifierInput<$components>>;
+            type PolynomialCommitment = MarlinKZG10<Self::InnerCurve>;
+        }
+    };
 }
 
-impl Testnet2Components for Components {
-    type EncryptionGroup = EdwardsBls12;
-    type EncryptionGroupGadget = EdwardsBls12Gadget;
-    type EncryptionParameters = EdwardsParameters;
-    type FiatShamirRng = FiatShamirAlgebraicSpongeRng<
-        Self::InnerScalarField,
-        Self::OuterScalarField,
-        PoseidonSponge<Self::OuterScalarField>,
-    >;
-    type InnerSNARK = Groth16<Self::InnerCurve, InnerCircuit<Components>, InnerCircuitVerifierInput<Components>>;
-    type InnerSNARKGadget = Groth16VerifierGadget<Self::InnerCurve, Self::OuterScalarField, PairingGadget>;
-    type MarlinMode = MarlinTestnet2Mode;
-    type MerkleHashGadget =
-        BoweHopwoodPedersenCompressedCRHGadget<EdwardsBls12, Self::InnerScalarField, EdwardsBls12Gadget>;
-    type MerkleParameters = CommitmentMerkleParameters;
-    type NoopProgramSNARK = MarlinSNARK<
-        Self::InnerScalarField,
-        Self::OuterScalarField,
-        Self::PolynomialCommitment,
-        Self::FiatShamirRng,
-        Self::MarlinMode,
-        NoopCircuit<Self>,
-        ProgramLocalData<Self>,
-    >;
-    type NoopProgramSNARKGadget = MarlinVerificationGadget<
-        Self::InnerScalarField,
-        Self::OuterScalarField,
-        Self::PolynomialCommitment,
-        MarlinKZG10Gadget<Self::InnerCurve, Self::OuterCurve, PairingGadget>,
-    >;
-    type OuterSNARK = Groth16<Self::OuterCurve, OuterCircuit<Components>, OuterCircuitVerifierInput<Components>>;
-    type PolynomialCommitment = MarlinKZG10<Self::InnerCurve>;
+define_testnet2_components!(
+    /// Testnet2 components for 2-input, 2-output transactions.
+    /// These are the components that the published testnet2 parameters are generated for.
+    Components, Testnet2DPC, Testnet2Transaction,
+    2, 2,
+    32, 63
+);
+
+pub type Components2x2 = Components;
+
+/// Testnet2 components for benchmarking transactions with other numbers of input and output records.
+///
+/// These components are for benchmarking only, and must not be deployed. The local data tree of a transaction always has
+/// two input and two output leaves, so it repeats the leaf of a single record, and the inner circuit only simulates the
+/// constraints of the records beyond the second, leaving them out of the local data root.
+pub mod benchmark {
+    use super::*;
+
+    define_testnet2_components!(
+        /// Benchmark-only testnet2 components for 1-input, 1-output transactions.
+        Components1x1, Testnet2DPC1x1, Testnet2Transaction1x1,
+        1, 1,
+        16, 63
+    );
+
+    define_testnet2_components!(
+        /// Benchmark-only testnet2 components for 4-input, 4-output transactions.
+        Components4x4, Testnet2DPC4x4, Testnet2Transaction4x4,
+        4, 4,
+        40, 58
+    );
+
+    define_testnet2_components!(
+        /// Benchmark-only testnet2 components for 8-input, 8-output transactions.
+        Components8x8, Testnet2DPC8x8, Testnet2Transaction8x8,
+        8, 8,
+        72, 61
+    );
 }
 
 // This is currently unused.
@@ -175,3 +225,60 @@ impl Testnet2Components for Components {
 //     PoseidonSponge<Self::OuterScalarField>,
 //     PoseidonSpongeVar<Self::OuterScalarField>,
 // >;
+
+#[cfg(test)]
+mod tests {
+    use super::{benchmark::*, *};
+    use crate::testnet2::parameters::SystemParameters;
+    use snarkvm_algorithms::traits::{MerkleParameters, SignatureScheme, CRH};
+    use snarkvm_r1cs::{ConstraintCounter, ConstraintSynthesizer, ConstraintSystem};
+    use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+    use std::sync::Arc;
+
+    /// Checks that the serial number nonce CRH accepts its largest input, and returns the number of
+    /// constraints in the inner circuit for the given components.
+    fn inner_circuit_constraints<C: Testnet2Components>() -> usize {
+        let mut rng = ChaChaRng::seed_from_u64(1231275789u64);
+        let system_parameters = Arc::new(SystemParameters::<C>::setup(&mut rng).unwrap());
+        let ledger_parameters = Arc::new(C::MerkleParameters::setup(&mut rng));
+
+        let serial_number = <C::AccountSignature as SignatureScheme>::PublicKey::default();
+        let serial_number_length = to_bytes_le![serial_number].unwrap().len();
+        let sn_nonce_input = vec![0u8; 1 + 32 + serial_number_length * C::NUM_INPUT_RECORDS];
+        let sn_nonce = <C::SerialNumberNonceCRH as CRH>::hash(&system_parameters.serial_number_nonce, &sn_nonce_input);
+        assert!(sn_nonce.is_ok());
+
+        let mut cs = ConstraintCounter::default();
+        InnerCircuit::blank(&system_parameters, &ledger_parameters)
+            .generate_constraints(&mut cs)
+            .unwrap();
+        cs.num_constraints
+    }
+
+    #[test]
+    fn test_components_1x1() {
+        assert_eq!(Components1x1::NUM_TOTAL_RECORDS, 2);
+        assert!(inner_circuit_constraints::<Components1x1>() > 0);
+    }
+
+    #[test]
+    fn test_components_2x2() {
+        assert_eq!(Components2x2::NUM_TOTAL_RECORDS, 4);
+        assert!(inner_circuit_constraints::<Components2x2>() > inner_circuit_constraints::<Components1x1>());
+    }
+
+    #[test]
+    fn test_components_4x4() {
+        assert_eq!(Components4x4::NUM_TOTAL_RECORDS, 8);
+        assert!(inner_circuit_constraints::<Components4x4>() > inner_circuit_constraints::<Components2x2>());
+    }
+
+    #[test]
+    fn test_components_8x8() {
+        assert_eq!(Components8x8::NUM_TOTAL_RECORDS, 16);
+        assert!(inner_circuit_constraints::<Components8x8>() > inner_circuit_constraints::<Components4x4>());
+    }
+}
@@ -381,12 +381,13 @@ where
 
         // TODO: (alex) currently even for NUM_INPUT_RECORDS = 2..=4, we don't change
         // this local_data_merkle_tree, we simulate (manually drive up the constraints required)
-        // in the inner circuit as a quick hack for benchmark purpose
+        // in the inner circuit as a quick hack for benchmark purpose, which is why the components
+        // for other arities are benchmark-only. A transaction with a single input or output record repeats its leaf.
         let leaves = [
             old_record_commitments[0].clone(),
-            old_record_commitments[1].clone(),
+            old_record_commitments[1.min(C::NUM_INPUT_RECORDS - 1)].clone(),
             new_record_commitments[0].clone(),
-            new_record_commitments[1].clone(),
+            new_record_commitments[1.min(C::NUM_OUTPUT_RECORDS - 1)].clone(),
         ];
         let local_data_merkle_tree = CommitmentMerkleTree::new(self.system_parameters.local_data_crh.clone(), &leaves)?;
 
@@ -891,6 +892,7 @@ where
     }
 
     /// Returns true iff all the transactions in the block are valid according to the ledger.
+    /// Unlike testnet1, the outer proofs are not batch verified, as `verify` does not check them in testnet2.
     fn verify_transactions(&self, transactions: &[Self::Transaction], ledger: &L) -> bool {
         for transaction in transactions {
             if !self.verify(transaction, ledger) {
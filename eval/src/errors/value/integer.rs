@@ -70,6 +70,30 @@ impl IntegerError {
         Self::new(message)
     }
 
+    pub fn checked_operation(operation: String, left: String, right: String) -> Self {
+        let message = format!(
+            "the integer operation `{} {} {}` overflowed or divided by zero",
+            left, operation, right
+        );
+
+        Self::new(message)
+    }
+
+    pub fn invalid_shift_amount(amount: String) -> Self {
+        let message = format!("the shift amount `{}` must be an unsigned integer", amount);
+
+        Self::new(message)
+    }
+
+    pub fn shift_overflow(amount: String, size: usize) -> Self {
+        let message = format!(
+            "the shift amount `{}` must be less than the integer bit size `{}`",
+            amount, size
+        );
+
+        Self::new(message)
+    }
+
     pub fn invalid_integer(actual: String) -> Self {
         let message = format!("failed to parse `{}` as expected integer type", actual);
 
@@ -16,10 +16,12 @@
 
 pub mod errors;
 mod evaluator;
+mod native;
 mod operations;
 mod setup;
 mod value;
 
 pub use evaluator::*;
+pub use native::*;
 pub use setup::*;
 pub use value::*;
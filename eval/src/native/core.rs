@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::{prf::Blake2s, PRF};
+use snarkvm_ir::Integer as IrInteger;
+
+use crate::{errors::ValueError, BLAKE2S_HASH_CORE, LEN_CORE};
+
+use super::*;
+
+/// Returns the bytes of a `[u8; 32]` argument of a core call.
+fn unwrap_u8_array_argument<F: PrimeField, G: GroupType<F>>(arg: &NativeValue<F, G>, fn_call: &str) -> Result<[u8; 32]> {
+    let args = arg
+        .extract_array()
+        .map_err(|_| anyhow!("illegal non-array type in `{}` call", fn_call))?;
+    if args.len() != 32 {
+        return Err(anyhow!("illegal `{}` parameter length, expected `{}`", fn_call, 32));
+    }
+
+    let mut out = [0u8; 32];
+    for (byte, item) in out.iter_mut().zip(args.iter()) {
+        *byte = match item {
+            NativeValue::Integer(IrInteger::U8(value)) => *value,
+            _ => return Err(anyhow!("illegal non-u8 type in `{}` call", fn_call)),
+        };
+    }
+    Ok(out)
+}
+
+impl<F: PrimeField, G: GroupType<F>> EvaluatorState<F, G> {
+    pub fn call_core(&mut self, name: &str, arguments: &[NativeValue<F, G>]) -> Result<NativeValue<F, G>> {
+        match name {
+            BLAKE2S_HASH_CORE => {
+                if arguments.len() != 2 {
+                    return Err(anyhow!("illegal blake2s hash call, expected 2 arguments"));
+                }
+
+                let input = unwrap_u8_array_argument(&arguments[1], "hash")?;
+                let seed = unwrap_u8_array_argument(&arguments[0], "hash")?;
+                let digest = Blake2s::evaluate(&seed, &input)?;
+
+                Ok(NativeValue::Array(
+                    digest
+                        .iter()
+                        .map(|byte| NativeValue::Integer(IrInteger::U8(*byte)))
+                        .collect(),
+                ))
+            }
+            LEN_CORE => match arguments.get(0) {
+                Some(NativeValue::Array(array)) => Ok(NativeValue::Integer(IrInteger::U32(array.len() as u32))),
+                Some(v) => Err(ValueError::incompatible_types(&format!("{}.len()", v)).into()),
+                None => Err(anyhow!("illegal `len` call, expected 1 argument")),
+            },
+            _ => Err(anyhow!("core call `{}` is not supported by the native evaluator", name)),
+        }
+    }
+}
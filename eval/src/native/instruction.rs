@@ -0,0 +1,361 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_ir::{
+    ArrayInitRepeatData,
+    CallCoreData,
+    Integer as IrInteger,
+    LogData,
+    LogLevel,
+    PredicateData,
+    QueryData,
+    VarData,
+};
+
+use crate::errors::ArrayError;
+
+use super::{operations, *};
+
+/// Returns the operand at `index` of an instruction.
+fn operand(values: &[Value], index: usize) -> Result<&Value> {
+    values
+        .get(index)
+        .ok_or_else(|| anyhow!("missing operand {} for instruction", index))
+}
+
+/// Returns the value of an array index, which must be an unsigned integer.
+fn array_index(index: &IrInteger) -> Result<usize, ArrayError> {
+    match index {
+        IrInteger::U8(_) | IrInteger::U16(_) | IrInteger::U32(_) | IrInteger::U64(_) | IrInteger::U128(_) => {
+            integer_to_usize(index).ok_or_else(ArrayError::array_index_out_of_legal_bounds)
+        }
+        index => Err(ArrayError::invalid_index(index.to_string())),
+    }
+}
+
+/// Returns the bounds of an array slice of `length` elements from `from` to `to`, checked against the length of the array.
+fn slice_bounds(from: usize, to: usize, length: usize, array_len: usize) -> Result<(usize, usize), ArrayError> {
+    if to.checked_sub(from) != Some(length) {
+        return Err(ArrayError::array_invalid_slice_length());
+    }
+    if to > array_len {
+        return Err(ArrayError::array_index_out_of_bounds(to, array_len));
+    }
+    Ok((from, to))
+}
+
+impl<F: PrimeField, G: GroupType<F>> EvaluatorState<F, G> {
+    fn resolve_binary(&self, data: &QueryData<2>) -> Result<(NativeValue<F, G>, NativeValue<F, G>)> {
+        let left = self.resolve(operand(&data.values, 0)?)?.into_owned();
+        let right = self.resolve(operand(&data.values, 1)?)?.into_owned();
+        Ok((left, right))
+    }
+
+    fn resolve_integer(&self, value: &Value, description: &str) -> Result<IrInteger> {
+        Ok(*self
+            .resolve(value)?
+            .extract_integer()
+            .map_err(|value| anyhow!("invalid value for {}: {}", description, value))?)
+    }
+
+    /// Evaluates a single instruction in the local [`EvaluatorState`] context. Control instructions are evaluated by the [`FunctionEvaluator`].
+    pub(super) fn evaluate_instruction(&mut self, instruction: &Instruction) -> Result<Option<NativeValue<F, G>>> {
+        match instruction {
+            Instruction::Add(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::add(left, right)?);
+            }
+            Instruction::Sub(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::sub(left, right)?);
+            }
+            Instruction::Mul(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::mul(left, right)?);
+            }
+            Instruction::Div(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::div(left, right)?);
+            }
+            Instruction::Pow(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::pow(left, right)?);
+            }
+            Instruction::Or(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::or(left, right)?);
+            }
+            Instruction::And(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::and(left, right)?);
+            }
+            Instruction::Eq(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, NativeValue::Boolean(operations::eq(&left, &right)?));
+            }
+            Instruction::Ne(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, NativeValue::Boolean(!operations::eq(&left, &right)?));
+            }
+            Instruction::Ge(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                let out = operations::compare(&left, &right, ">=")?;
+                self.store(data.destination, NativeValue::Boolean(out));
+            }
+            Instruction::Gt(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                let out = operations::compare(&left, &right, ">")?;
+                self.store(data.destination, NativeValue::Boolean(out));
+            }
+            Instruction::Le(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                let out = operations::compare(&left, &right, "<=")?;
+                self.store(data.destination, NativeValue::Boolean(out));
+            }
+            Instruction::Lt(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                let out = operations::compare(&left, &right, "<")?;
+                self.store(data.destination, NativeValue::Boolean(out));
+            }
+            Instruction::BitOr(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::bitwise(left, right, "|")?);
+            }
+            Instruction::BitAnd(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::bitwise(left, right, "&")?);
+            }
+            Instruction::BitXor(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::bitwise(left, right, "^")?);
+            }
+            Instruction::Shr(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::shift(left, right, ">>")?);
+            }
+            Instruction::ShrSigned(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::shift(left, right, ">>>")?);
+            }
+            Instruction::Shl(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::shift(left, right, "<<")?);
+            }
+            Instruction::Mod(data) => {
+                let (left, right) = self.resolve_binary(data)?;
+                self.store(data.destination, operations::rem(left, right)?);
+            }
+            Instruction::Not(QueryData { destination, values }) => {
+                let inner = self.resolve(operand(values, 0)?)?.into_owned();
+                self.store(*destination, operations::not(inner)?);
+            }
+            Instruction::Negate(QueryData { destination, values }) => {
+                let inner = self.resolve(operand(values, 0)?)?.into_owned();
+                self.store(*destination, operations::negate(inner)?);
+            }
+            Instruction::BitNot(QueryData { destination, values }) => {
+                let inner = self.resolve(operand(values, 0)?)?.into_owned();
+                self.store(*destination, operations::bit_not(inner)?);
+            }
+            Instruction::ArrayInitRepeat(ArrayInitRepeatData {
+                destination,
+                length,
+                value,
+            }) => {
+                let inner = self.resolve(value)?.into_owned();
+                // todo: max array length (DOS vector)
+                self.store(*destination, NativeValue::Array(vec![inner; *length as usize]));
+            }
+            Instruction::ArrayInit(VarData { destination, values }) => {
+                let mut inner = Vec::with_capacity(values.len());
+                for value in values {
+                    match self.resolve(value)?.into_owned() {
+                        NativeValue::Array(values) => inner.extend(values),
+                        value => inner.push(value),
+                    }
+                }
+                self.store(*destination, NativeValue::Array(inner));
+            }
+            Instruction::ArrayIndexGet(QueryData { destination, values }) => {
+                let index = self.resolve_integer(operand(values, 1)?, "array index")?;
+                let array = self.resolve(operand(values, 0)?)?;
+                let array = array
+                    .extract_array()
+                    .map_err(|value| anyhow!("invalid array for array index: {}", value))?;
+
+                let index = array_index(&index)?;
+                let out = array
+                    .get(index)
+                    .ok_or_else(|| ArrayError::array_index_out_of_bounds(index, array.len()))?
+                    .clone();
+                self.store(*destination, out);
+            }
+            Instruction::ArrayIndexStore(QueryData { destination, values }) => {
+                let index = self.resolve_integer(operand(values, 0)?, "array index store")?;
+                let target = self.resolve(operand(values, 1)?)?.into_owned();
+                let array = self.resolve(&Value::Ref(*destination))?;
+                let mut array = array
+                    .extract_array()
+                    .map_err(|value| anyhow!("invalid array for array index store: {}", value))?
+                    .clone();
+
+                let index = array_index(&index)?;
+                let array_len = array.len();
+                *array
+                    .get_mut(index)
+                    .ok_or_else(|| ArrayError::array_index_out_of_bounds(index, array_len))? = target;
+                self.store(*destination, NativeValue::Array(array));
+            }
+            Instruction::ArraySliceGet(QueryData { destination, values }) => {
+                let from = self.resolve_integer(operand(values, 1)?, "array slice from index")?;
+                let to = self.resolve_integer(operand(values, 2)?, "array slice to index")?;
+                let length = self.resolve_integer(operand(values, 3)?, "array slice length")?;
+                let length = integer_to_usize(&length).ok_or_else(|| anyhow!("illegal array slice length"))?;
+                let array = self.resolve(operand(values, 0)?)?;
+                let array = array
+                    .extract_array()
+                    .map_err(|value| anyhow!("illegal value for array slice: {}", value))?;
+
+                let (left, right) = slice_bounds(array_index(&from)?, array_index(&to)?, length, array.len())?;
+                let out = NativeValue::Array(array[left..right].to_vec());
+                self.store(*destination, out);
+            }
+            Instruction::ArraySliceStore(QueryData { destination, values }) => {
+                let from = self.resolve_integer(operand(values, 0)?, "array slice store from index")?;
+                let to = self.resolve_integer(operand(values, 1)?, "array slice store to index")?;
+                let target = self.resolve(operand(values, 2)?)?.into_owned();
+                let target = match target {
+                    NativeValue::Array(target) => target,
+                    value => return Err(anyhow!("illegal value for array slice store: {}", value)),
+                };
+                let array = self.resolve(&Value::Ref(*destination))?;
+                let mut array = array
+                    .extract_array()
+                    .map_err(|value| anyhow!("illegal target for array slice store: {}", value))?
+                    .clone();
+
+                let (left, right) = slice_bounds(array_index(&from)?, array_index(&to)?, target.len(), array.len())?;
+                array.splice(left..right, target);
+                self.store(*destination, NativeValue::Array(array));
+            }
+            Instruction::TupleInit(VarData { destination, values }) => {
+                let inner = values
+                    .iter()
+                    .map(|value| self.resolve(value).map(Cow::into_owned))
+                    .collect::<Result<Vec<_>>>()?;
+                self.store(*destination, NativeValue::Tuple(inner));
+            }
+            Instruction::TupleIndexGet(QueryData { destination, values }) => {
+                let index = self.resolve_integer(operand(values, 1)?, "tuple index")?;
+                let index = integer_to_usize(&index).ok_or_else(|| anyhow!("illegal tuple index: {}", index))?;
+
+                let tuple = self.resolve(operand(values, 0)?)?;
+                let tuple = tuple
+                    .extract_tuple()
+                    .map_err(|value| anyhow!("invalid tuple type for tuple index: {}", value))?;
+
+                let out = tuple
+                    .get(index)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "illegal index {} into tuple of length {} for tuple index",
+                            index,
+                            tuple.len()
+                        )
+                    })?
+                    .clone();
+                self.store(*destination, out);
+            }
+            Instruction::TupleIndexStore(QueryData { destination, values }) => {
+                let index = self.resolve_integer(operand(values, 0)?, "tuple store")?;
+                let index = integer_to_usize(&index).ok_or_else(|| anyhow!("illegal tuple index: {}", index))?;
+                let value = self.resolve(operand(values, 1)?)?.into_owned();
+
+                let tuple = self.resolve(&Value::Ref(*destination))?;
+                let mut tuple = tuple
+                    .extract_tuple()
+                    .map_err(|value| anyhow!("invalid tuple type for tuple store: {}", value))?
+                    .clone();
+
+                let tuple_len = tuple.len();
+                *tuple.get_mut(index).ok_or_else(|| {
+                    anyhow!(
+                        "illegal index {} into tuple of length {} for tuple store",
+                        index,
+                        tuple_len
+                    )
+                })? = value;
+                self.store(*destination, NativeValue::Tuple(tuple));
+            }
+            Instruction::Pick(QueryData { destination, values }) => {
+                let condition = self
+                    .resolve(operand(values, 0)?)?
+                    .extract_bool()
+                    .map_err(|value| anyhow!("invalid value for pick condition: {}", value))?;
+                let left = self.resolve(operand(values, 1)?)?.into_owned();
+                let right = self.resolve(operand(values, 2)?)?.into_owned();
+                self.store(*destination, operations::select(condition, left, right)?);
+            }
+            Instruction::Mask(_) | Instruction::Repeat(_) | Instruction::Call(_) => {
+                return Err(anyhow!("cannot eval control instructions directly"));
+            }
+            Instruction::Store(QueryData { destination, values }) => {
+                let value = self.resolve(operand(values, 0)?)?.into_owned();
+                self.store(*destination, value);
+            }
+            Instruction::Return(PredicateData { values }) => {
+                let value = self.resolve(operand(values, 0)?)?.into_owned();
+                return Ok(Some(value));
+            }
+            Instruction::Assert(PredicateData { values }) => {
+                let value = self.resolve(operand(values, 0)?)?;
+                match value.extract_bool() {
+                    Ok(true) => (),
+                    Ok(false) => return Err(anyhow!("assertion failed")),
+                    Err(_) => return Err(anyhow!("invalid type for assertion, expected boolean")),
+                }
+            }
+            Instruction::Log(LogData { log_level, parts }) => {
+                let mut out = String::new();
+                for part in parts {
+                    match part {
+                        Value::Str(s) => out += &**s,
+                        x => out += &*self.resolve(x)?.to_string(),
+                    }
+                }
+                match log_level {
+                    LogLevel::Error => tracing::error!("{}", out),
+                    LogLevel::Info => tracing::info!("{}", out),
+                    LogLevel::Debug => tracing::debug!("{}", out),
+                }
+            }
+            Instruction::CallCore(CallCoreData {
+                destination,
+                identifier,
+                arguments,
+            }) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|x| self.resolve(x).map(Cow::into_owned))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let out = self.call_core(&**identifier, &arguments)?;
+                self.store(*destination, out);
+            }
+        }
+        Ok(None)
+    }
+}
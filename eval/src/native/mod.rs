@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{borrow::Cow, marker::PhantomData};
+
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+use snarkvm_fields::PrimeField;
+use snarkvm_ir::{Input as IrInput, InputData, Instruction, Program, Value};
+
+use crate::{Evaluator, GroupType};
+
+mod core;
+mod instruction;
+mod operations;
+mod state;
+mod value;
+
+use state::*;
+pub use value::*;
+
+/// An evaluator for running a program directly over native values, without synthesizing any constraints.
+///
+/// Follows the semantics of the [`SetupEvaluator`](crate::SetupEvaluator), except that masked blocks with a
+/// false condition are skipped rather than evaluated and discarded, and that input-derived values may be used
+/// wherever a constant is expected.
+pub struct NativeEvaluator<F: PrimeField, G: GroupType<F>> {
+    _p: PhantomData<(F, G)>,
+}
+
+impl<F: PrimeField, G: GroupType<F>> NativeEvaluator<F, G> {
+    pub fn new() -> Self {
+        Self { _p: PhantomData }
+    }
+}
+
+impl<F: PrimeField, G: GroupType<F>> Default for NativeEvaluator<F, G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PrimeField, G: GroupType<F>> Evaluator<F, G> for NativeEvaluator<F, G> {
+    type Error = anyhow::Error;
+    type Output = NativeValue<F, G>;
+
+    fn evaluate(&mut self, program: &Program, input: &InputData) -> Result<Self::Output, Self::Error> {
+        let mut state = EvaluatorState::new();
+
+        state.handle_input_block(&program.header.main_inputs, &input.main)?;
+        state.handle_const_input_block(&program.header.constant_inputs, &input.constants)?;
+        state.handle_input_block(&program.header.register_inputs, &input.registers)?;
+        state.handle_input_block(&program.header.public_states, &input.public_states)?;
+        state.handle_input_block(&program.header.private_record_states, &input.private_record_states)?;
+        state.handle_input_block(&program.header.private_leaf_states, &input.private_leaf_states)?;
+
+        FunctionEvaluator::new(program).evaluate_main(state)
+    }
+}
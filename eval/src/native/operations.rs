@@ -0,0 +1,453 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Native counterparts of the constraint enforcing operations in `crate::operations`.
+
+use std::{cmp::Ordering, convert::TryFrom};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_ir::Integer as IrInteger;
+
+use crate::{
+    errors::{FieldError, IntegerError, ValueError},
+    GroupType,
+};
+
+use super::NativeValue;
+
+/// Applies `$expression` to two integers of the same type, returning `None` on mismatched types.
+macro_rules! match_native_integers {
+    (($a: ident, $b: ident) => $expression:expr) => {
+        match ($a, $b) {
+            (IrInteger::U8($a), IrInteger::U8($b)) => Some($expression.map(IrInteger::U8)),
+            (IrInteger::U16($a), IrInteger::U16($b)) => Some($expression.map(IrInteger::U16)),
+            (IrInteger::U32($a), IrInteger::U32($b)) => Some($expression.map(IrInteger::U32)),
+            (IrInteger::U64($a), IrInteger::U64($b)) => Some($expression.map(IrInteger::U64)),
+            (IrInteger::U128($a), IrInteger::U128($b)) => Some($expression.map(IrInteger::U128)),
+
+            (IrInteger::I8($a), IrInteger::I8($b)) => Some($expression.map(IrInteger::I8)),
+            (IrInteger::I16($a), IrInteger::I16($b)) => Some($expression.map(IrInteger::I16)),
+            (IrInteger::I32($a), IrInteger::I32($b)) => Some($expression.map(IrInteger::I32)),
+            (IrInteger::I64($a), IrInteger::I64($b)) => Some($expression.map(IrInteger::I64)),
+            (IrInteger::I128($a), IrInteger::I128($b)) => Some($expression.map(IrInteger::I128)),
+            (_, _) => None,
+        }
+    };
+}
+
+/// Returns the bit size of the integer.
+fn integer_size(integer: &IrInteger) -> usize {
+    match integer {
+        IrInteger::U8(_) | IrInteger::I8(_) => 8,
+        IrInteger::U16(_) | IrInteger::I16(_) => 16,
+        IrInteger::U32(_) | IrInteger::I32(_) => 32,
+        IrInteger::U64(_) | IrInteger::I64(_) => 64,
+        IrInteger::U128(_) | IrInteger::I128(_) => 128,
+    }
+}
+
+/// Returns the exponent of `**` as the unsigned integer with the same bits.
+fn pow_exponent(integer: &IrInteger) -> u128 {
+    match *integer {
+        IrInteger::U8(x) => x as u128,
+        IrInteger::U16(x) => x as u128,
+        IrInteger::U32(x) => x as u128,
+        IrInteger::U64(x) => x as u128,
+        IrInteger::U128(x) => x,
+        IrInteger::I8(x) => x as u8 as u128,
+        IrInteger::I16(x) => x as u16 as u128,
+        IrInteger::I32(x) => x as u32 as u128,
+        IrInteger::I64(x) => x as u64 as u128,
+        IrInteger::I128(x) => x as u128,
+    }
+}
+
+/// Returns the value of a checked integer operation, or an error if the operands have mismatched types,
+/// or if the operation overflowed or divided by zero.
+fn checked_integer_operation(
+    left: IrInteger,
+    right: IrInteger,
+    operation: &str,
+    result: Option<Option<IrInteger>>,
+) -> Result<IrInteger, IntegerError> {
+    result
+        .ok_or_else(|| IntegerError::binary_operation(operation.to_string()))?
+        .ok_or_else(|| IntegerError::checked_operation(operation.to_string(), left.to_string(), right.to_string()))
+}
+
+fn integer_add(left: IrInteger, right: IrInteger) -> Result<IrInteger, IntegerError> {
+    let result = match_native_integers!((left, right) => left.checked_add(right));
+    checked_integer_operation(left, right, "+", result)
+}
+
+fn integer_sub(left: IrInteger, right: IrInteger) -> Result<IrInteger, IntegerError> {
+    let result = match_native_integers!((left, right) => left.checked_sub(right));
+    checked_integer_operation(left, right, "-", result)
+}
+
+fn integer_mul(left: IrInteger, right: IrInteger) -> Result<IrInteger, IntegerError> {
+    let result = match_native_integers!((left, right) => left.checked_mul(right));
+    checked_integer_operation(left, right, "*", result)
+}
+
+fn integer_div(left: IrInteger, right: IrInteger) -> Result<IrInteger, IntegerError> {
+    let result = match_native_integers!((left, right) => left.checked_div(right));
+    checked_integer_operation(left, right, "÷", result)
+}
+
+fn integer_rem(left: IrInteger, right: IrInteger) -> Result<IrInteger, IntegerError> {
+    let result = match_native_integers!((left, right) => left.checked_rem(right));
+    checked_integer_operation(left, right, "%", result)
+}
+
+fn integer_pow(left: IrInteger, right: IrInteger) -> Result<IrInteger, IntegerError> {
+    // The exponent is read from its bits, as the pow gadgets do for signed exponents.
+    let exponent = pow_exponent(&right);
+    let result = match_native_integers!((left, right) => {
+        let _ = right;
+        match u32::try_from(exponent) {
+            Ok(exponent) => left.checked_pow(exponent),
+            // Only 0, 1 and -1 can be raised to an exponent this large without overflowing.
+            Err(_) => left.checked_pow(2 + (exponent % 2) as u32).filter(|x| *x == left),
+        }
+    });
+    checked_integer_operation(left, right, "**", result)
+}
+
+fn integer_bitwise(
+    left: IrInteger,
+    right: IrInteger,
+    operation: &str,
+) -> Result<IrInteger, IntegerError> {
+    let result = match operation {
+        "&" => match_native_integers!((left, right) => Some(left & right)),
+        "|" => match_native_integers!((left, right) => Some(left | right)),
+        _ => match_native_integers!((left, right) => Some(left ^ right)),
+    };
+    checked_integer_operation(left, right, operation, result)
+}
+
+fn integer_shift(integer: IrInteger, amount: IrInteger, operation: &str) -> Result<IrInteger, IntegerError> {
+    let size = integer_size(&integer);
+    let shift = match amount {
+        IrInteger::U8(x) => x as u128,
+        IrInteger::U16(x) => x as u128,
+        IrInteger::U32(x) => x as u128,
+        IrInteger::U64(x) => x as u128,
+        IrInteger::U128(x) => x,
+        amount => return Err(IntegerError::invalid_shift_amount(amount.to_string())),
+    };
+    if shift >= size as u128 {
+        return Err(IntegerError::shift_overflow(amount.to_string(), size));
+    }
+    let shift = shift as u32;
+
+    macro_rules! shift {
+        ($($variant: ident: $unsigned: ty, $signed: ty;)*) => {
+            match integer {
+                $(IrInteger::$variant(x) => IrInteger::$variant(match operation {
+                    "<<" => x << shift,
+                    ">>" => (x as $unsigned >> shift) as _,
+                    _ => (x as $signed >> shift) as _,
+                }),)*
+            }
+        };
+    }
+
+    Ok(shift! {
+        U8: u8, i8;
+        U16: u16, i16;
+        U32: u32, i32;
+        U64: u64, i64;
+        U128: u128, i128;
+        I8: u8, i8;
+        I16: u16, i16;
+        I32: u32, i32;
+        I64: u64, i64;
+        I128: u128, i128;
+    })
+}
+
+fn integer_negate(integer: IrInteger) -> Result<IrInteger, IntegerError> {
+    let result = match integer {
+        IrInteger::I8(x) => x.checked_neg().map(IrInteger::I8),
+        IrInteger::I16(x) => x.checked_neg().map(IrInteger::I16),
+        IrInteger::I32(x) => x.checked_neg().map(IrInteger::I32),
+        IrInteger::I64(x) => x.checked_neg().map(IrInteger::I64),
+        IrInteger::I128(x) => x.checked_neg().map(IrInteger::I128),
+        _ => return Err(IntegerError::negate_operation()),
+    };
+    result.ok_or_else(|| IntegerError::cannot_evaluate(format!("-{}", integer)))
+}
+
+fn integer_bit_not(integer: IrInteger) -> IrInteger {
+    match integer {
+        IrInteger::U8(x) => IrInteger::U8(!x),
+        IrInteger::U16(x) => IrInteger::U16(!x),
+        IrInteger::U32(x) => IrInteger::U32(!x),
+        IrInteger::U64(x) => IrInteger::U64(!x),
+        IrInteger::U128(x) => IrInteger::U128(!x),
+        IrInteger::I8(x) => IrInteger::I8(!x),
+        IrInteger::I16(x) => IrInteger::I16(!x),
+        IrInteger::I32(x) => IrInteger::I32(!x),
+        IrInteger::I64(x) => IrInteger::I64(!x),
+        IrInteger::I128(x) => IrInteger::I128(!x),
+    }
+}
+
+fn integer_cmp(left: &IrInteger, right: &IrInteger) -> Option<Ordering> {
+    match (left, right) {
+        (IrInteger::U8(a), IrInteger::U8(b)) => Some(a.cmp(b)),
+        (IrInteger::U16(a), IrInteger::U16(b)) => Some(a.cmp(b)),
+        (IrInteger::U32(a), IrInteger::U32(b)) => Some(a.cmp(b)),
+        (IrInteger::U64(a), IrInteger::U64(b)) => Some(a.cmp(b)),
+        (IrInteger::U128(a), IrInteger::U128(b)) => Some(a.cmp(b)),
+        (IrInteger::I8(a), IrInteger::I8(b)) => Some(a.cmp(b)),
+        (IrInteger::I16(a), IrInteger::I16(b)) => Some(a.cmp(b)),
+        (IrInteger::I32(a), IrInteger::I32(b)) => Some(a.cmp(b)),
+        (IrInteger::I64(a), IrInteger::I64(b)) => Some(a.cmp(b)),
+        (IrInteger::I128(a), IrInteger::I128(b)) => Some(a.cmp(b)),
+        (_, _) => None,
+    }
+}
+
+pub fn add<F: PrimeField, G: GroupType<F>>(
+    left: NativeValue<F, G>,
+    right: NativeValue<F, G>,
+) -> Result<NativeValue<F, G>, ValueError> {
+    match (left, right) {
+        (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => Ok(NativeValue::Integer(integer_add(num_1, num_2)?)),
+        (NativeValue::Field(field_1), NativeValue::Field(field_2)) => Ok(NativeValue::Field(field_1 + field_2)),
+        (NativeValue::Group(point_1), NativeValue::Group(point_2)) => Ok(NativeValue::Group(point_1 + point_2)),
+        (val_1, val_2) => Err(ValueError::incompatible_types(&*format!("{} + {}", val_1, val_2))),
+    }
+}
+
+pub fn sub<F: PrimeField, G: GroupType<F>>(
+    left: NativeValue<F, G>,
+    right: NativeValue<F, G>,
+) -> Result<NativeValue<F, G>, ValueError> {
+    match (left, right) {
+        (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => Ok(NativeValue::Integer(integer_sub(num_1, num_2)?)),
+        (NativeValue::Field(field_1), NativeValue::Field(field_2)) => Ok(NativeValue::Field(field_1 - field_2)),
+        (NativeValue::Group(point_1), NativeValue::Group(point_2)) => Ok(NativeValue::Group(point_1 - point_2)),
+        (val_1, val_2) => Err(ValueError::incompatible_types(&*format!("{} - {}", val_1, val_2))),
+    }
+}
+
+pub fn mul<F: PrimeField, G: GroupType<F>>(
+    left: NativeValue<F, G>,
+    right: NativeValue<F, G>,
+) -> Result<NativeValue<F, G>, ValueError> {
+    match (left, right) {
+        (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => Ok(NativeValue::Integer(integer_mul(num_1, num_2)?)),
+        (NativeValue::Field(field_1), NativeValue::Field(field_2)) => Ok(NativeValue::Field(field_1 * field_2)),
+        (val_1, val_2) => Err(ValueError::incompatible_types(&*format!("{} * {}", val_1, val_2))),
+    }
+}
+
+pub fn div<F: PrimeField, G: GroupType<F>>(
+    left: NativeValue<F, G>,
+    right: NativeValue<F, G>,
+) -> Result<NativeValue<F, G>, ValueError> {
+    match (left, right) {
+        (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => Ok(NativeValue::Integer(integer_div(num_1, num_2)?)),
+        (NativeValue::Field(field_1), NativeValue::Field(field_2)) => {
+            if field_2.is_zero() {
+                return Err(FieldError::no_inverse(field_2.to_string()).into());
+            }
+            Ok(NativeValue::Field(field_1 / field_2))
+        }
+        (val_1, val_2) => Err(ValueError::incompatible_types(&*format!("{} / {}", val_1, val_2))),
+    }
+}
+
+pub fn pow<F: PrimeField, G: GroupType<F>>(
+    left: NativeValue<F, G>,
+    right: NativeValue<F, G>,
+) -> Result<NativeValue<F, G>, ValueError> {
+    match (left, right) {
+        (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => Ok(NativeValue::Integer(integer_pow(num_1, num_2)?)),
+        (val_1, val_2) => Err(ValueError::incompatible_types(&*format!("{} ** {}", val_1, val_2))),
+    }
+}
+
+pub fn rem<F: PrimeField, G: GroupType<F>>(
+    left: NativeValue<F, G>,
+    right: NativeValue<F, G>,
+) -> Result<NativeValue<F, G>, ValueError> {
+    match (left, right) {
+        (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => Ok(NativeValue::Integer(integer_rem(num_1, num_2)?)),
+        (val_1, val_2) => Err(ValueError::incompatible_types(&*format!("{} % {}", val_1, val_2))),
+    }
+}
+
+/// Applies the bitwise operator `&`, `|` or `^` to two integers.
+pub fn bitwise<F: PrimeField, G: GroupType<F>>(
+    left: NativeValue<F, G>,
+    right: NativeValue<F, G>,
+    operation: &str,
+) -> Result<NativeValue<F, G>, ValueError> {
+    match (left, right) {
+        (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => {
+            Ok(NativeValue::Integer(integer_bitwise(num_1, num_2, operation)?))
+        }
+        (val_1, val_2) => Err(ValueError::incompatible_types(&*format!("{} {} {}", val_1, operation, val_2))),
+    }
+}
+
+/// Applies the shift operator `<<`, `>>` or `>>>` to an integer.
+pub fn shift<F: PrimeField, G: GroupType<F>>(
+    left: NativeValue<F, G>,
+    right: NativeValue<F, G>,
+    operation: &str,
+) -> Result<NativeValue<F, G>, ValueError> {
+    match (left, right) {
+        (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => {
+            Ok(NativeValue::Integer(integer_shift(num_1, num_2, operation)?))
+        }
+        (val_1, val_2) => Err(ValueError::incompatible_types(&*format!("{} {} {}", val_1, operation, val_2))),
+    }
+}
+
+pub fn negate<F: PrimeField, G: GroupType<F>>(value: NativeValue<F, G>) -> Result<NativeValue<F, G>, ValueError> {
+    match value {
+        NativeValue::Integer(integer) => Ok(NativeValue::Integer(integer_negate(integer)?)),
+        NativeValue::Field(field) => Ok(NativeValue::Field(-field)),
+        NativeValue::Group(group) => Ok(NativeValue::Group(-group)),
+        value => Err(ValueError::incompatible_types(&*format!("-{}", value))),
+    }
+}
+
+pub fn not<F: PrimeField, G: GroupType<F>>(value: NativeValue<F, G>) -> Result<NativeValue<F, G>, ValueError> {
+    match value {
+        NativeValue::Boolean(boolean) => Ok(NativeValue::Boolean(!boolean)),
+        value => Err(ValueError::incompatible_types(&*format!("!{}", value))),
+    }
+}
+
+pub fn bit_not<F: PrimeField, G: GroupType<F>>(value: NativeValue<F, G>) -> Result<NativeValue<F, G>, ValueError> {
+    match value {
+        NativeValue::Integer(integer) => Ok(NativeValue::Integer(integer_bit_not(integer))),
+        value => Err(ValueError::incompatible_types(&*format!("~{}", value))),
+    }
+}
+
+pub fn and<F: PrimeField, G: GroupType<F>>(
+    left: NativeValue<F, G>,
+    right: NativeValue<F, G>,
+) -> Result<NativeValue<F, G>, ValueError> {
+    match (left, right) {
+        (NativeValue::Boolean(bool_1), NativeValue::Boolean(bool_2)) => Ok(NativeValue::Boolean(bool_1 && bool_2)),
+        (val_1, val_2) => Err(ValueError::incompatible_types(&*format!("{} && {}", val_1, val_2))),
+    }
+}
+
+pub fn or<F: PrimeField, G: GroupType<F>>(
+    left: NativeValue<F, G>,
+    right: NativeValue<F, G>,
+) -> Result<NativeValue<F, G>, ValueError> {
+    match (left, right) {
+        (NativeValue::Boolean(bool_1), NativeValue::Boolean(bool_2)) => Ok(NativeValue::Boolean(bool_1 || bool_2)),
+        (val_1, val_2) => Err(ValueError::incompatible_types(&*format!("{} || {}", val_1, val_2))),
+    }
+}
+
+pub fn eq<F: PrimeField, G: GroupType<F>>(left: &NativeValue<F, G>, right: &NativeValue<F, G>) -> Result<bool, ValueError> {
+    Ok(match (left, right) {
+        (NativeValue::Address(address_1), NativeValue::Address(address_2)) => address_1 == address_2,
+        (NativeValue::Boolean(bool_1), NativeValue::Boolean(bool_2)) => bool_1 == bool_2,
+        (NativeValue::Char(char_1), NativeValue::Char(char_2)) => char_1 == char_2,
+        (NativeValue::Field(field_1), NativeValue::Field(field_2)) => field_1 == field_2,
+        (NativeValue::Group(point_1), NativeValue::Group(point_2)) => point_1 == point_2,
+        (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => {
+            integer_cmp(num_1, num_2)
+                .ok_or_else(|| ValueError::incompatible_types(&*format!("{} == {}", left, right)))?
+                == Ordering::Equal
+        }
+        (NativeValue::Array(arr_1), NativeValue::Array(arr_2)) => {
+            if arr_1.len() != arr_2.len() {
+                return Err(ValueError::array_sizes_must_match_in_eq(arr_1.len(), arr_2.len()));
+            }
+
+            let mut current = true;
+            for (left, right) in arr_1.iter().zip(arr_2.iter()) {
+                current &= eq(left, right)?;
+            }
+            current
+        }
+        (NativeValue::Tuple(tuple_1), NativeValue::Tuple(tuple_2)) => {
+            let mut current = true;
+            for (left, right) in tuple_1.iter().zip(tuple_2.iter()) {
+                current &= eq(left, right)?;
+            }
+            current
+        }
+        (val_1, val_2) => return Err(ValueError::incompatible_types(&*format!("{} == {}", val_1, val_2))),
+    })
+}
+
+/// Compares two integers with one of the operators `<`, `<=`, `>` or `>=`.
+pub fn compare<F: PrimeField, G: GroupType<F>>(
+    left: &NativeValue<F, G>,
+    right: &NativeValue<F, G>,
+    operation: &str,
+) -> Result<bool, ValueError> {
+    let ordering = match (left, right) {
+        (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => integer_cmp(num_1, num_2),
+        (_, _) => None,
+    }
+    .ok_or_else(|| ValueError::incompatible_types(&*format!("{} {} {}", left, operation, right)))?;
+
+    Ok(match operation {
+        "<" => ordering == Ordering::Less,
+        "<=" => ordering != Ordering::Greater,
+        ">" => ordering == Ordering::Greater,
+        _ => ordering != Ordering::Less,
+    })
+}
+
+/// Returns `first` if `condition` holds and `second` otherwise, as long as both values have the same shape.
+pub fn select<F: PrimeField, G: GroupType<F>>(
+    condition: bool,
+    first: NativeValue<F, G>,
+    second: NativeValue<F, G>,
+) -> Result<NativeValue<F, G>, ValueError> {
+    fn same_shape<F: PrimeField, G: GroupType<F>>(first: &NativeValue<F, G>, second: &NativeValue<F, G>) -> bool {
+        match (first, second) {
+            (NativeValue::Address(_), NativeValue::Address(_))
+            | (NativeValue::Boolean(_), NativeValue::Boolean(_))
+            | (NativeValue::Char(_), NativeValue::Char(_))
+            | (NativeValue::Field(_), NativeValue::Field(_))
+            | (NativeValue::Group(_), NativeValue::Group(_)) => true,
+            (NativeValue::Integer(num_1), NativeValue::Integer(num_2)) => integer_cmp(num_1, num_2).is_some(),
+            (NativeValue::Array(items_1), NativeValue::Array(items_2))
+            | (NativeValue::Tuple(items_1), NativeValue::Tuple(items_2)) => {
+                items_1.len() == items_2.len() && items_1.iter().zip(items_2.iter()).all(|(a, b)| same_shape(a, b))
+            }
+            (_, _) => false,
+        }
+    }
+
+    if !same_shape(&first, &second) {
+        return Err(ValueError::incompatible_types(&*format!(
+            "{} ? {} : {}",
+            condition, first, second
+        )));
+    }
+
+    Ok(if condition { first } else { second })
+}
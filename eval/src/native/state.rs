@@ -0,0 +1,364 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::convert::TryInto;
+
+use anyhow::Context;
+use snarkvm_ir::{CallData, Function, Integer as IrInteger, MaskData, RepeatData};
+
+use super::*;
+
+/// the variables and result of a single scope
+#[derive(Clone, Debug)]
+pub(super) struct EvaluatorState<F: PrimeField, G: GroupType<F>> {
+    variables: IndexMap<u32, NativeValue<F, G>>,
+    parent_variables: IndexMap<u32, NativeValue<F, G>>,
+    /// the value returned by the scope, if anything has been returned yet
+    result: Option<NativeValue<F, G>>,
+}
+
+impl<F: PrimeField, G: GroupType<F>> EvaluatorState<F, G> {
+    /// creates a new state with no variables
+    pub fn new() -> Self {
+        Self {
+            variables: IndexMap::new(),
+            parent_variables: IndexMap::new(),
+            result: None,
+        }
+    }
+
+    /// returns every variable visible from this scope
+    fn visible_variables(&self) -> IndexMap<u32, NativeValue<F, G>> {
+        let mut variables = self.parent_variables.clone();
+        variables.extend(self.variables.clone());
+        variables
+    }
+
+    /// creates a nested scope that can read every variable visible from this scope
+    fn child(&self) -> Self {
+        Self {
+            variables: IndexMap::new(),
+            parent_variables: self.visible_variables(),
+            result: None,
+        }
+    }
+
+    fn is_visible(&self, variable: u32) -> bool {
+        self.variables.contains_key(&variable) || self.parent_variables.contains_key(&variable)
+    }
+
+    pub fn resolve<'b>(&'b self, value: &Value) -> Result<Cow<'b, NativeValue<F, G>>> {
+        match value {
+            Value::Ref(i) => Ok(Cow::Borrowed(
+                self.variables
+                    .get(i)
+                    .or_else(|| self.parent_variables.get(i))
+                    .ok_or_else(|| anyhow!("reference to unknown variable"))?,
+            )),
+            Value::Array(items) => Ok(Cow::Owned(NativeValue::Array(
+                items
+                    .iter()
+                    .map(|item| self.resolve(item).map(Cow::into_owned))
+                    .collect::<Result<_>>()?,
+            ))),
+            Value::Tuple(items) => Ok(Cow::Owned(NativeValue::Tuple(
+                items
+                    .iter()
+                    .map(|item| self.resolve(item).map(Cow::into_owned))
+                    .collect::<Result<_>>()?,
+            ))),
+            value => Ok(Cow::Owned(NativeValue::constant(value)?)),
+        }
+    }
+
+    pub fn store(&mut self, variable: u32, value: NativeValue<F, G>) {
+        self.variables.insert(variable, value);
+    }
+
+    pub fn handle_input_block(&mut self, input_header: &[IrInput], input_values: &IndexMap<String, Value>) -> Result<()> {
+        for ir_input in input_header {
+            let value = input_values
+                .get(&ir_input.name)
+                .ok_or_else(|| anyhow!("missing input value for '{}'", ir_input.name))?;
+            if !value.matches_input_type(&ir_input.type_) {
+                return Err(anyhow!(
+                    "type mismatch for input '{}', expected {}",
+                    ir_input.name,
+                    ir_input.type_
+                ));
+            }
+            let value = NativeValue::from_input(&ir_input.type_, value)?;
+            self.variables.insert(ir_input.variable, value);
+        }
+        Ok(())
+    }
+
+    pub fn handle_const_input_block(
+        &mut self,
+        input_header: &[IrInput],
+        input_values: &IndexMap<String, Value>,
+    ) -> Result<()> {
+        for ir_input in input_header {
+            let value = input_values
+                .get(&ir_input.name)
+                .ok_or_else(|| anyhow!("missing input value for '{}'", ir_input.name))?;
+            if !value.matches_input_type(&ir_input.type_) {
+                return Err(anyhow!(
+                    "type mismatch for input '{}', expected {}",
+                    ir_input.name,
+                    ir_input.type_
+                ));
+            }
+            let value = self.resolve(value)?.into_owned();
+            self.variables.insert(ir_input.variable, value);
+        }
+        Ok(())
+    }
+}
+
+/// evaluates the functions of a program, recursing into the blocks of call, mask, and repeat instructions
+pub(super) struct FunctionEvaluator<'a, F: PrimeField, G: GroupType<F>> {
+    program: &'a Program,
+    /// the index and arguments of every function currently being evaluated
+    call_stack: Vec<(u32, Vec<NativeValue<F, G>>)>,
+}
+
+impl<'a, F: PrimeField, G: GroupType<F>> FunctionEvaluator<'a, F, G> {
+    pub fn new(program: &'a Program) -> Self {
+        Self {
+            program,
+            call_stack: Vec::new(),
+        }
+    }
+
+    fn function(&self, index: u32) -> Result<&'a Function> {
+        self.program
+            .functions
+            .get(index as usize)
+            .ok_or_else(|| anyhow!("missing function"))
+    }
+
+    /// evaluates the entrypoint of the program in a state holding the program inputs
+    pub fn evaluate_main(mut self, mut state: EvaluatorState<F, G>) -> Result<NativeValue<F, G>> {
+        let function = self.function(0)?;
+        self.call_stack.push((0, Vec::new()));
+        self.evaluate_block(0, function, &mut state, 0, function.instructions.len() as u32)?;
+        Ok(state.result.unwrap_or_else(|| NativeValue::Tuple(vec![])))
+    }
+
+    /// evaluates the target call instruction and returns the value returned by the called function
+    fn evaluate_call(&mut self, data: &CallData, state: &EvaluatorState<F, G>) -> Result<NativeValue<F, G>> {
+        let arguments = data
+            .arguments
+            .iter()
+            .map(|x| state.resolve(x).map(Cow::into_owned))
+            .collect::<Result<Vec<_>>>()?;
+
+        let function = self.function(data.index)?;
+        let mut inner = state.child();
+        for (register, argument) in (function.argument_start_variable..).zip(arguments.iter()) {
+            inner.store(register, argument.clone());
+        }
+
+        // the entrypoint is at depth 1
+        if self.call_stack.len() as u32 + 1 > self.program.header.inline_limit {
+            return Err(anyhow!("max inline limit hit"));
+        } else if self
+            .call_stack
+            .iter()
+            .any(|(index, old_arguments)| *index == data.index && *old_arguments == arguments)
+        {
+            return Err(anyhow!("infinite recursion detected"));
+        }
+
+        self.call_stack.push((data.index, arguments));
+        let result = self.evaluate_block(data.index, function, &mut inner, 0, function.instructions.len() as u32);
+        self.call_stack.pop();
+        result?;
+
+        Ok(inner.result.unwrap_or_else(|| NativeValue::Tuple(vec![])))
+    }
+
+    /// evaluates the block of the target mask instruction if its condition holds, and updates variables from it
+    fn evaluate_mask(
+        &mut self,
+        data: &MaskData,
+        function_index: u32,
+        function: &'a Function,
+        state: &mut EvaluatorState<F, G>,
+        instruction_index: u32,
+    ) -> Result<()> {
+        if data.instruction_count + instruction_index >= function.instructions.len() as u32 {
+            return Err(anyhow!("illegal mask block length"));
+        }
+
+        let condition = state
+            .resolve(&data.condition)?
+            .extract_bool()
+            .map_err(|value| anyhow!("illegal condition type for conditional block: {}", value))?;
+
+        if condition {
+            let mut inner = state.child();
+            inner.result = state.result.clone();
+            self.evaluate_block(
+                function_index,
+                function,
+                &mut inner,
+                instruction_index + 1,
+                data.instruction_count,
+            )?;
+
+            for (variable, value) in inner.variables {
+                if state.is_visible(variable) {
+                    state.store(variable, value);
+                }
+            }
+            if inner.result.is_some() {
+                state.result = inner.result;
+            }
+        }
+        Ok(())
+    }
+
+    /// evaluates the block of the target repeat instruction once per iteration, carrying assigned variables between iterations
+    fn evaluate_repeat(
+        &mut self,
+        data: &RepeatData,
+        function_index: u32,
+        function: &'a Function,
+        state: &mut EvaluatorState<F, G>,
+        instruction_index: u32,
+    ) -> Result<()> {
+        if data.instruction_count + instruction_index >= function.instructions.len() as u32 {
+            return Err(anyhow!("illegal repeat block length"));
+        }
+
+        let from = state.resolve(&data.from)?;
+        let from_int = *from
+            .extract_integer()
+            .map_err(|value| anyhow!("illegal type for loop init: {}", value))?;
+        let from = integer_to_usize(&from_int).ok_or_else(|| anyhow!("illegal input-derived loop index"))?;
+
+        let to = state.resolve(&data.to)?;
+        let to = to
+            .extract_integer()
+            .map_err(|value| anyhow!("illegal type for loop terminator: {}", value))?;
+        let to = integer_to_usize(to).ok_or_else(|| anyhow!("illegal input-derived loop terminator"))?;
+
+        let iter: Box<dyn Iterator<Item = usize>> = match (from < to, data.inclusive) {
+            (true, true) => Box::new(from..=to),
+            (true, false) => Box::new(from..to),
+            (false, true) => Box::new((to..=from).rev()),
+            // add the range to the values to get correct bound
+            (false, false) => Box::new(((to + 1)..(from + 1)).rev()),
+        };
+
+        //todo: max loop count (DOS vector)
+        let mut indices = Vec::new();
+        for i in iter {
+            indices.push(NativeValue::Integer(match from_int {
+                IrInteger::U8(_) => IrInteger::U8(
+                    i.try_into()
+                        .map_err(|_| anyhow!("loop index out of range for u8"))?,
+                ),
+                IrInteger::U16(_) => IrInteger::U16(
+                    i.try_into()
+                        .map_err(|_| anyhow!("loop index out of range for u16"))?,
+                ),
+                IrInteger::U32(_) => IrInteger::U32(
+                    i.try_into()
+                        .map_err(|_| anyhow!("loop index out of range for u32"))?,
+                ),
+                _ => return Err(anyhow!("illegal type for loop index")),
+            }));
+        }
+
+        let parent_variables = state.visible_variables();
+        let mut assignments = IndexMap::new();
+        for index in indices {
+            let mut inner = EvaluatorState {
+                variables: IndexMap::new(),
+                parent_variables: parent_variables.clone(),
+                result: state.result.clone(),
+            };
+            inner.store(data.iter_variable, index);
+            inner.variables.extend(assignments);
+
+            // a return ends the current iteration, and its value is discarded
+            self.evaluate_block(
+                function_index,
+                function,
+                &mut inner,
+                instruction_index + 1,
+                data.instruction_count,
+            )?;
+
+            assignments = inner
+                .variables
+                .into_iter()
+                .filter(|(variable, _)| *variable != data.iter_variable && parent_variables.contains_key(variable))
+                .collect();
+        }
+        for (variable, value) in assignments {
+            state.store(variable, value);
+        }
+        Ok(())
+    }
+
+    /// evaluates `instruction_count` instructions of the function starting at `block_start`, stopping early on a return
+    fn evaluate_block(
+        &mut self,
+        function_index: u32,
+        function: &'a Function,
+        state: &mut EvaluatorState<F, G>,
+        block_start: u32,
+        block_instruction_count: u32,
+    ) -> Result<()> {
+        let mut instruction_index = block_start;
+        while instruction_index < block_start + block_instruction_count {
+            let instruction = function
+                .instructions
+                .get(instruction_index as usize)
+                .ok_or_else(|| anyhow!("f#{} i#{}: missing instruction", function_index, instruction_index))?;
+            match instruction {
+                Instruction::Call(data) => {
+                    let result = self.evaluate_call(data, state)?;
+                    state.store(data.destination, result);
+                    instruction_index += 1;
+                }
+                Instruction::Mask(data) => {
+                    self.evaluate_mask(data, function_index, function, state, instruction_index)?;
+                    instruction_index += 1 + data.instruction_count;
+                }
+                Instruction::Repeat(data) => {
+                    self.evaluate_repeat(data, function_index, function, state, instruction_index)?;
+                    instruction_index += 1 + data.instruction_count;
+                }
+                instruction => match state
+                    .evaluate_instruction(instruction)
+                    .with_context(|| format!("f{}#{}: {}", function_index, instruction_index, instruction))?
+                {
+                    Some(returned) => {
+                        state.result = Some(returned);
+                        return Ok(());
+                    }
+                    None => instruction_index += 1,
+                },
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{convert::TryInto, fmt};
+
+use snarkvm_dpc::{account::address, testnet1::instantiated::Components};
+use snarkvm_fields::PrimeField;
+use snarkvm_ir::{Field, Integer as IrInteger, Type, Value};
+use snarkvm_utilities::{BigInteger, FromBytes};
+
+use crate::{
+    errors::{AddressError, FieldError, ValueError},
+    ConstrainedValue,
+    GroupType,
+};
+
+/// A value of a program evaluated by the [`NativeEvaluator`](super::NativeEvaluator).
+#[derive(Clone, Debug, PartialEq)]
+pub enum NativeValue<F: PrimeField, G: GroupType<F>> {
+    // Data types
+    Address(address::Address<Components>),
+    Boolean(bool),
+    Char(u32),
+    Field(F),
+    Group(G::Native),
+    Integer(IrInteger),
+
+    // Arrays
+    Array(Vec<NativeValue<F, G>>),
+
+    // Tuples
+    Tuple(Vec<NativeValue<F, G>>),
+}
+
+impl<F: PrimeField, G: GroupType<F>> NativeValue<F, G> {
+    pub fn extract_bool(&self) -> Result<bool, &Self> {
+        match self {
+            NativeValue::Boolean(x) => Ok(*x),
+            value => Err(value),
+        }
+    }
+
+    pub fn extract_integer(&self) -> Result<&IrInteger, &Self> {
+        match self {
+            NativeValue::Integer(x) => Ok(x),
+            value => Err(value),
+        }
+    }
+
+    pub fn extract_array(&self) -> Result<&Vec<Self>, &Self> {
+        match self {
+            NativeValue::Array(x) => Ok(x),
+            value => Err(value),
+        }
+    }
+
+    pub fn extract_tuple(&self) -> Result<&Vec<Self>, &Self> {
+        match self {
+            NativeValue::Tuple(x) => Ok(x),
+            value => Err(value),
+        }
+    }
+
+    /// Returns the native value of a constant IR value.
+    pub fn constant(value: &Value) -> Result<Self, ValueError> {
+        Ok(match value {
+            Value::Address(bytes) => NativeValue::Address(
+                address::Address::read_le(&mut &bytes[..])
+                    .map_err(|error| AddressError::account_error(error.into()))?,
+            ),
+            Value::Boolean(value) => NativeValue::Boolean(*value),
+            Value::Field(field) => NativeValue::Field(field_from_value(field)?),
+            Value::Char(c) => NativeValue::Char(*c),
+            Value::Group(g) => NativeValue::Group(G::native(g)?),
+            Value::Integer(i) => NativeValue::Integer(*i),
+            Value::Array(items) => NativeValue::Array(items.iter().map(Self::constant).collect::<Result<_, _>>()?),
+            Value::Tuple(items) => NativeValue::Tuple(items.iter().map(Self::constant).collect::<Result<_, _>>()?),
+            Value::Str(_) => return Err(ValueError::incompatible_types("cannot have resolved control string")),
+            Value::Ref(_) => return Err(ValueError::incompatible_types("cannot have constant variable reference")),
+        })
+    }
+
+    /// Returns the native value of a program input of type `type_`.
+    pub fn from_input(type_: &Type, value: &Value) -> Result<Self, ValueError> {
+        Ok(match type_ {
+            Type::Array(inner, len) => {
+                let values = match value {
+                    Value::Array(x) => x,
+                    value => return Err(ValueError::bad_value_for_type(&*type_.to_string(), &*value.to_string())),
+                };
+                if let Some(len) = len {
+                    if values.len() != *len as usize {
+                        return Err(ValueError::bad_value_for_type(
+                            &*type_.to_string(),
+                            &*format!("array of length {}", values.len()),
+                        ));
+                    }
+                }
+                NativeValue::Array(
+                    values
+                        .iter()
+                        .map(|value| Self::from_input(&**inner, value))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            Type::Tuple(inner) => {
+                let values = match value {
+                    Value::Tuple(x) => x,
+                    value => return Err(ValueError::bad_value_for_type(&*type_.to_string(), &*value.to_string())),
+                };
+                if values.len() != inner.len() {
+                    return Err(ValueError::bad_value_for_type(
+                        &*type_.to_string(),
+                        &*format!("tuple of length {}", values.len()),
+                    ));
+                }
+                NativeValue::Tuple(
+                    values
+                        .iter()
+                        .zip(inner.iter())
+                        .map(|(value, type_)| Self::from_input(type_, value))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            Type::Circuit(_) => return Err(ValueError::incompatible_types("cannot have circuit input")),
+            type_ => {
+                if !value.matches_input_type(type_) {
+                    return Err(ValueError::bad_value_for_type(&*type_.to_string(), &*value.to_string()));
+                }
+                Self::constant(value)?
+            }
+        })
+    }
+
+    /// Returns the native value of a constrained value, or `None` if any part of it is unknown.
+    pub fn from_constrained(value: &ConstrainedValue<F, G>) -> Option<Self> {
+        Some(match value {
+            ConstrainedValue::Address(address) => NativeValue::Address(address.address.clone()),
+            ConstrainedValue::Boolean(boolean) => NativeValue::Boolean(boolean.get_value()?),
+            ConstrainedValue::Char(character) => NativeValue::Char(character.character),
+            ConstrainedValue::Field(field) => NativeValue::Field(field.get_value()?),
+            ConstrainedValue::Group(group) => NativeValue::Group(group.native_value()?),
+            ConstrainedValue::Integer(integer) => NativeValue::Integer(integer.native_value()?),
+            ConstrainedValue::Array(items) => {
+                NativeValue::Array(items.iter().map(Self::from_constrained).collect::<Option<_>>()?)
+            }
+            ConstrainedValue::Tuple(items) => {
+                NativeValue::Tuple(items.iter().map(Self::from_constrained).collect::<Option<_>>()?)
+            }
+        })
+    }
+}
+
+/// Returns the value of an unsigned integer as a `usize`, or `None` if it is signed or too large.
+pub(super) fn integer_to_usize(integer: &IrInteger) -> Option<usize> {
+    match *integer {
+        IrInteger::U8(x) => Some(x as usize),
+        IrInteger::U16(x) => Some(x as usize),
+        IrInteger::U32(x) => x.try_into().ok(),
+        IrInteger::U64(x) => x.try_into().ok(),
+        IrInteger::U128(x) => x.try_into().ok(),
+        _ => None,
+    }
+}
+
+/// Returns the field element of a constant IR field value.
+pub(super) fn field_from_value<F: PrimeField>(number: &Field) -> Result<F, FieldError> {
+    let value = F::from_repr(<F as PrimeField>::BigInteger::from_slice(&number.values[..]))
+        .ok_or_else(|| FieldError::invalid_field(format!("{}", number)))?;
+
+    Ok(if number.negate { -value } else { value })
+}
+
+impl<F: PrimeField, G: GroupType<F>> fmt::Display for NativeValue<F, G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            // Data types
+            NativeValue::Address(ref value) => write!(f, "{}", value),
+            NativeValue::Boolean(ref value) => write!(f, "{}", value),
+            NativeValue::Char(value) => match std::char::from_u32(value) {
+                Some(c) => write!(f, "{}", c.escape_default()),
+                None => write!(f, "\\u{{{:X}}}", value),
+            },
+            NativeValue::Field(ref value) => write!(f, "{}", value),
+            NativeValue::Group(ref value) => write!(f, "{}", value),
+            NativeValue::Integer(ref value) => write!(f, "{}", value),
+
+            // Data type wrappers
+            NativeValue::Array(ref array) => {
+                if matches!(array.first(), Some(NativeValue::Char(_))) {
+                    for character in array {
+                        write!(f, "{}", character)?;
+                    }
+
+                    Ok(())
+                } else {
+                    let values = array.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+
+                    write!(f, "[{}]", values)
+                }
+            }
+            NativeValue::Tuple(ref tuple) => {
+                let values = tuple.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+
+                write!(f, "({})", values)
+            }
+        }
+    }
+}
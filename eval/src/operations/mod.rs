@@ -55,3 +55,27 @@ pub use or::*;
 
 mod not;
 pub use not::*;
+
+mod bit_and;
+pub use bit_and::*;
+
+mod bit_or;
+pub use bit_or::*;
+
+mod bit_xor;
+pub use bit_xor::*;
+
+mod bit_not;
+pub use bit_not::*;
+
+mod shl;
+pub use shl::*;
+
+mod shr;
+pub use shr::*;
+
+mod shr_signed;
+pub use shr_signed::*;
+
+mod rem;
+pub use rem::*;
@@ -44,7 +44,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
         )?;
         let bounds_check = match bounds_check {
             ConstrainedValue::Boolean(b) => b,
-            _ => unimplemented!("illegal non-Integer returned from lt"),
+            value => return Err(ValueError::bad_value_for_type("bool", &*value.to_string()).into()),
         };
         let namespace_string = format!("evaluate array access bounds");
         let mut unique_namespace = cs.ns(|| namespace_string);
@@ -62,11 +62,11 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
         let (destination, values) = if let Instruction::ArrayIndexGet(QueryData { destination, values }) = instruction {
             (destination, values)
         } else {
-            unimplemented!("unsupported instruction in evaluate_array_index_get");
+            return Err(anyhow!("unsupported instruction in evaluate_array_index_get: {}", instruction));
         };
 
-        let index = self.resolve(values.get(1).unwrap(), cs)?.into_owned();
-        let array = self.resolve(values.get(0).unwrap(), cs)?.into_owned();
+        let index = self.resolve(operand(values, 1)?, cs)?.into_owned();
+        let array = self.resolve(operand(values, 0)?, cs)?.into_owned();
         let index_resolved = index
             .extract_integer()
             .map_err(|value| anyhow!("invalid value for array index: {}", value))?;
@@ -74,10 +74,10 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
             .extract_array()
             .map_err(|value| anyhow!("invalid array for array index: {}", value))?;
         let out = if let Some(index) = index_resolved.to_usize() {
-            if index >= array.len() {
-                return Err(ArrayError::array_index_out_of_bounds(index, array.len()).into());
-            }
-            array.get(index).cloned().unwrap()
+            array
+                .get(index)
+                .cloned()
+                .ok_or_else(|| ArrayError::array_index_out_of_bounds(index, array.len()))?
         } else if array.is_empty() {
             return Err(ArrayError::array_index_out_of_bounds(0, 0).into());
         } else {
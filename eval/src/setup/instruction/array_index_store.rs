@@ -26,13 +26,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
         {
             (destination, values)
         } else {
-            unimplemented!("unsupported instruction in evaluate_array_index_store");
+            return Err(anyhow!("unsupported instruction in evaluate_array_index_store: {}", instruction));
         };
 
         //todo: optimize array copies here
 
-        let index = self.resolve(values.get(0).unwrap(), cs)?.into_owned();
-        let target = self.resolve(values.get(1).unwrap(), cs)?.into_owned();
+        let index = self.resolve(operand(values, 0)?, cs)?.into_owned();
+        let target = self.resolve(operand(values, 1)?, cs)?.into_owned();
         let array = self.resolve(&Value::Ref(*destination), cs)?.into_owned();
 
         let index_resolved = index
@@ -25,22 +25,22 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
         let (destination, values) = if let Instruction::ArraySliceGet(QueryData { destination, values }) = instruction {
             (destination, values)
         } else {
-            unimplemented!("unsupported instruction in evaluate_array_slice_get");
+            return Err(anyhow!("unsupported instruction in evaluate_array_slice_get: {}", instruction));
         };
 
-        let array = self.resolve(values.get(0).unwrap(), cs)?.into_owned();
+        let array = self.resolve(operand(values, 0)?, cs)?.into_owned();
         let array = array
             .extract_array()
             .map_err(|value| anyhow!("illegal value for array slice: {}", value))?;
-        let from = self.resolve(values.get(1).unwrap(), cs)?.into_owned();
+        let from = self.resolve(operand(values, 1)?, cs)?.into_owned();
         let from_resolved = from
             .extract_integer()
             .map_err(|value| anyhow!("invalid value for array slice from index: {}", value))?;
-        let to = self.resolve(values.get(2).unwrap(), cs)?.into_owned();
+        let to = self.resolve(operand(values, 2)?, cs)?.into_owned();
         let to_resolved = to
             .extract_integer()
             .map_err(|value| anyhow!("invalid value for array slice to index: {}", value))?;
-        let length = self.resolve(values.get(3).unwrap(), cs)?.into_owned();
+        let length = self.resolve(operand(values, 3)?, cs)?.into_owned();
         let length = length
             .extract_integer()
             .map_err(|value| anyhow!("invalid value for array slice length: {}", value))?
@@ -49,7 +49,11 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
 
         let const_dimensions = match (from_resolved.to_usize(), to_resolved.to_usize()) {
             (Some(from), Some(to)) => Some((from, to)),
-            (Some(from), None) => Some((from, from + length)),
+            (Some(from), None) => Some((
+                from,
+                from.checked_add(length)
+                    .ok_or_else(ArrayError::array_invalid_slice_length)?,
+            )),
             (None, Some(to)) => {
                 if to < length {
                     return Err(ArrayError::array_invalid_slice_length().into());
@@ -60,7 +64,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
         };
 
         let out = if let Some((left, right)) = const_dimensions {
-            if right - left != length {
+            if right.checked_sub(left) != Some(length) {
                 return Err(ArrayError::array_invalid_slice_length().into());
             }
             if right > array.len() {
@@ -77,7 +81,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
                 )?;
                 let calc_len = match calc_len {
                     ConstrainedValue::Integer(i) => i,
-                    _ => unimplemented!("illegal non-Integer returned from sub"),
+                    value => return Err(ValueError::bad_value_for_type("integer", &*value.to_string()).into()),
                 };
                 let namespace_string = format!("evaluate array range access length check");
                 let mut unique_namespace = cs.ns(|| namespace_string);
@@ -95,7 +99,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
                         .map_err(|_| ArrayError::array_length_out_of_bounds())?,
                 )?;
             }
-            let mut windows = array.windows(length);
+            // an empty slice selects from no windows, and `windows` rejects a size of zero
+            let mut windows = array.windows(length.max(1));
             let mut result = ConstrainedValue::Array(vec![]);
 
             for i in 0..length {
@@ -114,7 +119,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
                 )?;
                 let equality = match equality {
                     ConstrainedValue::Boolean(b) => b,
-                    _ => unimplemented!("unexpected non-Boolean for evaluate_eq"),
+                    value => return Err(ValueError::bad_value_for_type("bool", &*value.to_string()).into()),
                 };
 
                 let unique_namespace = unique_namespace.ns(|| format!("array index {}", i));
@@ -26,52 +26,52 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
         {
             (destination, values)
         } else {
-            unimplemented!("unsupported instruction in evaluate_array_slice_get");
+            return Err(anyhow!("unsupported instruction in evaluate_array_slice_store: {}", instruction));
         };
 
         let array = self.resolve(&Value::Ref(*destination), cs)?.into_owned();
         let array = array
             .extract_array()
             .map_err(|value| anyhow!("illegal target for array slice store: {}", value))?;
-        let from = self.resolve(values.get(0).unwrap(), cs)?.into_owned();
+        let from = self.resolve(operand(values, 0)?, cs)?.into_owned();
         let from_resolved = from
             .extract_integer()
             .map_err(|value| anyhow!("invalid value for array slice store from index: {}", value))?;
-        let to = self.resolve(values.get(1).unwrap(), cs)?.into_owned();
+        let to = self.resolve(operand(values, 1)?, cs)?.into_owned();
         let to_resolved = to
             .extract_integer()
             .map_err(|value| anyhow!("invalid value for array slice store to index: {}", value))?;
-        let target = self.resolve(values.get(2).unwrap(), cs)?.into_owned();
+        let target = self.resolve(operand(values, 2)?, cs)?.into_owned();
         let target_values = target
             .extract_array()
             .map_err(|value| anyhow!("illegal value for array slice store: {}", value))?;
 
-        let const_dimensions = match (from_resolved.to_usize(), to_resolved.to_usize()) {
-            (Some(from), Some(to)) => Some((from, to)),
-            (Some(from), None) => Some((from, from + target_values.len())),
+        let (left, right) = match (from_resolved.to_usize(), to_resolved.to_usize()) {
+            (Some(from), Some(to)) => (from, to),
+            (Some(from), None) => (
+                from,
+                from.checked_add(target_values.len())
+                    .ok_or_else(ArrayError::array_invalid_slice_length)?,
+            ),
             (None, Some(to)) => {
                 if to < target_values.len() {
                     return Err(ArrayError::array_invalid_slice_length().into());
                 }
-                Some((to - target_values.len(), to))
+                (to - target_values.len(), to)
             }
+            // todo: dynamic array slice assignment
             (None, None) => return Err(anyhow!("illegal reference to non-const array slice store index")),
         };
 
-        let out = if let Some((left, right)) = const_dimensions {
-            if right - left != target_values.len() {
-                return Err(ArrayError::array_invalid_slice_length().into());
-            }
-            if right > array.len() {
-                return Err(ArrayError::array_index_out_of_bounds(right, array.len()).into());
-            }
-            let mut new_value = array.clone();
-            let _: Vec<_> = new_value.splice(left..right, target_values.iter().cloned()).collect();
-            ConstrainedValue::Array(new_value)
-        } else {
-            unimplemented!("dynamic array slice assignment");
-        };
-        self.store(*destination, out);
+        if right.checked_sub(left) != Some(target_values.len()) {
+            return Err(ArrayError::array_invalid_slice_length().into());
+        }
+        if right > array.len() {
+            return Err(ArrayError::array_index_out_of_bounds(right, array.len()).into());
+        }
+        let mut out = array.clone();
+        let _: Vec<_> = out.splice(left..right, target_values.iter().cloned()).collect();
+        self.store(*destination, ConstrainedValue::Array(out));
         Ok(())
     }
 }
@@ -16,7 +16,8 @@
 
 use super::*;
 
-mod blake2s;
+pub mod blake2s;
+pub use blake2s::*;
 pub mod common;
 pub use common::*;
 mod from_bits;
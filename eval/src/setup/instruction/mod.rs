@@ -59,18 +59,25 @@ mod core;
 
 pub use self::core::*;
 
+/// Returns the operand at `index` of an instruction.
+fn operand(values: &[Value], index: usize) -> Result<&Value> {
+    values
+        .get(index)
+        .ok_or_else(|| anyhow!("missing operand {} for instruction", index))
+}
+
 impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
     fn resolve_binary<CS: ConstraintSystem<F>>(
         &mut self,
         data: &QueryData<2>,
         cs: &mut CS,
     ) -> Result<(ConstrainedValue<F, G>, ConstrainedValue<F, G>)> {
-        let left = self.resolve(data.values.get(0).unwrap(), cs)?.into_owned();
-        let right = self.resolve(data.values.get(1).unwrap(), cs)?.into_owned();
+        let left = self.resolve(operand(&data.values, 0)?, cs)?.into_owned();
+        let right = self.resolve(operand(&data.values, 1)?, cs)?.into_owned();
         Ok((left, right))
     }
 
-    /// Evaluates a single instruction in the local [`EvaluatorState`] context. Panics if `instruction` is a control instruction.
+    /// Evaluates a single instruction in the local [`EvaluatorState`] context. Fails if `instruction` is a control instruction.
     pub(super) fn evaluate_instruction<'b, CS: ConstraintSystem<F>>(
         &mut self,
         instruction: &'b Instruction,
@@ -143,24 +150,56 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
                 let out = operations::evaluate_lt(&mut self.cs(cs), left, right)?;
                 self.store(data.destination, out);
             }
-            Instruction::BitOr(_) => return Err(anyhow!("BitOr unimplemented")),
-            Instruction::BitAnd(_) => return Err(anyhow!("BitAnd unimplemented")),
-            Instruction::BitXor(_) => return Err(anyhow!("BitXor unimplemented")),
-            Instruction::Shr(_) => return Err(anyhow!("Shr unimplemented")),
-            Instruction::ShrSigned(_) => return Err(anyhow!("ShrSigned unimplemented")),
-            Instruction::Shl(_) => return Err(anyhow!("Shl unimplemented")),
-            Instruction::Mod(_) => return Err(anyhow!("Mod unimplemented")),
+            Instruction::BitOr(data) => {
+                let (left, right) = self.resolve_binary(data, cs)?;
+                let out = operations::enforce_bit_or(&mut self.cs(cs), left, right)?;
+                self.store(data.destination, out);
+            }
+            Instruction::BitAnd(data) => {
+                let (left, right) = self.resolve_binary(data, cs)?;
+                let out = operations::enforce_bit_and(&mut self.cs(cs), left, right)?;
+                self.store(data.destination, out);
+            }
+            Instruction::BitXor(data) => {
+                let (left, right) = self.resolve_binary(data, cs)?;
+                let out = operations::enforce_bit_xor(&mut self.cs(cs), left, right)?;
+                self.store(data.destination, out);
+            }
+            Instruction::Shr(data) => {
+                let (left, right) = self.resolve_binary(data, cs)?;
+                let out = operations::enforce_shr(&mut self.cs(cs), left, right)?;
+                self.store(data.destination, out);
+            }
+            Instruction::ShrSigned(data) => {
+                let (left, right) = self.resolve_binary(data, cs)?;
+                let out = operations::enforce_shr_signed(&mut self.cs(cs), left, right)?;
+                self.store(data.destination, out);
+            }
+            Instruction::Shl(data) => {
+                let (left, right) = self.resolve_binary(data, cs)?;
+                let out = operations::enforce_shl(&mut self.cs(cs), left, right)?;
+                self.store(data.destination, out);
+            }
+            Instruction::Mod(data) => {
+                let (left, right) = self.resolve_binary(data, cs)?;
+                let out = operations::enforce_rem(&mut self.cs(cs), left, right)?;
+                self.store(data.destination, out);
+            }
             Instruction::Not(QueryData { destination, values }) => {
-                let inner = self.resolve(values.get(0).unwrap(), cs)?.into_owned();
+                let inner = self.resolve(operand(values, 0)?, cs)?.into_owned();
                 let out = operations::evaluate_not(inner)?;
                 self.store(*destination, out);
             }
             Instruction::Negate(QueryData { destination, values }) => {
-                let inner = self.resolve(values.get(0).unwrap(), cs)?.into_owned();
+                let inner = self.resolve(operand(values, 0)?, cs)?.into_owned();
                 let out = operations::enforce_negate(&mut self.cs(cs), inner)?;
                 self.store(*destination, out);
             }
-            Instruction::BitNot(_) => return Err(anyhow!("BitNot unimplemented")),
+            Instruction::BitNot(QueryData { destination, values }) => {
+                let inner = self.resolve(operand(values, 0)?, cs)?.into_owned();
+                let out = operations::evaluate_bit_not(inner)?;
+                self.store(*destination, out);
+            }
             Instruction::ArrayInitRepeat(ArrayInitRepeatData {
                 destination,
                 length,
@@ -212,13 +251,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
             }
             Instruction::TupleIndexGet(QueryData { destination, values }) => {
                 let index = self
-                    .resolve(values.get(1).unwrap(), cs)?
+                    .resolve(operand(values, 1)?, cs)?
                     .extract_integer()
                     .map_err(|value| anyhow!("invalid index type for tuple index: {}", value))?
                     .to_usize()
                     .ok_or_else(|| anyhow!("illegal variable input for tuple index"))?;
 
-                let tuple = self.resolve(values.get(0).unwrap(), cs)?;
+                let tuple = self.resolve(operand(values, 0)?, cs)?;
                 let tuple = tuple
                     .extract_tuple()
                     .map_err(|value| anyhow!("invalid tuple type for tuple index: {}", value))?;
@@ -238,7 +277,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
             }
             Instruction::TupleIndexStore(QueryData { destination, values }) => {
                 let index = self
-                    .resolve(values.get(0).unwrap(), cs)?
+                    .resolve(operand(values, 0)?, cs)?
                     .extract_integer()
                     .map_err(|value| anyhow!("invalid index type for tuple store: {}", value))?
                     .to_usize()
@@ -258,40 +297,40 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
                         tuple_len
                     )
                 })?;
-                *out = self.resolve(values.get(1).unwrap(), cs)?.into_owned();
+                *out = self.resolve(operand(values, 1)?, cs)?.into_owned();
 
                 self.store(*destination, ConstrainedValue::Tuple(tuple));
             }
             Instruction::Pick(QueryData { destination, values }) => {
-                let condition = self.resolve(values.get(0).unwrap(), cs)?.into_owned();
+                let condition = self.resolve(operand(values, 0)?, cs)?.into_owned();
                 let condition = condition
                     .extract_bool()
                     .map_err(|value| anyhow!("invalid value for pick condition: {}", value))?;
-                let left = self.resolve(values.get(1).unwrap(), cs)?.into_owned();
-                let right = self.resolve(values.get(2).unwrap(), cs)?.into_owned();
+                let left = self.resolve(operand(values, 1)?, cs)?.into_owned();
+                let right = self.resolve(operand(values, 2)?, cs)?.into_owned();
                 let picked = ConstrainedValue::conditionally_select(&mut self.cs(cs), &condition, &left, &right)?;
                 self.store(*destination, picked);
             }
             Instruction::Mask(_) => {
-                panic!("cannot eval mask instructions directly");
+                return Err(anyhow!("cannot eval mask instructions directly"));
             }
             Instruction::Repeat(_) => {
-                panic!("cannot eval repeat instructions directly");
+                return Err(anyhow!("cannot eval repeat instructions directly"));
             }
             Instruction::Call(_) => {
-                panic!("cannot eval call instructions directly");
+                return Err(anyhow!("cannot eval call instructions directly"));
             }
             Instruction::Store(QueryData { destination, values }) => {
-                let value = self.resolve(values.get(0).unwrap(), cs)?.into_owned();
+                let value = self.resolve(operand(values, 0)?, cs)?.into_owned();
                 self.store(*destination, value);
             }
             Instruction::Return(PredicateData { values }) => {
-                let value = values.get(0).unwrap();
+                let value = operand(values, 0)?;
                 let value = self.resolve(value, cs)?.into_owned();
                 return Ok(Some(value));
             }
             Instruction::Assert(PredicateData { values }) => {
-                let value = values.get(0).unwrap();
+                let value = operand(values, 0)?;
                 let value = self.resolve(value, cs)?.into_owned();
                 match value {
                     ConstrainedValue::Boolean(b) => {
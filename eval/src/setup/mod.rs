@@ -36,20 +36,38 @@ use crate::{
 };
 
 mod instruction;
+mod source_map;
 mod state;
 
 pub use instruction::*;
+pub use source_map::*;
 use state::*;
 
 /// An evaluator for filling out a R1CS while also producing an expected output.
 pub struct SetupEvaluator<F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>> {
     cs: CS,
+    source_map: Option<SourceMap>,
     _p: PhantomData<(F, G)>,
 }
 
 impl<F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>> SetupEvaluator<F, G, CS> {
     pub fn new(cs: CS) -> Self {
-        Self { cs, _p: PhantomData }
+        Self {
+            cs,
+            source_map: None,
+            _p: PhantomData,
+        }
+    }
+
+    /// Enables attributing synthesized constraints to the instructions that produced them.
+    pub fn with_source_map(mut self) -> Self {
+        self.source_map = Some(SourceMap::default());
+        self
+    }
+
+    /// Returns the [`SourceMap`] of the last evaluation, if enabled via [`SetupEvaluator::with_source_map`].
+    pub fn source_map(&self) -> Option<&SourceMap> {
+        self.source_map.as_ref()
     }
 }
 
@@ -59,6 +77,11 @@ impl<F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>> Evaluator<F, G> fo
 
     fn evaluate(&mut self, program: &Program, input: &InputData) -> Result<Self::Output, Self::Error> {
         let mut state = EvaluatorState::new(program);
+        let mut source_map = self.source_map.as_mut().map(|source_map| {
+            *source_map = SourceMap::default();
+            source_map
+        });
+        let inputs_start = ConstraintCost::of::<F, _>(&self.cs);
 
         state.handle_input_block("main", &program.header.main_inputs, &input.main, &mut self.cs)?;
         state.handle_const_input_block(&program.header.constant_inputs, &input.constants, &mut self.cs)?;
@@ -86,8 +109,12 @@ impl<F: PrimeField, G: GroupType<F>, CS: ConstraintSystem<F>> Evaluator<F, G> fo
             &input.private_leaf_states,
             &mut self.cs,
         )?;
+        if let Some(source_map) = source_map.as_deref_mut() {
+            source_map.inputs = ConstraintCost::since::<F, _>(inputs_start, &self.cs);
+        }
+
         let function = state.setup_evaluate_function(0, &[])?;
-        let output = FunctionEvaluator::evaluate_function(function, state, 0, &mut self.cs)?; // arguments assigned via input system for entrypoint
+        let output = FunctionEvaluator::evaluate_function(function, state, 0, &mut self.cs, source_map)?; // arguments assigned via input system for entrypoint
         Ok(output)
     }
 }
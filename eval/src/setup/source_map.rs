@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{fmt, ops::AddAssign};
+
+use indexmap::IndexMap;
+use snarkvm_fields::Field;
+use snarkvm_ir::{CallCoreData, Instruction};
+use snarkvm_r1cs::ConstraintSystem;
+
+/// The number of constraints and variables synthesized by some part of a program.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintCost {
+    pub constraints: usize,
+    pub variables: usize,
+}
+
+impl ConstraintCost {
+    /// returns the current size of the constraint system
+    pub(crate) fn of<F: Field, CS: ConstraintSystem<F>>(cs: &CS) -> Self {
+        Self {
+            constraints: cs.num_constraints(),
+            variables: cs.num_public_variables() + cs.num_private_variables(),
+        }
+    }
+
+    /// returns the growth of the constraint system since `start` was measured
+    pub(crate) fn since<F: Field, CS: ConstraintSystem<F>>(start: Self, cs: &CS) -> Self {
+        let end = Self::of(cs);
+        Self {
+            constraints: end.constraints.saturating_sub(start.constraints),
+            variables: end.variables.saturating_sub(start.variables),
+        }
+    }
+}
+
+impl AddAssign for ConstraintCost {
+    fn add_assign(&mut self, other: Self) {
+        self.constraints += other.constraints;
+        self.variables += other.variables;
+    }
+}
+
+/// The location of an instruction within a program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InstructionLocation {
+    pub function: u32,
+    pub instruction: u32,
+}
+
+impl fmt::Display for InstructionLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "f#{}i#{}", self.function, self.instruction)
+    }
+}
+
+/// The accumulated cost of every evaluation of a single instruction.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InstructionCost {
+    /// the textual form of the instruction
+    pub instruction: String,
+    /// the number of times the instruction was evaluated, i.e. once per loop iteration or function call
+    pub evaluations: usize,
+    pub cost: ConstraintCost,
+}
+
+/// The accumulated cost of every call to a single core function.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CoreCallCost {
+    pub calls: usize,
+    pub cost: ConstraintCost,
+}
+
+/// Attributes the constraints synthesized by a [`SetupEvaluator`](crate::SetupEvaluator) to the instructions that produced them.
+///
+/// Constraints created by the body of a call, mask, or repeat block are attributed to the instructions within the block,
+/// while the control instruction itself is only charged for resolving its operands and, for masks, merging the
+/// block's assignments back into the enclosing scope.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    /// the cost of allocating the program's inputs
+    pub inputs: ConstraintCost,
+    /// the cost of each evaluated instruction, in order of first evaluation
+    pub instructions: IndexMap<InstructionLocation, InstructionCost>,
+    /// the cost of each core function called, keyed by its identifier
+    pub core_calls: IndexMap<String, CoreCallCost>,
+}
+
+impl SourceMap {
+    /// records a single evaluation of `instruction`
+    pub(crate) fn record(&mut self, function: u32, index: u32, instruction: &Instruction, cost: ConstraintCost) {
+        let entry = self
+            .instructions
+            .entry(InstructionLocation {
+                function,
+                instruction: index,
+            })
+            .or_insert_with(|| InstructionCost {
+                instruction: instruction.to_string(),
+                ..Default::default()
+            });
+        entry.evaluations += 1;
+        entry.cost += cost;
+
+        if let Instruction::CallCore(CallCoreData { identifier, .. }) = instruction {
+            let entry = self.core_calls.entry(identifier.clone()).or_default();
+            entry.calls += 1;
+            entry.cost += cost;
+        }
+    }
+
+    /// adds `cost` to an instruction that has already been recorded, without counting another evaluation
+    pub(crate) fn record_additional(&mut self, function: u32, index: u32, cost: ConstraintCost) {
+        if let Some(entry) = self.instructions.get_mut(&InstructionLocation {
+            function,
+            instruction: index,
+        }) {
+            entry.cost += cost;
+        }
+    }
+
+    /// returns the total cost of the inputs and every recorded instruction
+    pub fn total(&self) -> ConstraintCost {
+        let mut total = self.inputs;
+        for entry in self.instructions.values() {
+            total += entry.cost;
+        }
+        total
+    }
+
+    /// returns the total cost of each function's own instructions, ordered by function index
+    pub fn functions(&self) -> IndexMap<u32, ConstraintCost> {
+        let mut out = IndexMap::<u32, ConstraintCost>::new();
+        for (location, entry) in &self.instructions {
+            *out.entry(location.function).or_default() += entry.cost;
+        }
+        out.sort_keys();
+        out
+    }
+
+    /// returns up to `count` of the instructions with the most constraints, most expensive first
+    pub fn hottest(&self, count: usize) -> Vec<(&InstructionLocation, &InstructionCost)> {
+        let mut out = self.instructions.iter().collect::<Vec<_>>();
+        out.sort_by(|(a_location, a), (b_location, b)| {
+            b.cost
+                .constraints
+                .cmp(&a.cost.constraints)
+                .then(b.cost.variables.cmp(&a.cost.variables))
+                .then(a_location.cmp(b_location))
+        });
+        out.truncate(count);
+        out
+    }
+}
+
+impl fmt::Display for SourceMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.total();
+        writeln!(
+            f,
+            "total: {} constraints, {} variables",
+            total.constraints, total.variables
+        )?;
+        writeln!(
+            f,
+            "inputs: {} constraints, {} variables",
+            self.inputs.constraints, self.inputs.variables
+        )?;
+
+        writeln!(f, "functions:")?;
+        for (function, cost) in self.functions() {
+            writeln!(
+                f,
+                "  f#{}: {} constraints, {} variables",
+                function, cost.constraints, cost.variables
+            )?;
+        }
+
+        writeln!(f, "instructions:")?;
+        for (location, entry) in self.hottest(self.instructions.len()) {
+            writeln!(
+                f,
+                "  {}: {} constraints, {} variables over {} evaluation(s): {}",
+                location, entry.cost.constraints, entry.cost.variables, entry.evaluations, entry.instruction
+            )?;
+        }
+
+        if !self.core_calls.is_empty() {
+            writeln!(f, "core calls:")?;
+            for (identifier, entry) in &self.core_calls {
+                writeln!(
+                    f,
+                    "  {}: {} constraints, {} variables over {} call(s)",
+                    identifier, entry.cost.constraints, entry.cost.variables, entry.calls
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
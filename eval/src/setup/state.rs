@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use anyhow::Context;
 use snarkvm_gadgets::{Integer as GadgetInteger, UInt16, UInt32, UInt8};
 use snarkvm_ir::{CallData, Function, MaskData, RepeatData};
 
@@ -351,12 +352,14 @@ impl<'a, F: PrimeField, G: GroupType<F>> FunctionEvaluator<'a, F, G> {
     }
 
     /// iterates over every instruction in the function's code block and evaluates it.
-    /// if a new code block is hit (via mask, repeat, or call instructions) then the current blocks state is stored on a call stack while the new code block is evaluated
+    /// if a new code block is hit (via mask, repeat, or call instructions) then the current blocks state is stored on a call stack while the new code block is evaluated.
+    /// if a source map is given, the cost of every evaluated instruction is recorded in it
     pub fn evaluate_function<CS: ConstraintSystem<F>>(
         function: &'a Function,
         state: EvaluatorState<'a, F, G>,
         index: u32,
         cs: &mut CS,
+        mut source_map: Option<&mut SourceMap>,
     ) -> Result<ConstrainedValue<F, G>> {
         let mut evaluator = Self {
             call_stack: Vec::new(),
@@ -364,23 +367,36 @@ impl<'a, F: PrimeField, G: GroupType<F>> FunctionEvaluator<'a, F, G> {
             state_data: StateData::create_initial_state_data(state, function, Rc::new(Vec::new()), index)?,
         };
         loop {
-            match evaluator.state_data.evaluate_block(cs) {
-                Ok(Some(Instruction::Call(data))) => {
-                    evaluator.setup_call(data, cs)?;
-                }
-                Ok(Some(Instruction::Mask(data))) => {
-                    evaluator.setup_mask(data, cs)?;
-                }
-                Ok(Some(Instruction::Repeat(data))) => {
-                    evaluator.setup_repeat(data, cs)?;
+            match evaluator.state_data.evaluate_block(cs, source_map.as_deref_mut()) {
+                Ok(Some(instruction)) => {
+                    let function_index = evaluator.state_data.function_index;
+                    let instruction_index = evaluator.state_data.state.instruction_index;
+                    let start = ConstraintCost::of::<F, _>(cs);
+                    match instruction {
+                        Instruction::Call(data) => evaluator.setup_call(data, cs)?,
+                        Instruction::Mask(data) => evaluator.setup_mask(data, cs)?,
+                        Instruction::Repeat(data) => evaluator.setup_repeat(data, cs)?,
+                        e => return Err(anyhow!("invalid control instruction: {:?}", e)),
+                    }
+                    if let Some(source_map) = source_map.as_deref_mut() {
+                        let cost = ConstraintCost::since::<F, _>(start, cs);
+                        source_map.record(function_index, instruction_index, instruction, cost);
+                    }
                 }
-                Ok(Some(e)) => panic!("invalid control instruction: {:?}", e),
                 Ok(None) => match evaluator.state_data.parent_instruction {
                     ParentInstruction::Call(data) => {
                         evaluator.finish_call(data)?;
                     }
                     ParentInstruction::Mask(condition) => {
+                        // the merge is charged to the mask instruction, which directly precedes its block
+                        let function_index = evaluator.state_data.function_index;
+                        let instruction_index = evaluator.state_data.block_start - 1;
+                        let start = ConstraintCost::of::<F, _>(cs);
                         evaluator.finish_mask(condition, cs)?;
+                        if let Some(source_map) = source_map.as_deref_mut() {
+                            let cost = ConstraintCost::since::<F, _>(start, cs);
+                            source_map.record_additional(function_index, instruction_index, cost);
+                        }
                     }
                     ParentInstruction::Repeat(iter_variable) => {
                         evaluator.finish_repeat(iter_variable)?;
@@ -389,14 +405,8 @@ impl<'a, F: PrimeField, G: GroupType<F>> FunctionEvaluator<'a, F, G> {
                         return Ok(evaluator.finish_evaluation());
                     }
                 },
-                Err(e) => {
-                    return Err(anyhow!(
-                        "f#{} i#{}: {:?}",
-                        evaluator.state_data.function_index,
-                        evaluator.state_data.state.instruction_index,
-                        e
-                    ));
-                }
+                // the failing instruction was given as context when evaluating the block
+                Err(e) => return Err(e),
             }
         }
     }
@@ -484,10 +494,19 @@ impl<'a, F: PrimeField, G: GroupType<F>> StateData<'a, F, G> {
 
     /// evaluates each instruction in a block.
     /// if a control instruction was hit (mask, repeat, call) then it halts evaluation and returns the instruction
-    pub fn evaluate_block<CS: ConstraintSystem<F>>(&mut self, cs: &mut CS) -> Result<Option<&'a Instruction>> {
+    pub fn evaluate_block<CS: ConstraintSystem<F>>(
+        &mut self,
+        cs: &mut CS,
+        mut source_map: Option<&mut SourceMap>,
+    ) -> Result<Option<&'a Instruction>> {
         while self.state.instruction_index < self.block_start + self.block_instruction_count {
-            let instruction = &self.function.instructions[self.state.instruction_index as usize];
-            match self.evaluate_instruction(instruction, cs)? {
+            let function_index = self.function_index;
+            let instruction_index = self.state.instruction_index;
+            let instruction = &self.function.instructions[instruction_index as usize];
+            match self
+                .evaluate_instruction(instruction, cs, source_map.as_deref_mut())
+                .with_context(|| format!("f{}#{}: {}", function_index, instruction_index, instruction))?
+            {
                 ControlFlow::Recurse(ins) => return Ok(Some(ins)),
                 ControlFlow::Return => {
                     return Ok(None);
@@ -504,12 +523,13 @@ impl<'a, F: PrimeField, G: GroupType<F>> StateData<'a, F, G> {
         &mut self,
         instruction: &'a Instruction,
         cs: &mut CS,
+        source_map: Option<&mut SourceMap>,
     ) -> Result<ControlFlow<'a>> {
         match instruction {
             Instruction::Call(_) | Instruction::Mask(_) | Instruction::Repeat(_) => {
                 Ok(ControlFlow::Recurse(instruction))
             }
-            _ => match self.state.evaluate_instruction(instruction, self.condition, cs) {
+            _ => match self.evaluate_recorded_instruction(instruction, cs, source_map) {
                 Ok(Some(returned)) => {
                     self.result = Some(returned);
                     Ok(ControlFlow::Return)
@@ -522,6 +542,25 @@ impl<'a, F: PrimeField, G: GroupType<F>> StateData<'a, F, G> {
             },
         }
     }
+
+    /// evaluates a non-control instruction, recording its cost in the source map if one is given
+    fn evaluate_recorded_instruction<CS: ConstraintSystem<F>>(
+        &mut self,
+        instruction: &'a Instruction,
+        cs: &mut CS,
+        source_map: Option<&mut SourceMap>,
+    ) -> Result<Option<ConstrainedValue<F, G>>> {
+        let source_map = match source_map {
+            Some(source_map) => source_map,
+            None => return self.state.evaluate_instruction(instruction, self.condition, cs),
+        };
+        let instruction_index = self.state.instruction_index;
+        let start = ConstraintCost::of::<F, _>(cs);
+        let result = self.state.evaluate_instruction(instruction, self.condition, cs);
+        let cost = ConstraintCost::since::<F, _>(start, cs);
+        source_map.record(self.function_index, instruction_index, instruction, cost);
+        result
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -608,7 +647,7 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
                     Value::Array(x) => x,
                     value => return Err(ValueError::bad_value_for_type(&*type_.to_string(), &*value.to_string())),
                 };
-                if values.len() != len.unwrap() as usize {
+                if len.map(|len| values.len() != len as usize).unwrap_or(false) {
                     return Err(ValueError::bad_value_for_type(
                         &*type_.to_string(),
                         &*format!("array of length {}", values.len()),
@@ -749,7 +788,11 @@ impl<'a, F: PrimeField, G: GroupType<F>> EvaluatorState<'a, F, G> {
         index: u32,
         arguments: &[ConstrainedValue<F, G>],
     ) -> Result<&'a Function> {
-        let function = self.program.functions.get(index as usize).expect("missing function");
+        let function = self
+            .program
+            .functions
+            .get(index as usize)
+            .ok_or_else(|| anyhow!("missing function f#{}", index))?;
 
         let mut arg_register = function.argument_start_variable;
         for argument in arguments {
@@ -54,12 +54,27 @@ pub enum EdwardsGroupType {
 }
 
 impl GroupType<Fq> for EdwardsGroupType {
+    type Native = EdwardsAffine;
+
     fn constant(group: &Group) -> Result<Self, GroupError> {
         let value = Self::edwards_affine_from_value(group)?;
 
         Ok(EdwardsGroupType::Constant(value))
     }
 
+    fn native(group: &Group) -> Result<EdwardsAffine, GroupError> {
+        Self::edwards_affine_from_value(group)
+    }
+
+    fn native_value(&self) -> Option<EdwardsAffine> {
+        match self {
+            EdwardsGroupType::Constant(value) => Some(*value),
+            EdwardsGroupType::Allocated(allocated) => {
+                <EdwardsBls12Gadget as GroupGadget<Affine<EdwardsParameters>, Fq>>::get_value(allocated)
+            }
+        }
+    }
+
     fn to_allocated<CS: ConstraintSystem<Fq>>(&self, cs: CS) -> Result<Self, GroupError> {
         self.allocated(cs)
             .map(|ebg| EdwardsGroupType::Allocated(Box::new(ebg)))
@@ -340,49 +355,34 @@ impl PartialEq for EdwardsGroupType {
 
 impl Eq for EdwardsGroupType {}
 
-// fn compare_allocated_edwards_bls_gadgets<CS: ConstraintSystem<Fq>>(
-//     mut cs: CS,
-//     first: &EdwardsBls12Gadget,
-//     second: &EdwardsBls12Gadget,
-// ) -> Result<Boolean, SynthesisError> {
-//     // compare x coordinates
-//     let x_first = &first.x;
-//     let x_second = &second.x;
-//
-//     let compare_x = x_first.evaluate_equal(&mut cs.ns(|| format!("compare x")), x_second)?;
-//
-//     // compare y coordinates
-//     let y_first = &first.y;
-//     let y_second = &second.y;
-//
-//     let compare_y = y_first.evaluate_equal(&mut cs.ns(|| format!("compare y")), y_second)?;
-//
-//     Boolean::and(
-//         &mut cs.ns(|| format!("compare x and y results")),
-//         &compare_x,
-//         &compare_y,
-//     )
-// }
+fn compare_edwards_bls_gadgets<CS: ConstraintSystem<Fq>>(
+    mut cs: CS,
+    first: &EdwardsBls12Gadget,
+    second: &EdwardsBls12Gadget,
+) -> Result<Boolean, SynthesisError> {
+    let compare_x = first.x.is_eq(cs.ns(|| "compare x"), &second.x)?;
+    let compare_y = first.y.is_eq(cs.ns(|| "compare y"), &second.y)?;
+
+    Boolean::and(cs.ns(|| "compare x and y results"), &compare_x, &compare_y)
+}
 
 impl EvaluateEqGadget<Fq> for EdwardsGroupType {
-    fn evaluate_equal<CS: ConstraintSystem<Fq>>(&self, mut _cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
+    fn evaluate_equal<CS: ConstraintSystem<Fq>>(&self, cs: CS, other: &Self) -> Result<Boolean, SynthesisError> {
         match (self, other) {
             (EdwardsGroupType::Constant(self_value), EdwardsGroupType::Constant(other_value)) => {
                 Ok(Boolean::constant(self_value.eq(other_value)))
             }
-            _ => unimplemented!(),
-            // (EdwardsGroupType::Allocated(first), EdwardsGroupType::Allocated(second)) => {
-            //     compare_allocated_edwards_bls_gadgets(cs, first, second)
-            // }
-            // (EdwardsGroupType::Constant(constant_value), EdwardsGroupType::Allocated(allocated_value))
-            // | (EdwardsGroupType::Allocated(allocated_value), EdwardsGroupType::Constant(constant_value)) => {
-            //     let allocated_constant_value =
-            //         <EdwardsBls12Gadget as AllocGadget<Affine<EdwardsParameters>, Fq>>::alloc(
-            //             &mut cs.ns(|| format!("alloc constant for eq")),
-            //             || Ok(constant_value),
-            //         )?;
-            //     compare_allocated_edwards_bls_gadgets(cs, allocated_value, &allocated_constant_value)
-            // }
+            (EdwardsGroupType::Allocated(first), EdwardsGroupType::Allocated(second)) => {
+                compare_edwards_bls_gadgets(cs, first, second)
+            }
+            (EdwardsGroupType::Constant(constant_value), EdwardsGroupType::Allocated(allocated_value))
+            | (EdwardsGroupType::Allocated(allocated_value), EdwardsGroupType::Constant(constant_value)) => {
+                let constant_gadget = EdwardsBls12Gadget::new(
+                    FpGadget::Constant(constant_value.x),
+                    FpGadget::Constant(constant_value.y),
+                );
+                compare_edwards_bls_gadgets(cs, allocated_value, &constant_gadget)
+            }
         }
     }
 }
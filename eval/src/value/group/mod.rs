@@ -47,8 +47,16 @@ pub trait GroupType<F: Field>:
     + ToBitsBEGadget<F>
     + ToBytesGadget<F>
 {
+    /// The native group element held by constant values of this type.
+    type Native: snarkvm_curves::Group;
+
     fn constant(value: &Group) -> Result<Self, GroupError>;
 
+    fn native(value: &Group) -> Result<Self::Native, GroupError>;
+
+    /// Returns the native group element of this value, if it is known.
+    fn native_value(&self) -> Option<Self::Native>;
+
     fn to_allocated<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Self, GroupError>;
 
     fn negate<CS: ConstraintSystem<F>>(&self, cs: CS) -> Result<Self, GroupError>;
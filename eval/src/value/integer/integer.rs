@@ -25,7 +25,10 @@ use snarkvm_gadgets::{
     },
     traits::{
         alloc::AllocGadget,
-        bits::comparator::{ComparatorGadget, EvaluateLtGadget},
+        bits::{
+            comparator::{ComparatorGadget, EvaluateLtGadget},
+            Xor,
+        },
         eq::{ConditionalEqGadget, EqGadget, EvaluateEqGadget},
         integers::{Add, Div, Mul, Neg, Pow, Sub},
         select::CondSelectGadget,
@@ -42,6 +45,7 @@ use crate::{
     match_integer,
     match_integers,
     match_integers_arithmetic,
+    match_integers_fold,
     match_signed_integer,
     match_unsigned_integer,
     ConstrainedValue,
@@ -107,6 +111,96 @@ impl fmt::Display for IntegerType {
     }
 }
 
+impl IntegerType {
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            IntegerType::I8 | IntegerType::I16 | IntegerType::I32 | IntegerType::I64 | IntegerType::I128
+        )
+    }
+}
+
+/// A binary operation applied to each pair of bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Bitwise {
+    And,
+    Or,
+    Xor,
+}
+
+impl Bitwise {
+    fn operator(&self) -> &'static str {
+        match self {
+            Bitwise::And => "&",
+            Bitwise::Or => "|",
+            Bitwise::Xor => "^",
+        }
+    }
+
+    fn apply<F: PrimeField, CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        a: &Boolean,
+        b: &Boolean,
+    ) -> Result<Boolean, SynthesisError> {
+        match self {
+            Bitwise::And => Boolean::and(cs, a, b),
+            Bitwise::Or => Boolean::or(cs, a, b),
+            Bitwise::Xor => a.xor(cs, b),
+        }
+    }
+}
+
+/// The direction and fill behaviour of a bit shift.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Shift {
+    /// `<<`, fills the low bits with zeroes.
+    Left,
+    /// `>>`, fills the high bits with zeroes.
+    Right,
+    /// `>>` on a signed value, fills the high bits with the sign bit.
+    RightSigned,
+}
+
+impl Shift {
+    fn operator(&self) -> &'static str {
+        match self {
+            Shift::Left => "<<",
+            Shift::Right => ">>",
+            Shift::RightSigned => ">>>",
+        }
+    }
+
+    /// Shifts little-endian `bits` by the constant `amount`, filling vacated positions with `fill`.
+    fn apply(&self, bits: &[Boolean], amount: usize, fill: Boolean) -> Vec<Boolean> {
+        let size = bits.len();
+        (0..size)
+            .map(|i| match self {
+                Shift::Left if i >= amount => bits[i - amount],
+                Shift::Right | Shift::RightSigned if i + amount < size => bits[i + amount],
+                _ => fill,
+            })
+            .collect()
+    }
+}
+
+/// Returns the bits of `dividend - quotient * divisor`, computed modulo `2^U::SIZE`.
+fn wrapping_remainder<F: PrimeField, CS: ConstraintSystem<F>, U: UInt>(
+    cs: &mut CS,
+    dividend: &[Boolean],
+    divisor: &[Boolean],
+    quotient: &[Boolean],
+) -> Result<Vec<Boolean>, IntegerError> {
+    let product = U::from_bits_le(quotient).mul(cs.ns(|| "product"), &U::from_bits_le(divisor))?;
+
+    // `addmany` rejects negated operands that underflow, so use `a - b = !(!a + b)` instead.
+    let not_dividend = dividend.iter().map(Boolean::not).collect::<Vec<_>>();
+    let sum = U::addmany(cs.ns(|| "remainder"), &[U::from_bits_le(&not_dividend), product])
+        .map_err(IntegerError::synthesis)?;
+
+    Ok(sum.to_bits_le().iter().map(Boolean::not).collect())
+}
+
 impl Integer {
     ///
     /// Returns a new integer from an expression.
@@ -144,6 +238,40 @@ impl Integer {
         match_integer!(integer => integer.get_value())
     }
 
+    /// Returns the native value of the integer, if it is known.
+    pub fn native_value(&self) -> Option<IrInteger> {
+        Some(match self {
+            Integer::U8(integer) => IrInteger::U8(integer.value?),
+            Integer::U16(integer) => IrInteger::U16(integer.value?),
+            Integer::U32(integer) => IrInteger::U32(integer.value?),
+            Integer::U64(integer) => IrInteger::U64(integer.value?),
+            Integer::U128(integer) => IrInteger::U128(integer.value?),
+            Integer::I8(integer) => IrInteger::I8(integer.value?),
+            Integer::I16(integer) => IrInteger::I16(integer.value?),
+            Integer::I32(integer) => IrInteger::I32(integer.value?),
+            Integer::I64(integer) => IrInteger::I64(integer.value?),
+            Integer::I128(integer) => IrInteger::I128(integer.value?),
+        })
+    }
+
+    ///
+    /// Returns a new integer of type `type_` from its little-endian bits.
+    ///
+    pub fn from_bits_le(type_: &IntegerType, bits: &[Boolean]) -> Self {
+        match type_ {
+            IntegerType::U8 => Integer::U8(UInt8::from_bits_le(bits)),
+            IntegerType::U16 => Integer::U16(UInt16::from_bits_le(bits)),
+            IntegerType::U32 => Integer::U32(UInt32::from_bits_le(bits)),
+            IntegerType::U64 => Integer::U64(UInt64::from_bits_le(bits)),
+            IntegerType::U128 => Integer::U128(UInt128::from_bits_le(bits)),
+            IntegerType::I8 => Integer::I8(Int8::from_bits_le(bits)),
+            IntegerType::I16 => Integer::I16(Int16::from_bits_le(bits)),
+            IntegerType::I32 => Integer::I32(Int32::from_bits_le(bits)),
+            IntegerType::I64 => Integer::I64(Int64::from_bits_le(bits)),
+            IntegerType::I128 => Integer::I128(Int128::from_bits_le(bits)),
+        }
+    }
+
     pub fn get_type(&self) -> IntegerType {
         match self {
             Integer::U8(_) => IntegerType::U8,
@@ -283,6 +411,160 @@ impl Integer {
 
         result.ok_or_else(|| IntegerError::binary_operation("**".to_string()))
     }
+
+    pub fn rem<F: PrimeField, CS: ConstraintSystem<F>>(self, cs: &mut CS, other: Self) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} % {}", self, other);
+
+        if self.get_type() != other.get_type() {
+            return Err(IntegerError::binary_operation("%".to_string()));
+        }
+
+        // If both operands are constants, fold the remainder natively instead of generating constraints.
+        if !self.is_allocated() && !other.is_allocated() {
+            let a = &self;
+            let b = &other;
+
+            let result = match_integers_fold!((a, b) => a.checked_rem(b));
+
+            return result.ok_or_else(|| IntegerError::checked_operation("%".to_string(), self.to_string(), other.to_string()));
+        }
+
+        // a % b = a - (a / b) * b, with the truncating division of the integer gadgets.
+        // The remainder always fits the type, so the product and difference are computed with
+        // wrapping unsigned arithmetic, which also covers signed operands equal to `MIN`.
+        let mut cs = cs.ns(|| unique_namespace);
+        let quotient = self.clone().div(&mut cs.ns(|| "quotient"), other.clone())?;
+
+        let dividend = self.to_bits_le();
+        let divisor = other.to_bits_le();
+        let quotient = quotient.to_bits_le();
+
+        let remainder = match dividend.len() {
+            8 => wrapping_remainder::<_, _, UInt8>(&mut cs, &dividend, &divisor, &quotient)?,
+            16 => wrapping_remainder::<_, _, UInt16>(&mut cs, &dividend, &divisor, &quotient)?,
+            32 => wrapping_remainder::<_, _, UInt32>(&mut cs, &dividend, &divisor, &quotient)?,
+            64 => wrapping_remainder::<_, _, UInt64>(&mut cs, &dividend, &divisor, &quotient)?,
+            _ => wrapping_remainder::<_, _, UInt128>(&mut cs, &dividend, &divisor, &quotient)?,
+        };
+
+        Ok(Self::from_bits_le(&self.get_type(), &remainder))
+    }
+
+    pub fn bitand<F: PrimeField, CS: ConstraintSystem<F>>(self, cs: &mut CS, other: Self) -> Result<Self, IntegerError> {
+        self.enforce_bitwise(cs, other, Bitwise::And)
+    }
+
+    pub fn bitor<F: PrimeField, CS: ConstraintSystem<F>>(self, cs: &mut CS, other: Self) -> Result<Self, IntegerError> {
+        self.enforce_bitwise(cs, other, Bitwise::Or)
+    }
+
+    pub fn bitxor<F: PrimeField, CS: ConstraintSystem<F>>(self, cs: &mut CS, other: Self) -> Result<Self, IntegerError> {
+        self.enforce_bitwise(cs, other, Bitwise::Xor)
+    }
+
+    pub fn bitnot(self) -> Self {
+        let bits = self.to_bits_le().iter().map(Boolean::not).collect::<Vec<_>>();
+
+        Self::from_bits_le(&self.get_type(), &bits)
+    }
+
+    pub fn shl<F: PrimeField, CS: ConstraintSystem<F>>(self, cs: &mut CS, amount: Self) -> Result<Self, IntegerError> {
+        self.enforce_shift(cs, amount, Shift::Left)
+    }
+
+    pub fn shr<F: PrimeField, CS: ConstraintSystem<F>>(self, cs: &mut CS, amount: Self) -> Result<Self, IntegerError> {
+        self.enforce_shift(cs, amount, Shift::Right)
+    }
+
+    pub fn shr_signed<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        amount: Self,
+    ) -> Result<Self, IntegerError> {
+        self.enforce_shift(cs, amount, Shift::RightSigned)
+    }
+
+    /// Applies `operation` to each pair of bits. Constant bits never allocate, so constant operands fold.
+    fn enforce_bitwise<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        other: Self,
+        operation: Bitwise,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} {} {}", self, operation.operator(), other);
+
+        if self.get_type() != other.get_type() {
+            return Err(IntegerError::binary_operation(operation.operator().to_string()));
+        }
+
+        let mut cs = cs.ns(|| unique_namespace);
+        let bits = self
+            .to_bits_le()
+            .iter()
+            .zip(other.to_bits_le().iter())
+            .enumerate()
+            .map(|(i, (a, b))| operation.apply(cs.ns(|| format!("bit {}", i)), a, b))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(IntegerError::synthesis)?;
+
+        Ok(Self::from_bits_le(&self.get_type(), &bits))
+    }
+
+    /// Shifts `self` by `amount`, which must be an unsigned integer less than the bit size of `self`.
+    /// Constant amounts only rewire bits; allocated amounts use a barrel shifter over the low amount bits.
+    fn enforce_shift<F: PrimeField, CS: ConstraintSystem<F>>(
+        self,
+        cs: &mut CS,
+        amount: Self,
+        shift: Shift,
+    ) -> Result<Self, IntegerError> {
+        let unique_namespace = format!("enforce {} {} {}", self, shift.operator(), amount);
+
+        if amount.get_type().is_signed() {
+            return Err(IntegerError::invalid_shift_amount(amount.to_string()));
+        }
+
+        let type_ = self.get_type();
+        let bits = self.to_bits_le();
+        let size = bits.len();
+        let fill = match shift {
+            Shift::RightSigned => bits[size - 1],
+            Shift::Left | Shift::Right => Boolean::constant(false),
+        };
+
+        if !amount.is_allocated() {
+            return match amount.to_usize() {
+                Some(amount) if amount < size => Ok(Self::from_bits_le(&type_, &shift.apply(&bits, amount, fill))),
+                _ => Err(IntegerError::shift_overflow(amount.to_string(), size)),
+            };
+        }
+
+        let mut cs = cs.ns(|| unique_namespace);
+
+        // The bit sizes are powers of two, so `amount < size` holds iff every higher amount bit is unset.
+        let stages = size.trailing_zeros() as usize;
+        let amount_bits = amount.to_bits_le();
+        for (i, bit) in amount_bits.iter().enumerate().skip(stages) {
+            bit.enforce_equal(cs.ns(|| format!("amount bit {} is zero", i)), &Boolean::constant(false))
+                .map_err(IntegerError::synthesis)?;
+        }
+
+        let mut result = bits;
+        for (stage, bit) in amount_bits.iter().take(stages).enumerate() {
+            let shifted = shift.apply(&result, 1 << stage, fill);
+            result = result
+                .iter()
+                .zip(shifted.iter())
+                .enumerate()
+                .map(|(i, (current, shifted))| {
+                    Boolean::conditionally_select(cs.ns(|| format!("stage {} bit {}", stage, i)), bit, shifted, current)
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(IntegerError::synthesis)?;
+        }
+
+        Ok(Self::from_bits_le(&type_, &result))
+    }
 }
 
 impl<F: PrimeField> EvaluateEqGadget<F> for Integer {
@@ -325,8 +607,10 @@ impl<F: PrimeField> ConditionalEqGadget<F> for Integer {
         result.ok_or(SynthesisError::Unsatisfiable)
     }
 
+    /// A flat upper bound on the cost of enforcing any integer, namely the cost of the widest integer,
+    /// since the integer being enforced is not known.
     fn cost() -> usize {
-        unimplemented!() // cannot determine which integer we are enforcing
+        <UInt128 as ConditionalEqGadget<F>>::cost()
     }
 }
 
@@ -353,7 +637,9 @@ impl<F: PrimeField> CondSelectGadget<F> for Integer {
         }
     }
 
+    /// A flat upper bound on the cost of selecting any integer, namely the cost of the widest integer,
+    /// since the integer being selected is not known.
     fn cost() -> usize {
-        unimplemented!() // cannot determine which integer we are enforcing
+        <UInt128 as CondSelectGadget<F>>::cost()
     }
 }
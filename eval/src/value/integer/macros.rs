@@ -106,6 +106,58 @@ macro_rules! match_integers_arithmetic {
     };
 }
 
+/// Natively evaluates `$expression` over the values of two constant integers of the same type,
+/// where `$expression` returns `None` if the operation overflows.
+#[macro_export]
+macro_rules! match_integers_fold {
+    (($a: ident, $b: ident) => $expression:expr) => {
+        match ($a, $b) {
+            (Integer::U8($a), Integer::U8($b)) => match ($a.value, $b.value) {
+                (Some($a), Some($b)) => $expression.map(|x| Integer::U8(UInt8::constant(x))),
+                _ => None,
+            },
+            (Integer::U16($a), Integer::U16($b)) => match ($a.value, $b.value) {
+                (Some($a), Some($b)) => $expression.map(|x| Integer::U16(UInt16::constant(x))),
+                _ => None,
+            },
+            (Integer::U32($a), Integer::U32($b)) => match ($a.value, $b.value) {
+                (Some($a), Some($b)) => $expression.map(|x| Integer::U32(UInt32::constant(x))),
+                _ => None,
+            },
+            (Integer::U64($a), Integer::U64($b)) => match ($a.value, $b.value) {
+                (Some($a), Some($b)) => $expression.map(|x| Integer::U64(UInt64::constant(x))),
+                _ => None,
+            },
+            (Integer::U128($a), Integer::U128($b)) => match ($a.value, $b.value) {
+                (Some($a), Some($b)) => $expression.map(|x| Integer::U128(UInt128::constant(x))),
+                _ => None,
+            },
+
+            (Integer::I8($a), Integer::I8($b)) => match ($a.value, $b.value) {
+                (Some($a), Some($b)) => $expression.map(|x| Integer::I8(Int8::constant(x))),
+                _ => None,
+            },
+            (Integer::I16($a), Integer::I16($b)) => match ($a.value, $b.value) {
+                (Some($a), Some($b)) => $expression.map(|x| Integer::I16(Int16::constant(x))),
+                _ => None,
+            },
+            (Integer::I32($a), Integer::I32($b)) => match ($a.value, $b.value) {
+                (Some($a), Some($b)) => $expression.map(|x| Integer::I32(Int32::constant(x))),
+                _ => None,
+            },
+            (Integer::I64($a), Integer::I64($b)) => match ($a.value, $b.value) {
+                (Some($a), Some($b)) => $expression.map(|x| Integer::I64(Int64::constant(x))),
+                _ => None,
+            },
+            (Integer::I128($a), Integer::I128($b)) => match ($a.value, $b.value) {
+                (Some($a), Some($b)) => $expression.map(|x| Integer::I128(Int128::constant(x))),
+                _ => None,
+            },
+            (_, _) => None,
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! allocate_type {
     ($rust_ty:ty, $gadget_ty:ty, $leo_ty:path, $cs:expr, $name:expr, $value:expr) => {{
@@ -128,7 +128,7 @@ impl<F: PrimeField, G: GroupType<F>> fmt::Display for ConstrainedValue<F, G> {
 
             // Data type wrappers
             ConstrainedValue::Array(ref array) => {
-                if matches!(array[0], ConstrainedValue::Char(_)) {
+                if matches!(array.first(), Some(ConstrainedValue::Char(_))) {
                     for character in array {
                         write!(f, "{}", character)?;
                     }
@@ -204,8 +204,21 @@ impl<F: PrimeField, G: GroupType<F>> ConditionalEqGadget<F> for ConstrainedValue
         }
     }
 
+    /// A flat upper bound on the cost of enforcing a single scalar value, since the value being enforced is not known.
+    /// Arrays and tuples cost up to this much for each of their elements, which this bound does not account for.
     fn cost() -> usize {
-        unimplemented!()
+        [
+            <Address as ConditionalEqGadget<F>>::cost(),
+            <Boolean as ConditionalEqGadget<F>>::cost(),
+            <Char<F> as ConditionalEqGadget<F>>::cost(),
+            <FieldType<F> as ConditionalEqGadget<F>>::cost(),
+            <G as ConditionalEqGadget<F>>::cost(),
+            <Integer as ConditionalEqGadget<F>>::cost(),
+        ]
+        .iter()
+        .copied()
+        .max()
+        .unwrap()
     }
 }
 
@@ -275,7 +288,20 @@ impl<F: PrimeField, G: GroupType<F>> CondSelectGadget<F> for ConstrainedValue<F,
         })
     }
 
+    /// A flat upper bound on the cost of selecting a single scalar value, since the value being selected is not known.
+    /// Arrays and tuples cost up to this much for each of their elements, which this bound does not account for.
     fn cost() -> usize {
-        unimplemented!() //lower bound 1, upper bound 128 or length of static array
+        [
+            <Address as CondSelectGadget<F>>::cost(),
+            <Boolean as CondSelectGadget<F>>::cost(),
+            <Char<F> as CondSelectGadget<F>>::cost(),
+            <FieldType<F> as CondSelectGadget<F>>::cost(),
+            <G as CondSelectGadget<F>>::cost(),
+            <Integer as CondSelectGadget<F>>::cost(),
+        ]
+        .iter()
+        .copied()
+        .max()
+        .unwrap()
     }
 }
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Fixtures shared by the evaluator integration tests.
+
+#![allow(dead_code)]
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use snarkvm_ir::{Function, Header, Input, Program, SnarkVMVersion};
+
+fn inner_load_tests<P: AsRef<Path>>(path: P, out: &mut BTreeMap<String, Vec<u8>>) {
+    for item in path.as_ref().read_dir().unwrap() {
+        let item = item.unwrap();
+        let type_ = item.file_type().unwrap();
+        let path = item.path();
+        if type_.is_file() && path.extension().map(|x| x.to_string_lossy() == "ir").unwrap_or(false) {
+            out.insert(path.to_string_lossy().into_owned(), fs::read(&path).unwrap());
+        } else if type_.is_dir() {
+            inner_load_tests(&path, out);
+        }
+    }
+}
+
+/// Returns the serialized IR test programs, keyed by their path.
+pub fn load_tests() -> BTreeMap<String, Vec<u8>> {
+    let mut out = BTreeMap::new();
+    inner_load_tests("../tests/ir/", &mut out);
+    out
+}
+
+/// Returns a program with the given main inputs and functions, and no other inputs.
+pub fn program(main_inputs: Vec<Input>, functions: Vec<Function>) -> Program {
+    Program {
+        header: Header {
+            version: SnarkVMVersion::default(),
+            main_inputs,
+            constant_inputs: vec![],
+            register_inputs: vec![],
+            public_states: vec![],
+            private_record_states: vec![],
+            private_leaf_states: vec![],
+            inline_limit: 10,
+        },
+        functions,
+    }
+}
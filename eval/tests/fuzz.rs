@@ -0,0 +1,482 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+
+//! Evaluates randomly generated instruction streams, checking that malformed programs fail with errors
+//! rather than panics.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_eval::{edwards_bls12::EdwardsGroupType, ConstrainedValue, Evaluator, NativeEvaluator, SetupEvaluator};
+use snarkvm_ir::{
+    ArrayInitRepeatData,
+    CallCoreData,
+    CallData,
+    Field,
+    Function,
+    Group,
+    GroupCoordinate,
+    Header,
+    Input,
+    InputData,
+    Instruction,
+    Integer,
+    LogData,
+    LogLevel,
+    MaskData,
+    PredicateData,
+    Program,
+    QueryData,
+    RepeatData,
+    SnarkVMVersion,
+    Type,
+    Value,
+    VarData,
+};
+use snarkvm_r1cs::TestConstraintSystem;
+
+const CORE_FUNCTIONS: &[&str] = &[
+    "hash",
+    "len",
+    "bool_to_bits_le",
+    "u8_to_bits_be",
+    "i128_to_bytes_le",
+    "field_to_bits_le",
+    "group_to_bytes_be",
+    "address_to_bits_le",
+    "char_to_bytes_le",
+    "u8_from_bits_le",
+    "i32_from_bytes_be",
+    "field_from_bits_be",
+    "group_from_bytes_le",
+    "address_from_bytes_le",
+    "char_from_bits_le",
+    "unknown",
+];
+
+const INPUT_TYPES: &[Type] = &[
+    Type::Boolean,
+    Type::U8,
+    Type::U32,
+    Type::I8,
+    Type::I128,
+    Type::Field,
+    Type::Group,
+    Type::Char,
+];
+
+struct Generator {
+    rng: XorShiftRng,
+    variables: u32,
+    functions: u32,
+}
+
+impl Generator {
+    fn integer(&mut self) -> Integer {
+        // small values are more likely to pass the checks of an operation
+        let value: u128 = if self.rng.gen() { self.rng.gen_range(0..8) } else { self.rng.gen() };
+        match self.rng.gen_range(0..10) {
+            0 => Integer::U8(value as u8),
+            1 => Integer::U16(value as u16),
+            2 => Integer::U32(value as u32),
+            3 => Integer::U64(value as u64),
+            4 => Integer::U128(value),
+            5 => Integer::I8(value as i8),
+            6 => Integer::I16(value as i16),
+            7 => Integer::I32(value as i32),
+            8 => Integer::I64(value as i64),
+            _ => Integer::I128(value as i128),
+        }
+    }
+
+    fn field(&mut self) -> Field {
+        let limbs = self.rng.gen_range(0..6);
+        Field {
+            negate: self.rng.gen(),
+            values: (0..limbs)
+                .map(|_| if self.rng.gen() { self.rng.gen_range(0..4) } else { self.rng.gen() })
+                .collect(),
+        }
+    }
+
+    fn group_coordinate(&mut self) -> GroupCoordinate {
+        match self.rng.gen_range(0..4) {
+            0 => GroupCoordinate::Field(self.field()),
+            1 => GroupCoordinate::SignHigh,
+            2 => GroupCoordinate::SignLow,
+            _ => GroupCoordinate::Inferred,
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Value {
+        match self.rng.gen_range(0..14) {
+            0..=4 => Value::Ref(self.rng.gen_range(0..self.variables)),
+            5 => Value::Boolean(self.rng.gen()),
+            6 | 7 => Value::Integer(self.integer()),
+            8 => Value::Field(self.field()),
+            9 => match self.rng.gen() {
+                true => Value::Group(Group::Single(self.field())),
+                false => Value::Group(Group::Tuple(self.group_coordinate(), self.group_coordinate())),
+            },
+            10 => Value::Char(self.rng.gen_range(0..0x11_0010)),
+            11 => Value::Address((0..self.rng.gen_range(0..40)).map(|_| self.rng.gen()).collect()),
+            12 if depth < 2 => Value::Array(self.some_values(depth + 1, 5)),
+            13 if depth < 2 => Value::Tuple(self.some_values(depth + 1, 4)),
+            _ => Value::Str("str".to_string()),
+        }
+    }
+
+    fn values(&mut self, depth: usize, count: usize) -> Vec<Value> {
+        (0..count).map(|_| self.value(depth)).collect()
+    }
+
+    fn some_values(&mut self, depth: usize, max: usize) -> Vec<Value> {
+        let count = self.rng.gen_range(0..max);
+        self.values(depth, count)
+    }
+
+    /// returns `count` operands, or occasionally one more or less
+    fn operands(&mut self, count: usize) -> Vec<Value> {
+        let count = match self.rng.gen_range(0..20) {
+            0 => count.saturating_sub(1),
+            1 => count + 1,
+            _ => count,
+        };
+        self.values(0, count)
+    }
+
+    fn destination(&mut self) -> u32 {
+        self.rng.gen_range(0..self.variables + 2)
+    }
+
+    fn query<const N: usize>(&mut self) -> QueryData<N> {
+        QueryData {
+            destination: self.destination(),
+            values: self.operands(N),
+        }
+    }
+
+    /// returns a small unsigned integer, keeping loops short
+    fn bound(&mut self) -> Value {
+        let bound = self.rng.gen_range(0..4);
+        Value::Integer(match self.rng.gen_range(0..3) {
+            0 => Integer::U8(bound as u8),
+            1 => Integer::U32(bound),
+            _ => Integer::I8(bound as i8),
+        })
+    }
+
+    fn instruction(&mut self) -> Instruction {
+        match self.rng.gen_range(0..44) {
+            0 => Instruction::Add(self.query()),
+            1 => Instruction::Sub(self.query()),
+            2 => Instruction::Mul(self.query()),
+            3 => Instruction::Div(self.query()),
+            4 => Instruction::Pow(self.query()),
+            5 => Instruction::Or(self.query()),
+            6 => Instruction::And(self.query()),
+            7 => Instruction::Eq(self.query()),
+            8 => Instruction::Ne(self.query()),
+            9 => Instruction::Ge(self.query()),
+            10 => Instruction::Gt(self.query()),
+            11 => Instruction::Le(self.query()),
+            12 => Instruction::Lt(self.query()),
+            13 => Instruction::BitOr(self.query()),
+            14 => Instruction::BitAnd(self.query()),
+            15 => Instruction::BitXor(self.query()),
+            16 => Instruction::Shr(self.query()),
+            17 => Instruction::ShrSigned(self.query()),
+            18 => Instruction::Shl(self.query()),
+            19 => Instruction::Mod(self.query()),
+            20 => Instruction::Not(self.query()),
+            21 => Instruction::Negate(self.query()),
+            22 => Instruction::BitNot(self.query()),
+            23 => Instruction::ArrayInitRepeat(ArrayInitRepeatData {
+                destination: self.destination(),
+                length: self.rng.gen_range(0..5),
+                value: self.value(0),
+            }),
+            24 => Instruction::ArrayInit(VarData {
+                destination: self.destination(),
+                values: self.some_values(0, 4),
+            }),
+            25 => Instruction::ArrayIndexGet(self.query()),
+            26 => Instruction::ArraySliceGet(self.query()),
+            27 => Instruction::ArrayIndexStore(self.query()),
+            28 => Instruction::ArraySliceStore(self.query()),
+            29 => Instruction::TupleInit(VarData {
+                destination: self.destination(),
+                values: self.some_values(0, 4),
+            }),
+            30 => Instruction::TupleIndexGet(self.query()),
+            31 => Instruction::TupleIndexStore(self.query()),
+            32 => Instruction::Pick(self.query()),
+            33 | 34 => Instruction::Mask(MaskData {
+                instruction_count: self.rng.gen_range(0..5),
+                condition: self.value(0),
+            }),
+            35 => Instruction::Repeat(RepeatData {
+                instruction_count: self.rng.gen_range(0..5),
+                iter_variable: self.destination(),
+                inclusive: self.rng.gen(),
+                from: self.bound(),
+                to: self.bound(),
+            }),
+            36 | 37 => Instruction::Store(self.query()),
+            38 => Instruction::Call(CallData {
+                destination: self.destination(),
+                index: self.rng.gen_range(0..self.functions + 1),
+                arguments: self.some_values(0, 3),
+            }),
+            39 | 40 => Instruction::CallCore(CallCoreData {
+                destination: self.destination(),
+                identifier: CORE_FUNCTIONS.choose(&mut self.rng).unwrap().to_string(),
+                arguments: self.some_values(0, 3),
+            }),
+            41 => Instruction::Return(PredicateData { values: self.operands(1) }),
+            42 => Instruction::Assert(PredicateData { values: self.operands(1) }),
+            _ => Instruction::Log(LogData {
+                log_level: *[LogLevel::Error, LogLevel::Info, LogLevel::Debug]
+                    .choose(&mut self.rng)
+                    .unwrap(),
+                parts: self.some_values(0, 3),
+            }),
+        }
+    }
+
+    fn program(&mut self) -> (Program, InputData) {
+        let mut main_inputs = vec![];
+        let mut input = InputData::default();
+        for variable in 0..self.rng.gen_range(0..4) {
+            let type_ = INPUT_TYPES.choose(&mut self.rng).unwrap().clone();
+            let name = format!("input{}", variable);
+            let value = match &type_ {
+                Type::Boolean => Value::Boolean(self.rng.gen()),
+                Type::U8 => Value::Integer(Integer::U8(self.rng.gen_range(0..8))),
+                Type::U32 => Value::Integer(Integer::U32(self.rng.gen())),
+                Type::I8 => Value::Integer(Integer::I8(self.rng.gen())),
+                Type::I128 => Value::Integer(Integer::I128(self.rng.gen())),
+                Type::Field => Value::Field(self.field()),
+                Type::Group => Value::Group(Group::Single(Field {
+                    negate: false,
+                    values: vec![self.rng.gen_range(1..4)],
+                })),
+                _ => Value::Char(self.rng.gen_range(0..0x80)),
+            };
+            input.main.insert(name.clone(), value);
+            main_inputs.push(Input { variable, name, type_ });
+        }
+        self.variables = main_inputs.len() as u32 + 8;
+        self.functions = self.rng.gen_range(1..4);
+
+        let functions = (0..self.functions)
+            .map(|_| Function {
+                argument_start_variable: self.rng.gen_range(0..self.variables),
+                instructions: (0..self.rng.gen_range(0..12)).map(|_| self.instruction()).collect(),
+            })
+            .collect();
+        let program = Program {
+            header: Header {
+                version: SnarkVMVersion::default(),
+                main_inputs,
+                constant_inputs: vec![],
+                register_inputs: vec![],
+                public_states: vec![],
+                private_record_states: vec![],
+                private_leaf_states: vec![],
+                inline_limit: self.rng.gen_range(0..4),
+            },
+            functions,
+        };
+        (program, input)
+    }
+}
+
+fn generate(seed: u64, count: usize) -> Vec<(Program, InputData)> {
+    let mut generator = Generator {
+        rng: XorShiftRng::seed_from_u64(seed),
+        variables: 0,
+        functions: 0,
+    };
+    (0..count).map(|_| generator.program()).collect()
+}
+
+/// Returns `true` if the error is given the function and instruction indices of a failing instruction as context.
+fn has_instruction_context(error: &anyhow::Error) -> bool {
+    let message = error.to_string();
+    let indices = match message.strip_prefix('f').and_then(|message| message.split(": ").next()) {
+        Some(indices) => indices,
+        None => return false,
+    };
+    match indices.split_once('#') {
+        Some((function, instruction)) => function.parse::<u32>().is_ok() && instruction.parse::<u32>().is_ok(),
+        None => false,
+    }
+}
+
+#[test]
+fn fuzz_setup_evaluator() {
+    let mut instruction_errors = 0;
+    for (program, input) in generate(8461243, 1000) {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            SetupEvaluator::<Fr, EdwardsGroupType, _>::new(&mut cs).evaluate(&program, &input)
+        }));
+        match result {
+            Ok(Err(error)) if has_instruction_context(&error) => instruction_errors += 1,
+            Ok(_) => {}
+            Err(_) => panic!("setup evaluation panicked on:\n{}", program),
+        }
+    }
+    assert!(instruction_errors > 0);
+}
+
+#[test]
+fn fuzz_native_evaluator() {
+    let mut instruction_errors = 0;
+    for (program, input) in generate(2749913, 1000) {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            NativeEvaluator::<Fr, EdwardsGroupType>::new().evaluate(&program, &input)
+        }));
+        match result {
+            Ok(Err(error)) if has_instruction_context(&error) => instruction_errors += 1,
+            Ok(_) => {}
+            Err(_) => panic!("native evaluation panicked on:\n{}", program),
+        }
+    }
+    assert!(instruction_errors > 0);
+}
+
+fn group_input(name: &str, variable: u32) -> Input {
+    Input {
+        variable,
+        name: name.to_string(),
+        type_: Type::Group,
+    }
+}
+
+fn generator_multiple(scalar: u64) -> Value {
+    Value::Group(Group::Single(Field {
+        negate: false,
+        values: vec![scalar],
+    }))
+}
+
+fn evaluate_setup(program: &Program, input: &InputData) -> anyhow::Result<ConstrainedValue<Fr, EdwardsGroupType>> {
+    let mut cs = TestConstraintSystem::<Fr>::new();
+    let output = SetupEvaluator::<Fr, EdwardsGroupType, _>::new(&mut cs).evaluate(program, input)?;
+    assert!(cs.is_satisfied());
+    Ok(output)
+}
+
+#[test]
+fn test_allocated_group_equality() {
+    let program = Program {
+        header: Header {
+            version: SnarkVMVersion::default(),
+            main_inputs: vec![group_input("a", 0), group_input("b", 1)],
+            constant_inputs: vec![],
+            register_inputs: vec![],
+            public_states: vec![],
+            private_record_states: vec![],
+            private_leaf_states: vec![],
+            inline_limit: 1,
+        },
+        functions: vec![Function {
+            argument_start_variable: 0,
+            instructions: vec![
+                Instruction::Eq(QueryData {
+                    destination: 2,
+                    values: vec![Value::Ref(0), Value::Ref(1)],
+                }),
+                Instruction::Eq(QueryData {
+                    destination: 3,
+                    values: vec![Value::Ref(0), generator_multiple(2)],
+                }),
+                Instruction::TupleInit(VarData {
+                    destination: 4,
+                    values: vec![Value::Ref(2), Value::Ref(3)],
+                }),
+                Instruction::Return(PredicateData {
+                    values: vec![Value::Ref(4)],
+                }),
+            ],
+        }],
+    };
+
+    for (a, b, expected) in [(2, 2, (true, true)), (2, 3, (false, true)), (3, 3, (true, false))] {
+        let mut input = InputData::default();
+        input.main.insert("a".to_string(), generator_multiple(a));
+        input.main.insert("b".to_string(), generator_multiple(b));
+        let values = match evaluate_setup(&program, &input).unwrap() {
+            ConstrainedValue::Tuple(values) => values,
+            output => panic!("unexpected output {}", output),
+        };
+        let bits = values
+            .iter()
+            .map(|value| match value {
+                ConstrainedValue::Boolean(value) => value.get_value().unwrap(),
+                value => panic!("unexpected value {}", value),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(bits, vec![expected.0, expected.1]);
+    }
+}
+
+#[test]
+fn test_field_comparison_errors() {
+    let program = Program {
+        header: Header {
+            version: SnarkVMVersion::default(),
+            main_inputs: vec![Input {
+                variable: 0,
+                name: "a".to_string(),
+                type_: Type::Field,
+            }],
+            constant_inputs: vec![],
+            register_inputs: vec![],
+            public_states: vec![],
+            private_record_states: vec![],
+            private_leaf_states: vec![],
+            inline_limit: 1,
+        },
+        functions: vec![Function {
+            argument_start_variable: 0,
+            instructions: vec![
+                Instruction::Lt(QueryData {
+                    destination: 1,
+                    values: vec![Value::Ref(0), Value::Ref(0)],
+                }),
+                Instruction::Return(PredicateData {
+                    values: vec![Value::Ref(1)],
+                }),
+            ],
+        }],
+    };
+    let mut input = InputData::default();
+    input.main.insert(
+        "a".to_string(),
+        Value::Field(Field {
+            negate: false,
+            values: vec![1],
+        }),
+    );
+    let error = evaluate_setup(&program, &input).unwrap_err();
+    assert!(error.to_string().starts_with("f0#0: lt "));
+}
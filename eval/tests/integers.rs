@@ -0,0 +1,282 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_eval::{edwards_bls12::EdwardsGroupType, ConstrainedValue, Evaluator, SetupEvaluator};
+use snarkvm_ir::{
+    Function,
+    Header,
+    Input,
+    InputData,
+    Instruction,
+    Integer,
+    PredicateData,
+    Program,
+    QueryData,
+    SnarkVMVersion,
+    Type,
+    Value,
+};
+use snarkvm_r1cs::TestConstraintSystem;
+
+const ITERATIONS: usize = 10;
+
+/// Builds a program returning `op(a, b)`, where `a` and `b` are main inputs, or constant inputs if `constant` is set.
+fn binary_program(op: fn(QueryData<2>) -> Instruction, left: Type, right: Type, constant: bool) -> Program {
+    let inputs = vec![
+        Input {
+            variable: 0,
+            name: "a".to_string(),
+            type_: left,
+        },
+        Input {
+            variable: 1,
+            name: "b".to_string(),
+            type_: right,
+        },
+    ];
+    let (main_inputs, constant_inputs) = if constant { (vec![], inputs) } else { (inputs, vec![]) };
+    Program {
+        header: Header {
+            version: SnarkVMVersion::default(),
+            main_inputs,
+            constant_inputs,
+            register_inputs: vec![],
+            public_states: vec![],
+            private_record_states: vec![],
+            private_leaf_states: vec![],
+            inline_limit: 10,
+        },
+        functions: vec![Function {
+            argument_start_variable: 0,
+            instructions: vec![
+                op(QueryData {
+                    destination: 2,
+                    values: vec![Value::Ref(0), Value::Ref(1)],
+                }),
+                Instruction::Return(PredicateData {
+                    values: vec![Value::Ref(2)],
+                }),
+            ],
+        }],
+    }
+}
+
+/// Evaluates `op(a, b)` and returns the output value, or `None` if evaluation failed or the constraints are unsatisfied.
+fn evaluate_binary(
+    op: fn(QueryData<2>) -> Instruction,
+    left_type: Type,
+    right_type: Type,
+    left: Integer,
+    right: Integer,
+    constant: bool,
+) -> Option<(String, usize)> {
+    let program = binary_program(op, left_type, right_type, constant);
+    let mut input = InputData::default();
+    let inputs = if constant { &mut input.constants } else { &mut input.main };
+    inputs.insert("a".to_string(), Value::Integer(left));
+    inputs.insert("b".to_string(), Value::Integer(right));
+
+    let mut cs = TestConstraintSystem::<Fr>::new();
+    let output = SetupEvaluator::<Fr, EdwardsGroupType, _>::new(&mut cs)
+        .evaluate(&program, &input)
+        .ok()?;
+    if !cs.is_satisfied() {
+        return None;
+    }
+    match output {
+        ConstrainedValue::Integer(integer) => Some((integer.get_value()?, cs.num_constraints())),
+        output => panic!("expected integer output, found {}", output),
+    }
+}
+
+fn check_binary(
+    op: fn(QueryData<2>) -> Instruction,
+    left_type: Type,
+    right_type: Type,
+    left: Integer,
+    right: Integer,
+    expected: Option<String>,
+) {
+    let allocated = evaluate_binary(op, left_type.clone(), right_type.clone(), left, right, false);
+    assert_eq!(
+        allocated.map(|(value, _)| value),
+        expected,
+        "allocated {:?} {} {}",
+        op(QueryData {
+            destination: 0,
+            values: vec![]
+        }),
+        left,
+        right
+    );
+
+    let constant = evaluate_binary(op, left_type, right_type, left, right, true);
+    if let Some((_, num_constraints)) = &constant {
+        assert_eq!(*num_constraints, 0, "constant operands must fold");
+    }
+    assert_eq!(
+        constant.map(|(value, _)| value),
+        expected,
+        "constant {:?} {} {}",
+        op(QueryData {
+            destination: 0,
+            values: vec![]
+        }),
+        left,
+        right
+    );
+}
+
+fn bit_not_output(type_: Type, value: Integer) -> String {
+    let program = Program {
+        header: Header {
+            version: SnarkVMVersion::default(),
+            main_inputs: vec![Input {
+                variable: 0,
+                name: "a".to_string(),
+                type_,
+            }],
+            constant_inputs: vec![],
+            register_inputs: vec![],
+            public_states: vec![],
+            private_record_states: vec![],
+            private_leaf_states: vec![],
+            inline_limit: 10,
+        },
+        functions: vec![Function {
+            argument_start_variable: 0,
+            instructions: vec![
+                Instruction::BitNot(QueryData {
+                    destination: 1,
+                    values: vec![Value::Ref(0)],
+                }),
+                Instruction::Return(PredicateData {
+                    values: vec![Value::Ref(1)],
+                }),
+            ],
+        }],
+    };
+    let mut input = InputData::default();
+    input.main.insert("a".to_string(), Value::Integer(value));
+
+    let mut cs = TestConstraintSystem::<Fr>::new();
+    let output = SetupEvaluator::<Fr, EdwardsGroupType, _>::new(&mut cs)
+        .evaluate(&program, &input)
+        .unwrap();
+    assert!(cs.is_satisfied());
+    output.to_string()
+}
+
+macro_rules! test_integer_ops {
+    ($name: ident, $_type: ty, $unsigned: ty, $signed: ty, $variant: ident, $ir_type: expr) => {
+        #[test]
+        fn $name() {
+            let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+            let bits = <$_type>::BITS;
+
+            let mut pairs: Vec<($_type, $_type)> = vec![
+                (0, 0),
+                (<$_type>::MAX, <$_type>::MIN),
+                (<$_type>::MIN, 1),
+                (<$_type>::MIN, (0 as $_type).wrapping_sub(1)),
+            ];
+            for _ in 0..ITERATIONS {
+                pairs.push((rng.gen(), rng.gen()));
+            }
+
+            for (a, b) in pairs {
+                let left = Integer::$variant(a);
+                let right = Integer::$variant(b);
+
+                check_binary(Instruction::BitAnd, $ir_type, $ir_type, left, right, Some((a & b).to_string()));
+                check_binary(Instruction::BitOr, $ir_type, $ir_type, left, right, Some((a | b).to_string()));
+                check_binary(Instruction::BitXor, $ir_type, $ir_type, left, right, Some((a ^ b).to_string()));
+                check_binary(
+                    Instruction::Mod,
+                    $ir_type,
+                    $ir_type,
+                    left,
+                    right,
+                    a.checked_rem(b).map(|x| x.to_string()),
+                );
+                assert_eq!(bit_not_output($ir_type, left), (!a).to_string());
+
+                let mut amounts = vec![0, 1, bits - 1, bits, bits + 1];
+                amounts.push(rng.gen_range(0..bits));
+                for amount in amounts {
+                    let shift = Integer::U32(amount);
+                    check_binary(
+                        Instruction::Shl,
+                        $ir_type,
+                        Type::U32,
+                        left,
+                        shift,
+                        a.checked_shl(amount).map(|x| x.to_string()),
+                    );
+                    check_binary(
+                        Instruction::Shr,
+                        $ir_type,
+                        Type::U32,
+                        left,
+                        shift,
+                        (a as $unsigned).checked_shr(amount).map(|x| (x as $_type).to_string()),
+                    );
+                    check_binary(
+                        Instruction::ShrSigned,
+                        $ir_type,
+                        Type::U32,
+                        left,
+                        shift,
+                        (a as $signed).checked_shr(amount).map(|x| (x as $_type).to_string()),
+                    );
+                }
+            }
+        }
+    };
+}
+
+test_integer_ops!(test_u8_ops, u8, u8, i8, U8, Type::U8);
+test_integer_ops!(test_u16_ops, u16, u16, i16, U16, Type::U16);
+test_integer_ops!(test_u32_ops, u32, u32, i32, U32, Type::U32);
+test_integer_ops!(test_u64_ops, u64, u64, i64, U64, Type::U64);
+test_integer_ops!(test_u128_ops, u128, u128, i128, U128, Type::U128);
+test_integer_ops!(test_i8_ops, i8, u8, i8, I8, Type::I8);
+test_integer_ops!(test_i16_ops, i16, u16, i16, I16, Type::I16);
+test_integer_ops!(test_i32_ops, i32, u32, i32, I32, Type::I32);
+test_integer_ops!(test_i64_ops, i64, u64, i64, I64, Type::I64);
+test_integer_ops!(test_i128_ops, i128, u128, i128, I128, Type::I128);
+
+#[test]
+fn test_mismatched_operands() {
+    assert_eq!(
+        evaluate_binary(
+            Instruction::BitAnd,
+            Type::U8,
+            Type::U16,
+            Integer::U8(1),
+            Integer::U16(1),
+            false
+        ),
+        None
+    );
+    assert_eq!(
+        evaluate_binary(Instruction::Shl, Type::U8, Type::I8, Integer::U8(1), Integer::I8(1), false),
+        None
+    );
+}
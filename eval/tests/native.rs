@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fs;
+
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_eval::{edwards_bls12::EdwardsGroupType, Evaluator, NativeEvaluator, NativeValue, SetupEvaluator};
+use snarkvm_ir::{
+    CallData,
+    Function,
+    Input,
+    InputData,
+    Instruction,
+    Integer,
+    MaskData,
+    PredicateData,
+    Program,
+    QueryData,
+    RepeatData,
+    Type,
+    Value,
+};
+use snarkvm_r1cs::TestConstraintSystem;
+
+mod common;
+use common::{load_tests, program};
+
+type Native = NativeValue<Fr, EdwardsGroupType>;
+
+/// Evaluates a program with both evaluators, returning the native output of each.
+fn evaluate_both(program: &Program, input: &InputData) -> (anyhow::Result<Native>, anyhow::Result<Native>) {
+    let mut cs = TestConstraintSystem::<Fr>::new();
+    let setup = SetupEvaluator::<Fr, EdwardsGroupType, _>::new(&mut cs)
+        .evaluate(program, input)
+        .map(|output| NativeValue::from_constrained(&output).expect("setup output has no value"));
+    assert!(setup.is_err() || cs.is_satisfied());
+
+    let native = NativeEvaluator::<Fr, EdwardsGroupType>::new().evaluate(program, input);
+    (setup, native)
+}
+
+fn u32_input(name: &str, variable: u32) -> Input {
+    Input {
+        variable,
+        name: name.to_string(),
+        type_: Type::U32,
+    }
+}
+
+#[test]
+fn native_matches_setup() {
+    let tests = load_tests();
+
+    let mut fail = 0usize;
+    for (name, raw) in &tests {
+        let program = Program::deserialize(raw).unwrap();
+        let input = InputData::deserialize(&fs::read(&*format!("{}.input", name)).unwrap()).unwrap();
+        match evaluate_both(&program, &input) {
+            (Ok(setup), Ok(native)) if setup == native => (),
+            (Ok(setup), native) => {
+                eprintln!("<{}> native output {:?} differs from {}", name, native, setup);
+                fail += 1;
+            }
+            (Err(e), _) => eprintln!("<{}> skipped, setup failed due to: {:?}", name, e),
+        }
+    }
+    assert_eq!(fail, 0, "{}/{} tests differ", fail, tests.len());
+}
+
+#[test]
+fn test_masked_loop() {
+    // let sum = 0; for i in 0..5 { if i < a { sum += i; } } return sum;
+    let main = Function {
+        argument_start_variable: 1,
+        instructions: vec![
+            Instruction::Store(QueryData {
+                destination: 1,
+                values: vec![Value::Integer(Integer::U32(0))],
+            }),
+            Instruction::Repeat(RepeatData {
+                instruction_count: 3,
+                iter_variable: 2,
+                inclusive: false,
+                from: Value::Integer(Integer::U32(0)),
+                to: Value::Integer(Integer::U32(5)),
+            }),
+            Instruction::Lt(QueryData {
+                destination: 3,
+                values: vec![Value::Ref(2), Value::Ref(0)],
+            }),
+            Instruction::Mask(MaskData {
+                instruction_count: 1,
+                condition: Value::Ref(3),
+            }),
+            Instruction::Add(QueryData {
+                destination: 1,
+                values: vec![Value::Ref(1), Value::Ref(2)],
+            }),
+            Instruction::Return(PredicateData {
+                values: vec![Value::Ref(1)],
+            }),
+        ],
+    };
+    let program = program(vec![u32_input("a", 0)], vec![main]);
+
+    for (a, expected) in [(0, 0), (3, 3), (9, 10)] {
+        let mut input = InputData::default();
+        input.main.insert("a".to_string(), Value::Integer(Integer::U32(a)));
+
+        let (setup, native) = evaluate_both(&program, &input);
+        assert_eq!(native.unwrap(), Native::Integer(Integer::U32(expected)));
+        assert_eq!(setup.unwrap(), Native::Integer(Integer::U32(expected)));
+    }
+}
+
+#[test]
+fn test_call_errors() {
+    let main = Function {
+        argument_start_variable: 1,
+        instructions: vec![
+            Instruction::Call(CallData {
+                destination: 1,
+                index: 1,
+                arguments: vec![Value::Ref(0)],
+            }),
+            Instruction::Return(PredicateData {
+                values: vec![Value::Ref(1)],
+            }),
+        ],
+    };
+    // asserts its argument is below 10, then calls itself with the same argument if it is 7
+    let function = Function {
+        argument_start_variable: 2,
+        instructions: vec![
+            Instruction::Lt(QueryData {
+                destination: 3,
+                values: vec![Value::Ref(2), Value::Integer(Integer::U32(10))],
+            }),
+            Instruction::Assert(PredicateData {
+                values: vec![Value::Ref(3)],
+            }),
+            Instruction::Eq(QueryData {
+                destination: 4,
+                values: vec![Value::Ref(2), Value::Integer(Integer::U32(7))],
+            }),
+            Instruction::Mask(MaskData {
+                instruction_count: 1,
+                condition: Value::Ref(4),
+            }),
+            Instruction::Call(CallData {
+                destination: 5,
+                index: 1,
+                arguments: vec![Value::Ref(2)],
+            }),
+            Instruction::Return(PredicateData {
+                values: vec![Value::Ref(2)],
+            }),
+        ],
+    };
+    let program = program(vec![u32_input("a", 0)], vec![main, function]);
+    let evaluate = |a: u32| {
+        let mut input = InputData::default();
+        input.main.insert("a".to_string(), Value::Integer(Integer::U32(a)));
+        NativeEvaluator::<Fr, EdwardsGroupType>::new().evaluate(&program, &input)
+    };
+
+    assert_eq!(evaluate(3).unwrap(), Native::Integer(Integer::U32(3)));
+    // The failing instruction is reported as the context of the error.
+    let error = evaluate(12).unwrap_err();
+    assert!(error.to_string().starts_with("f1#1: assert "));
+    assert!(format!("{:#}", error).contains("assertion failed"));
+    assert!(
+        evaluate(7)
+            .unwrap_err()
+            .to_string()
+            .contains("infinite recursion detected")
+    );
+}
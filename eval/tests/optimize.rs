@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+
+use std::fs;
+
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_eval::{edwards_bls12::EdwardsGroupType, Evaluator, NativeEvaluator, NativeValue, SetupEvaluator};
+use snarkvm_ir::{InputData, Integer, Program, Value};
+use snarkvm_r1cs::{ConstraintCounter, TestConstraintSystem};
+
+mod common;
+use common::load_tests;
+
+type Native = NativeValue<Fr, EdwardsGroupType>;
+
+/// Evaluates a program, returning its output and the number of constraints counted while evaluating it.
+fn count_constraints(program: &Program, input: &InputData) -> (Native, usize) {
+    // the counter does not compute witnesses, so the output is taken from a test constraint system
+    let mut cs = TestConstraintSystem::<Fr>::new();
+    let output = SetupEvaluator::<Fr, EdwardsGroupType, _>::new(&mut cs)
+        .evaluate(program, input)
+        .unwrap();
+    assert!(cs.is_satisfied());
+    let output = NativeValue::from_constrained(&output).expect("setup output has no value");
+
+    let mut counter = ConstraintCounter::default();
+    SetupEvaluator::<Fr, EdwardsGroupType, _>::new(&mut counter)
+        .evaluate(program, input)
+        .unwrap();
+    (output, counter.num_constraints)
+}
+
+#[test]
+fn test_optimize_reduces_constraints() {
+    let program = Program::parse(
+        r#"
+        inline_limit 2
+        main_input &v0 "a": u32
+        main_input &v1 "b": u32
+        decl f0: <0>
+          call &v2, f1, v0, 3u32
+          lt &v3, v0, v1
+          eq &v4, v0, v1
+          mask 2, v3
+            mul &v5, 2u32, 3u32
+            add &v2, v2, v5
+          and &v6, v3, v4
+          retn v2
+        decl f1: <7>
+          mul &v9, v7, v8
+          retn v9
+    "#,
+    )
+    .unwrap();
+    let mut optimized = program.clone();
+    assert!(optimized.optimize());
+    assert!(optimized.validate().is_empty());
+
+    for (a, b) in [(4, 9), (9, 4)].iter() {
+        let mut input = InputData::default();
+        input.main.insert("a".to_string(), Value::Integer(Integer::U32(*a)));
+        input.main.insert("b".to_string(), Value::Integer(Integer::U32(*b)));
+
+        let (output, constraints) = count_constraints(&program, &input);
+        let (optimized_output, optimized_constraints) = count_constraints(&optimized, &input);
+        assert_eq!(optimized_output, output);
+        assert!(
+            optimized_constraints < constraints,
+            "{} constraints after optimizing, {} before",
+            optimized_constraints,
+            constraints
+        );
+    }
+}
+
+#[test]
+fn optimize_preserves_native_output() {
+    let tests = load_tests();
+
+    for (name, raw) in &tests {
+        let program = Program::deserialize(raw).unwrap();
+        let input = InputData::deserialize(&fs::read(&*format!("{}.input", name)).unwrap()).unwrap();
+        let mut optimized = program.clone();
+        optimized.optimize();
+
+        let evaluate = |program: &Program| NativeEvaluator::<Fr, EdwardsGroupType>::new().evaluate(program, &input);
+        match (evaluate(&program), evaluate(&optimized)) {
+            (Ok(output), Ok(optimized_output)) => assert_eq!(optimized_output, output, "<{}> output differs", name),
+            (Err(_), Err(_)) => (),
+            (output, optimized_output) => panic!(
+                "<{}> evaluated to {:?} before optimizing and {:?} after",
+                name, output, optimized_output
+            ),
+        }
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_eval::{edwards_bls12::EdwardsGroupType, Evaluator, InstructionLocation, SetupEvaluator, SourceMap};
+use snarkvm_ir::{
+    CallCoreData,
+    CallData,
+    Function,
+    Input,
+    InputData,
+    Instruction,
+    Integer,
+    MaskData,
+    PredicateData,
+    Program,
+    QueryData,
+    RepeatData,
+    Type,
+    Value,
+};
+use snarkvm_r1cs::{ConstraintCounter, ConstraintSystem, TestConstraintSystem};
+
+mod common;
+use common::program;
+
+/// Evaluates `program` with a source map, checking that the map accounts for everything added to `cs`.
+fn evaluate_mapped<CS: ConstraintSystem<Fr>>(mut cs: CS, program: &Program, input: &InputData) -> SourceMap {
+    let constraints = cs.num_constraints();
+    let variables = cs.num_public_variables() + cs.num_private_variables();
+
+    let mut evaluator = SetupEvaluator::<Fr, EdwardsGroupType, _>::new(&mut cs).with_source_map();
+    evaluator.evaluate(program, input).unwrap();
+    let source_map = evaluator.source_map().unwrap().clone();
+
+    let total = source_map.total();
+    assert_eq!(total.constraints, cs.num_constraints() - constraints);
+    assert_eq!(
+        total.variables,
+        cs.num_public_variables() + cs.num_private_variables() - variables
+    );
+    source_map
+}
+
+/// let sum = 0; for i in 0..5 { if i < a { sum = add(sum, i); } } return sum;
+fn masked_loop_program() -> Program {
+    let main = Function {
+        argument_start_variable: 1,
+        instructions: vec![
+            Instruction::Store(QueryData {
+                destination: 1,
+                values: vec![Value::Integer(Integer::U32(0))],
+            }),
+            Instruction::Repeat(RepeatData {
+                instruction_count: 3,
+                iter_variable: 2,
+                inclusive: false,
+                from: Value::Integer(Integer::U32(0)),
+                to: Value::Integer(Integer::U32(5)),
+            }),
+            Instruction::Lt(QueryData {
+                destination: 3,
+                values: vec![Value::Ref(2), Value::Ref(0)],
+            }),
+            Instruction::Mask(MaskData {
+                instruction_count: 1,
+                condition: Value::Ref(3),
+            }),
+            Instruction::Call(CallData {
+                destination: 1,
+                index: 1,
+                arguments: vec![Value::Ref(1), Value::Ref(2)],
+            }),
+            Instruction::Return(PredicateData {
+                values: vec![Value::Ref(1)],
+            }),
+        ],
+    };
+    let add = Function {
+        argument_start_variable: 4,
+        instructions: vec![
+            Instruction::Add(QueryData {
+                destination: 6,
+                values: vec![Value::Ref(4), Value::Ref(5)],
+            }),
+            Instruction::Return(PredicateData {
+                values: vec![Value::Ref(6)],
+            }),
+        ],
+    };
+    program(
+        vec![Input {
+            variable: 0,
+            name: "a".to_string(),
+            type_: Type::U32,
+        }],
+        vec![main, add],
+    )
+}
+
+#[test]
+fn test_source_map_attribution() {
+    let program = masked_loop_program();
+    let mut input = InputData::default();
+    input.main.insert("a".to_string(), Value::Integer(Integer::U32(3)));
+
+    let source_map = evaluate_mapped(ConstraintCounter::default(), &program, &input);
+    assert!(source_map.inputs.variables > 0);
+
+    let location = |function, instruction| InstructionLocation { function, instruction };
+    let lt = &source_map.instructions[&location(0, 2)];
+    assert_eq!(lt.evaluations, 5);
+    assert!(lt.cost.constraints > 0);
+    assert_eq!(source_map.instructions[&location(0, 3)].evaluations, 5);
+    assert_eq!(source_map.instructions[&location(0, 4)].evaluations, 5);
+    assert_eq!(source_map.instructions[&location(1, 0)].evaluations, 5);
+    assert_eq!(source_map.instructions[&location(0, 1)].evaluations, 1);
+
+    let functions = source_map.functions();
+    assert!(functions[&1].constraints > 0);
+
+    let hottest = source_map.hottest(1);
+    assert_eq!(hottest.len(), 1);
+    assert!(
+        source_map
+            .instructions
+            .values()
+            .all(|entry| entry.cost.constraints <= hottest[0].1.cost.constraints)
+    );
+
+    let report = source_map.to_string();
+    assert!(report.contains("f#1i#0"));
+    assert!(report.contains("over 5 evaluation(s)"));
+}
+
+#[test]
+fn test_source_map_matches_across_constraint_systems() {
+    let program = masked_loop_program();
+    let mut input = InputData::default();
+    input.main.insert("a".to_string(), Value::Integer(Integer::U32(2)));
+
+    let counted = evaluate_mapped(ConstraintCounter::default(), &program, &input);
+    let mut cs = TestConstraintSystem::<Fr>::new();
+    let tested = evaluate_mapped(&mut cs, &program, &input);
+    assert!(cs.is_satisfied());
+    assert_eq!(counted, tested);
+}
+
+#[test]
+fn test_source_map_core_calls() {
+    let bytes = Type::Array(Box::new(Type::U8), Some(32));
+    let main = Function {
+        argument_start_variable: 2,
+        instructions: vec![
+            Instruction::CallCore(CallCoreData {
+                destination: 2,
+                identifier: "hash".to_string(),
+                arguments: vec![Value::Ref(0), Value::Ref(1)],
+            }),
+            Instruction::Return(PredicateData {
+                values: vec![Value::Ref(2)],
+            }),
+        ],
+    };
+    let program = program(
+        vec![
+            Input {
+                variable: 0,
+                name: "seed".to_string(),
+                type_: bytes.clone(),
+            },
+            Input {
+                variable: 1,
+                name: "message".to_string(),
+                type_: bytes,
+            },
+        ],
+        vec![main],
+    );
+    let mut input = InputData::default();
+    let value = |byte| Value::Array(vec![Value::Integer(Integer::U8(byte)); 32]);
+    input.main.insert("seed".to_string(), value(1));
+    input.main.insert("message".to_string(), value(2));
+
+    let source_map = evaluate_mapped(ConstraintCounter::default(), &program, &input);
+    let hash = &source_map.core_calls["hash"];
+    assert_eq!(hash.calls, 1);
+    assert!(hash.cost.constraints > 0);
+    assert_eq!(hash.cost, source_map.hottest(1)[0].1.cost);
+    assert!(source_map.to_string().contains("core calls:"));
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{collections::BTreeMap, fs, path::Path};
+use std::fs;
 
 use anyhow::Result;
 use snarkvm_curves::bls12_377::Fr;
@@ -22,24 +22,8 @@ use snarkvm_eval::{edwards_bls12::EdwardsGroupType, ConstrainedValue, Evaluator,
 use snarkvm_ir::{InputData, Program, SnarkVMVersion};
 use snarkvm_r1cs::TestConstraintSystem;
 
-fn inner_load_tests<P: AsRef<Path>>(path: P, out: &mut BTreeMap<String, Vec<u8>>) {
-    for item in path.as_ref().read_dir().unwrap() {
-        let item = item.unwrap();
-        let type_ = item.file_type().unwrap();
-        let path = item.path();
-        if type_.is_file() && path.extension().map(|x| x.to_string_lossy() == "ir").unwrap_or(false) {
-            out.insert(path.to_string_lossy().into_owned(), fs::read(&path).unwrap());
-        } else if type_.is_dir() {
-            inner_load_tests(&path, out);
-        }
-    }
-}
-
-pub fn load_tests() -> BTreeMap<String, Vec<u8>> {
-    let mut out = BTreeMap::new();
-    inner_load_tests("../tests/ir/", &mut out);
-    out
-}
+mod common;
+use common::load_tests;
 
 // fn mock_type(type_: &Type, index: u32) -> Value {
 //     match type_ {
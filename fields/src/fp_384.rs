@@ -17,6 +17,7 @@
 use crate::{
     impl_add_sub_from_field_ref,
     impl_mul_div_from_field_ref,
+    ConstantTimeField,
     FftField,
     Field,
     FieldError,
@@ -42,6 +43,7 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use subtle::{Choice, ConditionallySelectable};
 
 pub trait Fp384Parameters: FieldParameters<BigInteger = BigInteger> {}
 
@@ -79,6 +81,17 @@ impl<P: Fp384Parameters> Fp384<P> {
         }
     }
 
+    /// Reduces `self` like `reduce`, without branching on the value of `self`.
+    #[inline]
+    fn reduce_constant_time(&mut self) {
+        // Subtract the modulus, and keep the difference unless it underflowed.
+        let mut reduced = *self;
+        let borrow = reduced.0.sub_noborrow(&P::MODULUS);
+        self.conditional_assign(&reduced, !Choice::from(borrow as u8));
+    }
+
+    /// Sets `self` to the Montgomery reduction of the given limbs, which is less than twice the modulus
+    /// and must then be reduced.
     #[inline]
     #[allow(clippy::too_many_arguments)]
     fn mont_reduce(
@@ -165,7 +178,61 @@ impl<P: Fp384Parameters> Fp384<P> {
         (self.0).0[3] = r9;
         (self.0).0[4] = r10;
         (self.0).0[5] = r11;
-        self.reduce();
+    }
+
+    /// Sets `self` to the Montgomery product of `self` and `other`, which is less than twice the modulus
+    /// and must then be reduced.
+    #[inline]
+    fn mul_without_reduce(&mut self, other: &Self) {
+        let mut carry = 0;
+        let r0 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[0], &mut carry);
+        let r1 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[1], &mut carry);
+        let r2 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[2], &mut carry);
+        let r3 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[3], &mut carry);
+        let r4 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[4], &mut carry);
+        let r5 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[5], &mut carry);
+        let r6 = carry;
+        let mut carry = 0;
+        let r1 = fa::mac_with_carry(r1, (self.0).0[1], (other.0).0[0], &mut carry);
+        let r2 = fa::mac_with_carry(r2, (self.0).0[1], (other.0).0[1], &mut carry);
+        let r3 = fa::mac_with_carry(r3, (self.0).0[1], (other.0).0[2], &mut carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[1], (other.0).0[3], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[1], (other.0).0[4], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[1], (other.0).0[5], &mut carry);
+        let r7 = carry;
+        let mut carry = 0;
+        let r2 = fa::mac_with_carry(r2, (self.0).0[2], (other.0).0[0], &mut carry);
+        let r3 = fa::mac_with_carry(r3, (self.0).0[2], (other.0).0[1], &mut carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[2], (other.0).0[2], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[2], (other.0).0[3], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[2], (other.0).0[4], &mut carry);
+        let r7 = fa::mac_with_carry(r7, (self.0).0[2], (other.0).0[5], &mut carry);
+        let r8 = carry;
+        let mut carry = 0;
+        let r3 = fa::mac_with_carry(r3, (self.0).0[3], (other.0).0[0], &mut carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[3], (other.0).0[1], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[3], (other.0).0[2], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[3], (other.0).0[3], &mut carry);
+        let r7 = fa::mac_with_carry(r7, (self.0).0[3], (other.0).0[4], &mut carry);
+        let r8 = fa::mac_with_carry(r8, (self.0).0[3], (other.0).0[5], &mut carry);
+        let r9 = carry;
+        let mut carry = 0;
+        let r4 = fa::mac_with_carry(r4, (self.0).0[4], (other.0).0[0], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[4], (other.0).0[1], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[4], (other.0).0[2], &mut carry);
+        let r7 = fa::mac_with_carry(r7, (self.0).0[4], (other.0).0[3], &mut carry);
+        let r8 = fa::mac_with_carry(r8, (self.0).0[4], (other.0).0[4], &mut carry);
+        let r9 = fa::mac_with_carry(r9, (self.0).0[4], (other.0).0[5], &mut carry);
+        let r10 = carry;
+        let mut carry = 0;
+        let r5 = fa::mac_with_carry(r5, (self.0).0[5], (other.0).0[0], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[5], (other.0).0[1], &mut carry);
+        let r7 = fa::mac_with_carry(r7, (self.0).0[5], (other.0).0[2], &mut carry);
+        let r8 = fa::mac_with_carry(r8, (self.0).0[5], (other.0).0[3], &mut carry);
+        let r9 = fa::mac_with_carry(r9, (self.0).0[5], (other.0).0[4], &mut carry);
+        let r10 = fa::mac_with_carry(r10, (self.0).0[5], (other.0).0[5], &mut carry);
+        let r11 = carry;
+        self.mont_reduce(r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11);
     }
 }
 
@@ -278,6 +345,7 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
         let r10 = fa::mac_with_carry(r10, (self.0).0[5], (self.0).0[5], &mut carry);
         let r11 = fa::adc(r11, 0, &mut carry);
         self.mont_reduce(r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11);
+        self.reduce();
         self
     }
 
@@ -383,6 +451,7 @@ impl<P: Fp384Parameters> PrimeField for Fp384<P> {
             0,
             0,
         );
+        r.reduce();
         r.0
     }
 
@@ -398,6 +467,63 @@ impl<P: Fp384Parameters> PrimeField for Fp384<P> {
     }
 }
 
+impl<P: Fp384Parameters> ConstantTimeField for Fp384<P> {
+    #[inline]
+    fn add_constant_time(&self, other: &Self) -> Self {
+        let mut result = *self;
+        // This cannot exceed the backing capacity.
+        result.0.add_nocarry(&other.0);
+        result.reduce_constant_time();
+        result
+    }
+
+    #[inline]
+    fn sub_constant_time(&self, other: &Self) -> Self {
+        // If `other` is larger than `self`, the difference underflows, so add the modulus back.
+        let mut result = *self;
+        let borrow = result.0.sub_noborrow(&other.0);
+        let mut corrected = result;
+        corrected.0.add_nocarry(&P::MODULUS);
+        result.conditional_assign(&corrected, Choice::from(borrow as u8));
+        result
+    }
+
+    #[inline]
+    fn mul_constant_time(&self, other: &Self) -> Self {
+        let mut result = *self;
+        result.mul_without_reduce(other);
+        result.reduce_constant_time();
+        result
+    }
+
+    #[inline]
+    fn inverse_constant_time(&self) -> Option<Self> {
+        // By Fermat's little theorem, self^(p - 2) = self^(-1) for any nonzero `self`.
+        let mut exponent = P::MODULUS;
+        exponent.sub_noborrow(&BigInteger::from(2));
+        let inverse = self.pow_constant_time(exponent);
+
+        if self.is_zero() { None } else { Some(inverse) }
+    }
+
+    #[inline]
+    fn to_repr_constant_time(&self) -> BigInteger {
+        // Multiplying by the unreduced one removes the Montgomery factor.
+        self.mul_constant_time(&Self(BigInteger::from(1), PhantomData)).0
+    }
+}
+
+impl<P: Fp384Parameters> ConditionallySelectable for Fp384<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut result = *a;
+        for (limb, other) in (result.0).0.iter_mut().zip(&(b.0).0) {
+            limb.conditional_assign(other, choice);
+        }
+        result
+    }
+}
+
 impl<P: Fp384Parameters> FftField for Fp384<P> {
     type FftParameters = P;
 
@@ -638,55 +764,8 @@ impl<'a, P: Fp384Parameters> SubAssign<&'a Self> for Fp384<P> {
 impl<'a, P: Fp384Parameters> MulAssign<&'a Self> for Fp384<P> {
     #[inline]
     fn mul_assign(&mut self, other: &Self) {
-        let mut carry = 0;
-        let r0 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[0], &mut carry);
-        let r1 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[1], &mut carry);
-        let r2 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[2], &mut carry);
-        let r3 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[3], &mut carry);
-        let r4 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[4], &mut carry);
-        let r5 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[5], &mut carry);
-        let r6 = carry;
-        let mut carry = 0;
-        let r1 = fa::mac_with_carry(r1, (self.0).0[1], (other.0).0[0], &mut carry);
-        let r2 = fa::mac_with_carry(r2, (self.0).0[1], (other.0).0[1], &mut carry);
-        let r3 = fa::mac_with_carry(r3, (self.0).0[1], (other.0).0[2], &mut carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[1], (other.0).0[3], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[1], (other.0).0[4], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[1], (other.0).0[5], &mut carry);
-        let r7 = carry;
-        let mut carry = 0;
-        let r2 = fa::mac_with_carry(r2, (self.0).0[2], (other.0).0[0], &mut carry);
-        let r3 = fa::mac_with_carry(r3, (self.0).0[2], (other.0).0[1], &mut carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[2], (other.0).0[2], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[2], (other.0).0[3], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[2], (other.0).0[4], &mut carry);
-        let r7 = fa::mac_with_carry(r7, (self.0).0[2], (other.0).0[5], &mut carry);
-        let r8 = carry;
-        let mut carry = 0;
-        let r3 = fa::mac_with_carry(r3, (self.0).0[3], (other.0).0[0], &mut carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[3], (other.0).0[1], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[3], (other.0).0[2], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[3], (other.0).0[3], &mut carry);
-        let r7 = fa::mac_with_carry(r7, (self.0).0[3], (other.0).0[4], &mut carry);
-        let r8 = fa::mac_with_carry(r8, (self.0).0[3], (other.0).0[5], &mut carry);
-        let r9 = carry;
-        let mut carry = 0;
-        let r4 = fa::mac_with_carry(r4, (self.0).0[4], (other.0).0[0], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[4], (other.0).0[1], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[4], (other.0).0[2], &mut carry);
-        let r7 = fa::mac_with_carry(r7, (self.0).0[4], (other.0).0[3], &mut carry);
-        let r8 = fa::mac_with_carry(r8, (self.0).0[4], (other.0).0[4], &mut carry);
-        let r9 = fa::mac_with_carry(r9, (self.0).0[4], (other.0).0[5], &mut carry);
-        let r10 = carry;
-        let mut carry = 0;
-        let r5 = fa::mac_with_carry(r5, (self.0).0[5], (other.0).0[0], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[5], (other.0).0[1], &mut carry);
-        let r7 = fa::mac_with_carry(r7, (self.0).0[5], (other.0).0[2], &mut carry);
-        let r8 = fa::mac_with_carry(r8, (self.0).0[5], (other.0).0[3], &mut carry);
-        let r9 = fa::mac_with_carry(r9, (self.0).0[5], (other.0).0[4], &mut carry);
-        let r10 = fa::mac_with_carry(r10, (self.0).0[5], (other.0).0[5], &mut carry);
-        let r11 = carry;
-        self.mont_reduce(r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11);
+        self.mul_without_reduce(other);
+        self.reduce();
     }
 }
 
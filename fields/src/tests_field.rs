@@ -14,14 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{traits::FftParameters, FftField, Field, LegendreSymbol, PrimeField, SquareRootField};
+use crate::{traits::FftParameters, ConstantTimeField, FftField, Field, LegendreSymbol, PrimeField, SquareRootField};
 use snarkvm_utilities::{
     io::Cursor,
-    serialize::{CanonicalDeserialize, CanonicalSerialize, Flags, SWFlags},
+    serialize::{CanonicalDeserialize, CanonicalSerialize, EdwardsFlags, Flags, SWFlags},
 };
 
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
+use subtle::Choice;
 
 pub const ITERATIONS: u32 = 10;
 
@@ -211,6 +212,40 @@ fn random_string_tests<F: PrimeField>() {
     }
 }
 
+fn random_constant_time_tests<F: PrimeField + ConstantTimeField>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+        let b = F::rand(&mut rng);
+
+        // Conditional selection
+        assert_eq!(F::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(F::conditional_select(&a, &b, Choice::from(1)), b);
+
+        // Arithmetic
+        assert_eq!(a.add_constant_time(&b), a + b);
+        assert_eq!(a.sub_constant_time(&b), a - b);
+        assert_eq!(b.sub_constant_time(&a), b - a);
+        assert_eq!(a.mul_constant_time(&b), a * b);
+        assert_eq!(a.square_constant_time(), a.square());
+        assert_eq!(a.to_repr_constant_time(), a.to_repr());
+
+        // Montgomery ladder
+        let exponent: [u64; 4] = rng.gen();
+        assert_eq!(a.pow_constant_time(exponent), a.pow(exponent));
+        assert_eq!(a.pow_constant_time([0u64]), F::one());
+
+        // Fermat inversion
+        assert_eq!(a.inverse_constant_time(), a.inverse());
+    }
+
+    assert_eq!(F::zero().sub_constant_time(&F::zero()), F::zero());
+    assert_eq!(F::zero().to_repr_constant_time(), F::zero().to_repr());
+    assert!(F::zero().inverse_constant_time().is_none());
+    assert_eq!(F::one().inverse_constant_time(), Some(F::one()));
+}
+
 fn random_sqrt_tests<F: SquareRootField>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -363,7 +398,7 @@ pub fn fft_field_test<F: PrimeField + FftField>() {
     // assert_eq!(generator.pow(trace.into_repr().as_ref()), two_adic_root_of_unity);
 }
 
-pub fn primefield_test<F: PrimeField>() {
+pub fn primefield_test<F: PrimeField + ConstantTimeField>() {
     let one = F::one();
     assert_eq!(F::from_repr(one.to_repr()).unwrap(), one);
     assert_eq!(F::from_str("1").ok().unwrap(), one);
@@ -375,6 +410,7 @@ pub fn primefield_test<F: PrimeField>() {
     assert_eq!(F::from_str(&two.to_string()).ok().unwrap(), two);
 
     random_string_tests::<F>();
+    random_constant_time_tests::<F>();
     fft_field_test::<F>();
 }
 
@@ -413,6 +449,11 @@ pub fn frobenius_test<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: us
 pub fn field_serialization_test<F: Field>() {
     let buf_size = F::SERIALIZED_SIZE;
 
+    let characteristic = F::characteristic();
+    let top_limb = characteristic.iter().rposition(|limb| *limb != 0).unwrap();
+    let modulus_bits = 64 * top_limb + (64 - characteristic[top_limb].leading_zeros() as usize);
+    let spare_bits = (8 - modulus_bits % 8) % 8;
+
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
     for _ in 0..ITERATIONS {
@@ -441,7 +482,9 @@ pub fn field_serialization_test<F: Field>() {
             assert_eq!(a, b);
         }
 
-        {
+        // Flags are packed into the spare bits of the most significant byte, so a 255-bit
+        // modulus (e.g. the BLS12-381 scalar field) only has room for `EdwardsFlags`.
+        if SWFlags::num_bits() <= spare_bits {
             let mut serialized = vec![0u8; buf_size];
             let mut cursor = Cursor::new(&mut serialized[..]);
             a.serialize_with_flags(&mut cursor, SWFlags::from_y_sign(true)).unwrap();
@@ -450,6 +493,14 @@ pub fn field_serialization_test<F: Field>() {
             assert_eq!(flags.is_positive(), Some(true));
             assert!(!flags.is_infinity());
             assert_eq!(a, b);
+        } else {
+            let mut serialized = vec![0u8; buf_size];
+            let mut cursor = Cursor::new(&mut serialized[..]);
+            a.serialize_with_flags(&mut cursor, EdwardsFlags::from_y_sign(true)).unwrap();
+            let mut cursor = Cursor::new(&serialized[..]);
+            let (b, flags) = F::deserialize_with_flags::<_, EdwardsFlags>(&mut cursor).unwrap();
+            assert!(flags.is_positive());
+            assert_eq!(a, b);
         }
 
         #[derive(Default, Clone, Copy, Debug)]
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::PrimeField;
+use snarkvm_utilities::bititerator::BitIteratorBE;
+
+use subtle::{Choice, ConditionallySelectable};
+
+/// The interface for a prime field with arithmetic on secret values that runs in constant time.
+///
+/// The methods of this trait perform the same sequence of limb operations and memory accesses
+/// for every input of a given size, so their running time does not depend on the values they
+/// are given. They are intended for private keys and other secret data, and must be called
+/// explicitly; the operators and the methods of `Field` are faster, but may branch on their inputs.
+pub trait ConstantTimeField: PrimeField + ConditionallySelectable {
+    /// Returns `self + other`.
+    #[must_use]
+    fn add_constant_time(&self, other: &Self) -> Self;
+
+    /// Returns `self - other`.
+    #[must_use]
+    fn sub_constant_time(&self, other: &Self) -> Self;
+
+    /// Returns `self * other`.
+    #[must_use]
+    fn mul_constant_time(&self, other: &Self) -> Self;
+
+    /// Returns `self * self`.
+    #[must_use]
+    fn square_constant_time(&self) -> Self {
+        self.mul_constant_time(self)
+    }
+
+    /// Exponentiates this element by a number represented with `u64` limbs,
+    /// least significant limb first, using a Montgomery ladder over every bit of `exp`.
+    #[must_use]
+    fn pow_constant_time<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        // Invariant: r1 = r0 * self.
+        let mut r0 = Self::one();
+        let mut r1 = *self;
+
+        for bit in BitIteratorBE::new(exp) {
+            let choice = Choice::from(bit as u8);
+            Self::conditional_swap(&mut r0, &mut r1, choice);
+            r1 = r1.mul_constant_time(&r0);
+            r0 = r0.square_constant_time();
+            Self::conditional_swap(&mut r0, &mut r1, choice);
+        }
+
+        r0
+    }
+
+    /// Computes the multiplicative inverse of `self` if `self` is nonzero,
+    /// running in constant time in the value of `self`.
+    #[must_use]
+    fn inverse_constant_time(&self) -> Option<Self>;
+
+    /// Returns the canonical representation of `self`, like `PrimeField::to_repr`.
+    fn to_repr_constant_time(&self) -> Self::BigInteger;
+}
@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+use snarkvm_algorithms::{
+    crh::{PoseidonCRH, PoseidonParameters},
+    traits::CRH,
+};
+use snarkvm_fields::{FieldParameters, PrimeField};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    fields::FpGadget,
+    integers::uint::UInt8,
+    traits::{algorithms::CRHGadget, alloc::AllocGadget, fields::FieldGadget, integers::Integer},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonParametersGadget<F: PrimeField> {
+    pub(crate) parameters: PoseidonParameters<F>,
+}
+
+impl<F: PrimeField> AllocGadget<PoseidonParameters<F>, F> for PoseidonParametersGadget<F> {
+    fn alloc<Fn: FnOnce() -> Result<T, SynthesisError>, T: Borrow<PoseidonParameters<F>>, CS: ConstraintSystem<F>>(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(PoseidonParametersGadget {
+            parameters: value_gen()?.borrow().clone(),
+        })
+    }
+
+    fn alloc_input<
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<PoseidonParameters<F>>,
+        CS: ConstraintSystem<F>,
+    >(
+        _cs: CS,
+        value_gen: Fn,
+    ) -> Result<Self, SynthesisError> {
+        Ok(PoseidonParametersGadget {
+            parameters: value_gen()?.borrow().clone(),
+        })
+    }
+}
+
+impl<F: PrimeField> PoseidonParametersGadget<F> {
+    /// Applies the Poseidon permutation to `state`, as in `PoseidonParameters::permute`.
+    pub fn permute<CS: ConstraintSystem<F>>(&self, cs: CS, state: &mut [FpGadget<F>]) -> Result<(), SynthesisError> {
+        poseidon_permute_gadget(cs, &self.parameters, state)
+    }
+
+    /// Absorbs `input` into a Poseidon sponge, as in `PoseidonParameters::evaluate`.
+    pub fn evaluate<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        input: &[FpGadget<F>],
+    ) -> Result<FpGadget<F>, SynthesisError> {
+        poseidon_evaluate_gadget(cs, &self.parameters, input)
+    }
+}
+
+/// Applies the Poseidon permutation with `parameters` to `state`, as in `PoseidonParameters::permute`.
+pub fn poseidon_permute_gadget<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    parameters: &PoseidonParameters<F>,
+    state: &mut [FpGadget<F>],
+) -> Result<(), SynthesisError> {
+    let full_rounds_over_2 = parameters.full_rounds / 2;
+
+    for round in 0..(parameters.full_rounds + parameters.partial_rounds) {
+        let mut cs = cs.ns(|| format!("round_{}", round));
+
+        for (i, (element, constant)) in state.iter_mut().zip(&parameters.ark[round as usize]).enumerate() {
+            *element = element.add_constant(cs.ns(|| format!("apply_ark_{}", i)), constant)?;
+        }
+
+        let is_full_round = round < full_rounds_over_2 || round >= full_rounds_over_2 + parameters.partial_rounds;
+        if is_full_round {
+            for (i, element) in state.iter_mut().enumerate() {
+                *element = element.pow_by_constant(cs.ns(|| format!("apply_s_box_{}", i)), &[parameters.alpha])?;
+            }
+        } else {
+            let last = state.len() - 1;
+            state[last] = state[last].pow_by_constant(cs.ns(|| "apply_s_box"), &[parameters.alpha])?;
+        }
+
+        let mut new_state = Vec::with_capacity(state.len());
+        for (i, row) in parameters.mds.iter().enumerate() {
+            let mut sum = FpGadget::zero(cs.ns(|| format!("zero_{}", i)))?;
+            for (j, (coefficient, element)) in row.iter().zip(state.iter()).enumerate() {
+                let term = element.mul_by_constant(cs.ns(|| format!("mds_mul_{}_{}", i, j)), coefficient)?;
+                sum = sum.add(cs.ns(|| format!("mds_add_{}_{}", i, j)), &term)?;
+            }
+            new_state.push(sum);
+        }
+        state.clone_from_slice(&new_state);
+    }
+
+    Ok(())
+}
+
+/// Absorbs `input` into a Poseidon sponge with `parameters`, as in `PoseidonParameters::evaluate`.
+pub fn poseidon_evaluate_gadget<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    parameters: &PoseidonParameters<F>,
+    input: &[FpGadget<F>],
+) -> Result<FpGadget<F>, SynthesisError> {
+    let zero = FpGadget::zero(cs.ns(|| "zero"))?;
+    let mut state = vec![zero; parameters.rate + parameters.capacity];
+    state[parameters.rate] =
+        state[parameters.rate].add_constant(cs.ns(|| "input_length"), &F::from(input.len() as u64))?;
+
+    if input.is_empty() {
+        poseidon_permute_gadget(cs.ns(|| "permute"), parameters, &mut state)?;
+    }
+    for (i, chunk) in input.chunks(parameters.rate).enumerate() {
+        for (j, (element, input)) in state.iter_mut().zip(chunk).enumerate() {
+            *element = element.add(cs.ns(|| format!("absorb_{}_{}", i, j)), input)?;
+        }
+        poseidon_permute_gadget(cs.ns(|| format!("permute_{}", i)), parameters, &mut state)?;
+    }
+
+    Ok(state[0].clone())
+}
+
+/// Packs `bytes` into field elements of `CAPACITY / 8` bytes each, as in `ToConstraintField<F>` for `[u8]`.
+/// The packing is a linear combination of the input bits, and adds no constraints.
+pub fn bytes_to_field_gadgets<F: PrimeField, CS: ConstraintSystem<F>>(
+    mut cs: CS,
+    bytes: &[UInt8],
+) -> Result<Vec<FpGadget<F>>, SynthesisError> {
+    let max_size = (<F as PrimeField>::Parameters::CAPACITY / 8) as usize;
+
+    let mut elements = Vec::with_capacity((bytes.len() + max_size - 1) / max_size);
+    for (i, chunk) in bytes.chunks(max_size).enumerate() {
+        let mut element = FpGadget::zero(cs.ns(|| format!("zero_{}", i)))?;
+        let mut coefficient = F::one();
+        for (j, bit) in chunk.iter().flat_map(|byte| byte.to_bits_le()).enumerate() {
+            element =
+                element.conditionally_add_constant(cs.ns(|| format!("add_bit_{}_{}", i, j)), &bit, coefficient)?;
+            coefficient.double_in_place();
+        }
+        elements.push(element);
+    }
+    Ok(elements)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonCRHGadget<F: PrimeField, const INPUT_SIZE_FE: usize> {
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField, const INPUT_SIZE_FE: usize> CRHGadget<PoseidonCRH<F, INPUT_SIZE_FE>, F>
+    for PoseidonCRHGadget<F, INPUT_SIZE_FE>
+{
+    type OutputGadget = FpGadget<F>;
+    type ParametersGadget = PoseidonParametersGadget<F>;
+
+    fn check_evaluation_gadget<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        parameters: &Self::ParametersGadget,
+        input: Vec<UInt8>,
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        let input_size_bits = <PoseidonCRH<F, INPUT_SIZE_FE> as CRH>::INPUT_SIZE_BITS;
+        if input.len() * 8 > input_size_bits {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // Pad the input if it is not the correct length.
+        let mut padded_input = input;
+        padded_input.resize(input_size_bits / 8, UInt8::constant(0u8));
+
+        let elements = bytes_to_field_gadgets(cs.ns(|| "pack_input"), &padded_input)?;
+        debug_assert_eq!(elements.len(), INPUT_SIZE_FE);
+
+        parameters.evaluate(cs.ns(|| "evaluate"), &elements)
+    }
+}
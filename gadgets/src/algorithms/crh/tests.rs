@@ -17,11 +17,18 @@
 use rand::{thread_rng, Rng};
 
 use snarkvm_algorithms::{
-    crh::{BoweHopwoodPedersenCRH, BoweHopwoodPedersenCompressedCRH, PedersenCRH, PedersenCompressedCRH},
+    crh::{
+        BoweHopwoodPedersenCRH,
+        BoweHopwoodPedersenCompressedCRH,
+        PedersenCRH,
+        PedersenCompressedCRH,
+        PoseidonCRH,
+    },
     traits::{CRHParameters, CRH},
 };
 use snarkvm_curves::{
     bls12_377::Fr,
+    bw6_761,
     edwards_bls12::{EdwardsAffine, EdwardsProjective},
 };
 use snarkvm_fields::{Field, PrimeField};
@@ -33,6 +40,7 @@ use crate::{
         BoweHopwoodPedersenCompressedCRHGadget,
         PedersenCRHGadget,
         PedersenCompressedCRHGadget,
+        PoseidonCRHGadget,
     },
     curves::edwards_bls12::EdwardsBls12Gadget,
     integers::uint::UInt8,
@@ -49,9 +57,13 @@ const PEDERSEN_WINDOW_SIZE: usize = 128;
 const BHP_NUM_WINDOWS: usize = 32;
 const BHP_WINDOW_SIZE: usize = 48;
 
+const POSEIDON_INPUT_SIZE_FE: usize = 5;
+
 const PEDERSEN_HASH_CONSTRAINTS: usize = 5632;
 const PEDERSEN_HASH_CONSTRAINTS_ON_AFFINE: usize = 6656;
 const BOWE_HOPWOOD_HASH_CONSTRAINTS: usize = 3974;
+const POSEIDON_HASH_CONSTRAINTS_ON_BLS12_377: usize = 2901;
+const POSEIDON_HASH_CONSTRAINTS_ON_BW6_761: usize = 2796;
 
 fn generate_input<F: Field, CS: ConstraintSystem<F>, R: Rng>(
     mut cs: CS,
@@ -230,3 +242,27 @@ mod bowe_hopwood_pedersen_compressed_crh_gadget_on_projective {
         primitive_crh_gadget_test::<Fr, TestCRH, TestCRHGadget>(BOWE_HOPWOOD_HASH_CONSTRAINTS)
     }
 }
+
+mod poseidon_crh_gadget_on_bls12_377 {
+    use super::*;
+
+    type TestCRH = PoseidonCRH<Fr, POSEIDON_INPUT_SIZE_FE>;
+    type TestCRHGadget = PoseidonCRHGadget<Fr, POSEIDON_INPUT_SIZE_FE>;
+
+    #[test]
+    fn primitive_gadget_test() {
+        primitive_crh_gadget_test::<Fr, TestCRH, TestCRHGadget>(POSEIDON_HASH_CONSTRAINTS_ON_BLS12_377)
+    }
+}
+
+mod poseidon_crh_gadget_on_bw6_761 {
+    use super::*;
+
+    type TestCRH = PoseidonCRH<bw6_761::Fr, POSEIDON_INPUT_SIZE_FE>;
+    type TestCRHGadget = PoseidonCRHGadget<bw6_761::Fr, POSEIDON_INPUT_SIZE_FE>;
+
+    #[test]
+    fn primitive_gadget_test() {
+        primitive_crh_gadget_test::<bw6_761::Fr, TestCRH, TestCRHGadget>(POSEIDON_HASH_CONSTRAINTS_ON_BW6_761)
+    }
+}
@@ -20,8 +20,8 @@ use digest::Digest;
 use itertools::Itertools;
 
 use snarkvm_algorithms::encryption::{GroupEncryption, GroupEncryptionParameters, GroupEncryptionPublicKey};
-use snarkvm_curves::traits::{Group, ProjectiveCurve};
-use snarkvm_fields::{Field, PrimeField};
+use snarkvm_curves::traits::{ConstantTimeGroup, Group, ProjectiveCurve};
+use snarkvm_fields::{ConstantTimeField, Field, PrimeField};
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
 use snarkvm_utilities::{to_bytes_le, CanonicalDeserialize, CanonicalSerialize, ToBytes};
 
@@ -484,12 +484,15 @@ pub struct GroupEncryptionGadget<G: Group + ProjectiveCurve, F: PrimeField, GG:
 }
 
 impl<
-    G: Group + ProjectiveCurve,
-    SG: Group + CanonicalSerialize + CanonicalDeserialize,
+    G: Group + ProjectiveCurve + ConstantTimeGroup,
+    SG: Group + ConstantTimeGroup + CanonicalSerialize + CanonicalDeserialize,
     D: Digest + Send + Sync,
     F: PrimeField,
     GG: CompressedGroupGadget<G, F>,
 > EncryptionGadget<GroupEncryption<G, SG, D>, F> for GroupEncryptionGadget<G, F, GG>
+where
+    <G as Group>::ScalarField: ConstantTimeField,
+    <SG as Group>::ScalarField: ConstantTimeField,
 {
     type BlindingExponentGadget = GroupEncryptionBlindingExponentsGadget<G>;
     type CiphertextGadget = GroupEncryptionCiphertextGadget<G, F, GG>;
@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::borrow::Borrow;
+
+use snarkvm_algorithms::{
+    merkle_tree::SparseMerklePath,
+    traits::{MerkleParameters, CRH},
+};
+use snarkvm_fields::Field;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    algorithms::merkle_tree::merkle_path::hash_inner_node_gadget,
+    bits::{boolean::Boolean, ToBitsLEGadget, ToBytesGadget},
+    integers::uint::UInt8,
+    traits::{algorithms::CRHGadget, alloc::AllocGadget, eq::ConditionalEqGadget, select::CondSelectGadget},
+};
+
+pub struct SparseMerklePathGadget<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: Field> {
+    siblings: Vec<HG::OutputGadget>,
+}
+
+impl<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: Field> SparseMerklePathGadget<P, HG, F> {
+    pub fn check_membership<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        parameters: &HG::ParametersGadget,
+        root: &HG::OutputGadget,
+        key: impl ToBytesGadget<F>,
+    ) -> Result<(), SynthesisError> {
+        self.conditionally_check_membership(cs, parameters, root, key, &Boolean::Constant(true))
+    }
+
+    pub fn conditionally_check_membership<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        parameters: &HG::ParametersGadget,
+        root: &HG::OutputGadget,
+        key: impl ToBytesGadget<F>,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        // The leaf of a key in the tree holds the hash of the key.
+        let key_bytes = key.to_bytes(&mut cs.ns(|| "key_to_bytes"))?;
+        let key_hash = HG::check_evaluation_gadget(cs.ns(|| "key_hash"), parameters, key_bytes)?;
+
+        let computed_root = self.compute_root(cs.ns(|| "compute_root"), parameters, &key_hash, &key_hash)?;
+
+        root.conditional_enforce_equal(&mut cs.ns(|| "root_is_last"), &computed_root, should_enforce)
+    }
+
+    pub fn check_non_membership<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        parameters: &HG::ParametersGadget,
+        root: &HG::OutputGadget,
+        key: impl ToBytesGadget<F>,
+    ) -> Result<(), SynthesisError> {
+        self.conditionally_check_non_membership(cs, parameters, root, key, &Boolean::Constant(true))
+    }
+
+    pub fn conditionally_check_non_membership<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        parameters: &HG::ParametersGadget,
+        root: &HG::OutputGadget,
+        key: impl ToBytesGadget<F>,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let key_bytes = key.to_bytes(&mut cs.ns(|| "key_to_bytes"))?;
+        let key_hash = HG::check_evaluation_gadget(cs.ns(|| "key_hash"), parameters, key_bytes)?;
+
+        // The leaf of a key that is not in the tree is empty, and is hashed as in `MerkleParameters::hash_empty`.
+        let empty_bytes = UInt8::constant_vec(&vec![0u8; <P::H as CRH>::INPUT_SIZE_BITS / 8]);
+        let empty_hash = HG::check_evaluation_gadget(cs.ns(|| "empty_hash"), parameters, empty_bytes)?;
+
+        let computed_root = self.compute_root(cs.ns(|| "compute_root"), parameters, &key_hash, &empty_hash)?;
+
+        root.conditional_enforce_equal(&mut cs.ns(|| "root_is_last"), &computed_root, should_enforce)
+    }
+
+    /// Returns the root obtained from the given leaf, placed at the position given by the key hash.
+    fn compute_root<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        parameters: &HG::ParametersGadget,
+        key_hash: &HG::OutputGadget,
+        leaf_hash: &HG::OutputGadget,
+    ) -> Result<HG::OutputGadget, SynthesisError> {
+        if self.siblings.len() != P::DEPTH {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // The position of the leaf is given by the first little-endian bits of the key hash,
+        // which must be its canonical encoding so that a key has a single position in the tree.
+        let key_hash_bytes = key_hash.to_bytes_strict(&mut cs.ns(|| "key_hash_to_bytes"))?;
+        let position = key_hash_bytes.to_bits_le(&mut cs.ns(|| "key_hash_to_bits"))?;
+        if position.len() < P::DEPTH {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // Check levels between leaf level and root.
+        let mut current_hash = leaf_hash.clone();
+        for (i, (sibling_hash, is_right)) in self.siblings.iter().zip(position).enumerate() {
+            let left_hash = HG::OutputGadget::conditionally_select(
+                &mut cs.ns(|| format!("select_left_{}", i)),
+                &is_right,
+                sibling_hash,
+                &current_hash,
+            )?;
+            let right_hash = HG::OutputGadget::conditionally_select(
+                &mut cs.ns(|| format!("select_right_{}", i)),
+                &is_right,
+                &current_hash,
+                sibling_hash,
+            )?;
+
+            current_hash = hash_inner_node_gadget::<P::H, HG, F, _>(
+                &mut cs.ns(|| format!("hash_inner_node_{}", i)),
+                parameters,
+                &left_hash,
+                &right_hash,
+            )?;
+        }
+
+        Ok(current_hash)
+    }
+}
+
+impl<P, HGadget, F> AllocGadget<SparseMerklePath<P>, F> for SparseMerklePathGadget<P, HGadget, F>
+where
+    P: MerkleParameters,
+    HGadget: CRHGadget<P::H, F>,
+    F: Field,
+{
+    fn alloc<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SparseMerklePath<P>>,
+    {
+        let mut siblings = Vec::new();
+        for (i, sibling) in value_gen()?.borrow().siblings.iter().enumerate() {
+            siblings.push(HGadget::OutputGadget::alloc(
+                &mut cs.ns(|| format!("sibling_{}", i)),
+                || Ok(*sibling),
+            )?);
+        }
+        Ok(SparseMerklePathGadget { siblings })
+    }
+
+    fn alloc_input<Fn, T, CS: ConstraintSystem<F>>(mut cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<SparseMerklePath<P>>,
+    {
+        let mut siblings = Vec::new();
+        for (i, sibling) in value_gen()?.borrow().siblings.iter().enumerate() {
+            siblings.push(HGadget::OutputGadget::alloc_input(
+                &mut cs.ns(|| format!("sibling_{}", i)),
+                || Ok(*sibling),
+            )?);
+        }
+
+        Ok(SparseMerklePathGadget { siblings })
+    }
+}
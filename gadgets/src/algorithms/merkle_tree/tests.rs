@@ -21,7 +21,7 @@ use blake2::{digest::Digest, Blake2s};
 use snarkvm_algorithms::{
     crh::{BoweHopwoodPedersenCompressedCRH, PedersenCRH, PedersenCompressedCRH},
     define_masked_merkle_tree_parameters,
-    merkle_tree::MerkleTree,
+    merkle_tree::{MerkleTree, SparseMerkleTree},
     traits::{MaskedMerkleParameters, MerkleParameters, CRH},
 };
 use snarkvm_curves::{
@@ -190,6 +190,82 @@ fn generate_masked_merkle_tree<P: MaskedMerkleParameters, F: PrimeField, HG: Mas
     assert!(cs.is_satisfied());
 }
 
+fn generate_sparse_merkle_tree<P: MerkleParameters, F: PrimeField, HG: CRHGadget<P::H, F>>(
+    keys: &[[u8; 30]],
+    absent_keys: &[[u8; 30]],
+    use_bad_root: bool,
+) {
+    let parameters = P::default();
+    let mut tree = SparseMerkleTree::<P>::new(Arc::new(parameters.clone())).unwrap();
+    for key in keys {
+        tree.insert(key).unwrap();
+    }
+    let root = tree.root();
+
+    let mut cs = TestConstraintSystem::<F>::new();
+
+    // Allocate Merkle tree root
+    let root = <HG as CRHGadget<_, _>>::OutputGadget::alloc(&mut cs.ns(|| "new_digest"), || {
+        if use_bad_root {
+            Ok(<P::H as CRH>::Output::default())
+        } else {
+            Ok(root)
+        }
+    })
+    .unwrap();
+
+    // Allocate Parameters for CRH
+    let crh_parameters = <HG as CRHGadget<_, _>>::ParametersGadget::alloc(&mut cs.ns(|| "new_parameters"), || {
+        Ok(parameters.parameters())
+    })
+    .unwrap();
+
+    for (i, key) in keys.iter().enumerate() {
+        let proof = tree.generate_proof(key).unwrap();
+        assert!(proof.verify_membership(&tree.root(), key).unwrap());
+        let path =
+            SparseMerklePathGadget::<_, HG, _>::alloc(&mut cs.ns(|| format!("new_membership_witness_{}", i)), || {
+                Ok(proof)
+            })
+            .unwrap();
+
+        let key_g = UInt8::constant_vec(key);
+        path.check_membership(
+            &mut cs.ns(|| format!("membership_check_{}", i)),
+            &crh_parameters,
+            &root,
+            &key_g.as_slice(),
+        )
+        .unwrap();
+    }
+
+    for (i, key) in absent_keys.iter().enumerate() {
+        let proof = tree.generate_proof(key).unwrap();
+        assert!(proof.verify_non_membership(&tree.root(), key).unwrap());
+        let path = SparseMerklePathGadget::<_, HG, _>::alloc(
+            &mut cs.ns(|| format!("new_non_membership_witness_{}", i)),
+            || Ok(proof),
+        )
+        .unwrap();
+
+        let key_g = UInt8::constant_vec(key);
+        path.check_non_membership(
+            &mut cs.ns(|| format!("non_membership_check_{}", i)),
+            &crh_parameters,
+            &root,
+            &key_g.as_slice(),
+        )
+        .unwrap();
+    }
+
+    if !cs.is_satisfied() {
+        println!("Unsatisfied constraint: {}", cs.which_is_unsatisfied().unwrap());
+    }
+    println!("number of constraints: {}", cs.num_constraints());
+
+    assert!(cs.is_satisfied());
+}
+
 mod merkle_tree_pedersen_crh_on_affine {
     use super::*;
 
@@ -269,6 +345,28 @@ mod merkle_tree_compressed_pedersen_crh_on_projective {
         }
         generate_masked_merkle_tree::<EdwardsMerkleParameters, Fr, HG>(&leaves, true);
     }
+
+    #[test]
+    fn good_sparse_root_test() {
+        // The absent key must not share its position at depth 4 with the present one.
+        let keys = [[1u8; 30], [3u8; 30]];
+        generate_sparse_merkle_tree::<EdwardsMerkleParameters, Fr, HG>(&keys[..1], &keys[1..], false);
+    }
+
+    #[should_panic]
+    #[test]
+    fn bad_sparse_root_test() {
+        // The absent key must not share its position at depth 4 with the present one.
+        let keys = [[1u8; 30], [3u8; 30]];
+        generate_sparse_merkle_tree::<EdwardsMerkleParameters, Fr, HG>(&keys[..1], &keys[1..], true);
+    }
+
+    #[should_panic]
+    #[test]
+    fn present_key_non_membership_test() {
+        let keys = [[1u8; 30]];
+        generate_sparse_merkle_tree::<EdwardsMerkleParameters, Fr, HG>(&keys, &keys, false);
+    }
 }
 
 mod merkle_tree_bowe_hopwood_pedersen_compressed_crh_on_projective {
@@ -17,5 +17,8 @@
 pub mod blake2s;
 pub use blake2s::*;
 
+pub mod poseidon;
+pub use poseidon::*;
+
 #[cfg(test)]
 pub mod tests;
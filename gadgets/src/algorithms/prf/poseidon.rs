@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    algorithms::crh::{bytes_to_field_gadgets, poseidon_evaluate_gadget},
+    fields::FpGadget,
+    integers::uint::UInt8,
+    traits::algorithms::PRFGadget,
+};
+use snarkvm_algorithms::prf::{PoseidonPRF, PoseidonPRFField};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use std::marker::PhantomData;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonPRFGadget<F: PoseidonPRFField> {
+    _field: PhantomData<F>,
+}
+
+impl<F: PoseidonPRFField> PRFGadget<PoseidonPRF<F>, F> for PoseidonPRFGadget<F> {
+    type OutputGadget = FpGadget<F>;
+
+    fn new_seed<CS: ConstraintSystem<F>>(mut cs: CS, seed: &[u8; 32]) -> Vec<UInt8> {
+        UInt8::alloc_vec(&mut cs.ns(|| "alloc_seed"), seed).unwrap()
+    }
+
+    fn check_evaluation_gadget<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        seed: &[UInt8],
+        input: &[UInt8],
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        assert_eq!(seed.len(), 32);
+
+        let mut bytes = seed.to_vec();
+        bytes.extend_from_slice(input);
+        let elements = bytes_to_field_gadgets(cs.ns(|| "pack_seed_and_input"), &bytes)?;
+
+        // The parameters of the PRF are fixed, so they are embedded as constants.
+        poseidon_evaluate_gadget(cs.ns(|| "poseidon_prf"), F::poseidon_prf_parameters(), &elements)
+    }
+}
@@ -19,8 +19,11 @@ use digest::{Update, VariableOutput};
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 
-use snarkvm_algorithms::{prf::blake2s::Blake2s as B2SPRF, traits::PRF};
-use snarkvm_curves::bls12_377::Fr;
+use snarkvm_algorithms::{
+    prf::{blake2s::Blake2s as B2SPRF, PoseidonPRF, PoseidonPRFField},
+    traits::PRF,
+};
+use snarkvm_curves::{bls12_377::Fr, bw6_761};
 use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
 
 use crate::{
@@ -157,3 +160,39 @@ fn test_blake2s() {
         }
     }
 }
+
+fn poseidon_prf_gadget_test<F: PoseidonPRFField>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let mut cs = TestConstraintSystem::<F>::new();
+
+    let mut seed = [0u8; 32];
+    rng.fill(&mut seed);
+    let input = F::rand(&mut rng);
+
+    let seed_gadget = PoseidonPRFGadget::<F>::new_seed(&mut cs.ns(|| "declare_seed"), &seed);
+    let input_gadget = UInt8::alloc_vec(&mut cs.ns(|| "declare_input"), &input.to_bytes_le().unwrap()).unwrap();
+    let out = PoseidonPRF::<F>::evaluate(&seed, &input).unwrap();
+    let actual_out_gadget =
+        <PoseidonPRFGadget<F> as PRFGadget<_, F>>::OutputGadget::alloc(&mut cs.ns(|| "declare_output"), || Ok(out))
+            .unwrap();
+
+    let output_gadget =
+        PoseidonPRFGadget::<F>::check_evaluation_gadget(&mut cs.ns(|| "eval_poseidon"), &seed_gadget, &input_gadget)
+            .unwrap();
+    output_gadget.enforce_equal(&mut cs, &actual_out_gadget).unwrap();
+
+    if !cs.is_satisfied() {
+        println!("which is unsatisfied: {:?}", cs.which_is_unsatisfied().unwrap());
+    }
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_poseidon_prf_on_bls12_377() {
+    poseidon_prf_gadget_test::<Fr>();
+}
+
+#[test]
+fn test_poseidon_prf_on_bw6_761() {
+    poseidon_prf_gadget_test::<bw6_761::Fr>();
+}
@@ -31,8 +31,8 @@ use snarkvm_algorithms::{
     prf::Blake2s,
     signature::{Schnorr, SchnorrParameters, SchnorrPublicKey, SchnorrSignature},
 };
-use snarkvm_curves::traits::Group;
-use snarkvm_fields::{Field, PrimeField};
+use snarkvm_curves::traits::{ConstantTimeGroup, Group};
+use snarkvm_fields::{ConstantTimeField, Field, PrimeField};
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
 use snarkvm_utilities::{
     serialize::{CanonicalDeserialize, CanonicalSerialize},
@@ -282,12 +282,14 @@ pub struct SchnorrPublicKeyRandomizationGadget<G: Group, F: PrimeField, GG: Grou
 }
 
 impl<
-    G: Group + CanonicalSerialize + CanonicalDeserialize,
+    G: Group + ConstantTimeGroup + CanonicalSerialize + CanonicalDeserialize,
     GG: GroupGadget<G, F>,
     FG: FieldGadget<F, F>,
     D: Digest + Send + Sync,
     F: PrimeField,
 > SignaturePublicKeyRandomizationGadget<Schnorr<G, D>, F> for SchnorrPublicKeyRandomizationGadget<G, F, GG, FG>
+where
+    <G as Group>::ScalarField: ConstantTimeField,
 {
     type ParametersGadget = SchnorrParametersGadget<G, F, D>;
     type PublicKeyGadget = SchnorrPublicKeyGadget<G, F, GG>;
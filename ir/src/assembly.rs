@@ -0,0 +1,625 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Textual assembly for IR programs.
+//!
+//! The assembly is the output of the `Display` impl of [`Program`], and [`Program::parse`] reads it back.
+//! A program is a sequence of lines. Blank lines and lines starting with `//` are ignored, as is indentation.
+//!
+//! The header comes first:
+//! ```text
+//! version 0.7.9                     // optional, defaults to the current version
+//! inline_limit 100
+//! main_input &v0 "a": u32           // one line per input, in variable order
+//! constant_input &v1 "b": [u8; 2]
+//! register_input &v2 "r": (bool, field)
+//! public_state &v3 "s": {x: u8, y: group}
+//! private_record_state &v4 "p": address
+//! private_leaf_state &v5 "l": [char; _]
+//! ```
+//!
+//! Each function is declared by `decl f<index>: <argument start variable>`, with indices counting up from zero,
+//! followed by its instructions. An instruction is its mnemonic followed by comma separated operands:
+//! ```text
+//! decl f0: <0>
+//!   add &v2, v0, 1u32               // destination variables are written `&v<index>`
+//!   mask 1, v2                      // the next 1 instruction is masked by v2
+//!     assert true
+//!   repeat 1, &v3, false, 0u8, 4u8  // instruction count, iteration variable, inclusive, from, to
+//!     log INFO, "{}", v3
+//!   call &v4, f1, v2                // calls function 1
+//!   ccall &v5, 'hash', v4           // calls a core function
+//!   retn v5
+//! ```
+//!
+//! Values are written as:
+//! * `v<index>`: a variable reference
+//! * `true`, `false`
+//! * `<integer><type>`: an integer, e.g. `-3i8` or `200u128`
+//! * `[<limbs>]field`, `-[<limbs>]field`: a field, with little endian `u64` limbs
+//! * `[<limbs>]group`, `(<coordinate>, <coordinate>)group`: a group, where a coordinate is a field, `+`, `-`, or `_`
+//! * `'<char>'`, `"<string>"`: with `\t`, `\r`, `\n`, `\'`, `\"`, `\\`, and `\u{<hex>}` escapes
+//! * `aleo1...`: an address
+//! * `[<values>]`, `(<values>)`: an array or a tuple
+//!
+//! Types are `address`, `bool`, `field`, `char`, `group`, `u8` through `u128`, `i8` through `i128`,
+//! `[<type>; <length>]`, `[<type>; _]`, `(<types>)`, and circuits `{<name>: <type>, ...}`.
+
+use std::str::FromStr;
+
+use crate::{
+    ir,
+    Field,
+    Function,
+    Group,
+    GroupCoordinate,
+    Header,
+    Input,
+    Instruction,
+    InstructionOp,
+    Integer,
+    LogLevel,
+    Program,
+    SnarkVMVersion,
+    Type,
+    Value,
+};
+
+use anyhow::{anyhow, Result};
+use bech32::FromBase32;
+
+impl Program {
+    /// parses a program from its textual assembly
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut parser = ProgramParser::default();
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            parser.parse_line(line).map_err(|e| anyhow!("line {}: {}", i + 1, e))?;
+        }
+        parser.finish()
+    }
+}
+
+impl FromStr for Program {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+}
+
+#[derive(Default)]
+struct ProgramParser {
+    version: Option<SnarkVMVersion>,
+    inline_limit: Option<u32>,
+    main_inputs: Vec<Input>,
+    constant_inputs: Vec<Input>,
+    register_inputs: Vec<Input>,
+    public_states: Vec<Input>,
+    private_record_states: Vec<Input>,
+    private_leaf_states: Vec<Input>,
+    functions: Vec<Function>,
+}
+
+impl ProgramParser {
+    fn parse_line(&mut self, line: &str) -> Result<()> {
+        let mut cursor = Cursor::new(line);
+        let keyword = cursor.parse_word();
+        match keyword {
+            "decl" => {
+                cursor.expect("f")?;
+                let index = cursor.parse_u32()?;
+                if index as usize != self.functions.len() {
+                    return Err(anyhow!(
+                        "expected declaration of f{}, found f{}",
+                        self.functions.len(),
+                        index
+                    ));
+                }
+                cursor.expect(":")?;
+                cursor.expect("<")?;
+                let argument_start_variable = cursor.parse_u32()?;
+                cursor.expect(">")?;
+                self.functions.push(Function {
+                    argument_start_variable,
+                    instructions: vec![],
+                });
+            }
+            "version" | "inline_limit" | "main_input" | "constant_input" | "register_input" | "public_state"
+            | "private_record_state" | "private_leaf_state"
+                if !self.functions.is_empty() =>
+            {
+                return Err(anyhow!("header directive `{}` after a function declaration", keyword));
+            }
+            "version" => {
+                let major = cursor.parse_u32()?;
+                cursor.expect(".")?;
+                let minor = cursor.parse_u32()?;
+                cursor.expect(".")?;
+                let patch = cursor.parse_u32()?;
+                self.version = Some(SnarkVMVersion::new(major, minor, patch));
+            }
+            "inline_limit" => {
+                self.inline_limit = Some(cursor.parse_u32()?);
+            }
+            "main_input" => self.main_inputs.push(cursor.parse_input()?),
+            "constant_input" => self.constant_inputs.push(cursor.parse_input()?),
+            "register_input" => self.register_inputs.push(cursor.parse_input()?),
+            "public_state" => self.public_states.push(cursor.parse_input()?),
+            "private_record_state" => self.private_record_states.push(cursor.parse_input()?),
+            "private_leaf_state" => self.private_leaf_states.push(cursor.parse_input()?),
+            mnemonic => {
+                let op = InstructionOp::from_mnemonic(mnemonic)
+                    .ok_or_else(|| anyhow!("unknown instruction `{}`", mnemonic))?;
+                let instruction = cursor.parse_instruction(op)?;
+                self.functions
+                    .last_mut()
+                    .ok_or_else(|| anyhow!("instruction outside of a function declaration"))?
+                    .instructions
+                    .push(instruction);
+            }
+        }
+        cursor.expect_end()
+    }
+
+    fn finish(self) -> Result<Program> {
+        Ok(Program {
+            header: Header {
+                version: self.version.unwrap_or_default(),
+                main_inputs: self.main_inputs,
+                constant_inputs: self.constant_inputs,
+                register_inputs: self.register_inputs,
+                public_states: self.public_states,
+                private_record_states: self.private_record_states,
+                private_leaf_states: self.private_leaf_states,
+                inline_limit: self
+                    .inline_limit
+                    .ok_or_else(|| anyhow!("missing `inline_limit` in program header"))?,
+            },
+            functions: self.functions,
+        })
+    }
+}
+
+fn control_u32(u32: u32) -> ir::Operand {
+    ir::Operand {
+        u32: Some(ir::U32 { u32 }),
+        ..Default::default()
+    }
+}
+
+fn control_bool(boolean: bool) -> ir::Operand {
+    ir::Operand {
+        boolean: Some(ir::Bool { boolean }),
+        ..Default::default()
+    }
+}
+
+fn control_string(string: String) -> ir::Operand {
+    ir::Operand {
+        string: Some(ir::String { string }),
+        ..Default::default()
+    }
+}
+
+/// a position within a single line of assembly
+struct Cursor<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.position = self.input.len() - self.rest().trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest().chars().next()
+    }
+
+    /// consumes `token` if the remaining input starts with it
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("`{}`", token)))
+        }
+    }
+
+    fn expect_end(&mut self) -> Result<()> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.unexpected("end of line")),
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> anyhow::Error {
+        if self.rest().is_empty() {
+            anyhow!("expected {}, found end of line", expected)
+        } else {
+            anyhow!("expected {}, found `{}`", expected, self.rest())
+        }
+    }
+
+    /// runs `parse`, rewinding the cursor if it fails
+    fn attempt<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T>) -> Option<T> {
+        let position = self.position;
+        let result = parse(self).ok();
+        if result.is_none() {
+            self.position = position;
+        }
+        result
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c: char| !predicate(c)).unwrap_or_else(|| rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+
+    /// parses a run of alphanumeric characters and underscores
+    fn parse_word(&mut self) -> &'a str {
+        self.skip_whitespace();
+        self.take_while(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    fn parse_number<T: FromStr>(&mut self) -> Result<T> {
+        self.skip_whitespace();
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        digits.parse().map_err(|_| self.unexpected("number"))
+    }
+
+    fn parse_u32(&mut self) -> Result<u32> {
+        self.parse_number()
+    }
+
+    /// parses a destination variable, `&v<index>`
+    fn parse_variable(&mut self) -> Result<u32> {
+        self.expect("&v")?;
+        self.parse_u32()
+    }
+
+    /// parses a comma separated list of items up to `close`
+    fn parse_list<T>(&mut self, close: &str, mut parse: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        let mut items = vec![];
+        if self.eat(close) {
+            return Ok(items);
+        }
+        loop {
+            items.push(parse(self)?);
+            if self.eat(close) {
+                return Ok(items);
+            }
+            self.expect(",")?;
+        }
+    }
+
+    /// parses the contents of a quoted char or string as code points, unescaping them
+    fn parse_quoted(&mut self, quote: char) -> Result<Vec<u32>> {
+        self.expect(&quote.to_string())?;
+        let mut out = vec![];
+        let mut chars = self.rest().char_indices();
+        loop {
+            let (index, c) = chars.next().ok_or_else(|| anyhow!("unterminated literal"))?;
+            match c {
+                c if c == quote => {
+                    self.position += index + c.len_utf8();
+                    return Ok(out);
+                }
+                '\\' => match chars.next().map(|(_, c)| c) {
+                    Some('t') => out.push('\t' as u32),
+                    Some('r') => out.push('\r' as u32),
+                    Some('n') => out.push('\n' as u32),
+                    Some(c @ '\'') | Some(c @ '"') | Some(c @ '\\') => out.push(c as u32),
+                    Some('u') => {
+                        if chars.next().map(|(_, c)| c) != Some('{') {
+                            return Err(anyhow!("expected `{{` in unicode escape"));
+                        }
+                        let mut hex = String::new();
+                        loop {
+                            match chars.next().map(|(_, c)| c) {
+                                Some('}') => break,
+                                Some(c) => hex.push(c),
+                                None => return Err(anyhow!("unterminated unicode escape")),
+                            }
+                        }
+                        out.push(
+                            u32::from_str_radix(&hex, 16).map_err(|_| anyhow!("invalid unicode escape `{}`", hex))?,
+                        );
+                    }
+                    Some(c) => return Err(anyhow!("unknown escape `\\{}`", c)),
+                    None => return Err(anyhow!("unterminated literal")),
+                },
+                c => out.push(c as u32),
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        self.parse_quoted('"')?
+            .into_iter()
+            .map(|c| std::char::from_u32(c).ok_or_else(|| anyhow!("invalid char in string: {:#x}", c)))
+            .collect()
+    }
+
+    /// parses an input declaration, `&v<index> "<name>": <type>`
+    fn parse_input(&mut self) -> Result<Input> {
+        let variable = self.parse_variable()?;
+        self.skip_whitespace();
+        let name = self.parse_string()?;
+        self.expect(":")?;
+        let type_ = self.parse_type()?;
+        Ok(Input { variable, name, type_ })
+    }
+
+    fn parse_type(&mut self) -> Result<Type> {
+        if self.eat("[") {
+            let inner = self.parse_type()?;
+            self.expect(";")?;
+            let length = if self.eat("_") { None } else { Some(self.parse_u32()?) };
+            self.expect("]")?;
+            return Ok(Type::Array(Box::new(inner), length));
+        }
+        if self.eat("(") {
+            return Ok(Type::Tuple(self.parse_list(")", Self::parse_type)?));
+        }
+        if self.eat("{") {
+            return Ok(Type::Circuit(self.parse_list("}", |cursor| {
+                let name = cursor.parse_word();
+                if name.is_empty() {
+                    return Err(cursor.unexpected("circuit member name"));
+                }
+                cursor.expect(":")?;
+                Ok((name.to_string(), cursor.parse_type()?))
+            })?));
+        }
+        let position = self.position;
+        Ok(match self.parse_word() {
+            "address" => Type::Address,
+            "bool" => Type::Boolean,
+            "field" => Type::Field,
+            "char" => Type::Char,
+            "group" => Type::Group,
+            "u8" => Type::U8,
+            "u16" => Type::U16,
+            "u32" => Type::U32,
+            "u64" => Type::U64,
+            "u128" => Type::U128,
+            "i8" => Type::I8,
+            "i16" => Type::I16,
+            "i32" => Type::I32,
+            "i64" => Type::I64,
+            "i128" => Type::I128,
+            _ => {
+                self.position = position;
+                return Err(self.unexpected("type"));
+            }
+        })
+    }
+
+    /// parses the limbs of a field, `[<u64>, ...]`, with an optional leading `-`
+    fn parse_field(&mut self) -> Result<Field> {
+        let negate = self.eat("-");
+        self.expect("[")?;
+        let values = self.parse_list("]", Self::parse_number)?;
+        Ok(Field { negate, values })
+    }
+
+    fn parse_group_coordinate(&mut self) -> Result<GroupCoordinate> {
+        if let Some(field) = self.attempt(Self::parse_field) {
+            return Ok(GroupCoordinate::Field(field));
+        }
+        if self.eat("+") {
+            Ok(GroupCoordinate::SignHigh)
+        } else if self.eat("-") {
+            Ok(GroupCoordinate::SignLow)
+        } else if self.eat("_") {
+            Ok(GroupCoordinate::Inferred)
+        } else {
+            Err(self.unexpected("group coordinate"))
+        }
+    }
+
+    fn parse_integer(&mut self) -> Result<Integer> {
+        self.skip_whitespace();
+        let start = self.position;
+        self.eat("-");
+        self.take_while(|c| c.is_ascii_digit());
+        let digits = &self.input[start..self.position];
+        let suffix = self.take_while(|c| c.is_ascii_alphanumeric());
+        let invalid = || anyhow!("invalid integer `{}{}`", digits, suffix);
+        Ok(match suffix {
+            "u8" => Integer::U8(digits.parse().map_err(|_| invalid())?),
+            "u16" => Integer::U16(digits.parse().map_err(|_| invalid())?),
+            "u32" => Integer::U32(digits.parse().map_err(|_| invalid())?),
+            "u64" => Integer::U64(digits.parse().map_err(|_| invalid())?),
+            "u128" => Integer::U128(digits.parse().map_err(|_| invalid())?),
+            "i8" => Integer::I8(digits.parse().map_err(|_| invalid())?),
+            "i16" => Integer::I16(digits.parse().map_err(|_| invalid())?),
+            "i32" => Integer::I32(digits.parse().map_err(|_| invalid())?),
+            "i64" => Integer::I64(digits.parse().map_err(|_| invalid())?),
+            "i128" => Integer::I128(digits.parse().map_err(|_| invalid())?),
+            _ => return Err(invalid()),
+        })
+    }
+
+    fn parse_value(&mut self) -> Result<Value> {
+        match self.peek() {
+            Some('[') | Some('-') => {
+                if let Some(field) = self.attempt(Self::parse_field) {
+                    if self.eat("field") {
+                        return Ok(Value::Field(field));
+                    }
+                    self.expect("group")?;
+                    return Ok(Value::Group(Group::Single(field)));
+                }
+                if self.eat("[") {
+                    return Ok(Value::Array(self.parse_list("]", Self::parse_value)?));
+                }
+                Ok(Value::Integer(self.parse_integer()?))
+            }
+            Some('(') => {
+                let group = self.attempt(|cursor| {
+                    cursor.expect("(")?;
+                    let left = cursor.parse_group_coordinate()?;
+                    cursor.expect(",")?;
+                    let right = cursor.parse_group_coordinate()?;
+                    cursor.expect(")")?;
+                    cursor.expect("group")?;
+                    Ok(Group::Tuple(left, right))
+                });
+                if let Some(group) = group {
+                    return Ok(Value::Group(group));
+                }
+                self.expect("(")?;
+                Ok(Value::Tuple(self.parse_list(")", Self::parse_value)?))
+            }
+            Some('\'') => {
+                let position = self.position;
+                match self.parse_quoted('\'')?.as_slice() {
+                    [c] => Ok(Value::Char(*c)),
+                    _ => {
+                        self.position = position;
+                        Err(self.unexpected("a single char"))
+                    }
+                }
+            }
+            Some('"') => Ok(Value::Str(self.parse_string()?)),
+            Some(c) if c.is_ascii_digit() => Ok(Value::Integer(self.parse_integer()?)),
+            Some(_) => {
+                let position = self.position;
+                let word = self.parse_word();
+                match word {
+                    "true" => Ok(Value::Boolean(true)),
+                    "false" => Ok(Value::Boolean(false)),
+                    word if word.starts_with("aleo1") => {
+                        let (hrp, data, _) =
+                            bech32::decode(word).map_err(|e| anyhow!("invalid address `{}`: {}", word, e))?;
+                        if hrp != "aleo" {
+                            return Err(anyhow!("invalid address prefix `{}`", hrp));
+                        }
+                        Ok(Value::Address(
+                            Vec::<u8>::from_base32(&data).map_err(|e| anyhow!("invalid address `{}`: {}", word, e))?,
+                        ))
+                    }
+                    word if word.starts_with('v') && word[1..].parse::<u32>().is_ok() => {
+                        Ok(Value::Ref(word[1..].parse()?))
+                    }
+                    _ => {
+                        self.position = position;
+                        Err(self.unexpected("value"))
+                    }
+                }
+            }
+            None => Err(self.unexpected("value")),
+        }
+    }
+
+    /// parses the values following the fixed operands of an instruction, each preceded by a comma
+    fn parse_trailing_values(&mut self, operands: &mut Vec<ir::Operand>) -> Result<()> {
+        while self.eat(",") {
+            operands.push(self.parse_value()?.encode());
+        }
+        Ok(())
+    }
+
+    /// parses the operands of an instruction, leaving operand count validation to `Instruction::decode`
+    fn parse_instruction(&mut self, op: InstructionOp) -> Result<Instruction> {
+        let mut operands = vec![];
+        match op {
+            InstructionOp::ArrayInitRepeat => {
+                operands.push(control_u32(self.parse_variable()?));
+                self.expect(",")?;
+                operands.push(control_u32(self.parse_u32()?));
+            }
+            InstructionOp::Mask => {
+                operands.push(control_u32(self.parse_u32()?));
+            }
+            InstructionOp::Repeat => {
+                operands.push(control_u32(self.parse_u32()?));
+                self.expect(",")?;
+                operands.push(control_u32(self.parse_variable()?));
+                self.expect(",")?;
+                let inclusive = match self.parse_word() {
+                    "true" => true,
+                    "false" => false,
+                    _ => return Err(self.unexpected("`true` or `false`")),
+                };
+                operands.push(control_bool(inclusive));
+            }
+            InstructionOp::Call => {
+                operands.push(control_u32(self.parse_variable()?));
+                self.expect(",")?;
+                self.expect("f")?;
+                operands.push(control_u32(self.parse_u32()?));
+            }
+            InstructionOp::CallCore => {
+                operands.push(control_u32(self.parse_variable()?));
+                self.expect(",")?;
+                self.expect("'")?;
+                let identifier = self.take_while(|c| c != '\'');
+                self.expect("'")?;
+                operands.push(control_string(identifier.to_string()));
+            }
+            InstructionOp::Log => {
+                let log_level = match self.parse_word() {
+                    "ERROR" => LogLevel::Error,
+                    "INFO" => LogLevel::Info,
+                    "DEBUG" => LogLevel::Debug,
+                    _ => return Err(self.unexpected("log level")),
+                };
+                operands.push(control_u32(log_level as u32));
+            }
+            InstructionOp::Return | InstructionOp::Assert => {
+                if self.peek().is_some() {
+                    operands.push(self.parse_value()?.encode());
+                }
+            }
+            _ => {
+                operands.push(control_u32(self.parse_variable()?));
+            }
+        }
+        self.parse_trailing_values(&mut operands)?;
+
+        Instruction::decode(ir::Instruction {
+            opcode: op as u32,
+            operands,
+        })
+    }
+}
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use std::fmt;
+
 use crate::{ir, Input};
 
 use anyhow::Result;
@@ -37,11 +39,21 @@ impl Default for SnarkVMVersion {
 }
 
 impl SnarkVMVersion {
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self { major, minor, patch }
+    }
+
     pub fn check_compatible(&self) -> bool {
         self == &Self::default()
     }
 }
 
+impl fmt::Display for SnarkVMVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Header {
     pub version: SnarkVMVersion,
@@ -111,3 +123,24 @@ impl Header {
         }
     }
 }
+
+impl fmt::Display for Header {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "version {}", self.version)?;
+        writeln!(f, "inline_limit {}", self.inline_limit)?;
+        let blocks = [
+            ("main_input", &self.main_inputs),
+            ("constant_input", &self.constant_inputs),
+            ("register_input", &self.register_inputs),
+            ("public_state", &self.public_states),
+            ("private_record_state", &self.private_record_states),
+            ("private_leaf_state", &self.private_leaf_states),
+        ];
+        for (kind, inputs) in blocks.iter() {
+            for input in inputs.iter() {
+                writeln!(f, "{} {}", kind, input)?;
+            }
+        }
+        Ok(())
+    }
+}
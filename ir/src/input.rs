@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use std::fmt;
+
 use crate::{ir, Type, Value};
 
 use anyhow::{anyhow, Result};
@@ -46,6 +48,12 @@ impl Input {
     }
 }
 
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "&v{} \"{}\": {}", self.variable, self.name.escape_default(), self.type_)
+    }
+}
+
 /// Concrete input data
 #[derive(Clone, Debug, Default)]
 pub struct InputData {
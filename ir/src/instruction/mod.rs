@@ -28,7 +28,7 @@ mod predicate;
 pub use predicate::*;
 
 mod op;
-use op::InstructionOp;
+pub(crate) use op::InstructionOp;
 
 mod code;
 
@@ -85,6 +85,15 @@ pub enum InstructionOp {
 }
 
 impl InstructionOp {
+    /// finds the instruction with the given mnemonic
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        (0u32..)
+            .map(Self::try_from_primitive)
+            .take_while(Result::is_ok)
+            .filter_map(Result::ok)
+            .find(|op| op.mnemonic() == mnemonic)
+    }
+
     pub fn mnemonic(&self) -> &'static str {
         match self {
             InstructionOp::Add => "add",
@@ -32,6 +32,14 @@ pub use types::*;
 mod program;
 pub use program::*;
 
+mod assembly;
+
+mod validate;
+pub use validate::*;
+
+mod optimize;
+pub use optimize::*;
+
 mod function;
 pub use function::*;
 
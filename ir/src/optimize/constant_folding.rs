@@ -0,0 +1,361 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{cmp::Ordering, convert::TryFrom};
+
+use indexmap::IndexMap;
+
+use crate::{Instruction, Integer, Program, QueryData, Value};
+
+use super::*;
+
+/// Replaces variables holding constants with their value, and evaluates instructions whose operands are all constant.
+///
+/// An evaluated instruction becomes a `store` of its result. Operations that would fail, such as an overflowing
+/// addition, are left in place so that the program still fails when evaluated. Masks with a constant condition are
+/// removed, or replaced by their block when it contains no `retn`.
+pub struct ConstantFolding;
+
+impl Pass for ConstantFolding {
+    fn name(&self) -> &'static str {
+        "constant-folding"
+    }
+
+    fn run(&self, program: &mut Program) -> bool {
+        let before = program.functions.clone();
+        map_functions(program, |nodes| fold_block(nodes, &mut IndexMap::new()));
+        program.functions != before
+    }
+}
+
+/// whether the value is a constant that can be substituted for a variable
+fn is_constant(value: &Value) -> bool {
+    match value {
+        Value::Array(items) | Value::Tuple(items) => items.iter().all(is_constant),
+        Value::Ref(_) | Value::Str(_) => false,
+        _ => true,
+    }
+}
+
+/// whether a `retn` appears in the nodes, at any depth
+fn contains_return(nodes: &[Node]) -> bool {
+    nodes.iter().any(|node| match node {
+        Node::Instruction(instruction) => matches!(instruction, Instruction::Return(_)),
+        Node::Mask(_, block) | Node::Repeat(_, block) => contains_return(block),
+    })
+}
+
+/// folds the nodes of a block in order, where `constants` holds the constant variables visible to the block
+fn fold_block(nodes: Vec<Node>, constants: &mut IndexMap<u32, Value>) -> Vec<Node> {
+    let mut out = vec![];
+    for node in nodes {
+        match node {
+            Node::Instruction(mut instruction) => {
+                for value in values_mut(&mut instruction) {
+                    replace_refs(value, &mut |variable| constants.get(&variable).cloned());
+                }
+                if let (Some(value), Some(destination)) = (fold(&instruction), destination(&instruction)) {
+                    instruction = Instruction::Store(QueryData {
+                        destination,
+                        values: vec![value],
+                    });
+                }
+                if let Some(destination) = destination(&instruction) {
+                    match &instruction {
+                        Instruction::Store(QueryData { values, .. })
+                            if values.len() == 1 && is_constant(&values[0]) =>
+                        {
+                            constants.insert(destination, values[0].clone());
+                        }
+                        _ => {
+                            constants.remove(&destination);
+                        }
+                    }
+                }
+                out.push(Node::Instruction(instruction));
+            }
+            Node::Mask(mut data, block) => {
+                replace_refs(&mut data.condition, &mut |variable| constants.get(&variable).cloned());
+                match data.condition {
+                    Value::Boolean(false) => (),
+                    Value::Boolean(true) if !contains_return(&block) => out.extend(fold_block(block, constants)),
+                    _ => {
+                        let block = fold_block(block, &mut constants.clone());
+                        visit_assignments(&block, &mut |variable| {
+                            constants.remove(&variable);
+                        });
+                        out.push(Node::Mask(data, block));
+                    }
+                }
+            }
+            Node::Repeat(mut data, block) => {
+                replace_refs(&mut data.from, &mut |variable| constants.get(&variable).cloned());
+                replace_refs(&mut data.to, &mut |variable| constants.get(&variable).cloned());
+                // values assigned in one iteration are not constant in the next
+                constants.remove(&data.iter_variable);
+                visit_assignments(&block, &mut |variable| {
+                    constants.remove(&variable);
+                });
+                let block = fold_block(block, &mut constants.clone());
+                out.push(Node::Repeat(data, block));
+            }
+        }
+    }
+    out
+}
+
+/// applies `$expression` to two integers of the same type, returning `None` on mismatched types or overflow
+macro_rules! match_integers {
+    (($a: ident, $b: ident) => $expression:expr) => {
+        match ($a, $b) {
+            (Integer::U8($a), Integer::U8($b)) => $expression.map(Integer::U8),
+            (Integer::U16($a), Integer::U16($b)) => $expression.map(Integer::U16),
+            (Integer::U32($a), Integer::U32($b)) => $expression.map(Integer::U32),
+            (Integer::U64($a), Integer::U64($b)) => $expression.map(Integer::U64),
+            (Integer::U128($a), Integer::U128($b)) => $expression.map(Integer::U128),
+            (Integer::I8($a), Integer::I8($b)) => $expression.map(Integer::I8),
+            (Integer::I16($a), Integer::I16($b)) => $expression.map(Integer::I16),
+            (Integer::I32($a), Integer::I32($b)) => $expression.map(Integer::I32),
+            (Integer::I64($a), Integer::I64($b)) => $expression.map(Integer::I64),
+            (Integer::I128($a), Integer::I128($b)) => $expression.map(Integer::I128),
+            (_, _) => None,
+        }
+    };
+}
+
+/// returns the bit size of the integer
+fn integer_size(integer: Integer) -> u32 {
+    match integer {
+        Integer::U8(_) | Integer::I8(_) => 8,
+        Integer::U16(_) | Integer::I16(_) => 16,
+        Integer::U32(_) | Integer::I32(_) => 32,
+        Integer::U64(_) | Integer::I64(_) => 64,
+        Integer::U128(_) | Integer::I128(_) => 128,
+    }
+}
+
+/// returns the value of an unsigned integer
+fn unsigned(integer: Integer) -> Option<u128> {
+    match integer {
+        Integer::U8(x) => Some(x as u128),
+        Integer::U16(x) => Some(x as u128),
+        Integer::U32(x) => Some(x as u128),
+        Integer::U64(x) => Some(x as u128),
+        Integer::U128(x) => Some(x),
+        _ => None,
+    }
+}
+
+/// returns the exponent of `**` as the unsigned integer with the same bits, as the evaluators read it
+fn pow_exponent(integer: Integer) -> u128 {
+    match integer {
+        Integer::I8(x) => x as u8 as u128,
+        Integer::I16(x) => x as u16 as u128,
+        Integer::I32(x) => x as u32 as u128,
+        Integer::I64(x) => x as u64 as u128,
+        Integer::I128(x) => x as u128,
+        integer => unsigned(integer).unwrap(),
+    }
+}
+
+fn compare(left: Integer, right: Integer) -> Option<Ordering> {
+    match (left, right) {
+        (Integer::U8(a), Integer::U8(b)) => Some(a.cmp(&b)),
+        (Integer::U16(a), Integer::U16(b)) => Some(a.cmp(&b)),
+        (Integer::U32(a), Integer::U32(b)) => Some(a.cmp(&b)),
+        (Integer::U64(a), Integer::U64(b)) => Some(a.cmp(&b)),
+        (Integer::U128(a), Integer::U128(b)) => Some(a.cmp(&b)),
+        (Integer::I8(a), Integer::I8(b)) => Some(a.cmp(&b)),
+        (Integer::I16(a), Integer::I16(b)) => Some(a.cmp(&b)),
+        (Integer::I32(a), Integer::I32(b)) => Some(a.cmp(&b)),
+        (Integer::I64(a), Integer::I64(b)) => Some(a.cmp(&b)),
+        (Integer::I128(a), Integer::I128(b)) => Some(a.cmp(&b)),
+        (_, _) => None,
+    }
+}
+
+fn shift(integer: Integer, amount: Integer, instruction: &Instruction) -> Option<Integer> {
+    let amount = unsigned(amount)?;
+    if amount >= integer_size(integer) as u128 {
+        return None;
+    }
+    let amount = amount as u32;
+
+    macro_rules! shift {
+        ($($variant: ident: $unsigned: ty, $signed: ty;)*) => {
+            match integer {
+                $(Integer::$variant(x) => Integer::$variant(match instruction {
+                    Instruction::Shl(_) => x << amount,
+                    Instruction::Shr(_) => (x as $unsigned >> amount) as _,
+                    _ => (x as $signed >> amount) as _,
+                }),)*
+            }
+        };
+    }
+
+    Some(shift! {
+        U8: u8, i8;
+        U16: u16, i16;
+        U32: u32, i32;
+        U64: u64, i64;
+        U128: u128, i128;
+        I8: u8, i8;
+        I16: u16, i16;
+        I32: u32, i32;
+        I64: u64, i64;
+        I128: u128, i128;
+    })
+}
+
+fn integer_binary(instruction: &Instruction, left: Integer, right: Integer) -> Option<Value> {
+    let integer = match instruction {
+        Instruction::Add(_) => match_integers!((left, right) => left.checked_add(right)),
+        Instruction::Sub(_) => match_integers!((left, right) => left.checked_sub(right)),
+        Instruction::Mul(_) => match_integers!((left, right) => left.checked_mul(right)),
+        Instruction::Div(_) => match_integers!((left, right) => left.checked_div(right)),
+        Instruction::Mod(_) => match_integers!((left, right) => left.checked_rem(right)),
+        Instruction::Pow(_) => {
+            // exponents past u32 only leave 0, 1 and -1 unchanged
+            let exponent = pow_exponent(right);
+            match_integers!((left, right) => {
+                let _ = right;
+                match u32::try_from(exponent) {
+                    Ok(exponent) => left.checked_pow(exponent),
+                    Err(_) => left.checked_pow(2 + (exponent % 2) as u32).filter(|x| *x == left),
+                }
+            })
+        }
+        Instruction::BitAnd(_) => match_integers!((left, right) => Some(left & right)),
+        Instruction::BitOr(_) => match_integers!((left, right) => Some(left | right)),
+        Instruction::BitXor(_) => match_integers!((left, right) => Some(left ^ right)),
+        Instruction::Shl(_) | Instruction::Shr(_) | Instruction::ShrSigned(_) => shift(left, right, instruction),
+        Instruction::Eq(_) => return compare(left, right).map(|order| Value::Boolean(order == Ordering::Equal)),
+        Instruction::Ne(_) => return compare(left, right).map(|order| Value::Boolean(order != Ordering::Equal)),
+        Instruction::Ge(_) => return compare(left, right).map(|order| Value::Boolean(order != Ordering::Less)),
+        Instruction::Gt(_) => return compare(left, right).map(|order| Value::Boolean(order == Ordering::Greater)),
+        Instruction::Le(_) => return compare(left, right).map(|order| Value::Boolean(order != Ordering::Greater)),
+        Instruction::Lt(_) => return compare(left, right).map(|order| Value::Boolean(order == Ordering::Less)),
+        _ => None,
+    };
+    integer.map(Value::Integer)
+}
+
+/// returns the result of the instruction if its operands are constant and it would not fail
+fn fold(instruction: &Instruction) -> Option<Value> {
+    match instruction {
+        Instruction::Pick(QueryData { values, .. }) => match values.as_slice() {
+            [Value::Boolean(condition), left, right] => Some(if *condition { left.clone() } else { right.clone() }),
+            _ => None,
+        },
+        Instruction::TupleInit(VarData { values, .. }) if values.iter().all(is_constant) => {
+            Some(Value::Tuple(values.clone()))
+        }
+        Instruction::ArrayInit(VarData { values, .. }) if values.iter().all(is_constant) => {
+            let mut items = vec![];
+            for value in values {
+                match value {
+                    Value::Array(inner) => items.extend(inner.iter().cloned()),
+                    value => items.push(value.clone()),
+                }
+            }
+            Some(Value::Array(items))
+        }
+        Instruction::ArrayIndexGet(QueryData { values, .. }) | Instruction::TupleIndexGet(QueryData { values, .. }) => {
+            match (instruction, values.as_slice()) {
+                (Instruction::ArrayIndexGet(_), [Value::Array(items), Value::Integer(index)])
+                | (Instruction::TupleIndexGet(_), [Value::Tuple(items), Value::Integer(index)])
+                    if is_constant(&values[0]) =>
+                {
+                    items.get(usize::try_from(unsigned(*index)?).ok()?).cloned()
+                }
+                _ => None,
+            }
+        }
+        Instruction::Not(QueryData { values, .. }) => match values.as_slice() {
+            [Value::Boolean(value)] => Some(Value::Boolean(!value)),
+            _ => None,
+        },
+        Instruction::BitNot(QueryData { values, .. }) => match values.as_slice() {
+            [Value::Integer(integer)] => Some(Value::Integer(match *integer {
+                Integer::U8(x) => Integer::U8(!x),
+                Integer::U16(x) => Integer::U16(!x),
+                Integer::U32(x) => Integer::U32(!x),
+                Integer::U64(x) => Integer::U64(!x),
+                Integer::U128(x) => Integer::U128(!x),
+                Integer::I8(x) => Integer::I8(!x),
+                Integer::I16(x) => Integer::I16(!x),
+                Integer::I32(x) => Integer::I32(!x),
+                Integer::I64(x) => Integer::I64(!x),
+                Integer::I128(x) => Integer::I128(!x),
+            })),
+            _ => None,
+        },
+        Instruction::Negate(QueryData { values, .. }) => match values.as_slice() {
+            [Value::Integer(integer)] => match *integer {
+                Integer::I8(x) => x.checked_neg().map(Integer::I8),
+                Integer::I16(x) => x.checked_neg().map(Integer::I16),
+                Integer::I32(x) => x.checked_neg().map(Integer::I32),
+                Integer::I64(x) => x.checked_neg().map(Integer::I64),
+                Integer::I128(x) => x.checked_neg().map(Integer::I128),
+                _ => None,
+            }
+            .map(Value::Integer),
+            _ => None,
+        },
+        Instruction::And(QueryData { values, .. })
+        | Instruction::Or(QueryData { values, .. })
+        | Instruction::Eq(QueryData { values, .. })
+        | Instruction::Ne(QueryData { values, .. })
+        | Instruction::Ge(QueryData { values, .. })
+        | Instruction::Gt(QueryData { values, .. })
+        | Instruction::Le(QueryData { values, .. })
+        | Instruction::Lt(QueryData { values, .. })
+        | Instruction::Add(QueryData { values, .. })
+        | Instruction::Sub(QueryData { values, .. })
+        | Instruction::Mul(QueryData { values, .. })
+        | Instruction::Div(QueryData { values, .. })
+        | Instruction::Mod(QueryData { values, .. })
+        | Instruction::Pow(QueryData { values, .. })
+        | Instruction::BitAnd(QueryData { values, .. })
+        | Instruction::BitOr(QueryData { values, .. })
+        | Instruction::BitXor(QueryData { values, .. })
+        | Instruction::Shl(QueryData { values, .. })
+        | Instruction::Shr(QueryData { values, .. })
+        | Instruction::ShrSigned(QueryData { values, .. }) => {
+            let equal = match (instruction, values.as_slice()) {
+                (_, [Value::Integer(left), Value::Integer(right)]) => {
+                    return integer_binary(instruction, *left, *right);
+                }
+                (Instruction::And(_), [Value::Boolean(left), Value::Boolean(right)]) => {
+                    return Some(Value::Boolean(*left && *right));
+                }
+                (Instruction::Or(_), [Value::Boolean(left), Value::Boolean(right)]) => {
+                    return Some(Value::Boolean(*left || *right));
+                }
+                (_, [Value::Boolean(left), Value::Boolean(right)]) => left == right,
+                (_, [Value::Char(left), Value::Char(right)]) => left == right,
+                (_, [Value::Address(left), Value::Address(right)]) => left == right,
+                _ => return None,
+            };
+            match instruction {
+                Instruction::Eq(_) => Some(Value::Boolean(equal)),
+                Instruction::Ne(_) => Some(Value::Boolean(!equal)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
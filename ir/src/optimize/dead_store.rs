@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use indexmap::IndexSet;
+
+use crate::{Instruction, Program};
+
+use super::*;
+
+/// Removes instructions whose result is never read, along with empty masks and repeats.
+///
+/// Only instructions that cannot fail in a valid program are removed, so arithmetic that may overflow or divide by
+/// zero, comparisons of arrays that may differ in length, and indexing that may be out of bounds are kept even when
+/// their result is unused. Since a called function can read the variables of its caller, a variable read anywhere in
+/// the program keeps every assignment to it.
+pub struct DeadStoreElimination;
+
+impl Pass for DeadStoreElimination {
+    fn name(&self) -> &'static str {
+        "dead-store-elimination"
+    }
+
+    fn run(&self, program: &mut Program) -> bool {
+        let before = program.functions.clone();
+        loop {
+            let mut read = IndexSet::new();
+            for instruction in program.functions.iter().flat_map(|function| function.instructions.iter()) {
+                visit_reads(instruction, &mut |variable| {
+                    read.insert(variable);
+                });
+            }
+
+            let count = instruction_count(program);
+            map_functions(program, |nodes| eliminate(nodes, &read));
+            if instruction_count(program) == count {
+                break;
+            }
+        }
+        program.functions != before
+    }
+}
+
+fn instruction_count(program: &Program) -> usize {
+    program.functions.iter().map(|function| function.instructions.len()).sum()
+}
+
+/// whether the instruction has no effect other than assigning its destination, and cannot fail
+fn is_pure(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::Store(_) | Instruction::TupleInit(_) | Instruction::ArrayInit(_)
+    )
+}
+
+fn eliminate(nodes: Vec<Node>, read: &IndexSet<u32>) -> Vec<Node> {
+    let mut out = vec![];
+    for node in nodes {
+        match node {
+            Node::Instruction(instruction) => {
+                let dead = is_pure(&instruction)
+                    && destination(&instruction)
+                        .map(|destination| !read.contains(&destination))
+                        .unwrap_or(false);
+                if !dead {
+                    out.push(Node::Instruction(instruction));
+                }
+            }
+            Node::Mask(data, block) => {
+                let block = eliminate(block, read);
+                if !block.is_empty() {
+                    out.push(Node::Mask(data, block));
+                }
+            }
+            Node::Repeat(data, block) => {
+                let block = eliminate(block, read);
+                if !block.is_empty() {
+                    out.push(Node::Repeat(data, block));
+                }
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,277 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{CallData, Function, Instruction, PredicateData, Program, QueryData, Value};
+
+use super::*;
+
+/// Replaces calls with the instructions of the called function, for up to `inline_limit` levels of calls.
+///
+/// A function is inlined when it makes no calls and ends in its only `retn`. Its arguments and the variables it
+/// assigns are renamed to variables unused by the program. Programs whose calls nest deeper than `inline_limit`, or
+/// recurse, fail to evaluate and are left unchanged.
+pub struct Inlining;
+
+impl Pass for Inlining {
+    fn name(&self) -> &'static str {
+        "inlining"
+    }
+
+    fn run(&self, program: &mut Program) -> bool {
+        if program.functions.is_empty() {
+            return false;
+        }
+        let mut depths = vec![None; program.functions.len()];
+        match call_depth(program, 0, &mut vec![false; program.functions.len()], &mut depths) {
+            Some(depth) if depth <= program.header.inline_limit => (),
+            _ => return false,
+        }
+
+        let before = program.functions.clone();
+        for _ in 0..program.header.inline_limit {
+            if !inline_calls(program) {
+                break;
+            }
+        }
+        program.functions != before
+    }
+}
+
+/// returns the depth of the deepest chain of calls from the function, counting the function itself,
+/// or `None` if the calls recurse or target a missing function
+fn call_depth(program: &Program, index: usize, visiting: &mut [bool], depths: &mut [Option<u32>]) -> Option<u32> {
+    if let Some(depth) = depths[index] {
+        return Some(depth);
+    }
+    if visiting[index] {
+        return None;
+    }
+    visiting[index] = true;
+    let mut depth = 1;
+    for instruction in program.functions[index].instructions.iter() {
+        if let Instruction::Call(CallData { index: callee, .. }) = instruction {
+            let callee = *callee as usize;
+            if callee >= program.functions.len() {
+                return None;
+            }
+            depth = depth.max(1 + call_depth(program, callee, visiting, depths)?);
+        }
+    }
+    visiting[index] = false;
+    depths[index] = Some(depth);
+    Some(depth)
+}
+
+/// returns the largest variable used by the program, including the arguments of every call
+fn max_variable(program: &Program) -> u32 {
+    let header = &program.header;
+    let inputs = [
+        &header.main_inputs,
+        &header.constant_inputs,
+        &header.register_inputs,
+        &header.public_states,
+        &header.private_record_states,
+        &header.private_leaf_states,
+    ];
+    let mut max = inputs
+        .iter()
+        .flat_map(|inputs| inputs.iter())
+        .map(|input| input.variable)
+        .max()
+        .unwrap_or(0);
+    for instruction in program.functions.iter().flat_map(|function| function.instructions.iter()) {
+        max = max.max(destination(instruction).unwrap_or(0));
+        for value in values(instruction) {
+            visit_refs(value, &mut |variable| max = max.max(variable));
+        }
+        if let Instruction::Call(CallData { index, arguments, .. }) = instruction {
+            if let Some(function) = program.functions.get(*index as usize) {
+                max = max.max(function.argument_start_variable + arguments.len() as u32);
+            }
+        }
+    }
+    max
+}
+
+/// inlines every call to a function that makes no calls, returning whether any call was inlined
+fn inline_calls(program: &mut Program) -> bool {
+    let mut next_variable = max_variable(program) + 1;
+    let mut changed = false;
+    let mut bodies = vec![];
+    for (index, function) in program.functions.iter().enumerate() {
+        let nodes = inline_block(
+            to_tree(&function.instructions),
+            program,
+            index as u32,
+            &mut next_variable,
+            &mut changed,
+        );
+        let mut instructions = vec![];
+        from_tree(nodes, &mut instructions);
+        bodies.push(instructions);
+    }
+    for (function, instructions) in program.functions.iter_mut().zip(bodies) {
+        function.instructions = instructions;
+    }
+    changed
+}
+
+fn inline_block(
+    nodes: Vec<Node>,
+    program: &Program,
+    caller: u32,
+    next_variable: &mut u32,
+    changed: &mut bool,
+) -> Vec<Node> {
+    let mut out = vec![];
+    for node in nodes {
+        match node {
+            Node::Instruction(Instruction::Call(data)) => {
+                let inlined = if data.index == caller {
+                    None
+                } else {
+                    program
+                        .functions
+                        .get(data.index as usize)
+                        .and_then(|function| inline_call(function, &data, next_variable))
+                };
+                match inlined {
+                    Some(nodes) => {
+                        out.extend(nodes);
+                        *changed = true;
+                    }
+                    None => out.push(Node::Instruction(Instruction::Call(data))),
+                }
+            }
+            Node::Instruction(instruction) => out.push(Node::Instruction(instruction)),
+            Node::Mask(data, block) => {
+                out.push(Node::Mask(data, inline_block(block, program, caller, next_variable, changed)));
+            }
+            Node::Repeat(data, block) => {
+                out.push(Node::Repeat(data, inline_block(block, program, caller, next_variable, changed)));
+            }
+        }
+    }
+    out
+}
+
+/// whether every variable in `assigned` is assigned before the nodes read it, so that no read of it
+/// falls through to the caller's variable of the same number
+fn assigned_before_read(nodes: &[Node], assigned: &IndexSet<u32>, defined: &mut IndexSet<u32>) -> bool {
+    let early = |instruction: &Instruction, defined: &IndexSet<u32>| {
+        let mut early = false;
+        visit_reads(instruction, &mut |variable| {
+            early |= assigned.contains(&variable) && !defined.contains(&variable);
+        });
+        early
+    };
+    for node in nodes {
+        match node {
+            Node::Instruction(instruction) => {
+                if early(instruction, defined) {
+                    return false;
+                }
+                defined.extend(destination(instruction));
+            }
+            // variables first assigned in a block are dropped at its end
+            Node::Mask(data, block) => {
+                if early(&Instruction::Mask(data.clone()), defined)
+                    || !assigned_before_read(block, assigned, &mut defined.clone())
+                {
+                    return false;
+                }
+            }
+            Node::Repeat(data, block) => {
+                if early(&Instruction::Repeat(data.clone()), defined) {
+                    return false;
+                }
+                let mut inner = defined.clone();
+                inner.insert(data.iter_variable);
+                if !assigned_before_read(block, assigned, &mut inner) {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+/// returns the instructions replacing a call to the function, or `None` if it cannot be inlined
+fn inline_call(function: &Function, data: &CallData, next_variable: &mut u32) -> Option<Vec<Node>> {
+    let (last, body) = function.instructions.split_last()?;
+    let returned = match last {
+        Instruction::Return(PredicateData { values }) if values.len() == 1 => &values[0],
+        _ => return None,
+    };
+    if body
+        .iter()
+        .any(|instruction| matches!(instruction, Instruction::Return(_) | Instruction::Call(_)))
+    {
+        return None;
+    }
+    let tree = to_tree(&function.instructions);
+    if !matches!(tree.last(), Some(Node::Instruction(Instruction::Return(_)))) {
+        return None;
+    }
+
+    let arguments = (function.argument_start_variable..)
+        .take(data.arguments.len())
+        .collect::<IndexSet<u32>>();
+    let assigned = body.iter().filter_map(destination).collect::<IndexSet<u32>>();
+    if !assigned_before_read(&tree, &assigned, &mut arguments.clone()) {
+        return None;
+    }
+
+    let mut renamed = IndexMap::new();
+    for variable in arguments.iter().chain(assigned.iter()) {
+        renamed.entry(*variable).or_insert_with(|| {
+            *next_variable += 1;
+            *next_variable - 1
+        });
+    }
+    let rename = |instruction: &mut Instruction| {
+        if let Some(destination) = destination_mut(instruction) {
+            *destination = renamed[&*destination];
+        }
+        for value in values_mut(instruction) {
+            replace_refs(value, &mut |variable| renamed.get(&variable).map(|x| Value::Ref(*x)));
+        }
+    };
+
+    let mut instructions = vec![];
+    for (variable, argument) in arguments.iter().zip(data.arguments.iter()) {
+        instructions.push(Instruction::Store(QueryData {
+            destination: renamed[variable],
+            values: vec![argument.clone()],
+        }));
+    }
+    for instruction in body {
+        let mut instruction = instruction.clone();
+        rename(&mut instruction);
+        instructions.push(instruction);
+    }
+    let mut result = Instruction::Store(QueryData {
+        destination: data.destination,
+        values: vec![returned.clone()],
+    });
+    for value in values_mut(&mut result) {
+        replace_refs(value, &mut |variable| renamed.get(&variable).map(|x| Value::Ref(*x)));
+    }
+    instructions.push(result);
+    Some(to_tree(&instructions))
+}
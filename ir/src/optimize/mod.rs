@@ -0,0 +1,340 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Optimization passes that rewrite a [`Program`] into an equivalent program that is cheaper to evaluate.
+//!
+//! The passes assume a well formed program, as checked by [`Program::validate`].
+
+mod constant_folding;
+pub use constant_folding::*;
+mod dead_store;
+pub use dead_store::*;
+mod inline;
+pub use inline::*;
+mod unused_function;
+pub use unused_function::*;
+
+use crate::{
+    ArrayInitRepeatData,
+    CallCoreData,
+    CallData,
+    Instruction,
+    LogData,
+    MaskData,
+    PredicateData,
+    Program,
+    QueryData,
+    RepeatData,
+    Value,
+    VarData,
+};
+
+/// A transformation of a whole program that preserves its behavior
+pub trait Pass {
+    /// a short name for the pass
+    fn name(&self) -> &'static str;
+
+    /// rewrites the program, returning whether anything changed
+    fn run(&self, program: &mut Program) -> bool;
+}
+
+/// Runs a sequence of passes over a program
+pub struct PassManager {
+    passes: Vec<Box<dyn Pass>>,
+}
+
+impl PassManager {
+    /// creates a pass manager without any passes
+    pub fn new() -> Self {
+        Self { passes: vec![] }
+    }
+
+    /// appends a pass to run after the passes already added
+    pub fn add<P: Pass + 'static>(&mut self, pass: P) -> &mut Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    /// returns the names of the passes, in the order they run
+    pub fn passes(&self) -> Vec<&'static str> {
+        self.passes.iter().map(|pass| pass.name()).collect()
+    }
+
+    /// runs every pass in order, returning whether any of them changed the program
+    pub fn run(&self, program: &mut Program) -> bool {
+        let mut changed = false;
+        for pass in self.passes.iter() {
+            changed |= pass.run(program);
+        }
+        changed
+    }
+}
+
+/// inlines calls, then folds constants, then removes the stores and functions left unused
+impl Default for PassManager {
+    fn default() -> Self {
+        let mut manager = Self::new();
+        manager
+            .add(Inlining)
+            .add(ConstantFolding)
+            .add(DeadStoreElimination)
+            .add(UnusedFunctionRemoval);
+        manager
+    }
+}
+
+impl Program {
+    /// runs the default optimization passes over the program, returning whether anything changed
+    pub fn optimize(&mut self) -> bool {
+        PassManager::default().run(self)
+    }
+}
+
+/// an instruction, or a mask or repeat instruction holding the instructions of its block
+#[derive(Clone, Debug)]
+enum Node {
+    Instruction(Instruction),
+    Mask(MaskData, Vec<Node>),
+    Repeat(RepeatData, Vec<Node>),
+}
+
+/// nests the instructions of each mask and repeat block under it, truncating blocks that run past their parent
+fn to_tree(instructions: &[Instruction]) -> Vec<Node> {
+    let mut nodes = vec![];
+    let mut index = 0;
+    while index < instructions.len() {
+        let block_end = |instruction_count: u32| (index + 1 + instruction_count as usize).min(instructions.len());
+        match &instructions[index] {
+            Instruction::Mask(data) => {
+                let end = block_end(data.instruction_count);
+                nodes.push(Node::Mask(data.clone(), to_tree(&instructions[index + 1..end])));
+                index = end;
+            }
+            Instruction::Repeat(data) => {
+                let end = block_end(data.instruction_count);
+                nodes.push(Node::Repeat(data.clone(), to_tree(&instructions[index + 1..end])));
+                index = end;
+            }
+            instruction => {
+                nodes.push(Node::Instruction(instruction.clone()));
+                index += 1;
+            }
+        }
+    }
+    nodes
+}
+
+/// flattens a tree of instructions, setting the instruction count of each mask and repeat from its block
+fn from_tree(nodes: Vec<Node>, out: &mut Vec<Instruction>) {
+    for node in nodes {
+        match node {
+            Node::Instruction(instruction) => out.push(instruction),
+            Node::Mask(mut data, block) => {
+                let mut inner = vec![];
+                from_tree(block, &mut inner);
+                data.instruction_count = inner.len() as u32;
+                out.push(Instruction::Mask(data));
+                out.extend(inner);
+            }
+            Node::Repeat(mut data, block) => {
+                let mut inner = vec![];
+                from_tree(block, &mut inner);
+                data.instruction_count = inner.len() as u32;
+                out.push(Instruction::Repeat(data));
+                out.extend(inner);
+            }
+        }
+    }
+}
+
+/// rewrites the instructions of every function as a tree
+fn map_functions(program: &mut Program, mut map: impl FnMut(Vec<Node>) -> Vec<Node>) {
+    for function in program.functions.iter_mut() {
+        let mut instructions = vec![];
+        from_tree(map(to_tree(&function.instructions)), &mut instructions);
+        function.instructions = instructions;
+    }
+}
+
+/// matches the variable assigned by an instruction, which for a repeat is its iteration variable
+macro_rules! match_destination {
+    ($instruction: expr) => {
+        match $instruction {
+            Instruction::Add(QueryData { destination, .. })
+            | Instruction::Sub(QueryData { destination, .. })
+            | Instruction::Mul(QueryData { destination, .. })
+            | Instruction::Div(QueryData { destination, .. })
+            | Instruction::Pow(QueryData { destination, .. })
+            | Instruction::Or(QueryData { destination, .. })
+            | Instruction::And(QueryData { destination, .. })
+            | Instruction::Eq(QueryData { destination, .. })
+            | Instruction::Ne(QueryData { destination, .. })
+            | Instruction::Ge(QueryData { destination, .. })
+            | Instruction::Gt(QueryData { destination, .. })
+            | Instruction::Le(QueryData { destination, .. })
+            | Instruction::Lt(QueryData { destination, .. })
+            | Instruction::BitOr(QueryData { destination, .. })
+            | Instruction::BitAnd(QueryData { destination, .. })
+            | Instruction::BitXor(QueryData { destination, .. })
+            | Instruction::Shr(QueryData { destination, .. })
+            | Instruction::ShrSigned(QueryData { destination, .. })
+            | Instruction::Shl(QueryData { destination, .. })
+            | Instruction::Mod(QueryData { destination, .. })
+            | Instruction::Not(QueryData { destination, .. })
+            | Instruction::Negate(QueryData { destination, .. })
+            | Instruction::BitNot(QueryData { destination, .. })
+            | Instruction::ArrayIndexGet(QueryData { destination, .. })
+            | Instruction::ArraySliceGet(QueryData { destination, .. })
+            | Instruction::ArrayIndexStore(QueryData { destination, .. })
+            | Instruction::ArraySliceStore(QueryData { destination, .. })
+            | Instruction::TupleIndexGet(QueryData { destination, .. })
+            | Instruction::TupleIndexStore(QueryData { destination, .. })
+            | Instruction::Pick(QueryData { destination, .. })
+            | Instruction::Store(QueryData { destination, .. })
+            | Instruction::ArrayInitRepeat(ArrayInitRepeatData { destination, .. })
+            | Instruction::ArrayInit(VarData { destination, .. })
+            | Instruction::TupleInit(VarData { destination, .. })
+            | Instruction::Call(CallData { destination, .. })
+            | Instruction::CallCore(CallCoreData { destination, .. })
+            | Instruction::Repeat(RepeatData {
+                iter_variable: destination,
+                ..
+            }) => Some(destination),
+            Instruction::Mask(_) | Instruction::Return(_) | Instruction::Assert(_) | Instruction::Log(_) => None,
+        }
+    };
+}
+
+/// matches the operands read by an instruction as a vector, not including the destination of in place stores
+macro_rules! match_values {
+    ($instruction: expr) => {
+        match $instruction {
+            Instruction::Add(QueryData { values, .. })
+            | Instruction::Sub(QueryData { values, .. })
+            | Instruction::Mul(QueryData { values, .. })
+            | Instruction::Div(QueryData { values, .. })
+            | Instruction::Pow(QueryData { values, .. })
+            | Instruction::Or(QueryData { values, .. })
+            | Instruction::And(QueryData { values, .. })
+            | Instruction::Eq(QueryData { values, .. })
+            | Instruction::Ne(QueryData { values, .. })
+            | Instruction::Ge(QueryData { values, .. })
+            | Instruction::Gt(QueryData { values, .. })
+            | Instruction::Le(QueryData { values, .. })
+            | Instruction::Lt(QueryData { values, .. })
+            | Instruction::BitOr(QueryData { values, .. })
+            | Instruction::BitAnd(QueryData { values, .. })
+            | Instruction::BitXor(QueryData { values, .. })
+            | Instruction::Shr(QueryData { values, .. })
+            | Instruction::ShrSigned(QueryData { values, .. })
+            | Instruction::Shl(QueryData { values, .. })
+            | Instruction::Mod(QueryData { values, .. })
+            | Instruction::Not(QueryData { values, .. })
+            | Instruction::Negate(QueryData { values, .. })
+            | Instruction::BitNot(QueryData { values, .. })
+            | Instruction::ArrayIndexGet(QueryData { values, .. })
+            | Instruction::ArraySliceGet(QueryData { values, .. })
+            | Instruction::ArrayIndexStore(QueryData { values, .. })
+            | Instruction::ArraySliceStore(QueryData { values, .. })
+            | Instruction::TupleIndexGet(QueryData { values, .. })
+            | Instruction::TupleIndexStore(QueryData { values, .. })
+            | Instruction::Pick(QueryData { values, .. })
+            | Instruction::Store(QueryData { values, .. })
+            | Instruction::ArrayInit(VarData { values, .. })
+            | Instruction::TupleInit(VarData { values, .. })
+            | Instruction::Return(PredicateData { values })
+            | Instruction::Assert(PredicateData { values })
+            | Instruction::Call(CallData { arguments: values, .. })
+            | Instruction::CallCore(CallCoreData { arguments: values, .. })
+            | Instruction::Log(LogData { parts: values, .. }) => values.into_iter().collect(),
+            Instruction::ArrayInitRepeat(ArrayInitRepeatData { value, .. }) => vec![value],
+            Instruction::Mask(MaskData { condition, .. }) => vec![condition],
+            Instruction::Repeat(RepeatData { from, to, .. }) => vec![from, to],
+        }
+    };
+}
+
+/// returns the variable assigned by the instruction, which for a repeat is its iteration variable
+fn destination(instruction: &Instruction) -> Option<u32> {
+    match_destination!(instruction).copied()
+}
+
+fn destination_mut(instruction: &mut Instruction) -> Option<&mut u32> {
+    match_destination!(instruction)
+}
+
+/// returns the operands read by the instruction, not including the destination of in place stores
+fn values(instruction: &Instruction) -> Vec<&Value> {
+    match_values!(instruction)
+}
+
+fn values_mut(instruction: &mut Instruction) -> Vec<&mut Value> {
+    match_values!(instruction)
+}
+
+/// whether the instruction reads its destination, updating it in place
+fn updates_destination(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::ArrayIndexStore(_) | Instruction::ArraySliceStore(_) | Instruction::TupleIndexStore(_)
+    )
+}
+
+/// calls `f` on every variable referenced by the value
+fn visit_refs(value: &Value, f: &mut impl FnMut(u32)) {
+    match value {
+        Value::Ref(variable) => f(*variable),
+        Value::Array(items) | Value::Tuple(items) => items.iter().for_each(|item| visit_refs(item, f)),
+        _ => (),
+    }
+}
+
+/// replaces every variable reference in the value with the result of `f`, where it returns one
+fn replace_refs(value: &mut Value, f: &mut impl FnMut(u32) -> Option<Value>) {
+    match value {
+        Value::Ref(variable) => {
+            if let Some(replacement) = f(*variable) {
+                *value = replacement;
+            }
+        }
+        Value::Array(items) | Value::Tuple(items) => items.iter_mut().for_each(|item| replace_refs(item, f)),
+        _ => (),
+    }
+}
+
+/// calls `f` on every variable read by the instruction, including the destination of in place stores
+fn visit_reads(instruction: &Instruction, f: &mut impl FnMut(u32)) {
+    if updates_destination(instruction) {
+        destination(instruction).into_iter().for_each(&mut *f);
+    }
+    for value in values(instruction) {
+        visit_refs(value, f);
+    }
+}
+
+/// calls `f` on every variable assigned by the nodes
+fn visit_assignments(nodes: &[Node], f: &mut impl FnMut(u32)) {
+    for node in nodes {
+        match node {
+            Node::Instruction(instruction) => destination(instruction).into_iter().for_each(&mut *f),
+            Node::Mask(_, block) => visit_assignments(block, f),
+            Node::Repeat(data, block) => {
+                f(data.iter_variable);
+                visit_assignments(block, f);
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use indexmap::IndexMap;
+
+use crate::{CallData, Instruction, Program};
+
+use super::Pass;
+
+/// Removes the functions that cannot be reached by calls from the main function, renumbering the calls that remain.
+pub struct UnusedFunctionRemoval;
+
+impl Pass for UnusedFunctionRemoval {
+    fn name(&self) -> &'static str {
+        "unused-function-removal"
+    }
+
+    fn run(&self, program: &mut Program) -> bool {
+        if program.functions.is_empty() {
+            return false;
+        }
+
+        let mut reachable = vec![false; program.functions.len()];
+        reachable[0] = true;
+        let mut pending = vec![0];
+        while let Some(index) = pending.pop() {
+            for instruction in program.functions[index].instructions.iter() {
+                if let Instruction::Call(CallData { index, .. }) = instruction {
+                    let index = *index as usize;
+                    if index < reachable.len() && !reachable[index] {
+                        reachable[index] = true;
+                        pending.push(index);
+                    }
+                }
+            }
+        }
+        if reachable.iter().all(|reachable| *reachable) {
+            return false;
+        }
+
+        // maps the old index of each kept function to its new index
+        let renumbered = reachable
+            .iter()
+            .enumerate()
+            .filter(|(_, reachable)| **reachable)
+            .enumerate()
+            .map(|(new, (old, _))| (old as u32, new as u32))
+            .collect::<IndexMap<u32, u32>>();
+
+        let functions = std::mem::take(&mut program.functions);
+        program.functions = functions
+            .into_iter()
+            .zip(reachable)
+            .filter(|(_, reachable)| *reachable)
+            .map(|(mut function, _)| {
+                for instruction in function.instructions.iter_mut() {
+                    if let Instruction::Call(CallData { index, .. }) = instruction {
+                        *index = renumbered[&*index];
+                    }
+                }
+                function
+            })
+            .collect();
+        true
+    }
+}
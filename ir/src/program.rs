@@ -61,6 +61,7 @@ impl Program {
 
 impl fmt::Display for Program {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.header.fmt(f)?;
         for (i, function) in self.functions.iter().enumerate() {
             writeln!(f, "decl f{}: <{}>", i, function.argument_start_variable)?;
             let mut indent = 1usize;
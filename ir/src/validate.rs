@@ -0,0 +1,783 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{convert::TryFrom, fmt};
+
+use indexmap::IndexMap;
+
+use crate::{
+    ArrayInitRepeatData,
+    CallCoreData,
+    CallData,
+    Function,
+    Input,
+    Instruction,
+    Integer,
+    LogData,
+    MaskData,
+    PredicateData,
+    Program,
+    QueryData,
+    RepeatData,
+    SnarkVMVersion,
+    Type,
+    Value,
+    VarData,
+};
+
+/// A problem found by [`Program::validate`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiagnosticKind {
+    IncompatibleVersion(SnarkVMVersion),
+    DuplicateInputVariable(u32),
+    UndefinedVariable(u32),
+    UndefinedFunction(u32),
+    UnknownCoreFunction(String),
+    OperandCount { expected: usize, found: usize },
+    CoreArgumentCount { identifier: String, expected: usize, found: usize },
+    BlockOutOfBounds { instruction_count: u32 },
+    TupleIndexOutOfBounds { index: u32, length: usize },
+    TypeMismatch { expected: String, found: Type },
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::IncompatibleVersion(version) => write!(
+                f,
+                "program version {} is incompatible with version {}",
+                version,
+                SnarkVMVersion::default()
+            ),
+            DiagnosticKind::DuplicateInputVariable(variable) => {
+                write!(f, "variable v{} is declared by more than one input", variable)
+            }
+            DiagnosticKind::UndefinedVariable(variable) => write!(f, "reference to undefined variable v{}", variable),
+            DiagnosticKind::UndefinedFunction(index) => write!(f, "call to undefined function f{}", index),
+            DiagnosticKind::UnknownCoreFunction(identifier) => write!(f, "unknown core function `{}`", identifier),
+            DiagnosticKind::OperandCount { expected, found } => {
+                write!(f, "expected {} operands, found {}", expected, found)
+            }
+            DiagnosticKind::CoreArgumentCount {
+                identifier,
+                expected,
+                found,
+            } => write!(
+                f,
+                "core function `{}` expects {} arguments, found {}",
+                identifier, expected, found
+            ),
+            DiagnosticKind::BlockOutOfBounds { instruction_count } => write!(
+                f,
+                "block of {} instructions extends past the end of its enclosing block",
+                instruction_count
+            ),
+            DiagnosticKind::TupleIndexOutOfBounds { index, length } => {
+                write!(f, "index {} out of bounds for tuple of length {}", index, length)
+            }
+            DiagnosticKind::TypeMismatch { expected, found } => write!(f, "expected {}, found {}", expected, found),
+        }
+    }
+}
+
+/// A problem found by [`Program::validate`], located at a function and instruction index where it is within a function
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub function: Option<u32>,
+    pub instruction: Option<u32>,
+    pub kind: DiagnosticKind,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.function, self.instruction) {
+            (Some(function), Some(instruction)) => write!(f, "f#{} i#{}: {}", function, instruction, self.kind),
+            (Some(function), None) => write!(f, "f#{}: {}", function, self.kind),
+            _ => write!(f, "header: {}", self.kind),
+        }
+    }
+}
+
+impl Program {
+    /// Checks the program for malformed structure, references to undefined variables and functions, and operands of
+    /// the wrong type, without evaluating it. Types that cannot be known statically, such as those of call results,
+    /// are not checked.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validator = Validator::new(self);
+        let inputs = validator.validate_header();
+        for index in validator.function_order() {
+            validator.validate_function(index, inputs.clone());
+        }
+        validator.diagnostics
+    }
+}
+
+/// the statically known type of each variable in scope, `None` where it is unknown
+type Scope = IndexMap<u32, Option<Type>>;
+
+struct Validator<'a> {
+    program: &'a Program,
+    diagnostics: Vec<Diagnostic>,
+    /// the argument types seen at calls to each function so far, `None` where calls disagree
+    arguments: Vec<Option<Vec<Option<Type>>>>,
+    /// the first argument variable of a function that is never called, whose arguments are unknown
+    unknown_arguments: Option<u32>,
+    function: Option<u32>,
+    instruction: Option<u32>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(program: &'a Program) -> Self {
+        Self {
+            program,
+            diagnostics: vec![],
+            arguments: vec![None; program.functions.len()],
+            unknown_arguments: None,
+            function: None,
+            instruction: None,
+        }
+    }
+
+    fn report(&mut self, kind: DiagnosticKind) {
+        self.diagnostics.push(Diagnostic {
+            function: self.function,
+            instruction: self.instruction,
+            kind,
+        });
+    }
+
+    /// checks the header, returning the variables declared by its inputs
+    fn validate_header(&mut self) -> Scope {
+        let header = &self.program.header;
+        if !header.version.check_compatible() {
+            self.report(DiagnosticKind::IncompatibleVersion(header.version));
+        }
+
+        let mut inputs = Scope::new();
+        let blocks: [&Vec<Input>; 6] = [
+            &header.main_inputs,
+            &header.constant_inputs,
+            &header.register_inputs,
+            &header.public_states,
+            &header.private_record_states,
+            &header.private_leaf_states,
+        ];
+        for input in blocks.iter().flat_map(|inputs| inputs.iter()) {
+            if inputs.contains_key(&input.variable) {
+                self.report(DiagnosticKind::DuplicateInputVariable(input.variable));
+            } else {
+                inputs.insert(input.variable, Some(value_type(&input.type_)));
+            }
+        }
+        inputs
+    }
+
+    /// orders functions so that callers are visited before the functions they call where possible
+    fn function_order(&self) -> Vec<u32> {
+        let count = self.program.functions.len() as u32;
+        let mut order: Vec<u32> = Vec::with_capacity(count as usize);
+        for root in 0..count {
+            if order.contains(&root) {
+                continue;
+            }
+            let start = order.len();
+            order.push(root);
+            let mut next = start;
+            while next < order.len() {
+                for instruction in self.program.functions[order[next] as usize].instructions.iter() {
+                    if let Instruction::Call(CallData { index, .. }) = instruction {
+                        if *index < count && !order.contains(index) {
+                            order.push(*index);
+                        }
+                    }
+                }
+                next += 1;
+            }
+        }
+        order
+    }
+
+    fn validate_function(&mut self, index: u32, mut scope: Scope) {
+        let function = &self.program.functions[index as usize];
+        self.function = Some(index);
+        self.instruction = None;
+
+        // the entrypoint receives its arguments through the header inputs
+        self.unknown_arguments = None;
+        if index != 0 {
+            match self.arguments[index as usize].clone() {
+                Some(arguments) => {
+                    for (variable, type_) in (function.argument_start_variable..).zip(arguments.into_iter()) {
+                        scope.insert(variable, type_);
+                    }
+                }
+                None => self.unknown_arguments = Some(function.argument_start_variable),
+            }
+        }
+        self.validate_block(function, 0, function.instructions.len() as u32, &mut scope);
+    }
+
+    /// returns the end of the block of `instruction_count` instructions following `index`, reporting it if the
+    /// block extends past `end`
+    fn block_end(&mut self, index: u32, instruction_count: u32, end: u32) -> u32 {
+        let block_end = index as u64 + 1 + instruction_count as u64;
+        if block_end > end as u64 {
+            self.report(DiagnosticKind::BlockOutOfBounds { instruction_count });
+            end
+        } else {
+            block_end as u32
+        }
+    }
+
+    fn validate_block(&mut self, function: &Function, start: u32, end: u32, scope: &mut Scope) {
+        let mut index = start;
+        while index < end {
+            self.instruction = Some(index);
+            match &function.instructions[index as usize] {
+                Instruction::Mask(MaskData {
+                    instruction_count,
+                    condition,
+                }) => {
+                    let condition = self.resolve(condition, scope);
+                    self.expect(&condition, "bool", |type_| *type_ == Type::Boolean);
+
+                    let block_end = self.block_end(index, *instruction_count, end);
+                    let mut inner = scope.clone();
+                    self.validate_block(function, index + 1, block_end, &mut inner);
+                    merge_scope(scope, inner);
+                    index = block_end;
+                }
+                Instruction::Repeat(RepeatData {
+                    instruction_count,
+                    iter_variable,
+                    from,
+                    to,
+                    ..
+                }) => {
+                    let from = self.resolve(from, scope);
+                    let to = self.resolve(to, scope);
+                    self.expect(&from, "integer", is_integer);
+                    self.expect(&to, "integer", is_integer);
+                    self.expect_compatible(&from, &to);
+
+                    let block_end = self.block_end(index, *instruction_count, end);
+                    let mut inner = scope.clone();
+                    inner.insert(*iter_variable, from);
+                    self.validate_block(function, index + 1, block_end, &mut inner);
+                    inner.remove(iter_variable);
+                    merge_scope(scope, inner);
+                    index = block_end;
+                }
+                instruction => {
+                    self.validate_instruction(instruction, scope);
+                    index += 1;
+                }
+            }
+        }
+    }
+
+    /// returns the type of `value`, reporting references to undefined variables
+    fn resolve(&mut self, value: &Value, scope: &Scope) -> Option<Type> {
+        match value {
+            Value::Ref(variable) => match scope.get(variable) {
+                Some(type_) => type_.clone(),
+                None if matches!(self.unknown_arguments, Some(start) if *variable >= start) => None,
+                None => {
+                    self.report(DiagnosticKind::UndefinedVariable(*variable));
+                    None
+                }
+            },
+            Value::Address(_) => Some(Type::Address),
+            Value::Boolean(_) => Some(Type::Boolean),
+            Value::Field(_) => Some(Type::Field),
+            Value::Char(_) => Some(Type::Char),
+            Value::Group(_) => Some(Type::Group),
+            Value::Integer(integer) => Some(integer.get_type()),
+            Value::Array(items) => {
+                let types = self.resolve_all(items, scope);
+                let first = types.first().cloned().flatten()?;
+                if types.iter().all(|type_| matches!(type_, Some(type_) if compatible(&first, type_))) {
+                    Some(Type::Array(Box::new(first), Some(items.len() as u32)))
+                } else {
+                    None
+                }
+            }
+            Value::Tuple(items) => {
+                let types = self.resolve_all(items, scope);
+                Some(Type::Tuple(types.into_iter().collect::<Option<Vec<_>>>()?))
+            }
+            Value::Str(_) => None,
+        }
+    }
+
+    fn resolve_all(&mut self, values: &[Value], scope: &Scope) -> Vec<Option<Type>> {
+        values.iter().map(|value| self.resolve(value, scope)).collect()
+    }
+
+    /// reports a known type that does not satisfy `predicate`
+    fn expect(&mut self, type_: &Option<Type>, expected: &str, predicate: impl Fn(&Type) -> bool) {
+        if let Some(type_) = type_ {
+            if !predicate(type_) {
+                self.report(DiagnosticKind::TypeMismatch {
+                    expected: expected.to_string(),
+                    found: type_.clone(),
+                });
+            }
+        }
+    }
+
+    /// reports two known types that differ, returning whichever is known
+    fn expect_compatible(&mut self, left: &Option<Type>, right: &Option<Type>) -> Option<Type> {
+        match (left, right) {
+            (Some(left), Some(right)) if !compatible(left, right) => {
+                self.report(DiagnosticKind::TypeMismatch {
+                    expected: left.to_string(),
+                    found: right.clone(),
+                });
+                None
+            }
+            _ => left.clone().or_else(|| right.clone()),
+        }
+    }
+
+    /// reports a variable that must already be defined, as it is updated in place
+    fn expect_defined(&mut self, variable: u32, scope: &Scope) -> Option<Type> {
+        self.resolve(&Value::Ref(variable), scope)
+    }
+
+    /// reports an operand count other than `expected`, returning the types of the operands if it matches
+    fn operands<const N: usize>(&mut self, values: &[Value], scope: &Scope) -> Option<Vec<Option<Type>>> {
+        let types = self.resolve_all(values, scope);
+        if values.len() != N {
+            self.report(DiagnosticKind::OperandCount {
+                expected: N,
+                found: values.len(),
+            });
+            return None;
+        }
+        Some(types)
+    }
+
+    /// validates an instruction other than a mask or repeat, and defines the variable it assigns
+    fn validate_instruction(&mut self, instruction: &Instruction, scope: &mut Scope) {
+        let (destination, type_) = match instruction {
+            Instruction::Add(QueryData { destination, values })
+            | Instruction::Sub(QueryData { destination, values }) => {
+                let type_ = self.validate_binary(values, scope, "field, group, or integer", |type_| {
+                    matches!(type_, Type::Field | Type::Group) || is_integer(type_)
+                });
+                (*destination, type_)
+            }
+            Instruction::Mul(QueryData { destination, values })
+            | Instruction::Div(QueryData { destination, values }) => {
+                let type_ = self.validate_binary(values, scope, "field or integer", |type_| {
+                    *type_ == Type::Field || is_integer(type_)
+                });
+                (*destination, type_)
+            }
+            Instruction::Pow(QueryData { destination, values })
+            | Instruction::Mod(QueryData { destination, values })
+            | Instruction::BitOr(QueryData { destination, values })
+            | Instruction::BitAnd(QueryData { destination, values })
+            | Instruction::BitXor(QueryData { destination, values }) => {
+                (*destination, self.validate_binary(values, scope, "integer", is_integer))
+            }
+            Instruction::Or(QueryData { destination, values })
+            | Instruction::And(QueryData { destination, values }) => {
+                self.validate_binary(values, scope, "bool", |type_| *type_ == Type::Boolean);
+                (*destination, Some(Type::Boolean))
+            }
+            Instruction::Eq(QueryData { destination, values })
+            | Instruction::Ne(QueryData { destination, values }) => {
+                self.validate_binary(values, scope, "any type", |_| true);
+                (*destination, Some(Type::Boolean))
+            }
+            Instruction::Ge(QueryData { destination, values })
+            | Instruction::Gt(QueryData { destination, values })
+            | Instruction::Le(QueryData { destination, values })
+            | Instruction::Lt(QueryData { destination, values }) => {
+                self.validate_binary(values, scope, "integer", is_integer);
+                (*destination, Some(Type::Boolean))
+            }
+            Instruction::Shr(QueryData { destination, values })
+            | Instruction::ShrSigned(QueryData { destination, values })
+            | Instruction::Shl(QueryData { destination, values }) => {
+                let type_ = self.operands::<2>(values, scope).and_then(|types| {
+                    self.expect(&types[0], "integer", is_integer);
+                    self.expect(&types[1], "unsigned integer", is_unsigned_integer);
+                    types[0].clone()
+                });
+                (*destination, type_)
+            }
+            Instruction::Not(QueryData { destination, values }) => {
+                if let Some(types) = self.operands::<1>(values, scope) {
+                    self.expect(&types[0], "bool", |type_| *type_ == Type::Boolean);
+                }
+                (*destination, Some(Type::Boolean))
+            }
+            Instruction::Negate(QueryData { destination, values }) => {
+                let type_ = self.operands::<1>(values, scope).and_then(|types| {
+                    self.expect(&types[0], "field, group, or signed integer", |type_| {
+                        matches!(type_, Type::Field | Type::Group) || is_signed_integer(type_)
+                    });
+                    types[0].clone()
+                });
+                (*destination, type_)
+            }
+            Instruction::BitNot(QueryData { destination, values }) => {
+                let type_ = self.operands::<1>(values, scope).and_then(|types| {
+                    self.expect(&types[0], "integer", is_integer);
+                    types[0].clone()
+                });
+                (*destination, type_)
+            }
+            Instruction::ArrayInitRepeat(ArrayInitRepeatData {
+                destination,
+                length,
+                value,
+            }) => {
+                let type_ = self.resolve(value, scope);
+                (
+                    *destination,
+                    type_.map(|type_| Type::Array(Box::new(type_), Some(*length))),
+                )
+            }
+            Instruction::ArrayInit(VarData { destination, values }) => {
+                let types = self.resolve_all(values, scope);
+                (*destination, self.validate_array_init(&types))
+            }
+            Instruction::ArrayIndexGet(QueryData { destination, values }) => {
+                let type_ = self.operands::<2>(values, scope).and_then(|types| {
+                    self.expect(&types[1], "integer", is_integer);
+                    self.array_element(&types[0])
+                });
+                (*destination, type_)
+            }
+            Instruction::ArraySliceGet(QueryData { destination, values }) => {
+                let type_ = self.operands::<4>(values, scope).and_then(|types| {
+                    self.expect(&types[1], "integer", is_integer);
+                    self.expect(&types[2], "integer", is_integer);
+                    self.expect(&types[3], "integer", is_integer);
+                    let length = match &values[3] {
+                        Value::Integer(Integer::U32(length)) => Some(*length),
+                        _ => None,
+                    };
+                    self.array_element(&types[0])
+                        .map(|element| Type::Array(Box::new(element), length))
+                });
+                (*destination, type_)
+            }
+            Instruction::ArrayIndexStore(QueryData { destination, values }) => {
+                let array = self.expect_defined(*destination, scope);
+                if let Some(types) = self.operands::<2>(values, scope) {
+                    self.expect(&types[0], "integer", is_integer);
+                    let element = self.array_element(&array);
+                    self.expect_compatible(&element, &types[1]);
+                }
+                (*destination, array)
+            }
+            Instruction::ArraySliceStore(QueryData { destination, values }) => {
+                let array = self.expect_defined(*destination, scope);
+                if let Some(types) = self.operands::<3>(values, scope) {
+                    self.expect(&types[0], "integer", is_integer);
+                    self.expect(&types[1], "integer", is_integer);
+                    let element = self.array_element(&array);
+                    let target = self.array_element(&types[2]);
+                    self.expect_compatible(&element, &target);
+                }
+                (*destination, array)
+            }
+            Instruction::TupleInit(VarData { destination, values }) => {
+                let types = self.resolve_all(values, scope);
+                (
+                    *destination,
+                    types.into_iter().collect::<Option<Vec<_>>>().map(Type::Tuple),
+                )
+            }
+            Instruction::TupleIndexGet(QueryData { destination, values }) => {
+                let type_ = self.operands::<2>(values, scope).and_then(|types| {
+                    self.expect(&types[1], "integer", is_integer);
+                    self.tuple_element(&types[0], &values[1])
+                });
+                (*destination, type_)
+            }
+            Instruction::TupleIndexStore(QueryData { destination, values }) => {
+                let tuple = self.expect_defined(*destination, scope);
+                if let Some(types) = self.operands::<2>(values, scope) {
+                    self.expect(&types[0], "integer", is_integer);
+                    let element = self.tuple_element(&tuple, &values[0]);
+                    self.expect_compatible(&element, &types[1]);
+                }
+                (*destination, tuple)
+            }
+            Instruction::Pick(QueryData { destination, values }) => {
+                let type_ = self.operands::<3>(values, scope).and_then(|types| {
+                    self.expect(&types[0], "bool", |type_| *type_ == Type::Boolean);
+                    self.expect_compatible(&types[1], &types[2])
+                });
+                (*destination, type_)
+            }
+            Instruction::Store(QueryData { destination, values }) => {
+                let type_ = self.operands::<1>(values, scope).and_then(|types| types[0].clone());
+                (*destination, type_)
+            }
+            Instruction::Call(CallData {
+                destination,
+                index,
+                arguments,
+            }) => {
+                let types = self.resolve_all(arguments, scope);
+                match self.arguments.get_mut(*index as usize) {
+                    Some(seen @ None) => *seen = Some(types),
+                    Some(Some(seen)) => {
+                        for (i, type_) in types.into_iter().enumerate() {
+                            match seen.get_mut(i) {
+                                Some(seen) if *seen != type_ => *seen = None,
+                                Some(_) => (),
+                                None => seen.push(type_),
+                            }
+                        }
+                    }
+                    None => self.report(DiagnosticKind::UndefinedFunction(*index)),
+                }
+                (*destination, None)
+            }
+            Instruction::Return(PredicateData { values }) => {
+                self.operands::<1>(values, scope);
+                return;
+            }
+            Instruction::Assert(PredicateData { values }) => {
+                if let Some(types) = self.operands::<1>(values, scope) {
+                    self.expect(&types[0], "bool", |type_| *type_ == Type::Boolean);
+                }
+                return;
+            }
+            Instruction::Log(LogData { parts, .. }) => {
+                self.resolve_all(parts, scope);
+                return;
+            }
+            Instruction::CallCore(CallCoreData {
+                destination,
+                identifier,
+                arguments,
+            }) => {
+                let types = self.resolve_all(arguments, scope);
+                (*destination, self.validate_core_call(identifier, &types))
+            }
+            Instruction::Mask(_) | Instruction::Repeat(_) => unreachable!("blocks are validated by `validate_block`"),
+        };
+        scope.insert(destination, type_);
+    }
+
+    /// validates the operands of a binary instruction of operands of the same type, returning that type
+    fn validate_binary(
+        &mut self,
+        values: &[Value],
+        scope: &Scope,
+        expected: &str,
+        predicate: impl Fn(&Type) -> bool,
+    ) -> Option<Type> {
+        let types = self.operands::<2>(values, scope)?;
+        self.expect(&types[0], expected, &predicate);
+        self.expect(&types[1], expected, &predicate);
+        self.expect_compatible(&types[0], &types[1])
+    }
+
+    /// returns the type of an array concatenating the given values, where arrays are flattened by one dimension
+    fn validate_array_init(&mut self, types: &[Option<Type>]) -> Option<Type> {
+        let mut element: Option<Type> = None;
+        let mut length = Some(0u32);
+        for type_ in types {
+            let (item, item_length) = match type_ {
+                Some(Type::Array(item, item_length)) => ((**item).clone(), *item_length),
+                Some(type_) => (type_.clone(), Some(1)),
+                None => return None,
+            };
+            if let Some(element) = &element {
+                if !compatible(element, &item) {
+                    self.report(DiagnosticKind::TypeMismatch {
+                        expected: element.to_string(),
+                        found: item,
+                    });
+                    return None;
+                }
+            } else {
+                element = Some(item);
+            }
+            length = length.and_then(|length| Some(length + item_length?));
+        }
+        Some(Type::Array(Box::new(element?), length))
+    }
+
+    fn array_element(&mut self, type_: &Option<Type>) -> Option<Type> {
+        self.expect(type_, "array", |type_| matches!(type_, Type::Array(..)));
+        match type_ {
+            Some(Type::Array(element, _)) => Some((**element).clone()),
+            _ => None,
+        }
+    }
+
+    fn tuple_element(&mut self, type_: &Option<Type>, index: &Value) -> Option<Type> {
+        self.expect(type_, "tuple", |type_| matches!(type_, Type::Tuple(_)));
+        match (type_, index) {
+            (Some(Type::Tuple(elements)), Value::Integer(index)) => {
+                let index = u32::try_from(*index).ok()?;
+                match elements.get(index as usize) {
+                    Some(element) => Some(element.clone()),
+                    None => {
+                        self.report(DiagnosticKind::TupleIndexOutOfBounds {
+                            index,
+                            length: elements.len(),
+                        });
+                        None
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// validates a call to a core function, returning the type of its result
+    fn validate_core_call(&mut self, identifier: &str, types: &[Option<Type>]) -> Option<Type> {
+        let bytes = Type::Array(Box::new(Type::U8), None);
+        let bits = Type::Array(Box::new(Type::Boolean), None);
+        let (parameters, output) = match identifier {
+            "hash" => {
+                let digest = Type::Array(Box::new(Type::U8), Some(32));
+                (vec![Some(digest.clone()), Some(digest.clone())], Some(digest))
+            }
+            "len" => (vec![None], Some(Type::U32)),
+            identifier => match core_conversion(identifier) {
+                Some((type_, Conversion::ToBits)) => (vec![Some(type_)], Some(bits)),
+                Some((type_, Conversion::ToBytes)) => (vec![Some(type_)], Some(bytes)),
+                Some((type_, Conversion::FromBits)) => (vec![Some(bits)], Some(type_)),
+                Some((type_, Conversion::FromBytes)) => (vec![Some(bytes)], Some(type_)),
+                None => {
+                    self.report(DiagnosticKind::UnknownCoreFunction(identifier.to_string()));
+                    return None;
+                }
+            },
+        };
+
+        if parameters.len() != types.len() {
+            self.report(DiagnosticKind::CoreArgumentCount {
+                identifier: identifier.to_string(),
+                expected: parameters.len(),
+                found: types.len(),
+            });
+            return output;
+        }
+        for (parameter, type_) in parameters.iter().zip(types.iter()) {
+            match parameter {
+                Some(parameter) => {
+                    self.expect(type_, &parameter.to_string(), |type_| compatible(parameter, type_));
+                }
+                None => self.expect(type_, "array", |type_| matches!(type_, Type::Array(..))),
+            }
+        }
+        output
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Conversion {
+    ToBits,
+    ToBytes,
+    FromBits,
+    FromBytes,
+}
+
+/// parses the names of the `<type>_{to,from}_{bits,bytes}_{le,be}` core functions
+fn core_conversion(identifier: &str) -> Option<(Type, Conversion)> {
+    let identifier = identifier
+        .strip_suffix("_le")
+        .or_else(|| identifier.strip_suffix("_be"))?;
+    let conversions = [
+        ("_to_bits", Conversion::ToBits),
+        ("_to_bytes", Conversion::ToBytes),
+        ("_from_bits", Conversion::FromBits),
+        ("_from_bytes", Conversion::FromBytes),
+    ];
+    let (type_, conversion) = conversions
+        .iter()
+        .find_map(|(suffix, conversion)| Some((identifier.strip_suffix(suffix)?, *conversion)))?;
+    let type_ = match type_ {
+        "address" => Type::Address,
+        "bool" => Type::Boolean,
+        "char" => Type::Char,
+        "field" => Type::Field,
+        "group" => Type::Group,
+        "u8" => Type::U8,
+        "u16" => Type::U16,
+        "u32" => Type::U32,
+        "u64" => Type::U64,
+        "u128" => Type::U128,
+        "i8" => Type::I8,
+        "i16" => Type::I16,
+        "i32" => Type::I32,
+        "i64" => Type::I64,
+        "i128" => Type::I128,
+        _ => return None,
+    };
+    Some((type_, conversion))
+}
+
+/// returns the type of the values of an input of type `type_`, where circuits are represented as tuples
+fn value_type(type_: &Type) -> Type {
+    match type_ {
+        Type::Array(inner, length) => Type::Array(Box::new(value_type(inner)), *length),
+        Type::Tuple(inner) => Type::Tuple(inner.iter().map(value_type).collect()),
+        Type::Circuit(members) => Type::Tuple(members.iter().map(|(_, type_)| value_type(type_)).collect()),
+        type_ => type_.clone(),
+    }
+}
+
+/// updates variables assigned within a block, which are only visible after it if they were defined before it
+fn merge_scope(scope: &mut Scope, inner: Scope) {
+    for (variable, type_) in inner {
+        if let Some(outer) = scope.get_mut(&variable) {
+            match (&*outer, &type_) {
+                (Some(outer_type), Some(type_)) if compatible(outer_type, type_) => (),
+                _ => *outer = None,
+            }
+        }
+    }
+}
+
+/// whether values of the two types are interchangeable, where arrays of unknown length match any length
+fn compatible(left: &Type, right: &Type) -> bool {
+    match (left, right) {
+        (Type::Array(left, left_length), Type::Array(right, right_length)) => {
+            compatible(left, right) && (left_length.is_none() || right_length.is_none() || left_length == right_length)
+        }
+        (Type::Tuple(left), Type::Tuple(right)) => {
+            left.len() == right.len() && left.iter().zip(right.iter()).all(|(left, right)| compatible(left, right))
+        }
+        (left, right) => left == right,
+    }
+}
+
+fn is_unsigned_integer(type_: &Type) -> bool {
+    matches!(type_, Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::U128)
+}
+
+fn is_signed_integer(type_: &Type) -> bool {
+    matches!(type_, Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::I128)
+}
+
+fn is_integer(type_: &Type) -> bool {
+    is_unsigned_integer(type_) || is_signed_integer(type_)
+}
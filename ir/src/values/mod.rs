@@ -81,6 +81,24 @@ impl fmt::Display for Integer {
     }
 }
 
+impl Integer {
+    /// Returns the type of the integer.
+    pub fn get_type(&self) -> Type {
+        match self {
+            Integer::U8(_) => Type::U8,
+            Integer::U16(_) => Type::U16,
+            Integer::U32(_) => Type::U32,
+            Integer::U64(_) => Type::U64,
+            Integer::U128(_) => Type::U128,
+            Integer::I8(_) => Type::I8,
+            Integer::I16(_) => Type::I16,
+            Integer::I32(_) => Type::I32,
+            Integer::I64(_) => Type::I64,
+            Integer::I128(_) => Type::I128,
+        }
+    }
+}
+
 /// A constant value in IR representation or variable reference
 #[derive(Clone, Debug, PartialEq, Serialize)]
 pub enum Value {
@@ -105,16 +123,13 @@ impl fmt::Display for Value {
                 bech32::encode("aleo", bytes.to_vec().to_base32(), bech32::Variant::Bech32).unwrap_or_default()
             ),
             Value::Boolean(x) => write!(f, "{}", x),
-            Value::Field(field) => write!(f, "{}", field),
-            Value::Char(c) => write!(
-                f,
-                "'{}'",
-                std::char::from_u32(*c)
-                    .unwrap_or(std::char::REPLACEMENT_CHARACTER)
-                    .escape_default()
-            ),
+            Value::Field(field) => write!(f, "{}field", field),
+            Value::Char(c) => match std::char::from_u32(*c) {
+                Some(c) => write!(f, "'{}'", c.escape_default()),
+                None => write!(f, "'\\u{{{:x}}}'", c),
+            },
             Value::Group(group) => group.fmt(f),
-            Value::Integer(x) => write!(f, "{}", x),
+            Value::Integer(x) => write!(f, "{}{}", x, x.get_type()),
             Value::Array(items) => {
                 write!(f, "[")?;
                 for (i, item) in items.iter().enumerate() {
@@ -129,7 +144,7 @@ impl fmt::Display for Value {
                 }
                 write!(f, ")")
             }
-            Value::Str(s) => write!(f, "\"{}\"", s),
+            Value::Str(s) => write!(f, "\"{}\"", s.escape_default()),
             Value::Ref(x) => write!(f, "v{}", x),
         }
     }
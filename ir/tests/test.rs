@@ -21,7 +21,7 @@ use std::{
     str::FromStr,
 };
 
-use snarkvm_ir::{InputData, Program};
+use snarkvm_ir::{DiagnosticKind, InputData, Instruction, PassManager, PredicateData, Program, Type};
 
 fn inner_load_tests<P: AsRef<Path>>(path: P, out: &mut BTreeMap<String, Vec<u8>>, extension: &str) {
     for item in path.as_ref().read_dir().unwrap() {
@@ -96,3 +96,222 @@ fn format_test() {
         };
     }
 }
+
+#[test]
+fn parse_test() {
+    let tests = load_tests();
+    for (name, raw) in tests {
+        let deserialized = Program::deserialize(&raw[..]).expect(&*format!("failed to deserialize {}", name));
+        let expectation_path = PathBuf::from_str(&*format!("{}.fmt", name)).unwrap();
+        let formatted = fs::read_to_string(&expectation_path).unwrap_or_else(|_| deserialized.to_string());
+        let parsed = Program::parse(&formatted).expect(&*format!("failed to parse {}", name));
+        if parsed != deserialized {
+            panic!("parse mismatch for {}:\nexpected:\n{}\ngot:\n{}", name, deserialized, parsed);
+        }
+    }
+}
+
+#[test]
+fn parse_handwritten_test() {
+    let program: Program = r#"
+        // swaps a pair if the flag is set
+        inline_limit 2
+        main_input &v0 "pair": (u8, [char; _])
+        constant_input &v1 "flag": bool
+        public_state &v2 "point": {x: group, y: [field; 2]}
+
+        decl f0: <0>
+          tget &v3, v0, 0u32
+          mask 2, v1
+            call &v4, f1, v3, [1, 2]field
+            log INFO, "swap {}\t\"{}\"", v4, '\u{1f600}'
+          retn (v4, -5i8, (+, [7])group, -[]group)
+        decl f1: <3>
+          retn ('\'', aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8s7pyjh9)
+    "#
+    .parse()
+    .unwrap();
+    assert_eq!(program.header.inline_limit, 2);
+    assert_eq!(program.header.public_states[0].name, "point");
+    assert_eq!(program.functions.len(), 2);
+    assert_eq!(program.functions[0].instructions.len(), 5);
+    assert_eq!(program.functions[1].argument_start_variable, 3);
+    assert_eq!(Program::parse(&program.to_string()).unwrap(), program);
+}
+
+#[test]
+fn parse_error_test() {
+    let error = Program::parse("inline_limit 1\ndecl f0: <0>\n  add &v1, v0, v0\n  sub &v2, v1, 1\n").unwrap_err();
+    assert!(error.to_string().starts_with("line 4:"), "{}", error);
+    let error = Program::parse("inline_limit 1\ndecl f1: <0>\n").unwrap_err();
+    assert_eq!(error.to_string(), "line 2: expected declaration of f0, found f1");
+    assert!(Program::parse("decl f0: <0>\n  retn\n").is_err());
+}
+
+#[test]
+fn validate_test() {
+    let tests = load_tests();
+    for (name, raw) in tests {
+        let deserialized = Program::deserialize(&raw[..]).expect(&*format!("failed to deserialize {}", name));
+        let diagnostics = deserialized.validate();
+        if !diagnostics.is_empty() {
+            panic!(
+                "validation failed for {}:\n{}\n{}",
+                name,
+                diagnostics.iter().map(|x| x.to_string()).collect::<Vec<_>>().join("\n"),
+                deserialized
+            );
+        }
+    }
+}
+
+#[test]
+fn validate_errors_test() {
+    let mut program = Program::parse(
+        r#"
+        inline_limit 2
+        main_input &v0 "a": u8
+        main_input &v0 "b": bool
+        decl f0: <0>
+          add &v1, v0, true
+          mask 1, v0
+            store &v2, 1u8
+          retn v2
+          call &v3, f2
+          ccall &v4, 'sha256', v0
+          ccall &v5, 'u8_to_bits_le', v0, v0
+          tget &v6, (1u8, 2u8), 2u32
+          repeat 4, &v7, false, 0u32, 3u32
+            shl &v8, v0, -1i8
+        "#,
+    )
+    .unwrap();
+    program.functions[0].instructions.push(Instruction::Assert(PredicateData { values: vec![] }));
+
+    let diagnostics = program
+        .validate()
+        .into_iter()
+        .map(|x| (x.function, x.instruction, x.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(diagnostics, vec![
+        (None, None, DiagnosticKind::DuplicateInputVariable(0)),
+        (Some(0), Some(0), DiagnosticKind::TypeMismatch {
+            expected: "field, group, or integer".to_string(),
+            found: Type::Boolean
+        }),
+        (Some(0), Some(0), DiagnosticKind::TypeMismatch {
+            expected: "u8".to_string(),
+            found: Type::Boolean
+        }),
+        (Some(0), Some(1), DiagnosticKind::TypeMismatch {
+            expected: "bool".to_string(),
+            found: Type::U8
+        }),
+        (Some(0), Some(3), DiagnosticKind::UndefinedVariable(2)),
+        (Some(0), Some(4), DiagnosticKind::UndefinedFunction(2)),
+        (Some(0), Some(5), DiagnosticKind::UnknownCoreFunction("sha256".to_string())),
+        (Some(0), Some(6), DiagnosticKind::CoreArgumentCount {
+            identifier: "u8_to_bits_le".to_string(),
+            expected: 1,
+            found: 2
+        }),
+        (Some(0), Some(7), DiagnosticKind::TupleIndexOutOfBounds { index: 2, length: 2 }),
+        (Some(0), Some(8), DiagnosticKind::BlockOutOfBounds { instruction_count: 4 }),
+        (Some(0), Some(9), DiagnosticKind::TypeMismatch {
+            expected: "unsigned integer".to_string(),
+            found: Type::I8
+        }),
+        (Some(0), Some(10), DiagnosticKind::OperandCount { expected: 1, found: 0 }),
+    ]);
+}
+
+#[test]
+fn optimize_test() {
+    let tests = load_tests();
+    for (name, raw) in tests {
+        let mut program = Program::deserialize(&raw[..]).unwrap();
+        program.optimize();
+        let diagnostics = program.validate();
+        assert!(diagnostics.is_empty(), "{} is invalid after optimizing: {:?}", name, diagnostics);
+        assert_eq!(Program::parse(&program.to_string()).unwrap(), program);
+    }
+}
+
+#[test]
+fn optimize_passes_test() {
+    let mut program = Program::parse(
+        r#"
+        inline_limit 2
+        main_input &v0 "a": u32
+        decl f0: <0>
+          add &v1, 2u32, 3u32
+          call &v2, f1, v0, v1
+          eq &v3, v0, v1
+          mask 1, false
+            assert v3
+          add &v11, 255u8, 1u8
+          retn v2
+        decl f1: <4>
+          mul &v6, v4, v5
+          retn v6
+        decl f2: <7>
+          retn v7
+    "#,
+    )
+    .unwrap();
+    assert_eq!(PassManager::default().passes(), vec![
+        "inlining",
+        "constant-folding",
+        "dead-store-elimination",
+        "unused-function-removal"
+    ]);
+    assert!(program.optimize());
+
+    // the comparison and the overflowing addition are kept, since they may fail
+    let expected = Program::parse(
+        r#"
+        inline_limit 2
+        main_input &v0 "a": u32
+        decl f0: <0>
+          store &v12, v0
+          mul &v14, v12, 5u32
+          store &v2, v14
+          eq &v3, v0, 5u32
+          add &v11, 255u8, 1u8
+          retn v2
+    "#,
+    )
+    .unwrap();
+    assert_eq!(program.to_string(), expected.to_string());
+    assert!(!program.optimize());
+}
+
+#[test]
+fn optimize_keeps_failing_dead_store_test() {
+    let mut program = Program::parse(
+        r#"
+        inline_limit 2
+        main_input &v0 "a": u32
+        decl f0: <0>
+          tinit &v1, 1u32, 2u32
+          tget &v2, v1, v0
+          store &v3, v1
+          retn v0
+    "#,
+    )
+    .unwrap();
+    assert!(program.optimize());
+
+    // the tuple index may be out of bounds, so it is kept even though its result is unused
+    let expected = Program::parse(
+        r#"
+        inline_limit 2
+        main_input &v0 "a": u32
+        decl f0: <0>
+          tget &v2, (1u32, 2u32), v0
+          retn v0
+    "#,
+    )
+    .unwrap();
+    assert_eq!(program.to_string(), expected.to_string());
+}
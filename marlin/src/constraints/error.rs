@@ -60,6 +60,9 @@ impl From<MarlinError> for MarlinConstraintsError {
             MarlinError::PolynomialCommitmentError(err) => Self {
                 error_msg: err.to_string(),
             },
+            MarlinError::EmptyBatch => Self {
+                error_msg: "the batch to prove has no instances".to_string(),
+            },
             MarlinError::Terminated => Self {
                 error_msg: "terminated".to_string(),
             },
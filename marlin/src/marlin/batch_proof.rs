@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ahp::prover::ProverMessage, Vec};
+use snarkvm_fields::PrimeField;
+use snarkvm_polycommit::{BatchLCProof, PolynomialCommitment};
+use snarkvm_utilities::{error, errors::SerializationError, serialize::*, FromBytes, ToBytes};
+
+use derivative::Derivative;
+use std::io::{
+    Read,
+    Write,
+    {self},
+};
+
+/// A zkSNARK proof for a batch of instances of one or more circuits.
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Clone(bound = ""))]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchProof<F: PrimeField, PC: PolynomialCommitment<F>> {
+    /// Commitments to the polynomials produced by the AHP prover, for each instance, ordered by circuit.
+    pub commitments: Vec<Vec<Vec<PC::Commitment>>>,
+    /// Evaluations of these polynomials, for each instance, ordered by circuit.
+    pub evaluations: Vec<Vec<F>>,
+    /// The field elements sent by the prover, for each instance, ordered by circuit.
+    pub prover_messages: Vec<Vec<ProverMessage<F>>>,
+    /// An evaluation proof from the polynomial commitment for each circuit, shared by its instances.
+    pub pc_proofs: Vec<BatchLCProof<F, PC>>,
+}
+
+impl<F: PrimeField, PC: PolynomialCommitment<F>> BatchProof<F, PC> {
+    /// Construct a new batch proof.
+    pub fn new(
+        commitments: Vec<Vec<Vec<PC::Commitment>>>,
+        evaluations: Vec<Vec<F>>,
+        prover_messages: Vec<Vec<ProverMessage<F>>>,
+        pc_proofs: Vec<BatchLCProof<F, PC>>,
+    ) -> Self {
+        Self {
+            commitments,
+            evaluations,
+            prover_messages,
+            pc_proofs,
+        }
+    }
+
+    /// Returns the number of instances in the batch.
+    pub fn num_instances(&self) -> usize {
+        self.commitments.len()
+    }
+}
+
+impl<F: PrimeField, PC: PolynomialCommitment<F>> ToBytes for BatchProof<F, PC> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        CanonicalSerialize::serialize(self, &mut w).map_err(|_| error("could not serialize BatchProof"))
+    }
+}
+
+impl<F: PrimeField, PC: PolynomialCommitment<F>> FromBytes for BatchProof<F, PC> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize(&mut r).map_err(|_| error("could not deserialize BatchProof"))
+    }
+}
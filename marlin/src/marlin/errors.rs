@@ -31,6 +31,8 @@ pub enum MarlinError {
     R1CSError(snarkvm_r1cs::SynthesisError),
     /// There was an error in the underlying polynomial commitment.
     PolynomialCommitmentError(snarkvm_polycommit::Error),
+    /// The batch to prove has no instances.
+    EmptyBatch,
     Terminated,
 }
 
@@ -15,19 +15,40 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
+    ahp::{indexer::CircuitInfo, prover::ProverMessage, AHPError, AHPForR1CS, EvaluationsProvider},
     fiat_shamir::traits::FiatShamirRng,
-    marlin::{compute_vk_hash, CircuitProvingKey, CircuitVerifyingKey, MarlinError, MarlinMode, Proof, UniversalSRS},
+    marlin::{
+        compute_vk_hash,
+        BatchProof,
+        CircuitProvingKey,
+        CircuitVerifyingKey,
+        MarlinError,
+        MarlinMode,
+        Proof,
+        UniversalSRS,
+    },
 };
 use snarkvm_algorithms::fft::EvaluationDomain;
 use snarkvm_fields::{PrimeField, ToConstraintField};
 use snarkvm_gadgets::nonnative::params::OptimizationType;
-use snarkvm_polycommit::{Evaluations, LabeledCommitment, LabeledPolynomial, PCUniversalParams, PolynomialCommitment};
+use snarkvm_polycommit::{
+    BatchLCProof,
+    CombinationsCheck,
+    Evaluations,
+    LCTerm,
+    LabeledCommitment,
+    LabeledPolynomial,
+    LinearCombination,
+    OpeningChallenges,
+    PCUniversalParams,
+    PolynomialCommitment,
+    QuerySet,
+};
 use snarkvm_r1cs::{ConstraintSynthesizer, SynthesisError};
 use snarkvm_utilities::{to_bytes_le, ToBytes};
 
 use crate::marlin::PreparedCircuitVerifyingKey;
-use core::marker::PhantomData;
+use core::{marker::PhantomData, ops::Range};
 use rand_core::RngCore;
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -396,29 +417,7 @@ where
             return Err(MarlinError::Terminated);
         }
 
-        let vanishing_polys = if is_recursion {
-            let domain_h = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_constraints)
-                .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-            let domain_k = EvaluationDomain::new(circuit_proving_key.circuit.index_info.num_non_zero)
-                .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
-
-            vec![
-                LabeledPolynomial::new(
-                    "vanishing_poly_h".to_string(),
-                    domain_h.vanishing_polynomial().into(),
-                    None,
-                    None,
-                ),
-                LabeledPolynomial::new(
-                    "vanishing_poly_k".to_string(),
-                    domain_k.vanishing_polynomial().into(),
-                    None,
-                    None,
-                ),
-            ]
-        } else {
-            vec![]
-        };
+        let vanishing_polys = Self::vanishing_polynomials(&circuit_proving_key.circuit.index_info)?;
 
         // Gather prover polynomials in one vector.
         let polynomials: Vec<_> = circuit_proving_key
@@ -576,22 +575,112 @@ where
     ) -> Result<bool, MarlinError> {
         let verifier_time = start_timer!(|| "Marlin::Verify");
 
-        let public_input = {
-            let domain_x = EvaluationDomain::<TargetField>::new(public_input.len() + 1).unwrap();
+        let (pc_check, mut fs_rng) = Self::verify_ahp(circuit_verifying_key, public_input, proof)?;
 
-            if cfg!(debug_assertions) {
-                println!("Number of given public inputs: {}", public_input.len());
-                println!("Size of evaluation domain x: {}", domain_x.size());
-            }
+        let evaluations_are_correct = if MM::RECURSION {
+            let opening_challenges_f = |i| pc_check.opening_challenges[i as usize];
 
-            let mut new_input = public_input.to_vec();
-            new_input.resize(
-                core::cmp::max(public_input.len(), domain_x.size() - 1),
-                TargetField::zero(),
-            );
-            new_input
+            PC::check_combinations_individual_opening_challenges(
+                &circuit_verifying_key.verifier_key,
+                &pc_check.linear_combinations,
+                &pc_check.commitments,
+                &pc_check.query_set,
+                &pc_check.evaluations,
+                &proof.pc_proof,
+                &opening_challenges_f,
+                &mut fs_rng,
+            )?
+        } else {
+            PC::check_combinations(
+                &circuit_verifying_key.verifier_key,
+                &pc_check.linear_combinations,
+                &pc_check.commitments,
+                &pc_check.query_set,
+                &pc_check.evaluations,
+                &proof.pc_proof,
+                pc_check.opening_challenges[0],
+                &mut fs_rng,
+            )?
         };
 
+        if !evaluations_are_correct {
+            eprintln!("PC::Check failed");
+        }
+        end_timer!(verifier_time, || format!(
+            " PC::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    /// Verify several proofs, possibly for different circuits, checking the evaluation proofs of all of them at once.
+    /// With `MarlinKZG10`, the pairing checks of all proofs are folded into a single batched check.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_proofs(
+        keys_inputs_and_proofs: &[(
+            &CircuitVerifyingKey<TargetField, PC>,
+            &[TargetField],
+            &Proof<TargetField, PC>,
+        )],
+    ) -> Result<bool, MarlinError> {
+        let verifier_time =
+            start_timer!(|| format!("Marlin::VerifyProofs with {} proofs", keys_inputs_and_proofs.len()));
+
+        let mut batch_fs_rng = FS::new();
+        batch_fs_rng.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME, keys_inputs_and_proofs.len() as u64].unwrap());
+
+        let mut pc_checks = Vec::with_capacity(keys_inputs_and_proofs.len());
+        for (circuit_verifying_key, public_input, proof) in keys_inputs_and_proofs {
+            let (pc_check, mut fs_rng) = Self::verify_ahp(circuit_verifying_key, public_input, proof)?;
+
+            // Bind the randomness of the batched check to the transcript of every proof.
+            let transcript_challenge = fs_rng.squeeze_128_bits_nonnative_field_elements(1)?;
+            batch_fs_rng.absorb_bytes(&to_bytes_le![transcript_challenge].unwrap());
+
+            pc_checks.push(pc_check);
+        }
+
+        let opening_challenges_f: Vec<_> = pc_checks
+            .iter()
+            .map(|pc_check| move |i: u64| pc_check.opening_challenges[i as usize])
+            .collect();
+        let checks: Vec<_> = keys_inputs_and_proofs
+            .iter()
+            .zip(&pc_checks)
+            .zip(&opening_challenges_f)
+            .map(
+                |(((circuit_verifying_key, _, proof), pc_check), opening_challenges_f)| {
+                    pc_check.combinations_check::<MM>(
+                        &circuit_verifying_key.verifier_key,
+                        &proof.pc_proof,
+                        opening_challenges_f,
+                    )
+                },
+            )
+            .collect();
+
+        let evaluations_are_correct = PC::batch_check_combinations(&checks, &mut batch_fs_rng)?;
+
+        if !evaluations_are_correct {
+            eprintln!("PC::Check failed");
+        }
+        end_timer!(verifier_time, || format!(
+            " PC::Check for AHP Verifier linear equations of {} proofs: {}",
+            keys_inputs_and_proofs.len(),
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    /// Runs the AHP verifier on `proof`, and returns the evaluation proof that remains to be checked,
+    /// along with the Fiat-Shamir RNG at the end of the transcript.
+    fn verify_ahp(
+        circuit_verifying_key: &CircuitVerifyingKey<TargetField, PC>,
+        public_input: &[TargetField],
+        proof: &Proof<TargetField, PC>,
+    ) -> Result<(PCCheck<TargetField, PC>, FS), MarlinError> {
+        let public_input = Self::pad_public_input(public_input);
+
         if cfg!(debug_assertions) {
             println!("Number of padded public variables: {}", public_input.len());
         }
@@ -720,59 +809,761 @@ where
             evaluations.insert(q, *eval);
         }
 
-        let lc_s =
+        let linear_combinations =
             AHPForR1CS::construct_linear_combinations(&public_input, &evaluations, &verifier_state, is_recursion)?;
 
-        let evaluations_are_correct = if is_recursion {
-            let num_open_challenges: usize = 7;
+        let num_open_challenges: usize = if is_recursion { 7 } else { 1 };
+        let opening_challenges = fs_rng.squeeze_128_bits_nonnative_field_elements(num_open_challenges)?;
 
-            let mut opening_challenges = Vec::new();
-            opening_challenges.append(&mut fs_rng.squeeze_128_bits_nonnative_field_elements(num_open_challenges)?);
+        let pc_check = PCCheck {
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            opening_challenges,
+        };
+        Ok((pc_check, fs_rng))
+    }
 
-            let opening_challenges_f = |i| opening_challenges[i as usize];
+    /// Verify that a proof for the constraint system defined by `C` asserts that
+    /// all constraints are satisfied using the prepared verifying key.
+    pub fn prepared_verify(
+        prepared_vk: &PreparedCircuitVerifyingKey<TargetField, PC>,
+        public_input: &[TargetField],
+        proof: &Proof<TargetField, PC>,
+    ) -> Result<bool, MarlinError> {
+        Self::verify(&prepared_vk.orig_vk, public_input, proof)
+    }
 
-            PC::check_combinations_individual_opening_challenges(
-                &circuit_verifying_key.verifier_key,
-                &lc_s,
-                &commitments,
-                &query_set,
-                &evaluations,
-                &proof.pc_proof,
-                &opening_challenges_f,
-                &mut fs_rng,
-            )?
+    /// Create a zkSNARK asserting that each of the given instances of each of the given circuits is satisfied.
+    /// The instances share one transcript, and the polynomials of the instances of a circuit are opened together.
+    #[allow(clippy::type_complexity)]
+    pub fn prove_batch<C: ConstraintSynthesizer<TargetField>, R: RngCore>(
+        keys_to_constraints: &[(&CircuitProvingKey<TargetField, PC>, &[C])],
+        zk_rng: &mut R,
+    ) -> Result<BatchProof<TargetField, PC>, MarlinError> {
+        let num_instances: usize = keys_to_constraints.iter().map(|(_, circuits)| circuits.len()).sum();
+        let prover_time = start_timer!(|| format!(
+            "Marlin::BatchProver with {} circuits and {} instances",
+            keys_to_constraints.len(),
+            num_instances
+        ));
+
+        if keys_to_constraints.is_empty() || keys_to_constraints.iter().any(|(_, circuits)| circuits.is_empty()) {
+            return Err(MarlinError::EmptyBatch);
+        }
+
+        let is_recursion = MM::RECURSION;
+        let hiding = !is_recursion;
+
+        // The instances of all circuits in order, each along with the index of its circuit.
+        let instances: Vec<_> = keys_to_constraints
+            .iter()
+            .enumerate()
+            .flat_map(|(j, (_, circuits))| circuits.iter().map(move |circuit| (j, circuit)))
+            .collect();
+        let instance_ranges = Self::instance_ranges(keys_to_constraints.iter().map(|(_, circuits)| circuits.len()));
+
+        let mut prover_states = Vec::with_capacity(num_instances);
+        let mut public_inputs = Vec::with_capacity(num_instances);
+        for (j, circuit) in &instances {
+            let prover_init_state = AHPForR1CS::prover_init(&keys_to_constraints[*j].0.circuit, *circuit)?;
+            public_inputs.push(prover_init_state.public_input());
+            prover_states.push(prover_init_state);
+        }
+
+        let mut fs_rng = FS::new();
+        let keys_to_inputs: Vec<_> = keys_to_constraints
+            .iter()
+            .zip(&instance_ranges)
+            .map(|((circuit_proving_key, _), range)| {
+                (
+                    &circuit_proving_key.circuit_verifying_key,
+                    &public_inputs[range.clone()],
+                )
+            })
+            .collect();
+        Self::absorb_batch_public_inputs(&mut fs_rng, &keys_to_inputs)?;
+
+        let mut commitments = vec![Vec::new(); num_instances];
+        let mut labeled_commitments = vec![Vec::new(); num_instances];
+        let mut commitment_randomnesses = vec![Vec::new(); num_instances];
+        let mut prover_messages = vec![Vec::new(); num_instances];
+
+        // --------------------------------------------------------------------
+        // First round
+
+        let first_round_time = start_timer!(|| "Proving and committing to first round polys");
+        let mut prover_first_oracles = Vec::with_capacity(num_instances);
+        let mut next_prover_states = Vec::with_capacity(num_instances);
+        for (k, prover_state) in prover_states.into_iter().enumerate() {
+            let committer_key = &keys_to_constraints[instances[k].0].0.committer_key;
+            let (prover_message, oracles, prover_state) = AHPForR1CS::prover_first_round(prover_state, zk_rng, hiding)?;
+            let (round_commitments, round_randomnesses) = PC::commit(committer_key, oracles.iter(), Some(zk_rng))?;
+
+            commitments[k].push(
+                round_commitments
+                    .iter()
+                    .map(|c| c.commitment().clone())
+                    .collect::<Vec<_>>(),
+            );
+            Self::absorb_prover_round(&mut fs_rng, &commitments[k][0], &prover_message);
+
+            labeled_commitments[k].extend(round_commitments);
+            commitment_randomnesses[k].extend(round_randomnesses);
+            prover_messages[k].push(prover_message);
+            prover_first_oracles.push(oracles);
+            next_prover_states.push(prover_state);
+        }
+        let prover_states = next_prover_states;
+        end_timer!(first_round_time);
+
+        let mut verifier_first_messages = Vec::with_capacity(keys_to_constraints.len());
+        let mut verifier_states = Vec::with_capacity(keys_to_constraints.len());
+        for (circuit_proving_key, _) in keys_to_constraints {
+            let (verifier_first_message, verifier_state) =
+                AHPForR1CS::verifier_first_round(circuit_proving_key.circuit_verifying_key.circuit_info, &mut fs_rng)?;
+            verifier_first_messages.push(verifier_first_message);
+            verifier_states.push(verifier_state);
+        }
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+
+        let second_round_time = start_timer!(|| "Proving and committing to second round polys");
+        let mut prover_second_oracles = Vec::with_capacity(num_instances);
+        let mut next_prover_states = Vec::with_capacity(num_instances);
+        for (k, prover_state) in prover_states.into_iter().enumerate() {
+            let j = instances[k].0;
+            let committer_key = &keys_to_constraints[j].0.committer_key;
+            let (prover_message, oracles, prover_state) =
+                AHPForR1CS::prover_second_round(&verifier_first_messages[j], prover_state, zk_rng, hiding);
+            let (round_commitments, round_randomnesses) = PC::commit(committer_key, oracles.iter(), Some(zk_rng))?;
+
+            commitments[k].push(
+                round_commitments
+                    .iter()
+                    .map(|c| c.commitment().clone())
+                    .collect::<Vec<_>>(),
+            );
+            Self::absorb_prover_round(&mut fs_rng, &commitments[k][1], &prover_message);
+
+            labeled_commitments[k].extend(round_commitments);
+            commitment_randomnesses[k].extend(round_randomnesses);
+            prover_messages[k].push(prover_message);
+            prover_second_oracles.push(oracles);
+            next_prover_states.push(prover_state);
+        }
+        let prover_states = next_prover_states;
+        end_timer!(second_round_time);
+
+        let mut verifier_second_messages = Vec::with_capacity(keys_to_constraints.len());
+        let mut next_verifier_states = Vec::with_capacity(keys_to_constraints.len());
+        for verifier_state in verifier_states {
+            let (verifier_second_message, verifier_state) =
+                AHPForR1CS::verifier_second_round(verifier_state, &mut fs_rng)?;
+            verifier_second_messages.push(verifier_second_message);
+            next_verifier_states.push(verifier_state);
+        }
+        let verifier_states = next_verifier_states;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+
+        let third_round_time = start_timer!(|| "Proving and committing to third round polys");
+        let mut prover_third_oracles = Vec::with_capacity(num_instances);
+        for (k, prover_state) in prover_states.into_iter().enumerate() {
+            let j = instances[k].0;
+            let committer_key = &keys_to_constraints[j].0.committer_key;
+            let (prover_message, oracles) =
+                AHPForR1CS::prover_third_round(&verifier_second_messages[j], prover_state, zk_rng)?;
+            let (round_commitments, round_randomnesses) = PC::commit(committer_key, oracles.iter(), Some(zk_rng))?;
+
+            commitments[k].push(
+                round_commitments
+                    .iter()
+                    .map(|c| c.commitment().clone())
+                    .collect::<Vec<_>>(),
+            );
+            Self::absorb_prover_round(&mut fs_rng, &commitments[k][2], &prover_message);
+
+            labeled_commitments[k].extend(round_commitments);
+            commitment_randomnesses[k].extend(round_randomnesses);
+            prover_messages[k].push(prover_message);
+            prover_third_oracles.push(oracles);
+        }
+        end_timer!(third_round_time);
+
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|verifier_state| AHPForR1CS::verifier_third_round(verifier_state, &mut fs_rng))
+            .collect::<Result<Vec<_>, _>>()?;
+        // --------------------------------------------------------------------
+
+        // Compute the AHP verifier's query set of each circuit, which is shared by its instances.
+        let mut query_sets = Vec::with_capacity(keys_to_constraints.len());
+        let mut next_verifier_states = Vec::with_capacity(keys_to_constraints.len());
+        for verifier_state in verifier_states {
+            let (query_set, verifier_state) = AHPForR1CS::verifier_query_set(verifier_state, &mut fs_rng, is_recursion);
+            query_sets.push(query_set);
+            next_verifier_states.push(verifier_state);
+        }
+        let verifier_states = next_verifier_states;
+
+        let vanishing_polynomials = keys_to_constraints
+            .iter()
+            .map(|(circuit_proving_key, _)| Self::vanishing_polynomials(&circuit_proving_key.circuit.index_info))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let eval_time = start_timer!(|| "Evaluating linear combinations over query sets");
+        let mut evaluations = Vec::with_capacity(num_instances);
+        let mut batch_polynomials = vec![Vec::new(); keys_to_constraints.len()];
+        let mut batch_linear_combinations = vec![Vec::new(); keys_to_constraints.len()];
+        let mut batch_query_sets = vec![QuerySet::new(); keys_to_constraints.len()];
+        for (k, public_input) in public_inputs.iter().enumerate() {
+            let j = instances[k].0;
+            let i = k - instance_ranges[j].start;
+
+            let instance_polynomials: Vec<_> = prover_first_oracles[k]
+                .iter()
+                .chain(prover_second_oracles[k].iter())
+                .chain(prover_third_oracles[k].iter())
+                .collect();
+            let polynomials: Vec<_> = keys_to_constraints[j]
+                .0
+                .circuit
+                .iter()
+                .chain(vanishing_polynomials[j].iter())
+                .chain(instance_polynomials.iter().copied())
+                .collect();
+
+            let lc_s = AHPForR1CS::construct_linear_combinations(
+                public_input,
+                &polynomials,
+                &verifier_states[j],
+                is_recursion,
+            )?;
+
+            let mut evaluations_unsorted = Vec::new();
+            for (label, point) in &query_sets[j] {
+                let lc = lc_s
+                    .iter()
+                    .find(|lc| &lc.label == label)
+                    .ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
+                let evaluation = polynomials.get_lc_eval(&lc, *point)?;
+                if !AHPForR1CS::<TargetField>::LC_WITH_ZERO_EVAL.contains(&lc.label.as_ref()) {
+                    evaluations_unsorted.push((label.to_string(), evaluation));
+                }
+                batch_query_sets[j].insert((Self::batch_label(label, i), *point));
+            }
+            evaluations_unsorted.sort_by(|a, b| a.0.cmp(&b.0));
+            evaluations.push(
+                evaluations_unsorted
+                    .into_iter()
+                    .map(|x| x.1)
+                    .collect::<Vec<TargetField>>(),
+            );
+
+            batch_polynomials[j].extend(instance_polynomials.into_iter().map(|p| {
+                LabeledPolynomial::new(
+                    Self::batch_label(p.label(), i),
+                    p.polynomial().clone(),
+                    p.degree_bound(),
+                    p.hiding_bound(),
+                )
+            }));
+            batch_linear_combinations[j].extend(lc_s.into_iter().map(|lc| Self::batch_linear_combination(lc, i)));
+        }
+        end_timer!(eval_time);
+
+        for instance_evaluations in &evaluations {
+            if is_recursion {
+                fs_rng.absorb_nonnative_field_elements(instance_evaluations, OptimizationType::Weight);
+            } else {
+                fs_rng.absorb_bytes(&to_bytes_le![instance_evaluations].unwrap());
+            }
+        }
+
+        let indexer_polynomials = if is_recursion {
+            AHPForR1CS::<TargetField>::INDEXER_POLYNOMIALS_WITH_VANISHING.to_vec()
         } else {
-            let opening_challenge: TargetField = fs_rng.squeeze_128_bits_nonnative_field_elements(1)?[0];
+            AHPForR1CS::<TargetField>::INDEXER_POLYNOMIALS.to_vec()
+        };
 
-            PC::check_combinations(
-                &circuit_verifying_key.verifier_key,
-                &lc_s,
-                &commitments,
-                &query_set,
-                &evaluations,
-                &proof.pc_proof,
-                opening_challenge,
-                &mut fs_rng,
-            )?
+        // Open the polynomials of the instances of each circuit together, with the committer key of the circuit.
+        // The indexed polynomials are shared by the instances of a circuit, and are only opened once.
+        let mut pc_proofs = Vec::with_capacity(keys_to_constraints.len());
+        for (j, (circuit_proving_key, _)) in keys_to_constraints.iter().enumerate() {
+            let polynomials: Vec<_> = circuit_proving_key
+                .circuit
+                .iter()
+                .chain(vanishing_polynomials[j].iter())
+                .chain(batch_polynomials[j].iter())
+                .collect();
+
+            let batch_commitments: Vec<_> = circuit_proving_key
+                .circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip(indexer_polynomials.iter())
+                .map(|(c, l)| LabeledCommitment::new(l.to_string(), c, None))
+                .chain(instance_ranges[j].clone().enumerate().flat_map(|(i, k)| {
+                    labeled_commitments[k].iter().map(move |c| {
+                        LabeledCommitment::new(
+                            Self::batch_label(c.label(), i),
+                            c.commitment().clone(),
+                            c.degree_bound(),
+                        )
+                    })
+                }))
+                .collect();
+
+            let batch_commitment_randomnesses: Vec<PC::Randomness> = circuit_proving_key
+                .circuit_commitment_randomness
+                .iter()
+                .chain(
+                    instance_ranges[j]
+                        .clone()
+                        .flat_map(|k| commitment_randomnesses[k].iter()),
+                )
+                .cloned()
+                .collect();
+
+            let pc_proof = if is_recursion {
+                let num_open_challenges: usize = 7 * instance_ranges[j].len();
+
+                let mut opening_challenges = Vec::new();
+                opening_challenges.append(&mut fs_rng.squeeze_128_bits_nonnative_field_elements(num_open_challenges)?);
+
+                let opening_challenges_f = |i| opening_challenges[i as usize];
+
+                PC::open_combinations_individual_opening_challenges(
+                    &circuit_proving_key.committer_key,
+                    &batch_linear_combinations[j],
+                    polynomials,
+                    &batch_commitments,
+                    &batch_query_sets[j],
+                    &opening_challenges_f,
+                    &batch_commitment_randomnesses,
+                )?
+            } else {
+                let opening_challenge: TargetField = fs_rng.squeeze_128_bits_nonnative_field_elements(1)?[0];
+
+                PC::open_combinations(
+                    &circuit_proving_key.committer_key,
+                    &batch_linear_combinations[j],
+                    polynomials,
+                    &batch_commitments,
+                    &batch_query_sets[j],
+                    opening_challenge,
+                    &batch_commitment_randomnesses,
+                    Some(zk_rng),
+                )?
+            };
+            pc_proofs.push(pc_proof);
+        }
+
+        let proof = BatchProof::new(commitments, evaluations, prover_messages, pc_proofs);
+        end_timer!(prover_time);
+
+        Ok(proof)
+    }
+
+    /// Verify that a batch proof asserts that all constraints are satisfied for each of the given public inputs
+    /// of each of the given circuits. The evaluation proofs of all circuits are checked at once.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch(
+        keys_to_inputs: &[(&CircuitVerifyingKey<TargetField, PC>, &[Vec<TargetField>])],
+        proof: &BatchProof<TargetField, PC>,
+    ) -> Result<bool, MarlinError> {
+        let num_instances: usize = keys_to_inputs
+            .iter()
+            .map(|(_, public_inputs)| public_inputs.len())
+            .sum();
+        let verifier_time = start_timer!(|| format!(
+            "Marlin::BatchVerify with {} circuits and {} instances",
+            keys_to_inputs.len(),
+            num_instances
+        ));
+
+        if keys_to_inputs.is_empty()
+            || keys_to_inputs.iter().any(|(_, public_inputs)| public_inputs.is_empty())
+            || proof.num_instances() != num_instances
+            || proof.evaluations.len() != num_instances
+            || proof.prover_messages.len() != num_instances
+            || proof.pc_proofs.len() != keys_to_inputs.len()
+            || proof.commitments.iter().any(|c| c.len() != 3)
+            || proof.prover_messages.iter().any(|m| m.len() != 3)
+        {
+            eprintln!("Batch proof does not match the number of public inputs");
+            return Ok(false);
+        }
+
+        let instance_ranges =
+            Self::instance_ranges(keys_to_inputs.iter().map(|(_, public_inputs)| public_inputs.len()));
+
+        let public_inputs: Vec<_> = keys_to_inputs
+            .iter()
+            .flat_map(|(_, public_inputs)| public_inputs.iter())
+            .map(|public_input| Self::pad_public_input(public_input))
+            .collect();
+
+        let is_recursion = MM::RECURSION;
+
+        let mut fs_rng = FS::new();
+        let padded_keys_to_inputs: Vec<_> = keys_to_inputs
+            .iter()
+            .zip(&instance_ranges)
+            .map(|((circuit_verifying_key, _), range)| (*circuit_verifying_key, &public_inputs[range.clone()]))
+            .collect();
+        Self::absorb_batch_public_inputs(&mut fs_rng, &padded_keys_to_inputs)?;
+
+        // --------------------------------------------------------------------
+        // First round
+
+        for (commitments, prover_messages) in proof.commitments.iter().zip(&proof.prover_messages) {
+            Self::absorb_prover_round(&mut fs_rng, &commitments[0], &prover_messages[0]);
+        }
+
+        let mut verifier_states = Vec::with_capacity(keys_to_inputs.len());
+        for (circuit_verifying_key, _) in keys_to_inputs {
+            let (_, verifier_state) =
+                AHPForR1CS::verifier_first_round(circuit_verifying_key.circuit_info, &mut fs_rng)?;
+            verifier_states.push(verifier_state);
+        }
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+
+        for (commitments, prover_messages) in proof.commitments.iter().zip(&proof.prover_messages) {
+            Self::absorb_prover_round(&mut fs_rng, &commitments[1], &prover_messages[1]);
+        }
+
+        let mut next_verifier_states = Vec::with_capacity(keys_to_inputs.len());
+        for verifier_state in verifier_states {
+            let (_, verifier_state) = AHPForR1CS::verifier_second_round(verifier_state, &mut fs_rng)?;
+            next_verifier_states.push(verifier_state);
+        }
+        let verifier_states = next_verifier_states;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+
+        for (commitments, prover_messages) in proof.commitments.iter().zip(&proof.prover_messages) {
+            Self::absorb_prover_round(&mut fs_rng, &commitments[2], &prover_messages[2]);
+        }
+
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|verifier_state| AHPForR1CS::verifier_third_round(verifier_state, &mut fs_rng))
+            .collect::<Result<Vec<_>, _>>()?;
+        // --------------------------------------------------------------------
+
+        let mut query_sets = Vec::with_capacity(keys_to_inputs.len());
+        let mut next_verifier_states = Vec::with_capacity(keys_to_inputs.len());
+        for verifier_state in verifier_states {
+            let (query_set, verifier_state) = AHPForR1CS::verifier_query_set(verifier_state, &mut fs_rng, is_recursion);
+            query_sets.push(query_set);
+            next_verifier_states.push(verifier_state);
+        }
+        let verifier_states = next_verifier_states;
+
+        for instance_evaluations in &proof.evaluations {
+            if is_recursion {
+                fs_rng.absorb_nonnative_field_elements(instance_evaluations, OptimizationType::Weight);
+            } else {
+                fs_rng.absorb_bytes(&to_bytes_le![instance_evaluations].unwrap());
+            }
+        }
+
+        let indexer_polynomials = if is_recursion {
+            AHPForR1CS::<TargetField>::INDEXER_POLYNOMIALS_WITH_VANISHING.to_vec()
+        } else {
+            AHPForR1CS::<TargetField>::INDEXER_POLYNOMIALS.to_vec()
         };
 
+        let mut pc_checks = Vec::with_capacity(keys_to_inputs.len());
+        for (j, (circuit_verifying_key, _)) in keys_to_inputs.iter().enumerate() {
+            // Collect degree bounds for the commitments of each instance. Indexed polynomials have *no*
+            // degree bounds because we know the committed index polynomial has the correct degree.
+            let index_info = circuit_verifying_key.circuit_info;
+            let degree_bounds: Vec<_> = AHPForR1CS::prover_first_round_degree_bounds(&index_info)
+                .chain(AHPForR1CS::prover_second_round_degree_bounds(&index_info))
+                .chain(AHPForR1CS::prover_third_round_degree_bounds(&index_info))
+                .collect();
+
+            // Gather the commitments of all instances of the circuit in one vector.
+            let mut commitments: Vec<_> = circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip(indexer_polynomials.iter())
+                .map(|(c, l)| LabeledCommitment::new(l.to_string(), c, None))
+                .collect();
+            for (i, k) in instance_ranges[j].clone().enumerate() {
+                commitments.extend(
+                    proof.commitments[k]
+                        .iter()
+                        .flatten()
+                        .cloned()
+                        .zip(&AHPForR1CS::<TargetField>::PROVER_POLYNOMIALS)
+                        .zip(&degree_bounds)
+                        .map(|((c, l), d)| LabeledCommitment::new(Self::batch_label(l, i), c, *d)),
+                );
+            }
+
+            let mut batch_linear_combinations = Vec::new();
+            let mut batch_query_set = QuerySet::new();
+            let mut batch_evaluations = Evaluations::new();
+            for (i, k) in instance_ranges[j].clone().enumerate() {
+                let mut evaluations = Evaluations::new();
+
+                let mut evaluation_labels = Vec::<(String, TargetField)>::new();
+
+                for q in query_sets[j].iter().cloned() {
+                    if AHPForR1CS::<TargetField>::LC_WITH_ZERO_EVAL.contains(&q.0.as_ref()) {
+                        evaluations.insert(q, TargetField::zero());
+                    } else {
+                        evaluation_labels.push(q);
+                    }
+                }
+                evaluation_labels.sort_by(|a, b| a.0.cmp(&b.0));
+                for (q, eval) in evaluation_labels.into_iter().zip(&proof.evaluations[k]) {
+                    evaluations.insert(q, *eval);
+                }
+
+                let lc_s = AHPForR1CS::construct_linear_combinations(
+                    &public_inputs[k],
+                    &evaluations,
+                    &verifier_states[j],
+                    is_recursion,
+                )?;
+
+                batch_query_set.extend(
+                    query_sets[j]
+                        .iter()
+                        .map(|(label, point)| (Self::batch_label(label, i), *point)),
+                );
+                batch_evaluations.extend(
+                    evaluations
+                        .into_iter()
+                        .map(|((label, point), eval)| ((Self::batch_label(&label, i), point), eval)),
+                );
+                batch_linear_combinations.extend(lc_s.into_iter().map(|lc| Self::batch_linear_combination(lc, i)));
+            }
+
+            let num_open_challenges: usize = if is_recursion { 7 * instance_ranges[j].len() } else { 1 };
+            let opening_challenges = fs_rng.squeeze_128_bits_nonnative_field_elements(num_open_challenges)?;
+
+            pc_checks.push(PCCheck {
+                linear_combinations: batch_linear_combinations,
+                commitments,
+                query_set: batch_query_set,
+                evaluations: batch_evaluations,
+                opening_challenges,
+            });
+        }
+
+        let opening_challenges_f: Vec<_> = pc_checks
+            .iter()
+            .map(|pc_check| move |i: u64| pc_check.opening_challenges[i as usize])
+            .collect();
+        let checks: Vec<_> = keys_to_inputs
+            .iter()
+            .zip(&pc_checks)
+            .zip(&proof.pc_proofs)
+            .zip(&opening_challenges_f)
+            .map(
+                |((((circuit_verifying_key, _), pc_check), pc_proof), opening_challenges_f)| {
+                    pc_check.combinations_check::<MM>(
+                        &circuit_verifying_key.verifier_key,
+                        pc_proof,
+                        opening_challenges_f,
+                    )
+                },
+            )
+            .collect();
+
+        let evaluations_are_correct = PC::batch_check_combinations(&checks, &mut fs_rng)?;
+
         if !evaluations_are_correct {
             eprintln!("PC::Check failed");
         }
         end_timer!(verifier_time, || format!(
-            " PC::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct
+            " PC::Check for AHP Verifier linear equations of {} instances: {}",
+            num_instances, evaluations_are_correct
         ));
         Ok(evaluations_are_correct)
     }
 
-    /// Verify that a proof for the constraint system defined by `C` asserts that
-    /// all constraints are satisfied using the prepared verifying key.
-    pub fn prepared_verify(
-        prepared_vk: &PreparedCircuitVerifyingKey<TargetField, PC>,
-        public_input: &[TargetField],
-        proof: &Proof<TargetField, PC>,
-    ) -> Result<bool, MarlinError> {
-        Self::verify(&prepared_vk.orig_vk, public_input, proof)
+    /// Pads the public input with zeros, to one less than the size of its evaluation domain.
+    fn pad_public_input(public_input: &[TargetField]) -> Vec<TargetField> {
+        let domain_x = EvaluationDomain::<TargetField>::new(public_input.len() + 1).unwrap();
+
+        if cfg!(debug_assertions) {
+            println!("Number of given public inputs: {}", public_input.len());
+            println!("Size of evaluation domain x: {}", domain_x.size());
+        }
+
+        let mut new_input = public_input.to_vec();
+        new_input.resize(
+            core::cmp::max(public_input.len(), domain_x.size() - 1),
+            TargetField::zero(),
+        );
+        new_input
+    }
+
+    /// Returns the vanishing polynomials over the domains of the circuit,
+    /// which are indexed alongside the circuit in recursive mode.
+    fn vanishing_polynomials(
+        circuit_info: &CircuitInfo<TargetField>,
+    ) -> Result<Vec<LabeledPolynomial<TargetField>>, MarlinError> {
+        if !MM::RECURSION {
+            return Ok(vec![]);
+        }
+
+        let domain_h =
+            EvaluationDomain::new(circuit_info.num_constraints).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_k =
+            EvaluationDomain::new(circuit_info.num_non_zero).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+
+        Ok(vec![
+            LabeledPolynomial::new(
+                "vanishing_poly_h".to_string(),
+                domain_h.vanishing_polynomial().into(),
+                None,
+                None,
+            ),
+            LabeledPolynomial::new(
+                "vanishing_poly_k".to_string(),
+                domain_k.vanishing_polynomial().into(),
+                None,
+                None,
+            ),
+        ])
+    }
+
+    /// Absorbs the circuit verifying keys and the public inputs of all instances of a batch
+    /// into the Fiat-Shamir RNG.
+    #[allow(clippy::type_complexity)]
+    fn absorb_batch_public_inputs(
+        fs_rng: &mut FS,
+        keys_to_inputs: &[(&CircuitVerifyingKey<TargetField, PC>, &[Vec<TargetField>])],
+    ) -> Result<(), MarlinError> {
+        fs_rng.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME, keys_to_inputs.len() as u64].unwrap());
+
+        for (circuit_verifying_key, public_inputs) in keys_to_inputs {
+            let num_instances = public_inputs.len() as u64;
+
+            if MM::RECURSION {
+                fs_rng.absorb_bytes(&to_bytes_le![num_instances].unwrap());
+                fs_rng.absorb_native_field_elements(&compute_vk_hash::<TargetField, BaseField, PC, FS>(
+                    circuit_verifying_key,
+                )?);
+                for public_input in public_inputs.iter() {
+                    fs_rng.absorb_nonnative_field_elements(public_input, OptimizationType::Weight);
+                }
+            } else {
+                fs_rng.absorb_bytes(&to_bytes_le![num_instances, circuit_verifying_key].unwrap());
+                for public_input in public_inputs.iter() {
+                    fs_rng.absorb_bytes(&to_bytes_le![public_input].unwrap());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the range of the indices of the instances of each circuit of a batch,
+    /// given the number of instances of each circuit.
+    fn instance_ranges(num_instances: impl Iterator<Item = usize>) -> Vec<Range<usize>> {
+        let mut start = 0;
+        num_instances
+            .map(|num_instances| {
+                let range = start..(start + num_instances);
+                start = range.end;
+                range
+            })
+            .collect()
+    }
+
+    /// Absorbs the commitments and the message of one prover round of an instance into the Fiat-Shamir RNG.
+    fn absorb_prover_round(
+        fs_rng: &mut FS,
+        commitments: &[PC::Commitment],
+        prover_message: &ProverMessage<TargetField>,
+    ) {
+        if MM::RECURSION {
+            fs_rng.absorb_native_field_elements(commitments);
+            if !prover_message.field_elements.is_empty() {
+                fs_rng.absorb_nonnative_field_elements(&prover_message.field_elements, OptimizationType::Weight);
+            }
+        } else {
+            fs_rng.absorb_bytes(&to_bytes_le![commitments, prover_message].unwrap());
+        }
+    }
+
+    /// Returns the label of a polynomial or linear combination of the instance `i` of a batch.
+    fn batch_label(label: &str, i: usize) -> String {
+        format!("{}_{}", label, i)
+    }
+
+    /// Relabels a linear combination of the instance `i` of a batch. The indexed polynomials
+    /// are shared by all instances, so only the terms for the prover polynomials are relabeled.
+    fn batch_linear_combination(lc: LinearCombination<TargetField>, i: usize) -> LinearCombination<TargetField> {
+        let terms = lc
+            .terms
+            .into_iter()
+            .map(|(coeff, term)| match term {
+                LCTerm::PolyLabel(label) if AHPForR1CS::<TargetField>::PROVER_POLYNOMIALS.contains(&label.as_str()) => {
+                    (coeff, LCTerm::PolyLabel(Self::batch_label(&label, i)))
+                }
+                term => (coeff, term),
+            })
+            .collect::<Vec<_>>();
+        LinearCombination::new(Self::batch_label(&lc.label, i), terms)
+    }
+}
+
+/// The check of the evaluation proof of a Marlin proof, which remains after the AHP verifier has run.
+struct PCCheck<F: PrimeField, PC: PolynomialCommitment<F>> {
+    /// The linear combinations of the committed polynomials checked by the AHP verifier.
+    linear_combinations: Vec<LinearCombination<F>>,
+    /// The commitments to the polynomials of the proof.
+    commitments: Vec<LabeledCommitment<PC::Commitment>>,
+    /// The points at which the linear combinations are queried.
+    query_set: QuerySet<'static, F>,
+    /// The claimed evaluations of the linear combinations.
+    evaluations: Evaluations<'static, F>,
+    /// The opening challenges, of which only the first is used outside of recursion mode.
+    opening_challenges: Vec<F>,
+}
+
+impl<F: PrimeField, PC: PolynomialCommitment<F>> PCCheck<F, PC> {
+    /// Returns the inputs to `PolynomialCommitment::batch_check_combinations` for this check.
+    fn combinations_check<'a, MM: MarlinMode>(
+        &'a self,
+        verifier_key: &'a PC::VerifierKey,
+        pc_proof: &'a BatchLCProof<F, PC>,
+        opening_challenges_f: &'a dyn Fn(u64) -> F,
+    ) -> CombinationsCheck<'a, F, PC> {
+        let opening_challenges = if MM::RECURSION {
+            OpeningChallenges::Individual(opening_challenges_f)
+        } else {
+            OpeningChallenges::Single(self.opening_challenges[0])
+        };
+
+        CombinationsCheck {
+            verifier_key,
+            linear_combinations: &self.linear_combinations,
+            commitments: &self.commitments,
+            query_set: &self.query_set,
+            evaluations: &self.evaluations,
+            proof: pc_proof,
+            opening_challenges,
+        }
     }
 }
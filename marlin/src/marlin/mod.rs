@@ -14,6 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+/// The Marlin zkSNARK batch proof.
+mod batch_proof;
+pub use batch_proof::*;
+
 /// The Marlin circuit proving key.
 mod circuit_proving_key;
 pub use circuit_proving_key::*;
@@ -14,8 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use snarkvm_curves::bls12_377::Fr;
 use snarkvm_fields::Field;
 use snarkvm_r1cs::errors::SynthesisError;
+use snarkvm_utilities::rand::UniformRand;
 
 use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem};
 
@@ -69,14 +71,48 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<Constrai
     }
 }
 
+/// Samples `batch_size` instances of the test circuit, along with their public inputs.
+#[allow(clippy::type_complexity)]
+fn batch_circuits<R: rand_core::RngCore>(
+    batch_size: usize,
+    num_constraints: usize,
+    num_variables: usize,
+    rng: &mut R,
+) -> (Vec<Circuit<Fr>>, Vec<Vec<Fr>>) {
+    let mut circuits = Vec::with_capacity(batch_size);
+    let mut public_inputs = Vec::with_capacity(batch_size);
+    for _ in 0..batch_size {
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let c = a * b;
+        let d = c * b;
+
+        circuits.push(Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints,
+            num_variables,
+        });
+        public_inputs.push(vec![c, d]);
+    }
+    (circuits, public_inputs)
+}
+
 mod marlin {
     use super::*;
     use crate::{
+        ahp::AHPForR1CS,
         fiat_shamir::FiatShamirChaChaRng,
-        marlin::{MarlinSNARK, MarlinTestnet1Mode},
+        marlin::{MarlinError, MarlinSNARK, MarlinTestnet1Mode},
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
-    use snarkvm_polycommit::{marlin_pc::MarlinKZG10, sonic_pc::SonicKZG10};
+    use snarkvm_fields::One;
+    use snarkvm_polycommit::{
+        kzg10::UniversalParams,
+        marlin_pc::MarlinKZG10,
+        powers_of_tau::PowersOfTau,
+        sonic_pc::SonicKZG10,
+    };
     use snarkvm_utilities::rand::{test_rng, UniformRand};
 
     use blake2::Blake2s;
@@ -124,6 +160,109 @@ mod marlin {
                         assert!(!$marlin_inst::verify(&index_vk, &[a, a], &proof).unwrap());
                     }
                 }
+
+                pub(crate) fn test_batch_circuit(num_constraints: usize, num_variables: usize, batch_size: usize) {
+                    let rng = &mut test_rng();
+
+                    let universal_srs = $marlin_inst::universal_setup(100, 25, 100, rng).unwrap();
+
+                    let (circuits, public_inputs) = batch_circuits(batch_size, num_constraints, num_variables, rng);
+
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circuits[0]).unwrap();
+                    println!("Called circuit setup");
+
+                    let proof = $marlin_inst::prove_batch(&[(&index_pk, &circuits[..])], rng).unwrap();
+                    println!("Called batch prover");
+
+                    assert!($marlin_inst::verify_batch(&[(&index_vk, &public_inputs[..])], &proof).unwrap());
+                    println!("Called batch verifier");
+                    println!("\nShould not verify (i.e. verifier messages should print below):");
+                    let mut invalid_inputs = public_inputs.clone();
+                    invalid_inputs[batch_size - 1] = vec![Fr::one(), Fr::one()];
+                    assert!(!$marlin_inst::verify_batch(&[(&index_vk, &invalid_inputs[..])], &proof).unwrap());
+                    assert!(!$marlin_inst::verify_batch(&[(&index_vk, &public_inputs[1..])], &proof).unwrap());
+                }
+
+                pub(crate) fn test_batch_of_circuits() {
+                    let rng = &mut test_rng();
+
+                    let universal_srs = $marlin_inst::universal_setup(100, 25, 100, rng).unwrap();
+
+                    let (small_circuits, small_inputs) = batch_circuits(2, 25, 25, rng);
+                    let (large_circuits, large_inputs) = batch_circuits(3, 100, 25, rng);
+
+                    let (small_pk, small_vk) = $marlin_inst::circuit_setup(&universal_srs, &small_circuits[0]).unwrap();
+                    let (large_pk, large_vk) = $marlin_inst::circuit_setup(&universal_srs, &large_circuits[0]).unwrap();
+
+                    let proof = $marlin_inst::prove_batch(
+                        &[(&small_pk, &small_circuits[..]), (&large_pk, &large_circuits[..])],
+                        rng,
+                    )
+                    .unwrap();
+                    assert_eq!(proof.num_instances(), 5);
+                    assert_eq!(proof.pc_proofs.len(), 2);
+
+                    assert!(
+                        $marlin_inst::verify_batch(
+                            &[(&small_vk, &small_inputs[..]), (&large_vk, &large_inputs[..])],
+                            &proof
+                        )
+                        .unwrap()
+                    );
+                    println!("\nShould not verify (i.e. verifier messages should print below):");
+                    assert!(
+                        !$marlin_inst::verify_batch(
+                            &[(&large_vk, &large_inputs[..]), (&small_vk, &small_inputs[..])],
+                            &proof
+                        )
+                        .unwrap()
+                    );
+                    let mut invalid_inputs = large_inputs.clone();
+                    invalid_inputs[0] = vec![Fr::one(), Fr::one()];
+                    assert!(
+                        !$marlin_inst::verify_batch(
+                            &[(&small_vk, &small_inputs[..]), (&large_vk, &invalid_inputs[..])],
+                            &proof
+                        )
+                        .unwrap()
+                    );
+                }
+
+                pub(crate) fn test_verify_proofs() {
+                    let rng = &mut test_rng();
+
+                    let universal_srs = $marlin_inst::universal_setup(100, 25, 100, rng).unwrap();
+
+                    let (small_circuits, small_inputs) = batch_circuits(2, 25, 25, rng);
+                    let (large_circuits, large_inputs) = batch_circuits(1, 100, 25, rng);
+
+                    let (small_pk, small_vk) = $marlin_inst::circuit_setup(&universal_srs, &small_circuits[0]).unwrap();
+                    let (large_pk, large_vk) = $marlin_inst::circuit_setup(&universal_srs, &large_circuits[0]).unwrap();
+
+                    let small_proofs: Vec<_> = small_circuits
+                        .iter()
+                        .map(|circuit| $marlin_inst::prove(&small_pk, circuit, rng).unwrap())
+                        .collect();
+                    let large_proof = $marlin_inst::prove(&large_pk, &large_circuits[0], rng).unwrap();
+
+                    assert!(
+                        $marlin_inst::verify_proofs(&[
+                            (&small_vk, &small_inputs[0], &small_proofs[0]),
+                            (&large_vk, &large_inputs[0], &large_proof),
+                            (&small_vk, &small_inputs[1], &small_proofs[1]),
+                        ])
+                        .unwrap()
+                    );
+                    println!("\nShould not verify (i.e. verifier messages should print below):");
+                    assert!(
+                        !$marlin_inst::verify_proofs(&[
+                            (&small_vk, &small_inputs[0], &small_proofs[0]),
+                            (&large_vk, &large_inputs[0], &large_proof),
+                            (&small_vk, &small_inputs[0], &small_proofs[1]),
+                        ])
+                        .unwrap()
+                    );
+                }
             }
         };
     }
@@ -175,6 +314,69 @@ mod marlin {
         MarlinPCTest::test_circuit(num_constraints, num_variables);
         SonicPCTest::test_circuit(num_constraints, num_variables);
     }
+
+    #[test]
+    fn prove_and_verify_batch() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        for batch_size in &[1, 2, 4] {
+            MarlinPCTest::test_batch_circuit(num_constraints, num_variables, *batch_size);
+            SonicPCTest::test_batch_circuit(num_constraints, num_variables, *batch_size);
+        }
+    }
+
+    #[test]
+    fn prove_and_verify_batch_of_circuits() {
+        MarlinPCTest::test_batch_of_circuits();
+        SonicPCTest::test_batch_of_circuits();
+    }
+
+    #[test]
+    fn verify_proofs() {
+        MarlinPCTest::test_verify_proofs();
+        SonicPCTest::test_verify_proofs();
+    }
+
+    #[test]
+    fn prove_and_verify_with_powers_of_tau_srs() {
+        let rng = &mut test_rng();
+
+        let max_degree = AHPForR1CS::<Fr>::max_degree(100, 25, 100).unwrap();
+        let mut powers_of_tau = PowersOfTau::<Bls12_377>::new(max_degree).unwrap();
+        powers_of_tau.contribute(rng).unwrap();
+        powers_of_tau.contribute(rng).unwrap();
+        powers_of_tau.verify(rng).unwrap();
+        let universal_srs = UniversalParams::from(&powers_of_tau);
+
+        let (circuits, public_inputs) = batch_circuits(1, 25, 25, rng);
+
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuits[0]).unwrap();
+        let proof = MarlinInst::prove(&index_pk, &circuits[0], rng).unwrap();
+        assert!(MarlinInst::verify(&index_vk, &public_inputs[0], &proof).unwrap());
+
+        let (index_pk, index_vk) = MarlinSonicInst::circuit_setup(&universal_srs, &circuits[0]).unwrap();
+        let proof = MarlinSonicInst::prove(&index_pk, &circuits[0], rng).unwrap();
+        assert!(MarlinSonicInst::verify(&index_vk, &public_inputs[0], &proof).unwrap());
+    }
+
+    #[test]
+    fn prove_batch_rejects_empty_batch() {
+        let rng = &mut test_rng();
+
+        let universal_srs = MarlinInst::universal_setup(100, 25, 100, rng).unwrap();
+        let (circuits, _) = batch_circuits(1, 25, 25, rng);
+        let (index_pk, _) = MarlinInst::circuit_setup(&universal_srs, &circuits[0]).unwrap();
+
+        assert!(matches!(
+            MarlinInst::prove_batch::<Circuit<Fr>, _>(&[], rng),
+            Err(MarlinError::EmptyBatch)
+        ));
+        assert!(matches!(
+            MarlinInst::prove_batch::<Circuit<Fr>, _>(&[(&index_pk, &[])], rng),
+            Err(MarlinError::EmptyBatch)
+        ));
+    }
 }
 
 mod marlin_recursion {
@@ -184,6 +386,7 @@ mod marlin_recursion {
         marlin::{MarlinRecursiveMode, MarlinSNARK},
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+    use snarkvm_fields::One;
     use snarkvm_polycommit::marlin_pc::MarlinKZG10;
     use snarkvm_utilities::rand::{test_rng, UniformRand};
 
@@ -226,6 +429,71 @@ mod marlin_recursion {
         }
     }
 
+    fn test_batch_circuit(num_constraints: usize, num_variables: usize, batch_size: usize) {
+        let rng = &mut test_rng();
+
+        let universal_srs = MarlinInst::universal_setup(100, 25, 100, rng).unwrap();
+
+        let (circuits, public_inputs) = batch_circuits(batch_size, num_constraints, num_variables, rng);
+
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuits[0]).unwrap();
+        println!("Called circuit setup");
+
+        let proof = MarlinInst::prove_batch(&[(&index_pk, &circuits[..])], rng).unwrap();
+        println!("Called batch prover");
+
+        assert!(MarlinInst::verify_batch(&[(&index_vk, &public_inputs[..])], &proof).unwrap());
+        println!("Called batch verifier");
+        println!("\nShould not verify (i.e. verifier messages should print below):");
+        let mut invalid_inputs = public_inputs.clone();
+        invalid_inputs[0] = vec![Fr::one(), Fr::one()];
+        assert!(!MarlinInst::verify_batch(&[(&index_vk, &invalid_inputs[..])], &proof).unwrap());
+    }
+
+    #[test]
+    fn prove_and_verify_batch_of_circuits() {
+        let rng = &mut test_rng();
+
+        let universal_srs = MarlinInst::universal_setup(100, 25, 100, rng).unwrap();
+
+        let (small_circuits, small_inputs) = batch_circuits(2, 25, 25, rng);
+        let (large_circuits, large_inputs) = batch_circuits(1, 100, 25, rng);
+
+        let (small_pk, small_vk) = MarlinInst::circuit_setup(&universal_srs, &small_circuits[0]).unwrap();
+        let (large_pk, large_vk) = MarlinInst::circuit_setup(&universal_srs, &large_circuits[0]).unwrap();
+
+        let proof = MarlinInst::prove_batch(
+            &[(&small_pk, &small_circuits[..]), (&large_pk, &large_circuits[..])],
+            rng,
+        )
+        .unwrap();
+        assert!(
+            MarlinInst::verify_batch(
+                &[(&small_vk, &small_inputs[..]), (&large_vk, &large_inputs[..])],
+                &proof
+            )
+            .unwrap()
+        );
+
+        let small_proof = MarlinInst::prove(&small_pk, &small_circuits[0], rng).unwrap();
+        let large_proof = MarlinInst::prove(&large_pk, &large_circuits[0], rng).unwrap();
+        assert!(
+            MarlinInst::verify_proofs(&[
+                (&small_vk, &small_inputs[0], &small_proof),
+                (&large_vk, &large_inputs[0], &large_proof),
+            ])
+            .unwrap()
+        );
+        println!("\nShould not verify (i.e. verifier messages should print below):");
+        assert!(
+            !MarlinInst::verify_proofs(&[
+                (&small_vk, &small_inputs[1], &small_proof),
+                (&large_vk, &large_inputs[0], &large_proof),
+            ])
+            .unwrap()
+        );
+    }
+
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
         let num_constraints = 100;
@@ -266,6 +534,16 @@ mod marlin_recursion {
         test_circuit(num_constraints, num_variables);
     }
 
+    #[test]
+    fn prove_and_verify_batch() {
+        let num_constraints = 25;
+        let num_variables = 25;
+
+        for batch_size in &[1, 2, 4] {
+            test_batch_circuit(num_constraints, num_variables, *batch_size);
+        }
+    }
+
     // #[test]
     // /// Test on a constraint system that will trigger outlining.
     // fn prove_and_test_outlining() {
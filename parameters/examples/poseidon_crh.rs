@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::{
+    crh::PoseidonParameters,
+    errors::CRHError,
+    traits::CRHParameters,
+};
+use snarkvm_curves::{bls12_377, bw6_761};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::ToBytes;
+
+use rand::thread_rng;
+use std::{env, path::PathBuf};
+
+mod utils;
+use utils::store;
+
+/// Generates the parameters of a Poseidon CRH over the scalar field `F`.
+pub fn setup<F: PrimeField>() -> Result<Vec<u8>, CRHError> {
+    let rng = &mut thread_rng();
+    let poseidon_crh_parameters = PoseidonParameters::<F>::setup(rng);
+    let poseidon_crh_parameters_bytes = poseidon_crh_parameters.to_bytes_le()?;
+
+    println!(
+        "poseidon_crh.params\n\tsize - {}\n\talpha - {}\n\tpartial rounds - {}",
+        poseidon_crh_parameters_bytes.len(),
+        poseidon_crh_parameters.alpha,
+        poseidon_crh_parameters.partial_rounds
+    );
+    Ok(poseidon_crh_parameters_bytes)
+}
+
+pub fn main() {
+    let field = env::args().nth(1).unwrap_or_else(|| "bls12_377".to_string());
+    let bytes = match field.as_str() {
+        "bls12_377" => setup::<bls12_377::Fr>(),
+        "bw6_761" => setup::<bw6_761::Fr>(),
+        _ => panic!("unsupported scalar field {}, expected one of bls12_377 or bw6_761", field),
+    }
+    .unwrap();
+
+    store(
+        &PathBuf::from(format!("poseidon_crh_{}.params", field)),
+        &PathBuf::from(format!("poseidon_crh_{}.checksum", field)),
+        &bytes,
+    )
+    .unwrap();
+}
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::{errors::CRHError, traits::CRH};
+use snarkvm_dpc::{
+    testnet2::instantiated::{
+        benchmark::{Components1x1, Components4x4, Components8x8},
+        Components2x2,
+    },
+    traits::DPCComponents,
+};
+use snarkvm_utilities::ToBytes;
+
+use rand::thread_rng;
+use std::{env, path::PathBuf};
+
+mod utils;
+use utils::store;
+
+/// Generates the parameters of the CRHs whose window sizes depend on the transaction arity,
+/// namely the serial number nonce CRH and the inner circuit ID CRH.
+pub fn setup<C: DPCComponents>() -> Result<(Vec<u8>, Vec<u8>), CRHError> {
+    let rng = &mut thread_rng();
+    let serial_number_nonce_crh = <C::SerialNumberNonceCRH as CRH>::setup(rng);
+    let serial_number_nonce_crh_parameters_bytes = serial_number_nonce_crh.parameters().to_bytes_le()?;
+    let inner_circuit_id_crh = <C::InnerCircuitIDCRH as CRH>::setup(rng);
+    let inner_circuit_id_crh_parameters_bytes = inner_circuit_id_crh.parameters().to_bytes_le()?;
+
+    println!(
+        "serial_number_nonce_crh.params\n\tsize - {}",
+        serial_number_nonce_crh_parameters_bytes.len()
+    );
+    println!(
+        "inner_circuit_id_crh.params\n\tsize - {}",
+        inner_circuit_id_crh_parameters_bytes.len()
+    );
+    Ok((serial_number_nonce_crh_parameters_bytes, inner_circuit_id_crh_parameters_bytes))
+}
+
+pub fn main() {
+    let arity = env::args().nth(1).unwrap_or_else(|| "2x2".to_string());
+    let (serial_number_nonce_crh, inner_circuit_id_crh) = match arity.as_str() {
+        "1x1" => setup::<Components1x1>(),
+        "2x2" => setup::<Components2x2>(),
+        "4x4" => setup::<Components4x4>(),
+        "8x8" => setup::<Components8x8>(),
+        _ => panic!("unsupported transaction arity {}, expected one of 1x1, 2x2, 4x4 or 8x8", arity),
+    }
+    .unwrap();
+
+    store(
+        &PathBuf::from(format!("serial_number_nonce_crh_{}.params", arity)),
+        &PathBuf::from(format!("serial_number_nonce_crh_{}.checksum", arity)),
+        &serial_number_nonce_crh,
+    )
+    .unwrap();
+    store(
+        &PathBuf::from(format!("inner_circuit_id_crh_{}.params", arity)),
+        &PathBuf::from(format!("inner_circuit_id_crh_{}.checksum", arity)),
+        &inner_circuit_id_crh,
+    )
+    .unwrap();
+}
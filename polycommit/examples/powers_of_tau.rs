@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runs a powers-of-tau ceremony for BLS12-377 from the command line, passing the transcript
+//! between participants as a file.
+//!
+//! ```text
+//! powers_of_tau new <max_degree> <transcript>
+//! powers_of_tau contribute <transcript> <new_transcript>
+//! powers_of_tau verify <transcript> [<previous_transcript>]
+//! powers_of_tau export <transcript> <universal_srs>
+//! ```
+
+use snarkvm_curves::bls12_377::Bls12_377;
+use snarkvm_polycommit::{kzg10::UniversalParams, powers_of_tau::PowersOfTau};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use rand::thread_rng;
+use std::{
+    env,
+    fs::File,
+    io::{BufReader, BufWriter},
+    process,
+};
+
+type Transcript = PowersOfTau<Bls12_377>;
+
+fn read_transcript(path: &str) -> Transcript {
+    let file = File::open(path).unwrap_or_else(|error| exit(&format!("could not open {}: {}", path, error)));
+    Transcript::read_le(BufReader::new(file))
+        .unwrap_or_else(|error| exit(&format!("could not read {}: {}", path, error)))
+}
+
+fn write<T: ToBytes>(value: &T, path: &str) {
+    let file = File::create(path).unwrap_or_else(|error| exit(&format!("could not create {}: {}", path, error)));
+    value
+        .write_le(BufWriter::new(file))
+        .unwrap_or_else(|error| exit(&format!("could not write {}: {}", path, error)));
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+pub fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let rng = &mut thread_rng();
+
+    match args.as_slice() {
+        ["new", max_degree, transcript] => {
+            let max_degree = max_degree
+                .parse()
+                .unwrap_or_else(|_| exit(&format!("invalid maximum degree {}", max_degree)));
+            let powers_of_tau = Transcript::new(max_degree).unwrap_or_else(|error| exit(&error.to_string()));
+            write(&powers_of_tau, transcript);
+        }
+        ["contribute", transcript, new_transcript] => {
+            let mut powers_of_tau = read_transcript(transcript);
+            powers_of_tau.contribute(rng).unwrap_or_else(|error| exit(&error.to_string()));
+            write(&powers_of_tau, new_transcript);
+            println!("contribution {} written to {}", powers_of_tau.contributions.len(), new_transcript);
+        }
+        ["verify", transcript] => {
+            let powers_of_tau = read_transcript(transcript);
+            powers_of_tau.verify(rng).unwrap_or_else(|error| exit(&error.to_string()));
+            println!("{} contributions verified", powers_of_tau.contributions.len());
+        }
+        ["verify", transcript, previous_transcript] => {
+            let powers_of_tau = read_transcript(transcript);
+            let previous = read_transcript(previous_transcript);
+            powers_of_tau
+                .verify_extends(&previous, rng)
+                .unwrap_or_else(|error| exit(&error.to_string()));
+            println!("{} contributions verified", powers_of_tau.contributions.len());
+        }
+        ["export", transcript, universal_srs] => {
+            let powers_of_tau = read_transcript(transcript);
+            powers_of_tau.verify(rng).unwrap_or_else(|error| exit(&error.to_string()));
+            write(&UniversalParams::from(&powers_of_tau), universal_srs);
+        }
+        _ => exit(
+            "usage:\n  powers_of_tau new <max_degree> <transcript>\n  powers_of_tau contribute <transcript> <new_transcript>\n  \
+             powers_of_tau verify <transcript> [<previous_transcript>]\n  powers_of_tau export <transcript> <universal_srs>",
+        ),
+    }
+}
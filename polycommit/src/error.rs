@@ -99,6 +99,9 @@ pub enum Error {
     /// The commitment was generated incorrectly, tampered with, or doesn't support the polynomial.
     MalformedCommitment(String),
 
+    /// The verifier keys passed to `batch_check_combinations` were not trimmed from the same parameters.
+    IncompatibleVerifierKeys,
+
     Terminated,
 }
 
@@ -158,6 +161,7 @@ impl core::fmt::Display for Error {
             ),
             Error::IncorrectInputLength(err) => write!(f, "{}", err),
             Error::MalformedCommitment(err) => write!(f, "{}", err),
+            Error::IncompatibleVerifierKeys => write!(f, "the verifier keys do not share the same parameters"),
             Error::Terminated => write!(f, "terminated"),
         }
     }
@@ -88,6 +88,13 @@ macro_rules! eprintln {
 /// [kzg]: http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf
 pub mod kzg10;
 
+/// A multi-party ceremony for the universal parameters of [[KZG10]][kzg],
+/// following the powers-of-tau protocol of [[BGM17]][bgm17].
+///
+/// [kzg]: http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf
+/// [bgm17]: https://eprint.iacr.org/2017/1050
+pub mod powers_of_tau;
+
 /// Polynomial commitment scheme from [[KZG10]][kzg] that enforces
 /// strict degree bounds and (optionally) enables hiding commitments by
 /// following the approach outlined in [[CHMMVW20, "Marlin"]][marlin].
@@ -129,6 +136,32 @@ pub struct BatchLCProof<F: Field, PC: PolynomialCommitment<F>> {
     pub evaluations: Option<Vec<F>>,
 }
 
+/// The challenges used to combine the polynomials opened at each point of a query set.
+pub enum OpeningChallenges<'a, F: Field> {
+    /// A single challenge, whose powers combine the polynomials, as in `check_combinations`.
+    Single(F),
+    /// One challenge for each polynomial, as in `check_combinations_individual_opening_challenges`.
+    Individual(&'a dyn Fn(u64) -> F),
+}
+
+/// The inputs to one check of linear combinations, for `PolynomialCommitment::batch_check_combinations`.
+pub struct CombinationsCheck<'a, F: Field, PC: PolynomialCommitment<F>> {
+    /// The verifier key of the commitments.
+    pub verifier_key: &'a PC::VerifierKey,
+    /// The linear combinations of the committed polynomials.
+    pub linear_combinations: &'a [LinearCombination<F>],
+    /// The commitments to the polynomials.
+    pub commitments: &'a [LabeledCommitment<PC::Commitment>],
+    /// The points at which the linear combinations are queried.
+    pub query_set: &'a QuerySet<'a, F>,
+    /// The claimed evaluations of the linear combinations.
+    pub evaluations: &'a Evaluations<'a, F>,
+    /// The proof of the evaluations.
+    pub proof: &'a BatchLCProof<F, PC>,
+    /// The challenges the proof was opened with.
+    pub opening_challenges: OpeningChallenges<'a, F>,
+}
+
 impl<F: Field, PC: PolynomialCommitment<F>> FromBytes for BatchLCProof<F, PC> {
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
         CanonicalDeserialize::deserialize(&mut reader).map_err(|_| error_fn("could not deserialize struct"))
@@ -501,6 +534,44 @@ pub trait PolynomialCommitment<F: Field>: Sized + Clone + Debug {
     ) -> Result<bool, Error>
     where
         Self::Commitment: 'a;
+
+    /// Checks each of `checks`, which may use different verifier keys of the same scheme.
+    /// Returns `true` only if every check succeeds.
+    fn batch_check_combinations<R: RngCore>(
+        checks: &[CombinationsCheck<'_, F, Self>],
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        for check in checks {
+            let is_correct = match check.opening_challenges {
+                OpeningChallenges::Single(opening_challenge) => Self::check_combinations(
+                    check.verifier_key,
+                    check.linear_combinations,
+                    check.commitments,
+                    check.query_set,
+                    check.evaluations,
+                    check.proof,
+                    opening_challenge,
+                    rng,
+                )?,
+                OpeningChallenges::Individual(opening_challenges) => {
+                    Self::check_combinations_individual_opening_challenges(
+                        check.verifier_key,
+                        check.linear_combinations,
+                        check.commitments,
+                        check.query_set,
+                        check.evaluations,
+                        check.proof,
+                        opening_challenges,
+                        rng,
+                    )?
+                }
+            };
+            if !is_correct {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
 
 /// Evaluate the given polynomials at `query_set`.
@@ -19,11 +19,13 @@ use crate::{
     BTreeMap,
     BTreeSet,
     BatchLCProof,
+    CombinationsCheck,
     Error,
     Evaluations,
     LabeledCommitment,
     LabeledPolynomial,
     LinearCombination,
+    OpeningChallenges,
     PCCommitterKey,
     PCRandomness,
     PCUniversalParams,
@@ -31,7 +33,6 @@ use crate::{
     PolynomialCommitment,
     QuerySet,
     String,
-    ToOwned,
     ToString,
     Vec,
 };
@@ -379,50 +380,14 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr> for MarlinKZG10<E> {
     where
         Self::Commitment: 'a,
     {
-        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
-        let mut query_to_labels_map = BTreeMap::new();
-
-        for (label, point) in query_set.iter() {
-            let labels = query_to_labels_map.entry(point).or_insert_with(BTreeSet::new);
-            labels.insert(label);
-        }
-        assert_eq!(proof.len(), query_to_labels_map.len());
-
-        let mut combined_comms = Vec::with_capacity(query_to_labels_map.len());
-        let mut combined_queries = Vec::with_capacity(query_to_labels_map.len());
-        let mut combined_evals = Vec::with_capacity(query_to_labels_map.len());
-        for (query, labels) in query_to_labels_map.into_iter() {
-            let lc_time = start_timer!(|| format!("Randomly combining {} commitments", labels.len()));
-            let mut comms_to_combine = Vec::with_capacity(labels.len());
-            let mut values_to_combine = Vec::with_capacity(labels.len());
-            for label in labels.into_iter() {
-                let commitment = commitments.get(label).ok_or(Error::MissingPolynomial {
-                    label: label.to_string(),
-                })?;
-                let degree_bound = commitment.degree_bound();
-                assert_eq!(degree_bound.is_some(), commitment.commitment().shifted_comm.is_some());
-
-                let v_i = values.get(&(label.clone(), *query)).ok_or(Error::MissingEvaluation {
-                    label: label.to_string(),
-                })?;
-
-                comms_to_combine.push(*commitment);
-                values_to_combine.push(*v_i);
-            }
-            let (c, v) =
-                Self::accumulate_commitments_and_values(vk, comms_to_combine, values_to_combine, opening_challenge)?;
-            end_timer!(lc_time);
-            combined_comms.push(c);
-            combined_queries.push(*query);
-            combined_evals.push(v);
-        }
-        let norm_time = start_timer!(|| "Normalizaing combined commitments");
-        E::G1Projective::batch_normalization(&mut combined_comms);
-        let combined_comms: Vec<_> = combined_comms
-            .into_iter()
-            .map(|c| kzg10::Commitment(c.into()))
-            .collect();
-        end_timer!(norm_time);
+        let (combined_comms, combined_queries, combined_evals) = Self::combine_and_normalize(
+            commitments,
+            query_set,
+            values,
+            &OpeningChallenges::Single(opening_challenge),
+            vk,
+        )?;
+        assert_eq!(proof.len(), combined_queries.len());
         let proof_time = start_timer!(|| "Checking KZG10::Proof");
         let result =
             kzg10::KZG10::batch_check(&vk.vk, &combined_comms, &combined_queries, &combined_evals, &proof, rng)?;
@@ -539,59 +504,7 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr> for MarlinKZG10<E> {
         Self::Commitment: 'a,
     {
         let BatchLCProof { proof, .. } = proof;
-        let label_comm_map = commitments
-            .into_iter()
-            .map(|c| (c.label().to_owned(), c))
-            .collect::<BTreeMap<_, _>>();
-
-        let mut lc_commitments = Vec::new();
-        let mut lc_info = Vec::new();
-        let mut evaluations = evaluations.clone();
-
-        let lc_processing_time = start_timer!(|| "Combining commitments");
-        for lc in lc_s {
-            let lc_label = lc.label().clone();
-            let num_polys = lc.len();
-
-            let mut degree_bound = None;
-            let mut coeffs_and_comms = Vec::new();
-
-            for (coeff, label) in lc.iter() {
-                if label.is_one() {
-                    for (&(ref label, _), ref mut eval) in evaluations.iter_mut() {
-                        if label == &lc_label {
-                            **eval -= coeff;
-                        }
-                    }
-                } else {
-                    let label: String = label.to_owned().try_into().unwrap();
-                    let cur_comm = label_comm_map.get(&label).ok_or(Error::MissingPolynomial {
-                        label: label.to_string(),
-                    })?;
-
-                    if num_polys == 1 && cur_comm.degree_bound().is_some() {
-                        assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
-                        degree_bound = cur_comm.degree_bound();
-                    } else if cur_comm.degree_bound().is_some() {
-                        return Err(Error::EquationHasDegreeBounds(lc_label));
-                    }
-                    coeffs_and_comms.push((*coeff, cur_comm.commitment()));
-                }
-            }
-            let lc_time = start_timer!(|| format!("Combining {} commitments for {}", num_polys, lc_label));
-            lc_commitments.push(Self::combine_commitments(coeffs_and_comms));
-            end_timer!(lc_time);
-            lc_info.push((lc_label, degree_bound));
-        }
-        end_timer!(lc_processing_time);
-        let combined_comms_norm_time = start_timer!(|| "Normalizing commitments");
-        let comms = Self::normalize_commitments(lc_commitments);
-        let lc_commitments: Vec<_> = lc_info
-            .into_iter()
-            .zip(comms)
-            .map(|((label, d), c)| LabeledCommitment::new(label, c, d))
-            .collect();
-        end_timer!(combined_comms_norm_time);
+        let (lc_commitments, evaluations) = Self::combine_linear_combinations(lc_s, commitments, evaluations)?;
 
         Self::batch_check(
             vk,
@@ -704,59 +617,8 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr> for MarlinKZG10<E> {
         Self::Commitment: 'a,
     {
         let BatchLCProof { proof, .. } = proof;
-        let label_comm_map = commitments
-            .into_iter()
-            .map(|c| (c.label(), c))
-            .collect::<BTreeMap<_, _>>();
-
-        let mut lc_commitments = Vec::new();
-        let mut lc_info = Vec::new();
-        let mut evaluations = evaluations.clone();
-
-        let lc_processing_time = start_timer!(|| "Combining commitments");
-        for lc in linear_combinations {
-            let lc_label = lc.label().clone();
-            let num_polys = lc.len();
-
-            let mut degree_bound = None;
-            let mut coeffs_and_comms = Vec::new();
-
-            for (coeff, label) in lc.iter() {
-                if label.is_one() {
-                    for (&(ref label, _), ref mut eval) in evaluations.iter_mut() {
-                        if label == &lc_label {
-                            **eval -= coeff;
-                        }
-                    }
-                } else {
-                    let label: &String = label.try_into().unwrap();
-                    let &cur_comm = label_comm_map.get(label).ok_or(Error::MissingPolynomial {
-                        label: label.to_string(),
-                    })?;
-
-                    if num_polys == 1 && cur_comm.degree_bound().is_some() {
-                        assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
-                        degree_bound = cur_comm.degree_bound();
-                    } else if cur_comm.degree_bound().is_some() {
-                        return Err(Error::EquationHasDegreeBounds(lc_label));
-                    }
-                    coeffs_and_comms.push((*coeff, cur_comm.commitment()));
-                }
-            }
-            let lc_time = start_timer!(|| format!("Combining {} commitments for {}", num_polys, lc_label));
-            lc_commitments.push(Self::combine_commitments(coeffs_and_comms));
-            end_timer!(lc_time);
-            lc_info.push((lc_label, degree_bound));
-        }
-        end_timer!(lc_processing_time);
-        let combined_comms_norm_time = start_timer!(|| "Normalizing commitments");
-        let comms = Self::normalize_commitments(lc_commitments);
-        let lc_commitments = lc_info
-            .into_iter()
-            .zip(comms)
-            .map(|((label, d), c)| LabeledCommitment::new(label, c, d))
-            .collect::<Vec<_>>();
-        end_timer!(combined_comms_norm_time);
+        let (lc_commitments, evaluations) =
+            Self::combine_linear_combinations(linear_combinations, commitments, evaluations)?;
 
         Self::batch_check_individual_opening_challenges(
             vk,
@@ -768,6 +630,55 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr> for MarlinKZG10<E> {
             rng,
         )
     }
+
+    /// Folds the KZG10 checks of all of `checks` into a single pairing check.
+    /// The verifier keys must be trimmed from the same universal parameters.
+    fn batch_check_combinations<R: RngCore>(
+        checks: &[CombinationsCheck<'_, E::Fr, Self>],
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        let vk = match checks.first() {
+            Some(check) => &check.verifier_key.vk,
+            None => return Ok(true),
+        };
+
+        let check_time = start_timer!(|| format!("Checking {} sets of linear combinations", checks.len()));
+        let mut combined_comms = Vec::new();
+        let mut combined_queries = Vec::new();
+        let mut combined_evals = Vec::new();
+        let mut proofs = Vec::new();
+        for check in checks {
+            let check_vk = &check.verifier_key.vk;
+            if (check_vk.g, check_vk.gamma_g, check_vk.h, check_vk.beta_h) != (vk.g, vk.gamma_g, vk.h, vk.beta_h) {
+                return Err(Error::IncompatibleVerifierKeys);
+            }
+
+            let (lc_commitments, evaluations) =
+                Self::combine_linear_combinations(check.linear_combinations, check.commitments, check.evaluations)?;
+            let (comms, queries, evals) = Self::combine_and_normalize(
+                &lc_commitments,
+                check.query_set,
+                &evaluations,
+                &check.opening_challenges,
+                check.verifier_key,
+            )?;
+            if check.proof.proof.len() != queries.len() {
+                eprintln!("Evaluation proof does not match the query set");
+                return Ok(false);
+            }
+
+            combined_comms.extend(comms);
+            combined_queries.extend(queries);
+            combined_evals.extend(evals);
+            proofs.extend(check.proof.proof.iter().cloned());
+        }
+
+        let proof_time = start_timer!(|| "Checking KZG10::Proof");
+        let result = kzg10::KZG10::batch_check(vk, &combined_comms, &combined_queries, &combined_evals, &proofs, rng)?;
+        end_timer!(proof_time);
+        end_timer!(check_time);
+        Ok(result)
+    }
 }
 
 impl<E: PairingEngine> MarlinKZG10<E> {
@@ -970,8 +881,13 @@ impl<E: PairingEngine> MarlinKZG10<E> {
     where
         <Self as PolynomialCommitment<E::Fr>>::Commitment: 'a,
     {
-        let (combined_comms, combined_queries, combined_evals) =
-            Self::combine_and_normalize(commitments, query_set, values, opening_challenges, vk)?;
+        let (combined_comms, combined_queries, combined_evals) = Self::combine_and_normalize(
+            commitments,
+            query_set,
+            values,
+            &OpeningChallenges::Individual(opening_challenges),
+            vk,
+        )?;
         assert_eq!(proof.len(), combined_queries.len());
         let proof_time = start_timer!(|| "Checking KZG10::Proof");
         let result =
@@ -1030,12 +946,77 @@ impl<E: PairingEngine> MarlinKZG10<E> {
         })
     }
 
+    /// Combines the commitments in each of `linear_combinations`, and subtracts the constant term
+    /// of each linear combination from its `evaluations`.
+    #[allow(clippy::type_complexity)]
+    fn combine_linear_combinations<'a>(
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        evaluations: &Evaluations<E::Fr>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Evaluations<'a, E::Fr>), Error> {
+        let label_comm_map = commitments
+            .into_iter()
+            .map(|c| (c.label(), c))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut lc_commitments = Vec::new();
+        let mut lc_info = Vec::new();
+        let mut evaluations = evaluations.clone();
+
+        let lc_processing_time = start_timer!(|| "Combining commitments");
+        for lc in linear_combinations {
+            let lc_label = lc.label().clone();
+            let num_polys = lc.len();
+
+            let mut degree_bound = None;
+            let mut coeffs_and_comms = Vec::new();
+
+            for (coeff, label) in lc.iter() {
+                if label.is_one() {
+                    for (&(ref label, _), ref mut eval) in evaluations.iter_mut() {
+                        if label == &lc_label {
+                            **eval -= coeff;
+                        }
+                    }
+                } else {
+                    let label: &String = label.try_into().unwrap();
+                    let &cur_comm = label_comm_map.get(label).ok_or(Error::MissingPolynomial {
+                        label: label.to_string(),
+                    })?;
+
+                    if num_polys == 1 && cur_comm.degree_bound().is_some() {
+                        assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
+                        degree_bound = cur_comm.degree_bound();
+                    } else if cur_comm.degree_bound().is_some() {
+                        return Err(Error::EquationHasDegreeBounds(lc_label));
+                    }
+                    coeffs_and_comms.push((*coeff, cur_comm.commitment()));
+                }
+            }
+            let lc_time = start_timer!(|| format!("Combining {} commitments for {}", num_polys, lc_label));
+            lc_commitments.push(Self::combine_commitments(coeffs_and_comms));
+            end_timer!(lc_time);
+            lc_info.push((lc_label, degree_bound));
+        }
+        end_timer!(lc_processing_time);
+        let combined_comms_norm_time = start_timer!(|| "Normalizing commitments");
+        let comms = Self::normalize_commitments(lc_commitments);
+        let lc_commitments = lc_info
+            .into_iter()
+            .zip(comms)
+            .map(|((label, d), c)| LabeledCommitment::new(label, c, d))
+            .collect::<Vec<_>>();
+        end_timer!(combined_comms_norm_time);
+
+        Ok((lc_commitments, evaluations))
+    }
+
     /// Combine and normalize a set of commitments
     fn combine_and_normalize<'a>(
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
         query_set: &QuerySet<E::Fr>,
         evaluations: &Evaluations<E::Fr>,
-        opening_challenges: &dyn Fn(u64) -> E::Fr,
+        opening_challenges: &OpeningChallenges<'_, E::Fr>,
         vk: &VerifierKey<E>,
     ) -> Result<(Vec<kzg10::Commitment<E>>, Vec<E::Fr>, Vec<E::Fr>), Error>
     where
@@ -1073,12 +1054,22 @@ impl<E: PairingEngine> MarlinKZG10<E> {
                 values_to_combine.push(*v_i);
             }
 
-            let (c, v) = Self::accumulate_commitments_and_values_individual_opening_challenges(
-                vk,
-                comms_to_combine,
-                values_to_combine,
-                opening_challenges,
-            )?;
+            let (c, v) = match opening_challenges {
+                OpeningChallenges::Single(opening_challenge) => Self::accumulate_commitments_and_values(
+                    vk,
+                    comms_to_combine,
+                    values_to_combine,
+                    *opening_challenge,
+                )?,
+                OpeningChallenges::Individual(opening_challenges) => {
+                    Self::accumulate_commitments_and_values_individual_opening_challenges(
+                        vk,
+                        comms_to_combine,
+                        values_to_combine,
+                        *opening_challenges,
+                    )?
+                }
+            };
             end_timer!(lc_time);
 
             combined_comms.push(c);
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A multi-party ceremony for the universal parameters of [`KZG10`](crate::kzg10::KZG10),
+//! following the powers-of-tau protocol of [[BGM17]][bgm17].
+//!
+//! The transcript is the first phase of the Groth16 ceremony in `snarkvm_algorithms`, whose powers of
+//! `τ` and `γ τ` are the powers of `β` and `γ β` in `kzg10::UniversalParams`. Each participant multiplies
+//! the secrets by fresh random factors, and appends a contribution that proves knowledge of those factors.
+//! The resulting parameters are secure as long as a single participant discards their factors.
+//!
+//! [bgm17]: https://eprint.iacr.org/2017/1050
+
+use crate::{kzg10::UniversalParams, BTreeMap};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine};
+
+pub use snarkvm_algorithms::snark::groth16::mpc::{KnowledgeProof, Phase1Contribution, PowersOfTau};
+
+impl<E: PairingEngine> From<&PowersOfTau<E>> for UniversalParams<E> {
+    /// Returns the universal parameters for KZG10 defined by the transcript.
+    /// The transcript should be verified first.
+    fn from(transcript: &PowersOfTau<E>) -> Self {
+        let h = E::G2Affine::prime_subgroup_generator();
+        let beta_h = transcript.powers_of_h[1];
+        Self {
+            powers_of_g: transcript.powers_of_g.clone(),
+            powers_of_gamma_g: transcript.powers_of_gamma_g.iter().copied().enumerate().collect(),
+            h,
+            beta_h,
+            prepared_neg_powers_of_h: transcript
+                .neg_powers_of_h
+                .iter()
+                .map(|power| power.prepare())
+                .enumerate()
+                .collect::<BTreeMap<_, _>>(),
+            prepared_h: h.prepare(),
+            prepared_beta_h: beta_h.prepare(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{kzg10::KZG10, PCUniversalParams, Polynomial};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::{
+        rand::{test_rng, UniformRand},
+        FromBytes,
+        ToBytes,
+    };
+
+    use core::sync::atomic::AtomicBool;
+
+    type Transcript = PowersOfTau<Bls12_377>;
+
+    #[test]
+    fn test_powers_of_tau_ceremony() {
+        let rng = &mut test_rng();
+        let max_degree = 16;
+
+        let mut transcript = Transcript::new(max_degree).unwrap();
+        transcript.verify(rng).unwrap();
+        for _ in 0..3 {
+            let previous = transcript.clone();
+            transcript.contribute(rng).unwrap();
+            transcript.verify_extends(&previous, rng).unwrap();
+        }
+
+        let transcript_bytes = transcript.to_bytes_le().unwrap();
+        assert_eq!(Transcript::read_le(&transcript_bytes[..]).unwrap(), transcript);
+
+        // The exported parameters can be used to commit to and open polynomials.
+        let pp = UniversalParams::from(&transcript);
+        assert_eq!(pp.max_degree(), max_degree);
+        let (ck, vk) = KZG10::trim(&pp, max_degree);
+        let p = Polynomial::rand(max_degree, rng);
+        let (comm, rand) = KZG10::<Bls12_377>::commit(&ck, &p, Some(1), &AtomicBool::new(false), Some(rng)).unwrap();
+        let point = Fr::rand(rng);
+        let proof = KZG10::<Bls12_377>::open(&ck, &p, point, &rand).unwrap();
+        assert!(KZG10::<Bls12_377>::check(&vk, &comm, point, p.evaluate(point), &proof).unwrap());
+    }
+}